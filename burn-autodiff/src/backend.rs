@@ -1,12 +1,38 @@
-use crate::{grads::Gradients, graph::backward::backward, tensor::ADTensor};
-use burn_tensor::backend::{ADBackend, Backend};
+use crate::{
+    grads::Gradients,
+    graph::{backward::backward, GraphStats},
+    tensor::ADTensor,
+};
+use burn_tensor::{
+    backend::{ADBackend, Backend},
+    Tensor,
+};
 
 /// A decorator for a backend that enables automatic differentiation.
+///
+/// # Notes
+///
+/// Higher order gradients can be computed by decorating an autodiff backend, e.g.
+/// `ADBackendDecorator<ADBackendDecorator<B>>`. The gradients computed by the outer backend are
+/// tensors of the inner one, which are tracked on its graph when computed from tensors requiring
+/// gradients, so a second backward pass can be run on them.
 #[derive(Clone, Copy, Debug, Default)]
 pub struct ADBackendDecorator<B> {
     _b: B,
 }
 
+impl<B: Backend> ADBackendDecorator<B> {
+    /// Get the [statistics](GraphStats) of the autodiff graph required to compute the
+    /// gradients of the given tensor.
+    ///
+    /// This is useful to debug memory usage, since every node of the graph keeps its backward
+    /// state alive until [backward](ADBackend::backward) is called.
+    pub fn graph_stats<const D: usize>(tensor: &Tensor<Self, D>) -> GraphStats {
+        let tensor = tensor.clone().into_primitive();
+        tensor.graph.stats(&tensor.node)
+    }
+}
+
 impl<B: Backend> Backend for ADBackendDecorator<B> {
     type Device = B::Device;
 
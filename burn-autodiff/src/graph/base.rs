@@ -1,9 +1,12 @@
 use spin::Mutex;
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use crate::grads::Gradients;
 
-use super::{NodeID, NodeRef};
+use super::{GraphStats, NodeID, NodeRef};
 
 /// Backward step for reverse mode autodiff.
 pub trait Step: Send + Sync + std::fmt::Debug {
@@ -11,6 +14,10 @@ pub trait Step: Send + Sync + std::fmt::Debug {
     fn step(self: Box<Self>, grads: &mut Gradients);
     /// The node associated to the step.
     fn node(&self) -> NodeRef;
+    /// Estimation of the number of bytes kept alive by the step until it is executed.
+    fn retained_bytes(&self) -> usize {
+        0
+    }
 }
 
 pub type StepBoxed = Box<dyn Step>;
@@ -19,9 +26,29 @@ pub type NodeSteps = HashMap<NodeID, StepBoxed>;
 /// Graph data structure.
 ///
 /// The graph contains the [node steps](Step), which can be access by [node id](NodeID).
+///
+/// # Notes
+///
+/// When two graphs are merged, the steps of one graph are moved into the other one, which the
+/// emptied graph then points to. This way, the tensors created before the merge still register
+/// their steps in the graph containing the steps of their parents.
 #[derive(Default, Clone, Debug)]
 pub struct Graph {
-    steps: Arc<Mutex<NodeSteps>>,
+    state: Arc<Mutex<GraphState>>,
+}
+
+#[derive(Debug)]
+enum GraphState {
+    /// The graph contains its steps.
+    Steps(NodeSteps),
+    /// The steps were moved into the given graph during a merge.
+    Merged(Graph),
+}
+
+impl Default for GraphState {
+    fn default() -> Self {
+        Self::Steps(NodeSteps::default())
+    }
 }
 
 impl Graph {
@@ -30,21 +57,32 @@ impl Graph {
         Self::default()
     }
 
-    /// Get all the steps for the graph.
+    /// Get the steps reachable from the given root node.
     ///
     /// # Notes
     ///
     /// This is a owned method, so the current graph will be freed. However, the steps can
-    /// be shared with other graphs, therefore they are going to be cleared.
+    /// be shared with other graphs, therefore they are going to be removed from the graph.
     ///
     /// This is usefull, since the graph is supposed to be consumed only once for backprop, and
     /// keeping all the tensors alive for multiple backward call is a heavy waste of ressources.
-    pub fn steps(self) -> NodeSteps {
-        let mut map_drain = HashMap::new();
+    /// The steps that aren't reachable from the root node are kept, since they may be used by
+    /// another backward pass.
+    pub fn steps(self, root: &NodeRef) -> NodeSteps {
         self.execute_mut(|map| {
-            std::mem::swap(&mut *map, &mut map_drain);
-        });
-        map_drain
+            let mut steps = HashMap::new();
+            let mut to_visit = vec![root.id.clone()];
+
+            while let Some(id) = to_visit.pop() {
+                if let Some(step) = map.remove(&id) {
+                    to_visit.extend(step.node().parents.iter().cloned());
+                    steps.insert(id, step);
+                }
+            }
+
+            steps
+        })
+        .1
     }
 
     /// Register a new step into the graph.
@@ -52,45 +90,124 @@ impl Graph {
         self.execute_mut(|map| {
             map.insert(id.clone(), ops);
         })
+        .0
     }
 
     /// Merge two graphs.
     pub fn merge(self, other: Self) -> Self {
-        if Arc::ptr_eq(&self.steps, &other.steps) {
-            return self;
-        }
+        let mut graph1 = self;
+        let mut graph2 = other;
 
-        self.merge_different(other)
-    }
+        loop {
+            graph1 = graph1.root();
+            graph2 = graph2.root();
 
-    fn execute_mut<F: FnOnce(&mut NodeSteps)>(mut self, func: F) -> Self {
-        match Arc::get_mut(&mut self.steps) {
-            Some(mutex) => {
-                let map = mutex.get_mut();
-                func(map);
+            if Arc::ptr_eq(&graph1.state, &graph2.state) {
+                return graph1;
             }
-            None => {
-                // Only lock when there are multiple references to the graph.
-                let mut map = self.steps.lock();
-                func(&mut map);
+
+            // Both graphs are locked in the same order by every thread to avoid deadlocks.
+            let (first, second) = match Arc::as_ptr(&graph1.state) < Arc::as_ptr(&graph2.state) {
+                true => (&graph1, &graph2),
+                false => (&graph2, &graph1),
+            };
+            let mut state1 = first.state.lock();
+            let mut state2 = second.state.lock();
+
+            let (map1, map2) = match (&mut *state1, &mut *state2) {
+                (GraphState::Steps(map1), GraphState::Steps(map2)) => (map1, map2),
+                // Another thread merged one of the graphs in the meantime.
+                _ => continue,
+            };
+
+            // The steps of the smallest graph are moved into the biggest one.
+            if map1.len() >= map2.len() {
+                map1.extend(core::mem::take(map2));
+                *state2 = GraphState::Merged(first.clone());
+
+                return first.clone();
+            } else {
+                map2.extend(core::mem::take(map1));
+                *state1 = GraphState::Merged(second.clone());
+
+                return second.clone();
             }
-        };
+        }
+    }
+
+    /// Collect the [statistics](GraphStats) of the steps reachable from the given root node.
+    ///
+    /// # Notes
+    ///
+    /// Contrary to [steps](Graph::steps), the graph isn't consumed, so it can still be used for
+    /// backprop afterward.
+    pub fn stats(&self, root: &NodeRef) -> GraphStats {
+        self.clone()
+            .execute_mut(|steps| {
+                let mut stats = GraphStats::default();
+                let mut visited = HashSet::new();
+                let mut to_visit = vec![root.id.clone()];
 
-        self
+                while let Some(id) = to_visit.pop() {
+                    if !visited.insert(id.clone()) {
+                        continue;
+                    }
+
+                    let step = match steps.get(&id) {
+                        Some(step) => step,
+                        None => continue,
+                    };
+
+                    stats.num_nodes += 1;
+                    stats.retained_bytes += step.retained_bytes();
+
+                    for parent in step.node().parents.iter() {
+                        if !visited.contains(parent) {
+                            to_visit.push(parent.clone());
+                        }
+                    }
+                }
+
+                stats
+            })
+            .1
     }
 
-    fn merge_different(self, other: Self) -> Self {
-        let mut map2 = other.steps();
+    /// Follow the merged graphs until the one containing the steps.
+    fn root(self) -> Self {
+        let mut graph = self;
 
-        self.execute_mut(|map1| {
-            if map1.len() > map2.len() {
-                map1.extend(map2.into_iter());
-            } else {
-                let mut map_drain = HashMap::new();
-                std::mem::swap(map1, &mut map_drain);
-                map2.extend(map_drain.into_iter());
-                std::mem::swap(map1, &mut map2);
+        loop {
+            let next = match &*graph.state.lock() {
+                GraphState::Steps(_) => None,
+                GraphState::Merged(next) => Some(next.clone()),
+            };
+
+            match next {
+                Some(next) => graph = next,
+                None => return graph,
             }
-        })
+        }
+    }
+
+    /// Execute the function on the steps, returning the graph containing them.
+    fn execute_mut<T, F: FnOnce(&mut NodeSteps) -> T>(self, func: F) -> (Self, T) {
+        let mut func = Some(func);
+        let mut graph = self;
+
+        loop {
+            graph = graph.root();
+
+            // The graph may have been merged since its root was found, in which case the new
+            // root is searched again.
+            let output = match &mut *graph.state.lock() {
+                GraphState::Steps(map) => Some(func.take().unwrap()(map)),
+                GraphState::Merged(_) => None,
+            };
+
+            if let Some(output) = output {
+                return (graph, output);
+            }
+        }
     }
 }
@@ -1,6 +1,7 @@
 mod base;
 mod node;
 mod requirement;
+mod stats;
 
 pub mod backward;
 pub mod traversal;
@@ -8,3 +9,4 @@ pub mod traversal;
 pub use base::*;
 pub use node::*;
 pub use requirement::*;
+pub use stats::*;
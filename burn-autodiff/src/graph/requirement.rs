@@ -1,4 +1,5 @@
 use super::NodeRef;
+use crate::no_grad::is_grad_enabled;
 
 /// Requirement for each tensor in the graph.
 #[derive(Debug, Clone, Copy)]
@@ -17,7 +18,14 @@ impl Requirement {
         matches!(self, Self::None)
     }
     /// Returns the right requirement from a list of nodes.
+    ///
+    /// Nothing is required inside a [no_grad](crate::no_grad) scope, so the operations aren't
+    /// tracked.
     pub fn from_nodes(nodes: &[NodeRef]) -> Self {
+        if !is_grad_enabled() {
+            return Requirement::None;
+        }
+
         nodes
             .iter()
             .map(|node| node.requirement)
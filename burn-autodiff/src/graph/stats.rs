@@ -0,0 +1,12 @@
+/// Statistics about the autodiff graph attached to a tensor.
+///
+/// They can be used to find which operations keep large states alive until the backward pass.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GraphStats {
+    /// The number of nodes with a registered backward step, including the tracked leaves.
+    pub num_nodes: usize,
+    /// An estimation of the number of bytes retained by the backward steps.
+    ///
+    /// Each stateful operation is assumed to keep a tensor of the same size as its output.
+    pub retained_bytes: usize,
+}
@@ -15,7 +15,7 @@ impl BreadthFirstSearch {
     ) {
         let mut visited = HashSet::with_capacity(root.order);
         let mut parents = Vec::with_capacity(root.order);
-        let mut steps = graph.steps();
+        let mut steps = graph.steps(&root);
         let root_step = steps
             .remove(&root.id)
             .expect("Root node should have a step registered, did you forget to call `Tensor::register_grad` on the tensor where you need gradients?");
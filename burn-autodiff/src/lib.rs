@@ -17,7 +17,10 @@ pub(crate) mod tensor;
 pub(crate) mod utils;
 
 mod backend;
+mod no_grad;
 pub use backend::*;
+pub use graph::GraphStats;
+pub use no_grad::no_grad;
 
 #[cfg(feature = "export_tests")]
 mod tests;
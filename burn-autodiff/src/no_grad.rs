@@ -0,0 +1,46 @@
+use std::cell::Cell;
+
+std::thread_local! {
+    static GRAD_ENABLED: Cell<bool> = const { Cell::new(true) };
+}
+
+/// Executes the closure without tracking the operations in the autodiff graph.
+///
+/// The tensors created inside the closure don't require gradients, even when computed from
+/// tensors that do, which avoids building the graph for inference. Calls can be nested, and the
+/// previous state is restored when the closure returns or panics.
+///
+/// # Notes
+///
+/// The state is local to the current thread.
+pub fn no_grad<F, R>(func: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    let _guard = GradModeGuard::new(false);
+    func()
+}
+
+/// Returns whether the operations are currently tracked in the autodiff graph.
+pub(crate) fn is_grad_enabled() -> bool {
+    GRAD_ENABLED.with(|enabled| enabled.get())
+}
+
+/// Sets the grad mode and restores the previous one when dropped.
+struct GradModeGuard {
+    previous: bool,
+}
+
+impl GradModeGuard {
+    fn new(enabled: bool) -> Self {
+        let previous = GRAD_ENABLED.with(|state| state.replace(enabled));
+
+        Self { previous }
+    }
+}
+
+impl Drop for GradModeGuard {
+    fn drop(&mut self) {
+        GRAD_ENABLED.with(|state| state.set(self.previous));
+    }
+}
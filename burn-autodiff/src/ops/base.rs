@@ -100,8 +100,12 @@ where
         );
         let parents = self.nodes.map(|node| node.clone_if_require_grad());
         let ops = Ops::new(parents, output.node.clone(), state);
+        let retained_bytes = match core::mem::size_of::<S>() {
+            0 => 0,
+            _ => B::shape(&output.primitive).num_elements() * core::mem::size_of::<B::FloatElem>(),
+        };
 
-        output.register_step(OpsStep::new(ops, self.backward))
+        output.register_step(OpsStep::new(ops, self.backward, retained_bytes))
     }
 }
 
@@ -129,6 +133,7 @@ where
 {
     ops: Ops<SB, N>,
     backward: T,
+    retained_bytes: usize,
     phantom: PhantomData<B>,
 }
 
@@ -145,4 +150,8 @@ where
     fn node(&self) -> NodeRef {
         self.ops.node.clone()
     }
+
+    fn retained_bytes(&self) -> usize {
+        self.retained_bytes
+    }
 }
@@ -0,0 +1,51 @@
+use super::{unary, Backward, Ops, OpsKind};
+use crate::{
+    grads::Gradients,
+    tensor::{ADTensor, FloatElem},
+};
+use burn_tensor::{backend::Backend, ElementConversion};
+
+/// Clamps a tensor, the gradient being zero where the input falls outside of the bounds.
+#[derive(Debug)]
+pub(crate) struct Clamp;
+
+impl Clamp {
+    /// Prepare the operation, the bounds being inclusive so the gradient still flows to the
+    /// elements exactly on them.
+    pub(crate) fn prepare_clamp<B: Backend, const D: usize>(
+        tensor: ADTensor<B, D>,
+        min: Option<FloatElem<B>>,
+        max: Option<FloatElem<B>>,
+        output: B::TensorPrimitive<D>,
+    ) -> ADTensor<B, D> {
+        match Clamp.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => prep.finish((tensor.primitive, min, max), output),
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        }
+    }
+}
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for Clamp {
+    type State = (
+        B::TensorPrimitive<D>,
+        Option<B::FloatElem>,
+        Option<B::FloatElem>,
+    );
+
+    fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+        let (tensor, min, max) = ops.state;
+
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |mut grad| {
+            if let Some(min) = min {
+                let mask = B::lower_elem(tensor.clone(), min);
+                grad = B::mask_fill(grad, mask, 0.elem());
+            }
+            if let Some(max) = max {
+                let mask = B::greater_elem(tensor, max);
+                grad = B::mask_fill(grad, mask, 0.elem());
+            }
+
+            grad
+        });
+    }
+}
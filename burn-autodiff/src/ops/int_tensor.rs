@@ -1,9 +1,9 @@
 use crate::{
-    tensor::{BoolTensor, IntTensor},
+    tensor::{ADTensor, BoolTensor, IntTensor},
     ADBackendDecorator,
 };
 
-use burn_tensor::{backend::Backend, ops::IntTensorOps, Data, Shape};
+use burn_tensor::{backend::Backend, ops::IntTensorOps, Data, Distribution, Shape};
 
 impl<B: Backend> IntTensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
     fn int_from_data<const D: usize>(
@@ -13,6 +13,14 @@ impl<B: Backend> IntTensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         B::int_from_data(data, device)
     }
 
+    fn int_random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution<B::IntElem>,
+        device: &B::Device,
+    ) -> IntTensor<B, D> {
+        B::int_random(shape, distribution, device)
+    }
+
     fn int_shape<const D: usize>(tensor: &IntTensor<B, D>) -> Shape<D> {
         B::int_shape(tensor)
     }
@@ -32,6 +40,10 @@ impl<B: Backend> IntTensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         B::int_to_device(tensor, device)
     }
 
+    fn int_into_float<const D: usize>(tensor: IntTensor<B, D>) -> ADTensor<B, D> {
+        ADTensor::new(B::int_into_float(tensor))
+    }
+
     fn int_device<const D: usize>(tensor: &IntTensor<B, D>) -> B::Device {
         B::int_device(tensor)
     }
@@ -1,20 +1,75 @@
-use super::{unary, Backward, Ops};
-use crate::grads::Gradients;
-use burn_tensor::{backend::Backend, Shape};
+use super::{binary, tensor::broadcast_shape, unary, Backward, Ops, OpsKind};
+use crate::{
+    grads::Gradients,
+    tensor::{ADTensor, BoolTensor},
+    utils::duplicate,
+};
+use burn_tensor::{backend::Backend, ElementConversion, Shape};
 
+/// Selection of elements along a dimension (max, min, top-k or sort), the gradient flowing back to
+/// the selected positions.
 #[derive(Debug)]
 pub(crate) struct MaxMinDim;
 
 impl<B: Backend, const D: usize> Backward<B, D, 1> for MaxMinDim {
-    type State = (B::IntTensorPrimitive<D>, Shape<D>);
+    type State = (B::IntTensorPrimitive<D>, Shape<D>, usize);
 
     fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
         unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
-            let (indices, shape) = ops.state;
+            let (indices, shape, dim) = ops.state;
             let device = B::device(&grad);
             let zeros = B::zeros(shape, &device);
 
-            B::scatter(D - 1, zeros, indices, grad)
+            B::scatter(dim, zeros, indices, grad)
         });
     }
 }
+
+/// Element-wise maximum or minimum of two tensors, the gradient flowing to the selected side.
+#[derive(Debug)]
+pub(crate) struct MaxMinPair;
+
+impl MaxMinPair {
+    /// Prepare the operation, the mask being true where the right hand side is selected.
+    pub(crate) fn prepare_pair<B: Backend, const D: usize>(
+        lhs: ADTensor<B, D>,
+        rhs: ADTensor<B, D>,
+        mask: BoolTensor<B, D>,
+        output: B::TensorPrimitive<D>,
+    ) -> ADTensor<B, D> {
+        match MaxMinPair
+            .prepare([lhs.node, rhs.node], [lhs.graph, rhs.graph])
+            .statefull()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                (mask, B::shape(&lhs.primitive), B::shape(&rhs.primitive)),
+                output,
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        }
+    }
+}
+
+impl<B: Backend, const D: usize> Backward<B, D, 2> for MaxMinPair {
+    type State = (BoolTensor<B, D>, Shape<D>, Shape<D>);
+
+    fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
+        let (mask, shape_lhs, shape_rhs) = ops.state;
+        let [mask_4lhs, mask_4rhs] = duplicate(&ops.parents, Some(mask));
+
+        binary::<B, D, D, D, _, _>(
+            ops.parents,
+            ops.node,
+            grads,
+            |grad| {
+                let grad = B::mask_fill(grad, mask_4lhs.unwrap(), 0.elem());
+                broadcast_shape::<B, D>(grad, shape_lhs)
+            },
+            |grad| {
+                let zeros = B::zeros(B::shape(&grad), &B::device(&grad));
+                let grad = B::mask_where(zeros, mask_4rhs.unwrap(), grad);
+                broadcast_shape::<B, D>(grad, shape_rhs)
+            },
+        );
+    }
+}
@@ -6,7 +6,9 @@ mod int_tensor;
 mod module;
 mod tensor;
 
+pub(crate) mod clamp;
 pub(crate) mod maxmin;
+pub(crate) mod scatter;
 
 pub use backward::*;
 pub use base::*;
@@ -5,6 +5,7 @@ use crate::ADBackendDecorator;
 
 use burn_tensor::backend::Backend;
 use burn_tensor::ops::*;
+use burn_tensor::Shape;
 
 use super::OpsKind;
 
@@ -42,8 +43,28 @@ impl<B: Backend> ModuleOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         output: ADTensor<B, 3>,
         indices: IntTensor<B, 2>,
     ) -> ADTensor<B, 2> {
-        let tensor = B::embedding_backward(weights.primitive, output.primitive, indices);
-        ADTensor::new(tensor)
+        #[derive(Debug)]
+        struct EmbeddingBackward;
+
+        impl<B: Backend> Backward<B, 2, 1> for EmbeddingBackward {
+            type State = IntTensor<B, 2>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, 2, 3, _>(ops.parents, ops.node, grads, |grad| {
+                    B::embedding(grad, ops.state)
+                });
+            }
+        }
+
+        let tensor = B::embedding_backward(weights.primitive, output.primitive, indices.clone());
+
+        match EmbeddingBackward
+            .prepare([output.node], [output.graph])
+            .statefull()
+        {
+            OpsKind::Tracked(prep) => prep.finish(indices, tensor),
+            OpsKind::UnTracked(prep) => prep.finish(tensor),
+        }
     }
 
     fn conv2d(
@@ -305,6 +326,184 @@ impl<B: Backend> ModuleOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         }
     }
 
+    fn max_pool1d(
+        x: ADTensor<B, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+    ) -> ADTensor<B, 3> {
+        match MaxPool1D.prepare([x.node], [x.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                let output =
+                    B::max_pool1d_with_indices(x.primitive.clone(), kernel_size, stride, padding);
+                prep.finish(
+                    (x.primitive, output.indices, kernel_size, stride, padding),
+                    output.output,
+                )
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::max_pool1d(x.primitive, kernel_size, stride, padding))
+            }
+        }
+    }
+
+    fn max_pool1d_with_indices(
+        x: ADTensor<B, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+    ) -> MaxPool1dWithIndices<ADBackendDecorator<B>> {
+        match MaxPool1D.prepare([x.node], [x.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                let output =
+                    B::max_pool1d_with_indices(x.primitive.clone(), kernel_size, stride, padding);
+
+                let output_tensor = prep.finish(
+                    (
+                        x.primitive,
+                        output.indices.clone(),
+                        kernel_size,
+                        stride,
+                        padding,
+                    ),
+                    output.output,
+                );
+
+                MaxPool1dWithIndices::new(output_tensor, output.indices)
+            }
+            OpsKind::UnTracked(prep) => {
+                let output = B::max_pool1d_with_indices(x.primitive, kernel_size, stride, padding);
+                let output_tensor = prep.finish(output.output);
+
+                MaxPool1dWithIndices::new(output_tensor, output.indices)
+            }
+        }
+    }
+
+    fn max_pool1d_with_indices_backward(
+        x: ADTensor<B, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        output_grad: ADTensor<B, 3>,
+        indices: IntTensor<B, 3>,
+    ) -> MaxPool1dBackward<ADBackendDecorator<B>> {
+        #[derive(Debug)]
+        struct MaxPool1DWithIndicesBackward;
+
+        impl<B: Backend> Backward<B, 3, 1> for MaxPool1DWithIndicesBackward {
+            type State = IntTensor<B, 3>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let indices = ops.state;
+
+                unary::<B, 3, 3, _>(ops.parents, ops.node, grads, |grad| {
+                    B::gather(2, grad, indices)
+                });
+            }
+        }
+
+        let output = B::max_pool1d_with_indices_backward(
+            x.primitive,
+            kernel_size,
+            stride,
+            padding,
+            output_grad.primitive,
+            indices.clone(),
+        );
+
+        let x_grad = match MaxPool1DWithIndicesBackward
+            .prepare([output_grad.node], [output_grad.graph])
+            .statefull()
+        {
+            OpsKind::Tracked(prep) => prep.finish(indices, output.x_grad),
+            OpsKind::UnTracked(prep) => prep.finish(output.x_grad),
+        };
+
+        MaxPool1dBackward::new(x_grad)
+    }
+
+    fn interpolate1d(
+        x: ADTensor<B, 3>,
+        output_size: usize,
+        mode: InterpolateMode,
+    ) -> ADTensor<B, 3> {
+        #[derive(Debug)]
+        struct Interpolate1D;
+
+        impl<B: Backend> Backward<B, 3, 1> for Interpolate1D {
+            type State = (B::TensorPrimitive<3>, InterpolateMode);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let [node_parent] = ops.parents;
+                let grad = grads.consume::<B, 3>(&ops.node);
+                let (x, mode) = ops.state;
+
+                if let Some(node) = node_parent {
+                    let grad = B::interpolate1d_backward(x, grad, mode);
+                    grads.register::<B, 3>(node, grad);
+                }
+            }
+        }
+
+        match Interpolate1D.prepare([x.node], [x.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                let output = B::interpolate1d(x.primitive.clone(), output_size, mode);
+                prep.finish((x.primitive, mode), output)
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::interpolate1d(x.primitive, output_size, mode))
+            }
+        }
+    }
+
+    fn grid_sample(
+        x: ADTensor<B, 4>,
+        grid: ADTensor<B, 4>,
+        mode: GridSampleMode,
+        padding: GridSamplePadding,
+    ) -> ADTensor<B, 4> {
+        #[derive(Debug)]
+        struct GridSample;
+
+        impl<B: Backend> Backward<B, 4, 2> for GridSample {
+            type State = (
+                B::TensorPrimitive<4>,
+                B::TensorPrimitive<4>,
+                GridSampleMode,
+                GridSamplePadding,
+            );
+
+            fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
+                let [node_x, node_grid] = ops.parents;
+                let grad = grads.consume::<B, 4>(&ops.node);
+
+                let (x, grid, mode, padding) = ops.state;
+                let backward = B::grid_sample_backward(x, grid, grad, mode, padding);
+
+                if let Some(node) = node_x {
+                    grads.register::<B, 4>(node, backward.x_grad)
+                }
+                if let Some(node) = node_grid {
+                    grads.register::<B, 4>(node, backward.grid_grad)
+                }
+            }
+        }
+
+        match GridSample
+            .prepare([x.node, grid.node], [x.graph, grid.graph])
+            .statefull()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                (x.primitive.clone(), grid.primitive.clone(), mode, padding),
+                B::grid_sample(x.primitive, grid.primitive, mode, padding),
+            ),
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::grid_sample(x.primitive, grid.primitive, mode, padding))
+            }
+        }
+    }
+
     fn avg_pool2d(
         x: ADTensor<B, 4>,
         kernel_size: [usize; 2],
@@ -346,9 +545,88 @@ impl<B: Backend> ModuleOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         stride: [usize; 2],
         padding: [usize; 2],
     ) -> ADTensor<B, 4> {
+        #[derive(Debug)]
+        struct AvgPool2DBackward;
+
+        impl<B: Backend> Backward<B, 4, 1> for AvgPool2DBackward {
+            type State = ([usize; 2], [usize; 2], [usize; 2]);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (kernel_size, stride, padding) = ops.state;
+
+                unary::<B, 4, 4, _>(ops.parents, ops.node, grads, |grad| {
+                    B::avg_pool2d(grad, kernel_size, stride, padding)
+                });
+            }
+        }
+
         let tensor =
             B::avg_pool2d_backward(x.primitive, grad.primitive, kernel_size, stride, padding);
-        ADTensor::new(tensor)
+
+        match AvgPool2DBackward
+            .prepare([grad.node], [grad.graph])
+            .statefull()
+        {
+            OpsKind::Tracked(prep) => prep.finish((kernel_size, stride, padding), tensor),
+            OpsKind::UnTracked(prep) => prep.finish(tensor),
+        }
+    }
+
+    fn adaptive_avg_pool2d(x: ADTensor<B, 4>, output_size: [usize; 2]) -> ADTensor<B, 4> {
+        #[derive(Debug)]
+        struct AdaptiveAvgPool2D;
+
+        impl<B: Backend> Backward<B, 4, 1> for AdaptiveAvgPool2D {
+            type State = B::TensorPrimitive<4>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let [node_parent] = ops.parents;
+                let grad = grads.consume::<B, 4>(&ops.node);
+
+                if let Some(node) = node_parent {
+                    let grad = B::adaptive_avg_pool2d_backward(ops.state, grad);
+                    grads.register::<B, 4>(node, grad);
+                }
+            }
+        }
+
+        match AdaptiveAvgPool2D.prepare([x.node], [x.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                let output = B::adaptive_avg_pool2d(x.primitive.clone(), output_size);
+                prep.finish(x.primitive, output)
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::adaptive_avg_pool2d(x.primitive, output_size))
+            }
+        }
+    }
+
+    fn adaptive_avg_pool2d_backward(x: ADTensor<B, 4>, grad: ADTensor<B, 4>) -> ADTensor<B, 4> {
+        #[derive(Debug)]
+        struct AdaptiveAvgPool2DBackward;
+
+        impl<B: Backend> Backward<B, 4, 1> for AdaptiveAvgPool2DBackward {
+            type State = [usize; 2];
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let output_size = ops.state;
+
+                unary::<B, 4, 4, _>(ops.parents, ops.node, grads, |grad| {
+                    B::adaptive_avg_pool2d(grad, output_size)
+                });
+            }
+        }
+
+        let [_, _, height_out, width_out] = B::shape(&grad.primitive).dims;
+        let tensor = B::adaptive_avg_pool2d_backward(x.primitive, grad.primitive);
+
+        match AdaptiveAvgPool2DBackward
+            .prepare([grad.node], [grad.graph])
+            .statefull()
+        {
+            OpsKind::Tracked(prep) => prep.finish([height_out, width_out], tensor),
+            OpsKind::UnTracked(prep) => prep.finish(tensor),
+        }
     }
 
     fn max_pool2d(
@@ -413,15 +691,73 @@ impl<B: Backend> ModuleOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         output_grad: ADTensor<B, 4>,
         indices: IntTensor<B, 4>,
     ) -> MaxPool2dBackward<ADBackendDecorator<B>> {
+        #[derive(Debug)]
+        struct MaxPool2DWithIndicesBackward;
+
+        impl<B: Backend> Backward<B, 4, 1> for MaxPool2DWithIndicesBackward {
+            type State = IntTensor<B, 4>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let indices = ops.state;
+
+                unary::<B, 4, 4, _>(ops.parents, ops.node, grads, |grad| {
+                    // The indices are flattened over the height and width of the input.
+                    let [batch_size, channels, height, width] = B::shape(&grad).dims;
+                    let [_, _, height_out, width_out] = B::int_shape(&indices).dims;
+
+                    let grad = B::reshape(grad, Shape::new([batch_size, channels, height * width]));
+                    let indices = B::int_reshape(
+                        indices,
+                        Shape::new([batch_size, channels, height_out * width_out]),
+                    );
+                    let grad = B::gather(2, grad, indices);
+
+                    B::reshape(
+                        grad,
+                        Shape::new([batch_size, channels, height_out, width_out]),
+                    )
+                });
+            }
+        }
+
         let output = B::max_pool2d_with_indices_backward(
             x.primitive,
             kernel_size,
             stride,
             padding,
             output_grad.primitive,
-            indices,
+            indices.clone(),
         );
-        MaxPool2dBackward::new(ADTensor::new(output.x_grad))
+
+        let x_grad = match MaxPool2DWithIndicesBackward
+            .prepare([output_grad.node], [output_grad.graph])
+            .statefull()
+        {
+            OpsKind::Tracked(prep) => prep.finish(indices, output.x_grad),
+            OpsKind::UnTracked(prep) => prep.finish(output.x_grad),
+        };
+
+        MaxPool2dBackward::new(x_grad)
+    }
+}
+
+#[derive(Debug)]
+struct MaxPool1D;
+
+impl<B: Backend> Backward<B, 3, 1> for MaxPool1D {
+    type State = (B::TensorPrimitive<3>, IntTensor<B, 3>, usize, usize, usize);
+
+    fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+        let [node_parent] = ops.parents;
+        let grad = grads.consume::<B, 3>(&ops.node);
+        let (x, indices, kernel_size, stride, padding) = ops.state;
+
+        if let Some(node) = node_parent {
+            let grad =
+                B::max_pool1d_with_indices_backward(x, kernel_size, stride, padding, grad, indices);
+
+            grads.register::<B, 3>(node, grad.x_grad);
+        }
     }
 }
 
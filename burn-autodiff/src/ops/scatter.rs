@@ -0,0 +1,58 @@
+use super::{binary, Backward, Ops};
+use crate::grads::Gradients;
+use crate::utils::duplicate;
+use burn_tensor::{backend::Backend, Data, ElementConversion, Shape};
+
+/// Backward of the [scatter max](burn_tensor::ops::TensorOps::scatter_max) and
+/// [scatter min](burn_tensor::ops::TensorOps::scatter_min) operations.
+///
+/// The gradient of each output position flows only to the element that was kept, which is
+/// given by the indices returned by the forward pass.
+#[derive(Debug)]
+pub(crate) struct ScatterMaxMin;
+
+impl<B: Backend, const D: usize> Backward<B, D, 2> for ScatterMaxMin {
+    type State = (
+        usize,
+        B::IntTensorPrimitive<D>,
+        B::IntTensorPrimitive<D>,
+        Shape<D>,
+    );
+
+    fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
+        let (dim, indices, sources, shape_rhs) = ops.state;
+        let [sources_4lhs, sources_4rhs] = duplicate(&ops.parents, Some(sources));
+
+        binary::<B, D, D, D, _, _>(
+            ops.parents,
+            ops.node,
+            grads,
+            |grad| {
+                let device = B::device(&grad);
+                let zeros = B::zeros(B::shape(&grad), &device);
+                let kept = B::int_equal_elem(sources_4lhs.unwrap(), (-1).elem());
+
+                B::mask_where(zeros, kept, grad)
+            },
+            |grad| {
+                let device = B::device(&grad);
+                let zeros = B::zeros(shape_rhs.clone(), &device);
+                let positions = B::int_from_data(positions(&shape_rhs, dim).convert(), &device);
+                let sources = B::int_gather(dim, sources_4rhs.unwrap(), indices.clone());
+                let kept = B::int_equal(sources, positions);
+
+                B::mask_where(zeros, kept, B::gather(dim, grad, indices))
+            },
+        );
+    }
+}
+
+/// Index of each element along the given dimension.
+fn positions<const D: usize>(shape: &Shape<D>, dim: usize) -> Data<i64, D> {
+    let size: usize = shape.dims[dim + 1..].iter().product();
+    let values = (0..shape.num_elements())
+        .map(|i| ((i / size) % shape.dims[dim]) as i64)
+        .collect();
+
+    Data::new(values, shape.clone())
+}
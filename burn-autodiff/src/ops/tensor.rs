@@ -9,9 +9,15 @@ use crate::{
     ADBackendDecorator,
 };
 
-use burn_tensor::{backend::Backend, ops::TensorOps, Data, ElementConversion, Shape, Tensor};
+use burn_tensor::{
+    backend::Backend,
+    ops::{TensorOps, QUANTIZATION_MAX, QUANTIZATION_MIN},
+    Data, ElementConversion, Shape, Tensor,
+};
 
-use super::maxmin::MaxMinDim;
+use super::clamp::Clamp;
+use super::maxmin::{MaxMinDim, MaxMinPair};
+use super::scatter::ScatterMaxMin;
 
 impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
     fn from_data<const D: usize>(
@@ -398,6 +404,56 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         }
     }
 
+    fn permute<const D: usize>(tensor: ADTensor<B, D>, axes: [usize; D]) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Permute;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Permute {
+            type State = [usize; D];
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let axes = ops.state;
+                let mut inverse = [0; D];
+                axes.iter()
+                    .enumerate()
+                    .for_each(|(i, axis)| inverse[*axis] = i);
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::permute(grad, inverse)
+                });
+            }
+        }
+
+        let output = B::permute(tensor.primitive, axes);
+
+        match Permute.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => prep.finish(axes, output),
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        }
+    }
+
+    fn flip<const D: usize>(tensor: ADTensor<B, D>, axes: &[usize]) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Flip;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Flip {
+            type State = Vec<usize>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let axes = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| B::flip(grad, &axes));
+            }
+        }
+
+        let output = B::flip(tensor.primitive, axes);
+
+        match Flip.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => prep.finish(axes.to_vec(), output),
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        }
+    }
+
     fn reshape<const D1: usize, const D2: usize>(
         tensor: ADTensor<B, D1>,
         shape: Shape<D2>,
@@ -525,6 +581,189 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         }
     }
 
+    fn scatter_max<const D: usize>(
+        dim: usize,
+        tensor: ADTensor<B, D>,
+        indices: IntTensor<B, D>,
+        value: ADTensor<B, D>,
+    ) -> ADTensor<B, D> {
+        match ScatterMaxMin
+            .prepare([tensor.node, value.node], [tensor.graph, value.graph])
+            .statefull()
+        {
+            OpsKind::Tracked(prep) => {
+                let shape = B::shape(&value.primitive);
+                let (output, sources) = B::scatter_max_with_indices(
+                    dim,
+                    tensor.primitive,
+                    indices.clone(),
+                    value.primitive,
+                );
+                prep.finish((dim, indices, sources, shape), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::scatter_max(
+                dim,
+                tensor.primitive,
+                indices,
+                value.primitive,
+            )),
+        }
+    }
+
+    fn scatter_min<const D: usize>(
+        dim: usize,
+        tensor: ADTensor<B, D>,
+        indices: IntTensor<B, D>,
+        value: ADTensor<B, D>,
+    ) -> ADTensor<B, D> {
+        match ScatterMaxMin
+            .prepare([tensor.node, value.node], [tensor.graph, value.graph])
+            .statefull()
+        {
+            OpsKind::Tracked(prep) => {
+                let shape = B::shape(&value.primitive);
+                let (output, sources) = B::scatter_min_with_indices(
+                    dim,
+                    tensor.primitive,
+                    indices.clone(),
+                    value.primitive,
+                );
+                prep.finish((dim, indices, sources, shape), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::scatter_min(
+                dim,
+                tensor.primitive,
+                indices,
+                value.primitive,
+            )),
+        }
+    }
+
+    fn quantize_per_channel<const D: usize>(
+        tensor: ADTensor<B, D>,
+        scale: ADTensor<B, 1>,
+        zero_point: IntTensor<B, 1>,
+        axis: usize,
+    ) -> IntTensor<B, D> {
+        B::quantize_per_channel(tensor.primitive, scale.primitive, zero_point, axis)
+    }
+
+    fn dequantize_per_channel<const D: usize>(
+        tensor: IntTensor<B, D>,
+        scale: ADTensor<B, 1>,
+        zero_point: IntTensor<B, 1>,
+        axis: usize,
+    ) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct DequantizePerChannel;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for DequantizePerChannel {
+            type State = (B::TensorPrimitive<D>, usize);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (centered, axis) = ops.state;
+
+                unary::<B, D, 1, _>(ops.parents, ops.node, grads, |grad| {
+                    // The scale of a channel multiplies all of its elements, so its gradient is
+                    // summed over every other axis.
+                    let grad = B::mul(grad, centered);
+                    let channels = B::shape(&grad).dims[axis];
+                    let grad = (0..D)
+                        .filter(|dim| *dim != axis)
+                        .fold(grad, |grad, dim| B::sum_dim(grad, dim));
+
+                    B::reshape(grad, Shape::new([channels]))
+                });
+            }
+        }
+
+        match DequantizePerChannel
+            .prepare([scale.node], [scale.graph])
+            .statefull()
+        {
+            OpsKind::Tracked(prep) => {
+                // The quantized values shifted by the zero point, which is the dequantized tensor
+                // with a scale of one.
+                let channels = B::shape(&scale.primitive).dims[0];
+                let ones = B::ones(Shape::new([channels]), &B::device(&scale.primitive));
+                let centered =
+                    B::dequantize_per_channel(tensor.clone(), ones, zero_point.clone(), axis);
+
+                prep.finish(
+                    (centered, axis),
+                    B::dequantize_per_channel(tensor, scale.primitive, zero_point, axis),
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::dequantize_per_channel(
+                tensor,
+                scale.primitive,
+                zero_point,
+                axis,
+            )),
+        }
+    }
+
+    fn fake_quantize_per_channel<const D: usize>(
+        tensor: ADTensor<B, D>,
+        scale: ADTensor<B, 1>,
+        zero_point: IntTensor<B, 1>,
+        axis: usize,
+    ) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct FakeQuantizePerChannel;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for FakeQuantizePerChannel {
+            type State = (BoolTensor<B, D>, BoolTensor<B, D>);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (mask_lower, mask_greater) = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let grad = B::mask_fill(grad, mask_lower, 0.elem());
+                    B::mask_fill(grad, mask_greater, 0.elem())
+                });
+            }
+        }
+
+        match FakeQuantizePerChannel
+            .prepare([tensor.node], [tensor.graph])
+            .statefull()
+        {
+            OpsKind::Tracked(prep) => {
+                // The gradient is only passed through for the elements that aren't clamped.
+                let shape = B::shape(&tensor.primitive);
+                let device = B::device(&tensor.primitive);
+                let bound = |value: i64| {
+                    let tensor = B::int_full(shape.clone(), value.elem(), &device);
+                    B::dequantize_per_channel(
+                        tensor,
+                        scale.primitive.clone(),
+                        zero_point.clone(),
+                        axis,
+                    )
+                };
+                let mask_lower = B::lower(tensor.primitive.clone(), bound(QUANTIZATION_MIN));
+                let mask_greater = B::greater(tensor.primitive.clone(), bound(QUANTIZATION_MAX));
+
+                prep.finish(
+                    (mask_lower, mask_greater),
+                    B::fake_quantize_per_channel(
+                        tensor.primitive,
+                        scale.primitive,
+                        zero_point,
+                        axis,
+                    ),
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::fake_quantize_per_channel(
+                tensor.primitive,
+                scale.primitive,
+                zero_point,
+                axis,
+            )),
+        }
+    }
+
     fn select<const D: usize>(
         tensor: ADTensor<B, D>,
         dim: usize,
@@ -573,24 +812,17 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         struct IndexSelectDimAssign<const D: usize>;
 
         impl<B: Backend, const D: usize> Backward<B, D, 2> for IndexSelectDimAssign<D> {
-            type State = (usize, IntTensor<B, 1>, Shape<D>, Shape<D>, B::Device);
+            type State = (usize, IntTensor<B, 1>);
 
             fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
-                let (dim, indices, shape_lhs, shape_rhs, device) = ops.state;
-                let [indices_4lhs, indices_4rhs] = duplicate(&ops.parents, Some(indices));
+                let (dim, indices) = ops.state;
 
                 binary::<B, D, D, D, _, _>(
                     ops.parents,
                     ops.node,
                     grads,
-                    |grad| {
-                        let zeros = B::zeros(shape_lhs, &device);
-                        B::select_assign(grad, dim, indices_4lhs.unwrap(), zeros)
-                    },
-                    |grad| {
-                        let zeros = B::zeros(shape_rhs, &device);
-                        B::select_assign(zeros, dim, indices_4rhs.unwrap(), grad)
-                    },
+                    |grad| grad,
+                    |grad| B::select(grad, dim, indices),
                 );
             }
         }
@@ -600,13 +832,7 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
             .statefull()
         {
             OpsKind::Tracked(prep) => prep.finish(
-                (
-                    dim,
-                    indices.clone(),
-                    B::shape(&tensor.primitive),
-                    B::shape(&value.primitive),
-                    B::device(&value.primitive),
-                ),
+                (dim, indices.clone()),
                 B::select_assign(tensor.primitive, dim, indices, value.primitive),
             ),
             OpsKind::UnTracked(prep) => prep.finish(B::select_assign(
@@ -717,12 +943,13 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
                     ops.node,
                     grads,
                     |grad| {
-                        let zeros = B::zeros(shape_lhs, &device);
-                        B::mask_where(grad, mask_4lhs.unwrap(), zeros)
+                        let grad = B::mask_fill(grad, mask_4lhs.unwrap(), 0.elem());
+                        broadcast_shape::<B, D>(grad, shape_lhs)
                     },
                     |grad| {
-                        let zeros = B::zeros(shape_rhs, &device);
-                        B::mask_where(zeros, mask_4rhs.unwrap(), grad)
+                        let zeros = B::zeros(B::shape(&grad), &device);
+                        let grad = B::mask_where(zeros, mask_4rhs.unwrap(), grad);
+                        broadcast_shape::<B, D>(grad, shape_rhs)
                     },
                 );
             }
@@ -747,6 +974,44 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         }
     }
 
+    fn maximum<const D: usize>(lhs: ADTensor<B, D>, rhs: ADTensor<B, D>) -> ADTensor<B, D> {
+        // Positions where the right hand side is the maximum, the left hand side winning ties.
+        let mask = B::lower(lhs.primitive.clone(), rhs.primitive.clone());
+        let output = B::maximum(lhs.primitive.clone(), rhs.primitive.clone());
+
+        MaxMinPair::prepare_pair(lhs, rhs, mask, output)
+    }
+
+    fn minimum<const D: usize>(lhs: ADTensor<B, D>, rhs: ADTensor<B, D>) -> ADTensor<B, D> {
+        // Positions where the right hand side is the minimum, the left hand side winning ties.
+        let mask = B::lower(rhs.primitive.clone(), lhs.primitive.clone());
+        let output = B::minimum(lhs.primitive.clone(), rhs.primitive.clone());
+
+        MaxMinPair::prepare_pair(lhs, rhs, mask, output)
+    }
+
+    fn clamp<const D: usize>(
+        tensor: ADTensor<B, D>,
+        min: FloatElem<B>,
+        max: FloatElem<B>,
+    ) -> ADTensor<B, D> {
+        let output = B::clamp(tensor.primitive.clone(), min, max);
+
+        Clamp::prepare_clamp(tensor, Some(min), Some(max), output)
+    }
+
+    fn clamp_min<const D: usize>(tensor: ADTensor<B, D>, min: FloatElem<B>) -> ADTensor<B, D> {
+        let output = B::clamp_min(tensor.primitive.clone(), min);
+
+        Clamp::prepare_clamp(tensor, Some(min), None, output)
+    }
+
+    fn clamp_max<const D: usize>(tensor: ADTensor<B, D>, max: FloatElem<B>) -> ADTensor<B, D> {
+        let output = B::clamp_max(tensor.primitive.clone(), max);
+
+        Clamp::prepare_clamp(tensor, None, Some(max), output)
+    }
+
     fn mask_fill<const D: usize>(
         tensor: ADTensor<B, D>,
         mask: BoolTensor<B, D>,
@@ -773,6 +1038,46 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         }
     }
 
+    fn tril<const D: usize>(tensor: ADTensor<B, D>, diagonal: i64) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Tril;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Tril {
+            type State = i64;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::tril(grad, ops.state)
+                });
+            }
+        }
+
+        match Tril.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => prep.finish(diagonal, B::tril(tensor.primitive, diagonal)),
+            OpsKind::UnTracked(prep) => prep.finish(B::tril(tensor.primitive, diagonal)),
+        }
+    }
+
+    fn triu<const D: usize>(tensor: ADTensor<B, D>, diagonal: i64) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Triu;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Triu {
+            type State = i64;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::triu(grad, ops.state)
+                });
+            }
+        }
+
+        match Triu.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => prep.finish(diagonal, B::triu(tensor.primitive, diagonal)),
+            OpsKind::UnTracked(prep) => prep.finish(B::triu(tensor.primitive, diagonal)),
+        }
+    }
+
     fn equal<const D: usize>(lhs: ADTensor<B, D>, rhs: ADTensor<B, D>) -> BoolTensor<B, D> {
         B::equal(lhs.primitive, rhs.primitive)
     }
@@ -961,6 +1266,32 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         }
     }
 
+    fn cumsum<const D: usize>(tensor: ADTensor<B, D>, dim: usize) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Cumsum;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Cumsum {
+            type State = usize;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let dim = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    // Reversed cumulative sum: grad_in[i] = sum(grad[i..]).
+                    let total = B::sum_dim(grad.clone(), dim);
+                    let cumsum = B::cumsum(grad.clone(), dim);
+
+                    B::sub(B::add(grad, total), cumsum)
+                });
+            }
+        }
+
+        match Cumsum.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => prep.finish(dim, B::cumsum(tensor.primitive, dim)),
+            OpsKind::UnTracked(prep) => prep.finish(B::cumsum(tensor.primitive, dim)),
+        }
+    }
+
     fn to_full_precision<const D: usize>(
         tensor: &ADTensor<B, D>,
     ) -> ADTensor<B::FullPrecisionBackend, D> {
@@ -1018,6 +1349,10 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
             .stateless(B::from_full_precision(tensor.primitive))
     }
 
+    fn into_int<const D: usize>(tensor: ADTensor<B, D>) -> IntTensor<B, D> {
+        B::into_int(tensor.primitive)
+    }
+
     fn argmax<const D: usize>(tensor: ADTensor<B, D>, dim: usize) -> IntTensor<B, D> {
         B::argmax(tensor.primitive, dim)
     }
@@ -1122,6 +1457,56 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         }
     }
 
+    fn powf_tensor<const D: usize>(lhs: ADTensor<B, D>, rhs: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct PowFTensor;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 2> for PowFTensor {
+            type State = (B::TensorPrimitive<D>, B::TensorPrimitive<D>);
+
+            fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
+                let (lhs, rhs) = ops.state;
+                let [lhs_4lhs, lhs_4rhs] = duplicate(&ops.parents, Some(lhs));
+                let [rhs_4lhs, rhs_4rhs] = duplicate(&ops.parents, Some(rhs));
+
+                binary::<B, D, D, D, _, _>(
+                    ops.parents,
+                    ops.node,
+                    grads,
+                    |grad| {
+                        let lhs = lhs_4lhs.unwrap();
+                        let rhs = rhs_4lhs.unwrap();
+                        let shape_lhs = B::shape(&lhs);
+                        let tmp = B::powf_tensor(lhs, B::sub_scalar(rhs.clone(), 1.elem()));
+                        let value = B::mul(tmp, rhs);
+
+                        broadcast_shape::<B, D>(B::mul(grad, value), shape_lhs)
+                    },
+                    |grad| {
+                        let lhs = lhs_4rhs.unwrap();
+                        let rhs = rhs_4rhs.unwrap();
+                        let shape_rhs = B::shape(&rhs);
+                        let tmp = B::powf_tensor(lhs.clone(), rhs);
+                        let value = B::mul(tmp, B::log(lhs));
+
+                        broadcast_shape::<B, D>(B::mul(grad, value), shape_rhs)
+                    },
+                );
+            }
+        }
+
+        match PowFTensor
+            .prepare([lhs.node, rhs.node], [lhs.graph, rhs.graph])
+            .statefull()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                (lhs.primitive.clone(), rhs.primitive.clone()),
+                B::powf_tensor(lhs.primitive, rhs.primitive),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::powf_tensor(lhs.primitive, rhs.primitive)),
+        }
+    }
+
     fn sqrt<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
         #[derive(Debug)]
         struct Sqrt;
@@ -1246,6 +1631,99 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         }
     }
 
+    // The gradient of piecewise constant functions is zero almost everywhere.
+    fn floor<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Floor;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Floor {
+            type State = ();
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::zeros(B::shape(&grad), &B::device(&grad))
+                });
+            }
+        }
+
+        Floor
+            .prepare([tensor.node], [tensor.graph])
+            .stateless(B::floor(tensor.primitive))
+    }
+
+    fn ceil<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Ceil;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Ceil {
+            type State = ();
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::zeros(B::shape(&grad), &B::device(&grad))
+                });
+            }
+        }
+
+        Ceil.prepare([tensor.node], [tensor.graph])
+            .stateless(B::ceil(tensor.primitive))
+    }
+
+    fn round<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Round;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Round {
+            type State = ();
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::zeros(B::shape(&grad), &B::device(&grad))
+                });
+            }
+        }
+
+        Round
+            .prepare([tensor.node], [tensor.graph])
+            .stateless(B::round(tensor.primitive))
+    }
+
+    fn repeat<const D: usize>(tensor: ADTensor<B, D>, dim: usize, times: usize) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Repeat;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Repeat {
+            type State = (usize, usize, Shape<D>);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (dim, times, shape) = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let size = shape.dims[dim];
+                    let ranges = B::shape(&grad).dims.map(|dim| 0..dim);
+
+                    // Each copy of the input receives its own slice of the gradient.
+                    (0..times)
+                        .map(|i| {
+                            let mut ranges = ranges.clone();
+                            ranges[dim] = i * size..(i + 1) * size;
+                            B::slice(grad.clone(), ranges)
+                        })
+                        .reduce(B::add)
+                        .unwrap_or_else(|| B::zeros(shape, &B::device(&grad)))
+                });
+            }
+        }
+
+        match Repeat.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => prep.finish(
+                (dim, times, B::shape(&tensor.primitive)),
+                B::repeat(tensor.primitive, dim, times),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::repeat(tensor.primitive, dim, times)),
+        }
+    }
+
     fn cat<const D: usize>(tensors: Vec<ADTensor<B, D>>, dim: usize) -> ADTensor<B, D> {
         #[derive(new, Debug)]
         struct CatStep<B: Backend, const D: usize> {
@@ -1269,12 +1747,15 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
                 self.nodes
                     .into_iter()
                     .zip(self.dim_sizes.into_iter())
-                    .filter_map(|(node, dim_size)| node.map(|node| (node, dim_size)))
                     .for_each(|(node, dim_size)| {
                         let mut ranges = ranges.clone();
                         ranges[self.dim] = current_index..dim_size + current_index;
                         current_index += dim_size;
-                        grads.register::<B, D>(node, B::slice(grad.clone(), ranges));
+
+                        // Untracked tensors still offset the position of the following ones.
+                        if let Some(node) = node {
+                            grads.register::<B, D>(node, B::slice(grad.clone(), ranges));
+                        }
                     });
             }
 
@@ -1317,7 +1798,7 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
             OpsKind::Tracked(prep) => {
                 let shape = B::shape(&tensor.primitive);
                 let (tensor, index) = B::max_dim_with_indices(tensor.primitive, dim);
-                prep.finish((index, shape), tensor)
+                prep.finish((index, shape, dim), tensor)
             }
             OpsKind::UnTracked(prep) => prep.finish(B::max_dim(tensor.primitive, dim)),
         }
@@ -1330,7 +1811,7 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
             OpsKind::Tracked(prep) => {
                 let shape = B::shape(&tensor.primitive);
                 let (tensor, index) = B::max_dim_with_indices(tensor.primitive, dim);
-                let tensor = prep.finish((index.clone(), shape), tensor);
+                let tensor = prep.finish((index.clone(), shape, dim), tensor);
 
                 (tensor, index)
             }
@@ -1347,7 +1828,7 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
             OpsKind::Tracked(prep) => {
                 let shape = B::shape(&tensor.primitive);
                 let (tensor, index) = B::min_dim_with_indices(tensor.primitive, dim);
-                prep.finish((index, shape), tensor)
+                prep.finish((index, shape, dim), tensor)
             }
             OpsKind::UnTracked(prep) => prep.finish(B::min_dim(tensor.primitive, dim)),
         }
@@ -1360,7 +1841,7 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
             OpsKind::Tracked(prep) => {
                 let shape = B::shape(&tensor.primitive);
                 let (tensor, index) = B::min_dim_with_indices(tensor.primitive, dim);
-                let tensor = prep.finish((index.clone(), shape), tensor);
+                let tensor = prep.finish((index.clone(), shape, dim), tensor);
 
                 (tensor, index)
             }
@@ -1372,13 +1853,57 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
             }
         }
     }
+
+    fn topk<const D: usize>(
+        tensor: ADTensor<B, D>,
+        k: usize,
+        dim: usize,
+    ) -> (ADTensor<B, D>, IntTensor<B, D>) {
+        match MaxMinDim.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                let shape = B::shape(&tensor.primitive);
+                let (tensor, index) = B::topk(tensor.primitive, k, dim);
+                let tensor = prep.finish((index.clone(), shape, dim), tensor);
+
+                (tensor, index)
+            }
+            OpsKind::UnTracked(prep) => {
+                let (tensor, index) = B::topk(tensor.primitive, k, dim);
+                let tensor = prep.finish(tensor);
+
+                (tensor, index)
+            }
+        }
+    }
+
+    fn sort<const D: usize>(
+        tensor: ADTensor<B, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (ADTensor<B, D>, IntTensor<B, D>) {
+        match MaxMinDim.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                let shape = B::shape(&tensor.primitive);
+                let (tensor, index) = B::sort(tensor.primitive, dim, descending);
+                let tensor = prep.finish((index.clone(), shape, dim), tensor);
+
+                (tensor, index)
+            }
+            OpsKind::UnTracked(prep) => {
+                let (tensor, index) = B::sort(tensor.primitive, dim, descending);
+                let tensor = prep.finish(tensor);
+
+                (tensor, index)
+            }
+        }
+    }
 }
 
 /// Make sure the grad tensor has the given shape.
 ///
 /// If broadcasting happened during the forward pass, the gradients will be sum along the
 /// broadcasted dimension.
-fn broadcast_shape<B: Backend, const D: usize>(
+pub(crate) fn broadcast_shape<B: Backend, const D: usize>(
     mut grad: B::TensorPrimitive<D>,
     shape: Shape<D>,
 ) -> B::TensorPrimitive<D> {
@@ -118,4 +118,34 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::from([[18.0, 18.0], [71.0, 71.0]]), 5);
     }
+
+    #[test]
+    fn should_diff_var_bias() {
+        let tensor_1 = TestADTensor::from_data(Data::from([[1.0, 2.0, 3.0, 6.0]])).require_grad();
+
+        let tensor_2 = tensor_1.clone().var_bias(1);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        // d/dx_i = 2 * (x_i - mean) / n
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[-1.0, -0.5, 0.0, 1.5]]), 5);
+    }
+
+    #[test]
+    fn should_diff_std_bias() {
+        let tensor_1 = TestADTensor::from_data(Data::from([[1.0, 2.0, 3.0, 6.0]])).require_grad();
+
+        let tensor_2 = tensor_1.clone().std_bias(1);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        // d/dx_i = (x_i - mean) / (n * std)
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[-0.26726, -0.13363, 0.0, 0.40089]]), 4);
+    }
 }
@@ -0,0 +1,50 @@
+#[burn_tensor_testgen::testgen(ad_clamp)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_clamp() {
+        let data_1 = Data::<f32, 2>::from([[-2.0, -1.0, 0.0], [1.0, 2.0, 3.0]]);
+        let data_2 = Data::<f32, 2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data_1).require_grad();
+        let tensor_2 = TestADTensor::from_data(data_2).require_grad();
+
+        let tensor_3 = tensor_1.clone().clamp(-1.0, 2.0);
+        let tensor_4 = tensor_3.mul(tensor_2.clone());
+        let grads = tensor_4.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        // The bounds are inclusive, so the gradient flows to the elements exactly on them.
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, 2.0, 3.0], [4.0, 5.0, 0.0]]), 5);
+        grad_2
+            .to_data()
+            .assert_approx_eq(&Data::from([[-1.0, -1.0, 0.0], [1.0, 2.0, 2.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_clamp_min_max() {
+        let data = Data::<f32, 1>::from([-2.0, -1.0, 0.0, 1.0, 2.0]);
+
+        let tensor_1 = TestADTensor::from_data(data.clone()).require_grad();
+        let tensor_2 = TestADTensor::from_data(data).require_grad();
+
+        let grads_1 = tensor_1.clone().clamp_min(-1.0).sum().backward();
+        let grads_2 = tensor_2.clone().clamp_max(1.0).sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads_1).unwrap();
+        let grad_2 = tensor_2.grad(&grads_2).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([0.0, 1.0, 1.0, 1.0, 1.0]), 5);
+        grad_2
+            .to_data()
+            .assert_approx_eq(&Data::from([1.0, 1.0, 1.0, 1.0, 0.0]), 5);
+    }
+}
@@ -78,4 +78,27 @@ mod tests {
         );
         assert_eq!(grad_2.to_data(), Data::from([[223.0, 279.0], [63.0, 79.0]]));
     }
+
+    #[test]
+    fn should_diff_tensors_created_before_their_graph_was_merged() {
+        let data: Data<f32, 2> = Data::from([[0.0, 1.0], [-1.0, 2.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data.clone()).require_grad();
+        let tensor_2 = tensor_1.clone().exp();
+        // The steps of the graph of tensor_2 are merged with the graph of the zeros, but
+        // tensor_2 still refers to its own graph.
+        let _tensor_3 = TestADTensor::zeros([2, 2]) + tensor_2.clone();
+        let tensor_4 = tensor_2.mul_scalar(2.0);
+
+        let grads = tensor_4.backward();
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1.to_data().assert_approx_eq(
+            &TestADTensor::from_data(data)
+                .exp()
+                .mul_scalar(2.0)
+                .into_data(),
+            3,
+        );
+    }
 }
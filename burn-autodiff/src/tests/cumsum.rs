@@ -0,0 +1,38 @@
+#[burn_tensor_testgen::testgen(ad_cumsum)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_cumsum() {
+        let tensor_1 = TestADTensor::from_data(Data::from([1.0, 2.0, 3.0, 4.0])).require_grad();
+        let weights = TestADTensor::from_data(Data::from([1.0, 2.0, 3.0, 4.0]));
+
+        let tensor_2 = tensor_1.clone().cumsum(0);
+        let tensor_3 = tensor_2.clone().mul(weights);
+        let grads = tensor_3.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        // Each input contributes to every output after it.
+        assert_eq!(tensor_2.into_data(), Data::from([1.0, 3.0, 6.0, 10.0]));
+        assert_eq!(grad_1.to_data(), Data::from([10.0, 9.0, 7.0, 4.0]));
+    }
+
+    #[test]
+    fn should_diff_cumsum_dim1() {
+        let tensor_1 =
+            TestADTensor::from_data(Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]])).require_grad();
+        let tensor_2 = TestADTensor::from_data(Data::from([[1.0, 0.0, 2.0], [-1.0, 1.0, 1.0]]));
+
+        let tensor_3 = tensor_1.clone().cumsum(1).mul(tensor_2);
+        let grads = tensor_3.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(
+            grad_1.to_data(),
+            Data::from([[3.0, 2.0, 2.0], [1.0, 2.0, 1.0]])
+        );
+    }
+}
@@ -0,0 +1,21 @@
+#[burn_tensor_testgen::testgen(ad_diff)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_diff() {
+        let tensor_1 = TestADTensor::from_data(Data::from([1.0, 4.0, 9.0, 16.0])).require_grad();
+        let weights = TestADTensor::from_data(Data::from([1.0, 2.0, 3.0]));
+
+        let tensor_2 = tensor_1.clone().diff(0, 1);
+        let tensor_3 = tensor_2.clone().mul(weights);
+        let grads = tensor_3.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        // Each input contributes positively to the previous output and negatively to the next.
+        assert_eq!(tensor_2.into_data(), Data::from([3.0, 5.0, 7.0]));
+        assert_eq!(grad_1.to_data(), Data::from([-1.0, -1.0, -1.0, 3.0]));
+    }
+}
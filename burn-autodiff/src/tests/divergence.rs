@@ -0,0 +1,27 @@
+#[burn_tensor_testgen::testgen(ad_divergence)]
+mod tests {
+    use super::*;
+    use burn_tensor::loss::{kl_divergence, Reduction};
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_diff_kl_divergence() {
+        let log_p = Tensor::<TestADBackend, 2>::from_floats([[0.5, 0.5]])
+            .log()
+            .require_grad();
+        let q = Tensor::<TestADBackend, 2>::from_floats([[0.9, 0.1]]).require_grad();
+
+        let divergence = kl_divergence(log_p.clone(), q.clone(), 1, Reduction::Sum);
+        let grads = divergence.backward();
+        let grad_log_p = log_p.grad(&grads).unwrap();
+        let grad_q = q.grad(&grads).unwrap();
+
+        // d/dlog_p = -q, d/dq = log(q) + 1 - log_p
+        grad_log_p
+            .to_data()
+            .assert_approx_eq(&Data::from([[-0.9, -0.1]]), 3);
+        grad_q
+            .to_data()
+            .assert_approx_eq(&Data::from([[1.5878, -0.6094]]), 3);
+    }
+}
@@ -0,0 +1,117 @@
+#[burn_tensor_testgen::testgen(ad_double_backward)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{avg_pool2d, embedding, max_pool2d};
+    use burn_tensor::{Data, Int, Tensor};
+
+    type TestDoubleADBackend = burn_autodiff::ADBackendDecorator<TestADBackend>;
+    type TestDoubleADTensor<const D: usize> = Tensor<TestDoubleADBackend, D>;
+
+    #[test]
+    fn should_diff_second_order_powf() {
+        let data = Data::<f32, 1>::from([-1.0, 0.5, 2.0]);
+
+        let tensor_inner = TestADTensor::from_data(data).require_grad();
+        let tensor = TestDoubleADTensor::from_inner(tensor_inner.clone()).require_grad();
+
+        let grads = tensor.clone().powf(3.0).sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+        let grads = grad.clone().sum().backward();
+        let grad_grad = tensor_inner.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_approx_eq(&Data::from([3.0, 0.75, 12.0]), 3);
+        grad_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([-6.0, 3.0, 12.0]), 3);
+    }
+
+    #[test]
+    fn should_diff_second_order_with_respect_to_other_tensor() {
+        let data_1 = Data::<f32, 2>::from([[0.1, 0.2], [0.3, -0.1]]);
+        let data_2 = Data::<f32, 2>::from([[0.5, -0.5], [0.25, 1.0]]);
+
+        let tensor_1 = TestDoubleADTensor::from_data(data_1).require_grad();
+        let tensor_2_inner = TestADTensor::from_data(data_2).require_grad();
+        let tensor_2 = TestDoubleADTensor::from_inner(tensor_2_inner.clone());
+
+        let grads = tensor_1.clone().matmul(tensor_2).tanh().sum().backward();
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grads = grad_1.powf(2.0).sum().backward();
+        let grad_2 = tensor_2_inner.grad(&grads).unwrap();
+
+        grad_2
+            .to_data()
+            .assert_approx_eq(&Data::from([[-0.0015, 0.3073], [4.7526, 4.3758]]), 3);
+    }
+
+    #[test]
+    fn should_diff_second_order_avg_pool2d() {
+        let tensor_inner = Tensor::<TestADBackend, 4>::ones([1, 1, 4, 4]).require_grad();
+        let tensor = TestDoubleADTensor::from_inner(tensor_inner.clone()).require_grad();
+
+        let grads = avg_pool2d(tensor.clone(), [2, 2], [2, 2], [0, 0])
+            .powf(2.0)
+            .sum()
+            .backward();
+        let grad = tensor.grad(&grads).unwrap();
+        let grads = grad.sum().backward();
+        let grad_grad = tensor_inner.grad(&grads).unwrap();
+
+        grad_grad.to_data().assert_approx_eq(
+            &Tensor::<TestADBackend, 4>::full([1, 1, 4, 4], 0.5).to_data(),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_diff_second_order_max_pool2d() {
+        let tensor_inner = TestADTensor::from_floats([[[
+            [1.0, 2.0, 0.0, 0.0],
+            [0.0, 0.0, 3.0, 0.0],
+            [0.0, 4.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 5.0],
+        ]]])
+        .require_grad();
+        let tensor = TestDoubleADTensor::from_inner(tensor_inner.clone()).require_grad();
+
+        let grads = max_pool2d(tensor.clone(), [2, 2], [2, 2], [0, 0])
+            .powf(2.0)
+            .sum()
+            .backward();
+        let grad = tensor.grad(&grads).unwrap();
+        let grads = grad.sum().backward();
+        let grad_grad = tensor_inner.grad(&grads).unwrap();
+
+        grad_grad.to_data().assert_approx_eq(
+            &Data::from([[[
+                [0.0, 2.0, 0.0, 0.0],
+                [0.0, 0.0, 2.0, 0.0],
+                [0.0, 2.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 2.0],
+            ]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_diff_second_order_embedding() {
+        let weights_inner =
+            TestADTensor::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]).require_grad();
+        let weights = TestDoubleADTensor::from_inner(weights_inner.clone()).require_grad();
+        let indices = Tensor::<TestDoubleADBackend, 2, Int>::from_data(Data::from([[0, 2, 2]]));
+
+        let grads = embedding(weights.clone(), indices)
+            .powf(2.0)
+            .sum()
+            .backward();
+        let grad = weights.grad(&grads).unwrap();
+        let grads = grad.sum().backward();
+        let grad_grad = weights_inner.grad(&grads).unwrap();
+
+        // Each embedding is selected as many times as its index appears.
+        grad_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([[2.0, 2.0], [0.0, 0.0], [4.0, 4.0]]), 3);
+    }
+}
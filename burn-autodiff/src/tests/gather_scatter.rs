@@ -49,4 +49,57 @@ mod tests {
             Data::from([[19., 19., 19.], [64., 64., 64.]])
         );
     }
+
+    #[test]
+    fn test_scatter_max_grad() {
+        let tensor_1 =
+            TestADTensor::from_data(Data::from([[0.0, 8.0, 0.0], [4.0, 0.0, 0.0]])).require_grad();
+        let values =
+            TestADTensor::from_data(Data::from([[1.0, 3.0, 7.0, 2.0], [4.0, 1.0, 6.0, 9.0]]))
+                .require_grad();
+        let indices = TestADTensor::from_data(Data::from([[0, 0, 1, 2], [0, 2, 2, 1]]));
+        let weights = TestADTensor::from_data(Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+
+        let tensor_2 = tensor_1.clone().scatter_max(1, indices, values.clone());
+        let grads = (tensor_2 * weights).sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = values.grad(&grads).unwrap();
+
+        // The existing element is kept on ties, so the gradient of [1, 0] stays in tensor_1.
+        assert_eq!(
+            grad_1.into_data(),
+            Data::from([[0.0, 2.0, 0.0], [4.0, 0.0, 0.0]])
+        );
+        assert_eq!(
+            grad_2.into_data(),
+            Data::from([[0.0, 1.0, 0.0, 3.0], [0.0, 0.0, 6.0, 5.0]])
+        );
+    }
+
+    #[test]
+    fn test_scatter_min_grad() {
+        let tensor_1 =
+            TestADTensor::from_data(Data::from([[4.0, 5.0, 4.0], [8.0, 8.0, 8.0]])).require_grad();
+        let values =
+            TestADTensor::from_data(Data::from([[1.0, 3.0, 7.0, 2.0], [4.0, 1.0, 6.0, 9.0]]))
+                .require_grad();
+        let indices = TestADTensor::from_data(Data::from([[0, 0, 1, 2], [0, 2, 2, 1]]));
+        let weights = TestADTensor::from_data(Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+
+        let tensor_2 = tensor_1.clone().scatter_min(1, indices, values.clone());
+        let grads = (tensor_2 * weights).sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = values.grad(&grads).unwrap();
+
+        assert_eq!(
+            grad_1.into_data(),
+            Data::from([[0.0, 2.0, 0.0], [0.0, 5.0, 0.0]])
+        );
+        assert_eq!(
+            grad_2.into_data(),
+            Data::from([[1.0, 0.0, 0.0, 3.0], [4.0, 6.0, 0.0, 0.0]])
+        );
+    }
 }
@@ -0,0 +1,52 @@
+#[burn_tensor_testgen::testgen(ad_graph_stats)]
+mod tests {
+    use super::*;
+    use burn_tensor::{backend::Backend, Data};
+
+    #[test]
+    fn should_count_retained_nodes() {
+        let tensor_1 = TestADTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]])).require_grad();
+        let tensor_2 = TestADTensor::from_data(Data::from([[5.0, 6.0], [7.0, 8.0]])).require_grad();
+
+        let tensor_3 = tensor_1.clone().mul(tensor_2.clone());
+        let tensor_4 = tensor_3.mul(tensor_2.clone());
+        let tensor_5 = tensor_4.clone().mul(tensor_1.clone());
+
+        let stats = TestADBackend::graph_stats(&tensor_5);
+        let elem_size = core::mem::size_of::<<TestBackend as Backend>::FloatElem>();
+
+        // Two tracked leaves and three multiplications retaining their operands.
+        assert_eq!(stats.num_nodes, 5);
+        assert_eq!(stats.retained_bytes, 3 * 4 * elem_size);
+
+        let tensor_6 = tensor_4.detach().mul(tensor_1);
+        let stats_detached = TestADBackend::graph_stats(&tensor_6);
+
+        assert_eq!(stats_detached.num_nodes, 2);
+        assert!(stats_detached.retained_bytes < stats.retained_bytes);
+    }
+
+    #[test]
+    fn should_not_consume_graph() {
+        let tensor_1 = TestADTensor::from_data(Data::from([1.0, 2.0])).require_grad();
+        let tensor_2 = tensor_1.clone().mul_scalar(2.0);
+
+        assert_eq!(TestADBackend::graph_stats(&tensor_2).num_nodes, 2);
+
+        let grads = tensor_2.backward();
+        let grad = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad.to_data(), Data::from([2.0, 2.0]));
+    }
+
+    #[test]
+    fn should_be_empty_without_tracked_tensor() {
+        let tensor_1 = TestADTensor::from_data(Data::from([1.0, 2.0]));
+        let tensor_2 = tensor_1.exp();
+
+        let stats = TestADBackend::graph_stats(&tensor_2);
+
+        assert_eq!(stats.num_nodes, 0);
+        assert_eq!(stats.retained_bytes, 0);
+    }
+}
@@ -0,0 +1,93 @@
+#[burn_tensor_testgen::testgen(ad_grid_sample)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::grid_sample;
+    use burn_tensor::ops::{GridSampleMode, GridSamplePadding};
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn test_grid_sample_bilinear_grad() {
+        let x = Tensor::<TestADBackend, 4>::from_data([[[[1.0, 2.0], [3.0, 4.0]]]]).require_grad();
+        // Pixel coordinates (0.25, 0.5).
+        let grid = Tensor::<TestADBackend, 4>::from_data([[[[-0.25, 0.0]]]]).require_grad();
+
+        let output = grid_sample(
+            x.clone(),
+            grid.clone(),
+            GridSampleMode::Bilinear,
+            GridSamplePadding::Zeros,
+        );
+        let grads = output.sum().backward();
+
+        let x_grad = x.grad(&grads).unwrap();
+        let grid_grad = grid.grad(&grads).unwrap();
+
+        x_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([[[[0.375, 0.125], [0.375, 0.125]]]]), 3);
+        // d/dx = (x01 - x00) (1 - wy) + (x11 - x10) wy, scaled by width / 2.
+        // d/dy = (x10 - x00) (1 - wx) + (x11 - x01) wx, scaled by height / 2.
+        grid_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([[[[1.0, 2.0]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear_border_grad() {
+        let x = Tensor::<TestADBackend, 4>::from_data([[
+            [[1.0, 2.0], [3.0, 4.0]],
+            [[5.0, 6.0], [7.0, 8.0]],
+        ]])
+        .require_grad();
+        // Pixel coordinates (1.5, 0.5), the x coordinate being clamped to the border.
+        let grid = Tensor::<TestADBackend, 4>::from_data([[[[1.0, 0.0]]]]).require_grad();
+
+        let output = grid_sample(
+            x.clone(),
+            grid.clone(),
+            GridSampleMode::Bilinear,
+            GridSamplePadding::Border,
+        );
+        let grads = output.sum().backward();
+
+        let x_grad = x.grad(&grads).unwrap();
+        let grid_grad = grid.grad(&grads).unwrap();
+
+        x_grad.to_data().assert_approx_eq(
+            &Data::from([[[[0.0, 0.5], [0.0, 0.5]], [[0.0, 0.5], [0.0, 0.5]]]]),
+            3,
+        );
+        // The clamped coordinate doesn't receive any gradient.
+        grid_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([[[[0.0, 4.0]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_nearest_grad() {
+        let x = Tensor::<TestADBackend, 4>::from_data([[[[1.0, 2.0], [3.0, 4.0]]]]).require_grad();
+        // Pixel coordinates (0.2, 0.1), (0.4, -0.2) and (1.0, 1.0), the first two samples using
+        // the same pixel.
+        let grid =
+            Tensor::<TestADBackend, 4>::from_data([[[[-0.3, -0.4], [-0.1, -0.7], [0.5, 0.5]]]])
+                .require_grad();
+
+        let output = grid_sample(
+            x.clone(),
+            grid.clone(),
+            GridSampleMode::Nearest,
+            GridSamplePadding::Zeros,
+        );
+        let grads = output.sum().backward();
+
+        let x_grad = x.grad(&grads).unwrap();
+        let grid_grad = grid.grad(&grads).unwrap();
+
+        x_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([[[[2.0, 0.0], [0.0, 1.0]]]]), 3);
+        grid_grad
+            .to_data()
+            .assert_approx_eq(&Data::zeros([1, 1, 3, 2]), 3);
+    }
+}
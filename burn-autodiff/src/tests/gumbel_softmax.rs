@@ -0,0 +1,25 @@
+#[burn_tensor_testgen::testgen(ad_gumbel_softmax)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Data, Tensor};
+
+    #[test]
+    fn test_gumbel_softmax_hard_grad_flows_through_soft_samples() {
+        let logits =
+            Tensor::<TestADBackend, 2>::from_data([[1.0, 2.0, 0.5, -1.0], [0.0, 0.0, 1.0, 3.0]])
+                .require_grad();
+        let weights = Tensor::<TestADBackend, 2>::from_data([[1.0, 2.0, 3.0, 4.0]; 2]);
+
+        let output = activation::gumbel_softmax(logits.clone(), 1.0, true, 1);
+        let grads = output.mul(weights).sum().backward();
+        let grad = logits.grad(&grads).unwrap();
+
+        // The gradient of the soft samples through the softmax sums to zero along the dimension,
+        // while the one-hot samples alone wouldn't have any gradient.
+        grad.clone()
+            .sum_dim(1)
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0], [0.0]]), 4);
+        assert!(grad.powf(2.0).sum().into_scalar() > 1e-8);
+    }
+}
@@ -0,0 +1,52 @@
+#[burn_tensor_testgen::testgen(ad_interpolate1d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::interpolate1d;
+    use burn_tensor::ops::InterpolateMode;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_diff_interpolate1d_linear() {
+        let x = TestADTensor::from_floats([[[1.0, 3.0, 2.0, 6.0]]]).require_grad();
+        let weights = TestADTensor::from_floats([[[0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]]]);
+
+        let output = interpolate1d(x.clone(), 8, InterpolateMode::Linear);
+        let grads = (output * weights).sum().backward();
+
+        let x_grad = x.grad(&grads).unwrap();
+        x_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([[[1.25, 5.0, 9.0, 12.75]]]), 3);
+    }
+
+    #[test]
+    fn should_diff_interpolate1d_nearest() {
+        let x = TestADTensor::from_floats([[[1.0, 3.0, 2.0, 6.0]]]).require_grad();
+        let weights = TestADTensor::from_floats([[[0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]]]);
+
+        let output = interpolate1d(x.clone(), 8, InterpolateMode::Nearest);
+        let grads = (output * weights).sum().backward();
+
+        let x_grad = x.grad(&grads).unwrap();
+        x_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([[[1.0, 5.0, 9.0, 13.0]]]), 3);
+    }
+
+    #[test]
+    fn should_diff_interpolate1d_nearest_upsampling_sums_the_shared_elements() {
+        let x = TestADTensor::from_floats([[[1.0, 3.0], [2.0, 6.0]], [[0.0, 4.0], [5.0, 7.0]]])
+            .require_grad();
+        let weights = TestADTensor::from_floats([[[1.0, 2.0, 3.0, 4.0, 5.0]]]);
+
+        // Output elements [0, 1, 2] use the first input element, [3, 4] the second one.
+        let output = interpolate1d(x.clone(), 5, InterpolateMode::Nearest);
+        let grads = (output * weights).sum().backward();
+
+        let x_grad = x.grad(&grads).unwrap();
+        x_grad.to_data().assert_approx_eq(
+            &Data::from([[[6.0, 9.0], [6.0, 9.0]], [[6.0, 9.0], [6.0, 9.0]]]),
+            3,
+        );
+    }
+}
@@ -0,0 +1,41 @@
+#[burn_tensor_testgen::testgen(ad_leaky_relu)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Data};
+
+    #[test]
+    fn should_diff_leaky_relu() {
+        let tensor_1 =
+            TestADTensor::from_floats([[-2.0, 0.0, 3.0], [0.5, -0.1, 0.0]]).require_grad();
+        let tensor_2 = TestADTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let x = activation::leaky_relu(tensor_1.clone(), 0.2).mul(tensor_2);
+        let grads = x.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        // The slope is applied to the negative inputs and at zero.
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.2, 0.4, 3.0], [4.0, 1.0, 1.2]]), 5);
+    }
+
+    #[test]
+    fn should_diff_prelu() {
+        let tensor_1 = TestADTensor::from_floats([[-2.0, 3.0], [4.0, -1.0]]).require_grad();
+        let alpha = TestADTensor::from_floats([0.1, 0.3]).require_grad();
+
+        let x = activation::prelu(tensor_1.clone(), alpha.clone());
+        let grads = x.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_alpha = alpha.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.1, 1.0], [1.0, 0.3]]), 5);
+        grad_alpha
+            .to_data()
+            .assert_approx_eq(&Data::from([-2.0, -1.0]), 5);
+    }
+}
@@ -0,0 +1,26 @@
+#[burn_tensor_testgen::testgen(ad_log_sigmoid)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Data};
+
+    #[test]
+    fn should_diff_log_sigmoid() {
+        let data = Data::<f32, 1>::from([-100.0, -1.0, 0.0, 2.0, 100.0]);
+        let tensor = TestADTensor::from_data(data.clone()).require_grad();
+
+        let output = activation::log_sigmoid(tensor.clone());
+        let grads = output.sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        // d/dx log_sigmoid(x) = 1 - sigmoid(x)
+        let expected = activation::sigmoid(TestADTensor::from_data(data))
+            .neg()
+            .add_scalar(1.0)
+            .into_data();
+        let grad = grad.into_data();
+
+        assert!(grad.value.iter().all(|value| value.is_finite()));
+        grad.assert_approx_eq(&expected, 4);
+        grad.assert_approx_eq(&Data::from([1.0, 0.7311, 0.5, 0.1192, 0.0]), 4);
+    }
+}
@@ -50,4 +50,22 @@ mod tests {
             .into_data()
             .assert_approx_eq(&Data::from([[15., 18.], [23., 29.]]), 3);
     }
+
+    #[test]
+    fn should_diff_mask_where_broadcasted() {
+        let tensor_1 = TestADTensor::from_data([[1.0, 7.0], [2.0, 3.0]]).require_grad();
+        let tensor_2 = TestADTensor::from_data([[4.0, 5.0]]).require_grad();
+        let tensor_3 = TestADTensor::from_data([[1.0, 2.0], [3.0, 4.0]]);
+        let mask = TestADTensor::from_data([[true], [false]]);
+
+        let tensor_4 = tensor_1.clone().mask_where(mask, tensor_2.clone());
+        let tensor_5 = tensor_4.mul(tensor_3);
+        let grads = tensor_5.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[0.0, 0.0], [3.0, 4.0]]));
+        assert_eq!(grad_2.to_data(), Data::from([[1.0, 2.0]]));
+    }
 }
@@ -42,4 +42,150 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::from([[10.0, 8.0], [15.0, 56.0]]), 5);
     }
+
+    #[test]
+    fn should_diff_max_dim_with_indices_first_dim() {
+        let tensor_1 =
+            TestADTensor::from_floats([[1.0, 7.0], [-2.0, 9.0], [4.0, 3.0]]).require_grad();
+        let weights = TestADTensor::from_floats([[2.0, 3.0]]);
+
+        let (tensor_2, indices) = tensor_1.clone().max_dim_with_indices(0);
+        let tensor_3 = tensor_2.mul(weights);
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(indices.into_data(), Data::from([[2, 1]]));
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, 0.0], [0.0, 3.0], [2.0, 0.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_min_dim_first_dim() {
+        let tensor_1 =
+            TestADTensor::from_floats([[1.0, 7.0], [-2.0, 9.0], [4.0, 3.0]]).require_grad();
+
+        let tensor_2 = tensor_1.clone().min_dim(0);
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_max_pair() {
+        let tensor_1 = TestADTensor::from_floats([[1.0, 5.0], [3.0, -2.0]]).require_grad();
+        let tensor_2 = TestADTensor::from_floats([[2.0, 5.0], [1.0, 0.0]]).require_grad();
+        let weights = TestADTensor::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+
+        let tensor_3 = tensor_1.clone().max_pair(tensor_2.clone());
+        let grads = tensor_3.clone().mul(weights).sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        tensor_3
+            .into_data()
+            .assert_approx_eq(&Data::from([[2.0, 5.0], [3.0, 0.0]]), 5);
+        // The tie at [0, 1] goes to the first tensor.
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, 2.0], [3.0, 0.0]]), 5);
+        grad_2
+            .to_data()
+            .assert_approx_eq(&Data::from([[1.0, 0.0], [0.0, 4.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_max_pair_with_untracked_input() {
+        let tensor_1 = TestADTensor::from_floats([[1.0, -1.0], [4.0, 2.0]]).require_grad();
+        let tensor_2 = TestADTensor::from_floats([[0.0, 0.0], [0.0, 3.0]]);
+
+        let grads = tensor_1.clone().max_pair(tensor_2).sum().backward();
+
+        tensor_1
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([[1.0, 0.0], [1.0, 0.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_min_pair() {
+        let tensor_1 = TestADTensor::from_floats([[1.0, 5.0], [3.0, -2.0]]).require_grad();
+        let tensor_2 = TestADTensor::from_floats([[2.0, 5.0], [1.0, 0.0]]).require_grad();
+        let weights = TestADTensor::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+
+        let tensor_3 = tensor_1.clone().min_pair(tensor_2.clone());
+        let grads = tensor_3.clone().mul(weights).sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        tensor_3
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 5.0], [1.0, -2.0]]), 5);
+        // The tie at [0, 1] goes to the first tensor.
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[1.0, 2.0], [0.0, 4.0]]), 5);
+        grad_2
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, 0.0], [3.0, 0.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_max_pair_broadcast() {
+        let tensor_1 = TestADTensor::from_floats([[1.0, 5.0], [3.0, -2.0]]).require_grad();
+        let tensor_2 = TestADTensor::from_floats([[2.0, 0.0]]).require_grad();
+
+        let grads = tensor_1.clone().max_pair(tensor_2.clone()).sum().backward();
+
+        tensor_1
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, 1.0], [1.0, 0.0]]), 5);
+        tensor_2
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([[1.0, 1.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_topk() {
+        let tensor_1 = TestADTensor::from_floats([[1.0, 5.0, 3.0], [4.0, 2.0, 6.0]]).require_grad();
+        let tensor_2 = TestADTensor::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+
+        let (values, _indices) = tensor_1.clone().topk(2, 1);
+        let tensor_3 = values.mul(tensor_2);
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, 1.0, 2.0], [4.0, 0.0, 3.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_sort() {
+        let tensor_1 = TestADTensor::from_floats([[3.0, 1.0, 2.0], [0.0, 2.0, 0.0]]).require_grad();
+        let tensor_2 = TestADTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let (values, _indices) = tensor_1.clone().sort(1, false);
+        let tensor_3 = values.mul(tensor_2);
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[3.0, 1.0, 2.0], [4.0, 6.0, 5.0]]), 5);
+    }
 }
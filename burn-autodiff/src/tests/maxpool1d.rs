@@ -0,0 +1,44 @@
+#[burn_tensor_testgen::testgen(ad_max_pool1d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{max_pool1d, max_pool1d_with_indices};
+
+    #[test]
+    fn test_max_pool1d_simple() {
+        let kernel_size = 3;
+        let padding = 1;
+        let stride = 2;
+
+        let x = TestADTensor::from_floats([[[0.5, 0.9, 0.1, 0.7, 0.3, 0.2]]]).require_grad();
+        let x_grad_expected = TestADTensor::from_floats([[[0., 2., 0., 1., 0., 0.]]]);
+
+        let output = max_pool1d(x.clone(), kernel_size, stride, padding);
+        let grads = output.backward();
+
+        // Asserts
+        let x_grad_actual = x.grad(&grads).unwrap();
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq(&x_grad_actual.to_data(), 3);
+    }
+
+    #[test]
+    fn test_max_pool1d_with_indices() {
+        let kernel_size = 2;
+        let padding = 1;
+        let stride = 1;
+
+        let x = TestADTensor::from_floats([[[0.6, 0.2, 0.4, 0.8], [0.1, 0.9, 0.3, 0.5]]])
+            .require_grad();
+        let x_grad_expected = TestADTensor::from_floats([[[2., 0., 1., 2.], [1., 2., 0., 2.]]]);
+
+        let (output, _indices) = max_pool1d_with_indices(x.clone(), kernel_size, stride, padding);
+        let grads = output.backward();
+
+        // Asserts
+        let x_grad_actual = x.grad(&grads).unwrap();
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq(&x_grad_actual.to_data(), 3);
+    }
+}
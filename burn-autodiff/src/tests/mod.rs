@@ -1,5 +1,6 @@
 #![allow(missing_docs)]
 
+mod adaptive_avgpool2d;
 mod add;
 mod aggregation;
 mod avgpool1d;
@@ -7,36 +8,57 @@ mod avgpool2d;
 mod backward;
 mod broadcast;
 mod cat;
+mod clamp;
 mod complex;
 mod conv1d;
 mod conv2d;
 mod cos;
 mod cross_entropy;
+mod cumsum;
+mod diff;
 mod div;
+mod divergence;
+mod double_backward;
 mod erf;
 mod exp;
+mod flip;
 mod gather_scatter;
 mod gelu;
+mod graph_stats;
+mod grid_sample;
+mod gumbel_softmax;
+mod interpolate1d;
+mod leaky_relu;
 mod log;
 mod log1p;
+mod log_sigmoid;
 mod mask;
 mod matmul;
 mod maxmin;
+mod maxpool1d;
 mod maxpool2d;
+mod moving_average;
 mod mul;
 mod multithread;
 mod neg;
+mod no_grad;
+mod pixel_shuffle;
 mod pow;
+mod quantization;
 mod relu;
+mod repeat;
 mod reshape;
+mod round;
 mod select;
 mod sin;
 mod slice;
 mod softmax;
 mod sqrt;
+mod stack;
 mod sub;
 mod tanh;
 mod transpose;
+mod triangular;
 
 #[macro_export]
 macro_rules! testgen_all {
@@ -46,17 +68,28 @@ macro_rules! testgen_all {
 
         // Behavior
         burn_autodiff::testgen_ad_broadcast!();
+        burn_autodiff::testgen_ad_graph_stats!();
+        burn_autodiff::testgen_ad_double_backward!();
+        burn_autodiff::testgen_ad_no_grad!();
 
         // Activation
         burn_autodiff::testgen_ad_relu!();
+        burn_autodiff::testgen_ad_leaky_relu!();
         burn_autodiff::testgen_ad_gelu!();
+        burn_autodiff::testgen_ad_log_sigmoid!();
+        burn_autodiff::testgen_ad_gumbel_softmax!();
 
         // Modules
         burn_autodiff::testgen_ad_conv1d!();
         burn_autodiff::testgen_ad_conv2d!();
+        burn_autodiff::testgen_ad_max_pool1d!();
         burn_autodiff::testgen_ad_max_pool2d!();
         burn_autodiff::testgen_ad_avg_pool1d!();
         burn_autodiff::testgen_ad_avg_pool2d!();
+        burn_autodiff::testgen_ad_adaptive_avg_pool2d!();
+        burn_autodiff::testgen_ad_interpolate1d!();
+        burn_autodiff::testgen_ad_pixel_shuffle!();
+        burn_autodiff::testgen_ad_grid_sample!();
         burn_autodiff::testgen_module_backward!();
 
         // Tensor
@@ -65,13 +98,16 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_add!();
         burn_autodiff::testgen_ad_aggregation!();
         burn_autodiff::testgen_ad_maxmin!();
+        burn_autodiff::testgen_ad_clamp!();
         burn_autodiff::testgen_ad_cat!();
         burn_autodiff::testgen_ad_cos!();
         burn_autodiff::testgen_ad_cross_entropy_loss!();
+        burn_autodiff::testgen_ad_divergence!();
         burn_autodiff::testgen_ad_div!();
         burn_autodiff::testgen_ad_erf!();
         burn_autodiff::testgen_ad_exp!();
         burn_autodiff::testgen_ad_slice!();
+        burn_autodiff::testgen_ad_flip!();
         burn_autodiff::testgen_ad_gather_scatter!();
         burn_autodiff::testgen_ad_select!();
         burn_autodiff::testgen_ad_log!();
@@ -82,11 +118,19 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_neg!();
         burn_autodiff::testgen_ad_powf!();
         burn_autodiff::testgen_ad_reshape!();
+        burn_autodiff::testgen_ad_repeat!();
+        burn_autodiff::testgen_ad_round!();
         burn_autodiff::testgen_ad_sin!();
         burn_autodiff::testgen_ad_softmax!();
         burn_autodiff::testgen_ad_sqrt!();
+        burn_autodiff::testgen_ad_stack!();
         burn_autodiff::testgen_ad_sub!();
         burn_autodiff::testgen_ad_tanh!();
         burn_autodiff::testgen_ad_transpose!();
+        burn_autodiff::testgen_ad_triangular!();
+        burn_autodiff::testgen_ad_diff!();
+        burn_autodiff::testgen_ad_cumsum!();
+        burn_autodiff::testgen_ad_moving_average!();
+        burn_autodiff::testgen_ad_fake_quantize!();
     };
 }
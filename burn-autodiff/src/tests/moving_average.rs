@@ -0,0 +1,37 @@
+#[burn_tensor_testgen::testgen(ad_moving_average)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_moving_average() {
+        let tensor_1 =
+            TestADTensor::from_data(Data::from([1.0, 4.0, 2.0, 8.0, 5.0])).require_grad();
+        let weights = TestADTensor::from_data(Data::from([0.0, 1.0, 0.0]));
+
+        let tensor_2 = tensor_1.clone().moving_average(3, 0);
+        let tensor_3 = tensor_2.mul(weights);
+        let grads = tensor_3.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        // Only the second window contributes, uniformly over its elements.
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([0.0, 1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0, 0.0]), 5);
+    }
+
+    #[test]
+    fn should_diff_moving_average_all_windows() {
+        let tensor_1 = TestADTensor::from_data(Data::from([[1.0, 4.0, 2.0, 8.0]])).require_grad();
+
+        let tensor_2 = tensor_1.clone().moving_average(2, 1);
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.5, 1.0, 1.0, 0.5]]), 5);
+    }
+}
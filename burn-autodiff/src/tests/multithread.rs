@@ -82,4 +82,50 @@ mod tests {
         assert_eq!(grad_1.to_data(), grad_1_moved.to_data());
         assert_eq!(grad_2.to_data(), grad_2_moved.to_data());
     }
+
+    #[test]
+    fn should_not_lose_steps_with_concurrent_merges() {
+        let num_threads = 8;
+        let num_ops = 20;
+
+        for _ in 0..20 {
+            let tensor =
+                TestADTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]])).require_grad();
+            let barrier = std::sync::Arc::new(std::sync::Barrier::new(num_threads));
+
+            let handles = (0..num_threads)
+                .map(|_| {
+                    let tensor = tensor.clone();
+                    let barrier = barrier.clone();
+
+                    std::thread::spawn(move || {
+                        // The graph of each thread is bigger than the one of the shared tensor,
+                        // so every thread moves the steps of the shared graph concurrently.
+                        let ones = (0..num_ops)
+                            .fold(TestADTensor::ones([2, 2]).require_grad(), |ones, _| {
+                                ones.mul_scalar(1.0)
+                            });
+                        // Start the merges of all threads at the same time.
+                        barrier.wait();
+                        tensor.mul(ones)
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            let output = handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .reduce(|lhs, rhs| lhs.add(rhs))
+                .unwrap();
+
+            let grads = output.backward();
+            let grad = tensor.grad(&grads).unwrap();
+
+            let expected = num_threads as f32;
+            assert_eq!(
+                grad.to_data(),
+                Data::from([[expected, expected], [expected, expected]])
+            );
+        }
+    }
 }
@@ -0,0 +1,42 @@
+#[burn_tensor_testgen::testgen(ad_no_grad)]
+mod tests {
+    use super::*;
+    use burn_autodiff::no_grad;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_not_track_operations_inside_no_grad() {
+        let tensor_1 = TestADTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]])).require_grad();
+        let tensor_2 = TestADTensor::from_data(Data::from([[5.0, 6.0], [7.0, 8.0]])).require_grad();
+
+        let tensor_3 = no_grad(|| tensor_1.clone().mul(tensor_2.clone()));
+
+        assert!(!tensor_3.is_require_grad());
+        assert!(tensor_1.is_require_grad());
+
+        // The untracked tensor is a constant for the operations tracked afterward.
+        let tensor_4 = tensor_3.clone().mul(tensor_1.clone());
+        let grads = tensor_4.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        assert_eq!(grad_1.to_data(), tensor_3.into_data());
+        assert!(tensor_2.grad(&grads).is_none());
+    }
+
+    #[test]
+    fn should_restore_tracking_after_nested_no_grad() {
+        let tensor_1 = TestADTensor::from_data(Data::from([1.0, 2.0])).require_grad();
+
+        let tensor_2 = no_grad(|| {
+            let tensor = no_grad(|| tensor_1.clone().mul_scalar(2.0));
+            // Still untracked after the inner scope.
+            tensor.add(tensor_1.clone())
+        });
+        let tensor_3 = tensor_1.clone().mul_scalar(3.0).add(tensor_2);
+
+        let grads = tensor_3.backward();
+        let grad = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad.to_data(), Data::from([3.0, 3.0]));
+    }
+}
@@ -0,0 +1,22 @@
+#[burn_tensor_testgen::testgen(ad_pixel_shuffle)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{pixel_shuffle, pixel_unshuffle};
+    use burn_tensor::{Distribution, Tensor};
+
+    #[test]
+    fn test_pixel_shuffle_grad() {
+        let x =
+            Tensor::<TestADBackend, 4>::random([2, 8, 3, 2], Distribution::Default).require_grad();
+        let weights = Tensor::<TestADBackend, 4>::random([2, 2, 6, 4], Distribution::Default);
+
+        let output = pixel_shuffle(x.clone(), 2);
+        let grads = output.mul(weights.clone()).sum().backward();
+        let x_grad = x.grad(&grads).unwrap();
+
+        // The gradient of a permutation is the inverse permutation.
+        x_grad
+            .to_data()
+            .assert_approx_eq(&pixel_unshuffle(weights, 2).into_data().convert(), 3);
+    }
+}
@@ -25,4 +25,42 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::from([[23.5081, 25.2779], [26.0502, 28.6383]]), 3);
     }
+
+    #[test]
+    fn should_diff_powf_tensor() {
+        let base = TestADTensor::from_data([[1.0, 2.0], [3.0, 4.0]]).require_grad();
+        let exponent = TestADTensor::from_data([[2.0, 0.5], [1.5, 3.0]]).require_grad();
+
+        let output = base.clone().powf_tensor(exponent.clone()).sum();
+        let grads = output.backward();
+
+        let grad_base = base.grad(&grads).unwrap();
+        let grad_exponent = exponent.grad(&grads).unwrap();
+
+        grad_base
+            .to_data()
+            .assert_approx_eq(&Data::from([[2.0, 0.3536], [2.5981, 48.0]]), 3);
+        grad_exponent
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, 0.9803], [5.7085, 88.7228]]), 3);
+    }
+
+    #[test]
+    fn should_diff_powf_tensor_with_broadcast() {
+        let base = TestADTensor::from_data([[1.0, 2.0], [3.0, 4.0]]).require_grad();
+        let exponent = TestADTensor::from_data([[2.0], [1.0]]).require_grad();
+
+        let output = base.clone().powf_tensor(exponent.clone()).sum();
+        let grads = output.backward();
+
+        let grad_base = base.grad(&grads).unwrap();
+        let grad_exponent = exponent.grad(&grads).unwrap();
+
+        grad_base
+            .to_data()
+            .assert_approx_eq(&Data::from([[2.0, 4.0], [1.0, 1.0]]), 3);
+        grad_exponent
+            .to_data()
+            .assert_approx_eq(&Data::from([[2.7726], [8.8410]]), 3);
+    }
 }
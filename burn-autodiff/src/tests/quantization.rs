@@ -0,0 +1,46 @@
+#[burn_tensor_testgen::testgen(ad_fake_quantize)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_diff_fake_quantize_per_channel() {
+        let data = Data::<f32, 2>::from([[0.26, -0.74, 70.0], [0.1, -40.0, 3.0]]);
+
+        let tensor = TestADTensor::from_data(data).require_grad();
+        let scale = TestADTensor::from_floats([0.5, 0.25]);
+        let zero_point = Tensor::<TestADBackend, 1, Int>::from_ints([0, 10]);
+
+        let output = tensor
+            .clone()
+            .fake_quantize_per_channel(scale, zero_point, 0);
+        let grads = output.clone().mul_scalar(2.0).sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.5, -0.5, 63.5], [0.0, -34.5, 3.0]]), 3);
+        // The gradient is passed through, except for the clamped elements.
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[2.0, 2.0, 0.0], [2.0, 0.0, 2.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_dequantize_per_channel_scale() {
+        let tensor = Tensor::<TestADBackend, 2, Int>::from_ints([[1, 2, 3], [13, 8, 12]]);
+        let scale = TestADTensor::from_floats([0.5, 0.25, 1.0]).require_grad();
+        let zero_point = Tensor::<TestADBackend, 1, Int>::from_ints([0, 10, -1]);
+
+        let output = tensor.dequantize_per_channel(scale.clone(), zero_point, 1);
+        let grads = output.clone().mul_scalar(2.0).sum().backward();
+        let grad = scale.grad(&grads).unwrap();
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.5, -2.0, 4.0], [6.5, -0.5, 13.0]]), 3);
+        // The gradient of a scale is the sum of the quantized values of its channel, shifted by
+        // the zero point.
+        grad.to_data()
+            .assert_approx_eq(&Data::from([28.0, -20.0, 34.0]), 3);
+    }
+}
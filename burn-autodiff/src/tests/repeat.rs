@@ -0,0 +1,48 @@
+#[burn_tensor_testgen::testgen(ad_repeat)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_repeat() {
+        let data_1: Data<f32, 2> = Data::from([[1.0, 7.0], [2.0, 3.0]]);
+        let data_2: Data<f32, 2> = Data::from([[1.0], [2.0], [3.0], [4.0], [5.0], [6.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data_1).require_grad();
+        let tensor_2 = TestADTensor::from_data(data_2).require_grad();
+
+        let tensor_3 = tensor_1.clone().repeat(1, 3);
+        let tensor_4 = tensor_3.matmul(tensor_2.clone());
+        let grads = tensor_4.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[9.0, 12.0], [9.0, 12.0]]));
+        assert_eq!(
+            grad_2.to_data(),
+            Data::from([[3.0], [10.0], [3.0], [10.0], [3.0], [10.0]])
+        );
+    }
+
+    #[test]
+    fn should_diff_repeat_single_row() {
+        let data_1: Data<f32, 2> = Data::from([[1.0, 2.0]]);
+        let data_2: Data<f32, 2> = Data::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data_1).require_grad();
+        let tensor_2 = TestADTensor::from_data(data_2).require_grad();
+
+        let tensor_3 = tensor_1.clone().repeat(0, 3).mul(tensor_2.clone());
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[9.0, 12.0]]));
+        assert_eq!(
+            grad_2.to_data(),
+            Data::from([[1.0, 2.0], [1.0, 2.0], [1.0, 2.0]])
+        );
+    }
+}
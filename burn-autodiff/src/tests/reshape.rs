@@ -21,4 +21,27 @@ mod tests {
         assert_eq!(grad_1.to_data(), Data::from([[11.0, 5.0], [11.0, 5.0]]));
         assert_eq!(grad_2.to_data(), Data::from([3.0, 3.0, 10.0, 10.0]));
     }
+
+    #[test]
+    fn should_diff_flatten_and_unsqueeze_dim() {
+        let data_1: Data<f32, 3> = Data::from([[[1.0], [7.0]], [[2.0], [3.0]]]);
+        let data_2: Data<f32, 1> = Data::from([4.0, 7.0]);
+
+        let tensor_1 = TestADTensor::from_data(data_1).require_grad();
+        let tensor_2 = TestADTensor::from_data(data_2).require_grad();
+
+        let tensor_3 = tensor_1.clone().flatten::<2>(1, 2);
+        let tensor_4 = tensor_2.clone().unsqueeze_dim::<2>(1);
+        let tensor_5 = tensor_3.matmul(tensor_4);
+        let grads = tensor_5.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(
+            grad_1.to_data(),
+            Data::from([[[4.0], [7.0]], [[4.0], [7.0]]])
+        );
+        assert_eq!(grad_2.to_data(), Data::from([3.0, 10.0]));
+    }
 }
@@ -0,0 +1,54 @@
+#[burn_tensor_testgen::testgen(ad_round)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_floor() {
+        let data_1 = Data::<f32, 2>::from([[1.2, 7.5], [2.5, -3.7]]);
+        let data_2 = Data::<f32, 2>::from([[4.0, 7.0], [2.0, 3.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data_1).require_grad();
+        let tensor_2 = TestADTensor::from_data(data_2).require_grad();
+
+        let tensor_3 = tensor_1.clone().floor().add(tensor_1.clone());
+        let tensor_4 = tensor_3.matmul(tensor_2.clone());
+        let grads = tensor_4.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        // Only the identity branch contributes to the gradient of tensor_1.
+        assert_eq!(grad_1.to_data(), Data::from([[11.0, 5.0], [11.0, 5.0]]));
+        grad_2
+            .to_data()
+            .assert_approx_eq(&Data::from([[6.7, 6.7], [6.8, 6.8]]), 3);
+    }
+
+    #[test]
+    fn should_diff_ceil() {
+        let data = Data::<f32, 2>::from([[1.2, 7.5], [2.5, -3.7]]);
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+        let tensor_2 = tensor_1.clone().ceil();
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[0.0, 0.0], [0.0, 0.0]]));
+    }
+
+    #[test]
+    fn should_diff_round() {
+        let data = Data::<f32, 2>::from([[1.2, 7.5], [2.5, -3.7]]);
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+        let tensor_2 = tensor_1.clone().round().mul(tensor_1.clone());
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        // d(round(x) * x)/dx = round(x)
+        assert_eq!(grad_1.to_data(), Data::from([[1.0, 8.0], [2.0, -4.0]]));
+    }
+}
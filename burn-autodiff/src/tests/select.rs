@@ -49,4 +49,49 @@ mod tests {
             Data::from([[64., 64., 64.], [19., 19., 19.]])
         );
     }
+
+    #[test]
+    fn test_select_by_mask_grad() {
+        let tensor_1 =
+            TestADTensor::from_data(Data::from([[1.0, 5.0, 2.0], [7.0, 3.0, 9.0]])).require_grad();
+        let mask = tensor_1.clone().greater_elem(4.0);
+
+        let (values, indices) = tensor_1.clone().select_by_mask(mask);
+        let values = values * TestADTensor::from_data(Data::from([1.0, 2.0, 3.0]));
+        let tensor_2 = TestADTensor::zeros([6])
+            .select_assign(0, indices, values)
+            .reshape([2, 3]);
+
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(
+            grad_1.into_data(),
+            Data::from([[0.0, 1.0, 0.0], [2.0, 0.0, 3.0]])
+        );
+    }
+
+    #[test]
+    fn test_batched_index_select_grad() {
+        let tensor_1 = TestADTensor::from_data(Data::from([
+            [[0.0, 1.0], [2.0, 3.0], [4.0, 5.0]],
+            [[6.0, 7.0], [8.0, 9.0], [10.0, 11.0]],
+        ]))
+        .require_grad();
+        let indices = TestADTensor::from_data(Data::from([[2, 0], [1, 1]]));
+
+        let tensor_2 = tensor_1.clone().batched_index_select(1, indices);
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(
+            grad_1.into_data(),
+            Data::from([
+                [[1.0, 1.0], [0.0, 0.0], [1.0, 1.0]],
+                [[0.0, 0.0], [2.0, 2.0], [0.0, 0.0]]
+            ])
+        );
+    }
 }
@@ -25,6 +25,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_diff_chunk() {
+        let data: Data<f32, 2> = Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let tensor = TestADTensor::from_data(data).require_grad();
+
+        let chunks = tensor.clone().chunk(2, 1);
+        let tensor_2 = chunks[0].clone().mul_scalar(2.0).sum();
+        let tensor_3 = chunks[1].clone().mul_scalar(3.0).sum();
+        let grads = tensor_2.add(tensor_3).backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        assert_eq!(
+            grad.to_data(),
+            Data::from([[2.0, 2.0, 3.0], [2.0, 2.0, 3.0]])
+        );
+    }
+
     #[test]
     fn should_diff_matmul_with_slice_assign() {
         let data_1: Data<f32, 2> = Data::from([[1.0, 7.0], [2.0, 3.0]]);
@@ -0,0 +1,44 @@
+#[burn_tensor_testgen::testgen(ad_stack)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_diff_stack() {
+        let tensor_1 = TestADTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]).require_grad();
+        let tensor_2 =
+            TestADTensor::from_data([[7.0, 8.0, 9.0], [10.0, 11.0, 12.0]]).require_grad();
+        let tensor_3 =
+            TestADTensor::from_data([[13.0, 14.0, 15.0], [16.0, 17.0, 18.0]]).require_grad();
+        let weights = TestADTensor::from_data([
+            [[1.0, 1.0, 1.0], [1.0, 1.0, 1.0]],
+            [[2.0, 2.0, 2.0], [2.0, 2.0, 2.0]],
+            [[3.0, 4.0, 5.0], [6.0, 7.0, 8.0]],
+        ]);
+
+        let tensor_4 = Tensor::stack::<3>(
+            vec![tensor_1.clone(), tensor_2.clone(), tensor_3.clone()],
+            0,
+        );
+        assert_eq!(tensor_4.dims(), [3, 2, 3]);
+
+        let grads = tensor_4.mul(weights).sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+        let grad_3 = tensor_3.grad(&grads).unwrap();
+
+        assert_eq!(
+            grad_1.to_data(),
+            Data::from([[1.0, 1.0, 1.0], [1.0, 1.0, 1.0]])
+        );
+        assert_eq!(
+            grad_2.to_data(),
+            Data::from([[2.0, 2.0, 2.0], [2.0, 2.0, 2.0]])
+        );
+        assert_eq!(
+            grad_3.to_data(),
+            Data::from([[3.0, 4.0, 5.0], [6.0, 7.0, 8.0]])
+        );
+    }
+}
@@ -0,0 +1,274 @@
+use alloc::vec::Vec;
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use rand_distr::Beta;
+
+use crate::tensor::backend::Backend;
+use crate::tensor::{Data, Distribution, ElementConversion, Int, Shape, Tensor};
+
+/// A batch of inputs augmented by mixing each sample with another sample of the batch.
+#[derive(Debug)]
+pub struct MixedBatch<B: Backend, const D: usize> {
+    /// The mixed inputs.
+    pub inputs: Tensor<B, D>,
+    /// The mixed targets.
+    pub targets: Tensor<B, 2>,
+    /// The proportion of each sample kept from the original batch.
+    pub lambda: f64,
+    /// The index of the sample each sample was mixed with.
+    pub permutation: Vec<usize>,
+}
+
+/// Applies mixup to a batch, blending each sample with another sample of the batch, as
+/// described in the paper [mixup: Beyond Empirical Risk Minimization](https://arxiv.org/abs/1710.09412).
+///
+/// `x = lambda * x_i + (1 - lambda) * x_j`, where `lambda` is sampled from `Beta(alpha, alpha)`
+/// with the backend's random number generator, the targets being mixed the same way.
+///
+/// Each sample is mixed with the sample at its position in a random permutation of the batch.
+///
+/// # Shapes
+///
+/// - inputs: `[batch_size, ...]`
+/// - targets: `[batch_size, num_classes]`, as one-hot encoded or soft labels.
+pub fn mixup<B: Backend, const D: usize>(
+    inputs: Tensor<B, D>,
+    targets: Tensor<B, 2>,
+    alpha: f64,
+) -> MixedBatch<B, D> {
+    let mut rng = seeded_rng::<B>();
+    let lambda = sample_beta(&mut rng, alpha);
+    let permutation = sample_permutation(&mut rng, inputs.dims()[0]);
+
+    mix(inputs, targets, lambda, permutation)
+}
+
+/// Applies cutmix to a batch of images, pasting a random box of another sample of the batch
+/// into each sample, as described in the paper
+/// [CutMix: Regularization Strategy to Train Strong Classifiers with Localizable Features](https://arxiv.org/abs/1905.04899).
+///
+/// The box covers `1 - lambda` of the image, where `lambda` is sampled from `Beta(alpha, alpha)`
+/// with the backend's random number generator. Since the box is clipped to the image borders,
+/// `lambda` is then adjusted to the area actually kept before mixing the targets.
+///
+/// Each sample receives the box of the sample at its position in a random permutation of the
+/// batch.
+///
+/// # Shapes
+///
+/// - inputs: `[batch_size, channels, height, width]`
+/// - targets: `[batch_size, num_classes]`, as one-hot encoded or soft labels.
+pub fn cutmix<B: Backend>(
+    inputs: Tensor<B, 4>,
+    targets: Tensor<B, 2>,
+    alpha: f64,
+) -> MixedBatch<B, 4> {
+    let [batch_size, _, height, width] = inputs.dims();
+    let mut rng = seeded_rng::<B>();
+    let lambda = sample_beta(&mut rng, alpha);
+    let permutation = sample_permutation(&mut rng, batch_size);
+    let ratio = libm::sqrt(1.0 - lambda);
+    let center: [f64; 2] = [rng.gen(), rng.gen()];
+
+    let bounds = |size: usize, center: f64| {
+        let center = (center * size as f64) as usize;
+        let half = (size as f64 * ratio) as usize / 2;
+
+        center.saturating_sub(half)..usize::min(center + half, size)
+    };
+    let rows = bounds(height, center[0]);
+    let columns = bounds(width, center[1]);
+
+    let mut mask = alloc::vec![0.0; height * width];
+    for row in rows.clone() {
+        for column in columns.clone() {
+            mask[row * width + column] = 1.0;
+        }
+    }
+    let mask = Tensor::<B, 4>::from_data_device(
+        Data::new(mask, Shape::new([1, 1, height, width])).convert(),
+        &inputs.device(),
+    );
+    let lambda = 1.0 - (rows.len() * columns.len()) as f64 / (height * width) as f64;
+
+    let inputs_permuted = permute(inputs.clone(), &permutation);
+    let inputs = inputs * mask.clone().neg().add_scalar(1.0) + inputs_permuted * mask;
+
+    MixedBatch {
+        inputs,
+        targets: mix_targets(targets, lambda, &permutation),
+        lambda,
+        permutation,
+    }
+}
+
+fn mix<B: Backend, const D: usize>(
+    inputs: Tensor<B, D>,
+    targets: Tensor<B, 2>,
+    lambda: f64,
+    permutation: Vec<usize>,
+) -> MixedBatch<B, D> {
+    let inputs =
+        inputs.clone().mul_scalar(lambda) + permute(inputs, &permutation).mul_scalar(1.0 - lambda);
+
+    MixedBatch {
+        inputs,
+        targets: mix_targets(targets, lambda, &permutation),
+        lambda,
+        permutation,
+    }
+}
+
+fn mix_targets<B: Backend>(
+    targets: Tensor<B, 2>,
+    lambda: f64,
+    permutation: &[usize],
+) -> Tensor<B, 2> {
+    targets.clone().mul_scalar(lambda) + permute(targets, permutation).mul_scalar(1.0 - lambda)
+}
+
+/// Reorder the samples of the batch following the permutation.
+fn permute<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    permutation: &[usize],
+) -> Tensor<B, D> {
+    let indices = permutation.iter().map(|index| *index as i64).collect();
+    let indices = Tensor::<B, 1, Int>::from_data_device(
+        Data::new(indices, Shape::new([permutation.len()])).convert(),
+        &tensor.device(),
+    );
+
+    tensor.select(0, indices)
+}
+
+/// Create a generator seeded from the backend's random values, so that the augmentations are
+/// reproducible with the backend seed.
+fn seeded_rng<B: Backend>() -> StdRng {
+    let seed = Tensor::<B, 1>::random([8], Distribution::Uniform(0.elem(), 256.elem()))
+        .into_data()
+        .value
+        .into_iter()
+        .fold(0u64, |seed, value| {
+            (seed << 8) | (value.elem::<f64>() as u64 & 0xFF)
+        });
+
+    StdRng::seed_from_u64(seed)
+}
+
+/// Sample from `Beta(alpha, alpha)`, a non-positive `alpha` disabling the mixing.
+fn sample_beta(rng: &mut StdRng, alpha: f64) -> f64 {
+    if alpha <= 0.0 {
+        return 1.0;
+    }
+
+    rng.sample(Beta::new(alpha, alpha).unwrap())
+}
+
+fn sample_permutation(rng: &mut StdRng, batch_size: usize) -> Vec<usize> {
+    let mut permutation = (0..batch_size).collect::<Vec<_>>();
+    permutation.shuffle(rng);
+
+    permutation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_mixup_with_lambda_one_returns_original_batch() {
+        let inputs = Tensor::<TestBackend, 3>::random([4, 2, 3], Distribution::Default);
+        let targets =
+            Tensor::<TestBackend, 2>::from_data([[1.0, 0.0], [0.0, 1.0], [0.0, 1.0], [1.0, 0.0]]);
+
+        let batch = mix(inputs.clone(), targets.clone(), 1.0, vec![3, 2, 0, 1]);
+
+        batch
+            .inputs
+            .into_data()
+            .assert_approx_eq(&inputs.into_data(), 5);
+        batch
+            .targets
+            .into_data()
+            .assert_approx_eq(&targets.into_data(), 5);
+    }
+
+    #[test]
+    fn test_mixup_mixes_targets() {
+        let inputs = Tensor::<TestBackend, 2>::from_data([[1.0, 2.0], [3.0, 4.0]]);
+        let targets = Tensor::<TestBackend, 2>::from_data([[1.0, 0.0], [0.0, 1.0]]);
+
+        let batch = mixup(inputs, targets.clone(), 0.4);
+        let lambda = batch.lambda as f32;
+        let expected = targets.clone().mul_scalar(lambda)
+            + permute(targets, &batch.permutation).mul_scalar(1.0 - lambda);
+
+        assert!((0.0..=1.0).contains(&lambda));
+        batch
+            .targets
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn test_mixup_permutes_the_batch() {
+        let batch_size = 8;
+        let inputs = Tensor::<TestBackend, 2>::zeros([batch_size, 2]);
+        let targets = Tensor::<TestBackend, 2>::zeros([batch_size, 2]);
+
+        let batch = mixup(inputs, targets, 0.4);
+        let mut permutation = batch.permutation.clone();
+        permutation.sort();
+
+        assert_eq!(permutation, (0..batch_size).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_sample_beta_large_alpha() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let num_samples = 1000;
+
+        let samples = (0..num_samples)
+            .map(|_| sample_beta(&mut rng, 100.0))
+            .collect::<Vec<_>>();
+        let mean = samples.iter().sum::<f64>() / num_samples as f64;
+
+        // Beta(100, 100) is concentrated around 0.5 with a standard deviation of 0.035.
+        assert!(samples.iter().all(|lambda| (0.0..=1.0).contains(lambda)));
+        assert!((mean - 0.5).abs() < 0.01, "{mean}");
+    }
+
+    #[test]
+    fn test_cutmix_pasted_area_fraction() {
+        let inputs = Tensor::<TestBackend, 4>::cat(
+            vec![Tensor::zeros([1, 1, 32, 32]), Tensor::ones([1, 1, 32, 32])],
+            0,
+        );
+        let targets = Tensor::<TestBackend, 2>::from_data([[1.0, 0.0], [0.0, 1.0]]);
+        let num_trials = 500;
+
+        let mut area_total = 0.0;
+        let mut num_pasted = 0;
+        for _ in 0..num_trials {
+            let batch = cutmix(inputs.clone(), targets.clone(), 1.0);
+            let area = batch
+                .inputs
+                .slice([0..1, 0..1, 0..32, 0..32])
+                .mean()
+                .into_scalar() as f64;
+
+            // The first sample is only pasted ones when its partner is the second sample.
+            if batch.permutation[0] == 1 {
+                assert!((area - (1.0 - batch.lambda)).abs() < 1e-5);
+                area_total += area;
+                num_pasted += 1;
+            }
+        }
+
+        // With lambda ~ U(0, 1) and the box clipped to the borders, the expected area fraction is
+        // E[(r - r^2 / 4)^2] = 0.3208, where r = sqrt(1 - lambda).
+        let area_mean = area_total / num_pasted as f64;
+        assert!((area_mean - 0.3208).abs() < 0.05, "{area_mean}");
+    }
+}
@@ -1,3 +1,6 @@
+/// Batch augmentation module.
+pub mod augmentation;
+
 /// Dataloader module.
 pub mod dataloader;
 
@@ -182,6 +182,25 @@ pub trait Module<B: Backend>: Clone + Send + Sync + core::fmt::Debug {
 
     /// Convert the module into a record containing the state.
     fn into_record(self) -> Self::Record;
+
+    /// Update part of the module state using its record, the rest of the state being kept as is.
+    ///
+    /// This is useful to override a few parameters of a freshly initialized module, such as
+    /// loading pretrained embeddings while the other parameters keep their initialization.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let model = config
+    ///     .init::<B>()
+    ///     .update_record(|record| record.embedding.weight = Param::from(pretrained));
+    /// ```
+    fn update_record<F: FnOnce(&mut Self::Record)>(self, update: F) -> Self {
+        let mut record = self.clone().into_record();
+        update(&mut record);
+
+        self.load_record(record)
+    }
 }
 
 /// Module visitor trait.
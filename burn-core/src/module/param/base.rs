@@ -14,6 +14,18 @@ impl<T> core::fmt::Display for Param<T> {
     }
 }
 
+impl<T> Param<T> {
+    /// Gets the parameter id.
+    ///
+    /// # Returns
+    ///
+    /// The id passed to [visitors](crate::module::ModuleVisitor) and
+    /// [mappers](crate::module::ModuleMapper) for this parameter.
+    pub fn id(&self) -> &ParamId {
+        &self.id
+    }
+}
+
 impl<T: Clone> Param<T> {
     /// Gets the parameter value.
     ///
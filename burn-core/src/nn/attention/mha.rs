@@ -208,11 +208,9 @@ impl<B: Backend> MultiHeadAttention<B> {
     }
 
     fn attn_scores(&self, query: Tensor<B, 4>, key: Tensor<B, 4>) -> Tensor<B, 4> {
-        let attn_scores = query
+        query
             .matmul(key.transpose())
-            .div_scalar(sqrtf(self.d_k as f32));
-
-        self.dropout.forward(attn_scores)
+            .div_scalar(sqrtf(self.d_k as f32))
     }
 
     fn attn_weights(
@@ -239,7 +237,8 @@ impl<B: Backend> MultiHeadAttention<B> {
             );
         }
 
-        activation::softmax(attn_scores, 3)
+        // Dropout is applied on the probabilities, zeroing a score wouldn't drop the connection.
+        self.dropout.forward(activation::softmax(attn_scores, 3))
     }
 
     fn attention_linear(&self, x: Tensor<B, 3>, linear: &nn::Linear<B>) -> Tensor<B, 4> {
@@ -406,6 +405,42 @@ mod tests {
             );
     }
 
+    #[test]
+    fn test_self_attention_masked_positions_have_zero_weights() {
+        let [batch_size, seq_length, d_model, n_heads, num_padded] = [3, 6, 32, 2, 2];
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads).init::<TestBackend>();
+
+        let mask_pad: Tensor<TestBackend, 2, Int> = Tensor::zeros([batch_size, seq_length]);
+        let mask_pad = mask_pad.slice_assign(
+            [0..batch_size, seq_length - num_padded..seq_length],
+            Tensor::ones([batch_size, num_padded]),
+        );
+        let mask_pad = mask_pad.equal_elem(1);
+
+        let input = MhaInput::self_attn(Tensor::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+        ))
+        .mask_pad(mask_pad);
+
+        let output = mha.forward(input);
+
+        output
+            .weights
+            .slice([
+                0..batch_size,
+                0..n_heads,
+                0..seq_length,
+                seq_length - num_padded..seq_length,
+            ])
+            .into_data()
+            .assert_approx_eq(
+                &Tensor::<TestBackend, 4>::zeros([batch_size, n_heads, seq_length, num_padded])
+                    .into_data(),
+                3,
+            );
+    }
+
     #[test]
     fn test_autoregressive_mask_should_have_same_output_as_autoregressive_decoding() {
         let [batch_size, seq_length, d_model, n_heads] = [3, 4, 12, 2];
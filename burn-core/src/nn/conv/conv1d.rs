@@ -3,9 +3,10 @@ use crate as burn;
 use crate::config::Config;
 use crate::module::Module;
 use crate::module::Param;
-use crate::nn::{Initializer, PaddingConfig1d};
+use crate::nn::{Initializer, PadMode, PaddingConfig1d};
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
+use alloc::vec;
 use burn_tensor::module::conv1d;
 use burn_tensor::ops::ConvOptions;
 use libm::sqrt;
@@ -31,6 +32,9 @@ pub struct Conv1dConfig {
     /// The padding configuration.
     #[config(default = "PaddingConfig1d::Valid")]
     pub padding: PaddingConfig1d,
+    /// The values used to fill the padded area.
+    #[config(default = "PadMode::Zeros")]
+    pub pad_mode: PadMode,
     /// If bias should be added to the output.
     #[config(default = true)]
     pub bias: bool,
@@ -57,13 +61,16 @@ pub struct Conv1d<B: Backend> {
     dilation: usize,
     groups: usize,
     padding: PaddingConfig1d,
+    pad_mode: PadMode,
 }
 
 impl Conv1dConfig {
     /// Initialize a new [conv1d](Conv1d) module.
     pub fn init<B: Backend>(&self) -> Conv1d<B> {
-        let shape = [self.channels_out, self.channels_in, self.kernel_size];
-        let fan_in: usize = self.channels_in * self.kernel_size;
+        // Each output channel only sees the input channels of its group.
+        let channels_per_group = self.channels_in / self.groups;
+        let shape = [self.channels_out, channels_per_group, self.kernel_size];
+        let fan_in: usize = channels_per_group * self.kernel_size;
         let weight = self.initializer.init_with(shape, Some(fan_in), None);
         let bias = if self.bias {
             Some(
@@ -80,6 +87,7 @@ impl Conv1dConfig {
             stride: self.stride,
             kernel_size: self.kernel_size,
             padding: self.padding.clone(),
+            pad_mode: self.pad_mode.clone(),
             dilation: self.dilation,
             groups: self.groups,
         }
@@ -92,6 +100,7 @@ impl Conv1dConfig {
             stride: self.stride,
             kernel_size: self.kernel_size,
             padding: self.padding.clone(),
+            pad_mode: self.pad_mode.clone(),
             dilation: self.dilation,
             groups: self.groups,
         }
@@ -111,6 +120,11 @@ impl<B: Backend> Conv1d<B> {
             .padding
             .calculate_padding_1d(length, self.kernel_size, self.stride);
 
+        let (input, padding) = match self.pad_mode {
+            PadMode::Zeros => (input, padding),
+            PadMode::Circular => (pad_circular(input, padding), 0),
+        };
+
         conv1d(
             input,
             self.weight.val(),
@@ -120,8 +134,31 @@ impl<B: Backend> Conv1d<B> {
     }
 }
 
+/// Pad the last dimension of the input by wrapping its values around.
+fn pad_circular<B: Backend>(input: Tensor<B, 3>, padding: usize) -> Tensor<B, 3> {
+    if padding == 0 {
+        return input;
+    }
+
+    let [batch_size, channels, length] = input.dims();
+
+    if padding > length {
+        panic!("Circular padding ({padding}) can't be larger than the input length ({length})");
+    }
+
+    let left = input
+        .clone()
+        .slice([0..batch_size, 0..channels, length - padding..length]);
+    let right = input
+        .clone()
+        .slice([0..batch_size, 0..channels, 0..padding]);
+
+    Tensor::cat(vec![left, input, right], 2)
+}
+
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
     use burn_tensor::Data;
     use libm::sqrt;
 
@@ -147,6 +184,21 @@ mod tests {
         conv.weight.to_data().assert_within_range(-k..k);
     }
 
+    #[test]
+    fn initializer_fan_in_with_groups() {
+        TestBackend::seed(0);
+
+        let config = Conv1dConfig::new(4, 64, 5).with_groups(4);
+        let k = sqrt(1.0 / (config.channels_in / config.groups * config.kernel_size) as f64) as f32;
+        let k_ungrouped = sqrt(1.0 / (config.channels_in * config.kernel_size) as f64) as f32;
+        let conv = config.init::<TestBackend>();
+
+        assert_eq!(conv.weight.shape().dims, [64, 1, 5]);
+        let weight = conv.weight.to_data();
+        weight.assert_within_range(-k..k);
+        assert!(weight.value.iter().any(|value| value.abs() > k_ungrouped));
+    }
+
     #[test]
     fn initializer_zeros() {
         TestBackend::seed(0);
@@ -160,6 +212,32 @@ mod tests {
             .assert_approx_eq(&Data::zeros(conv.weight.shape()), 3);
     }
 
+    #[test]
+    fn forward_circular_padding() {
+        let config = Conv1dConfig::new(1, 1, 3)
+            .with_padding(PaddingConfig1d::Same)
+            .with_pad_mode(PadMode::Circular)
+            .with_bias(false);
+        let mut conv = config.init::<TestBackend>();
+        conv.weight = Param::from(Tensor::from_floats([[[1.0, 2.0, 3.0]]]));
+        let signal = [1.0, 2.0, 3.0, 4.0, 5.0];
+
+        let output = conv.forward(Tensor::from_floats([[signal]]));
+
+        // Manual wrap-around reference: y[i] = sum_k w[k] * x[(i + k - 1) mod n]
+        let weights = [1.0, 2.0, 3.0];
+        let expected: Vec<f32> = (0..signal.len())
+            .map(|i| {
+                (0..weights.len())
+                    .map(|k| weights[k] * signal[(i + k + signal.len() - 1) % signal.len()])
+                    .sum()
+            })
+            .collect();
+        output
+            .into_data()
+            .assert_approx_eq(&Data::new(expected, [1, 1, 5].into()), 3);
+    }
+
     #[test]
     fn configured_custom() {
         let config = Conv1dConfig::new(2, 2, 2)
@@ -177,7 +255,7 @@ mod tests {
         assert!(conv.bias.is_none());
         assert_eq!(conv.dilation, 2);
         assert_eq!(conv.groups, 2);
-        assert_eq!(conv.weight.shape().dims, [2, 2, 2]);
-        assert_eq!(conv.weight.to_data(), Data::zeros([2, 2, 2]));
+        assert_eq!(conv.weight.shape().dims, [2, 1, 2]);
+        assert_eq!(conv.weight.to_data(), Data::zeros([2, 1, 2]));
     }
 }
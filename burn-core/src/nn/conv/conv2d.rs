@@ -33,6 +33,11 @@ pub struct Conv2dConfig {
     /// If bias should be added to the output.
     #[config(default = true)]
     pub bias: bool,
+    /// If the weights should be standardized to zero mean and unit variance for each output
+    /// channel before the convolution, as described in
+    /// [Micro-Batch Training with Batch-Channel Normalization and Weight Standardization](https://arxiv.org/abs/1903.10520).
+    #[config(default = false)]
+    pub weight_standardization: bool,
     /// The type of function used to initialize neural network parameters
     #[config(default = "Initializer::KaimingUniform{gain:1.0/sqrt(3.0),fan_out_only:false}")]
     pub initializer: Initializer,
@@ -56,18 +61,21 @@ pub struct Conv2d<B: Backend> {
     dilation: [usize; 2],
     groups: usize,
     padding: PaddingConfig2d,
+    weight_standardization: bool,
 }
 
 impl Conv2dConfig {
     /// Initialize a new [conv2d](Conv2d) module.
     pub fn init<B: Backend>(&self) -> Conv2d<B> {
+        // Each output channel only sees the input channels of its group.
+        let channels_per_group = self.channels[0] / self.groups;
         let shape = [
             self.channels[1],
-            self.channels[0],
+            channels_per_group,
             self.kernel_size[0],
             self.kernel_size[1],
         ];
-        let fan_in = self.channels[0] * self.kernel_size.iter().product::<usize>();
+        let fan_in = channels_per_group * self.kernel_size.iter().product::<usize>();
         let weight = self.initializer.init_with(shape, Some(fan_in), None);
         let bias = if self.bias {
             Some(
@@ -86,6 +94,7 @@ impl Conv2dConfig {
             dilation: self.dilation,
             padding: self.padding.clone(),
             groups: self.groups,
+            weight_standardization: self.weight_standardization,
         }
     }
 
@@ -99,6 +108,7 @@ impl Conv2dConfig {
             kernel_size: self.kernel_size,
             padding: self.padding.clone(),
             groups: self.groups,
+            weight_standardization: self.weight_standardization,
         }
     }
 }
@@ -115,23 +125,50 @@ impl<B: Backend> Conv2d<B> {
         let padding =
             self.padding
                 .calculate_padding_2d(height_in, width_in, &self.kernel_size, &self.stride);
+        let weight = match self.weight_standardization {
+            true => standardize_weight(self.weight.val()),
+            false => self.weight.val(),
+        };
+
         conv2d(
             input,
-            self.weight.val(),
+            weight,
             self.bias.as_ref().map(|bias| bias.val()),
             ConvOptions::new(self.stride, padding, self.dilation, self.groups),
         )
     }
 }
 
+const WEIGHT_STANDARDIZATION_EPSILON: f64 = 1e-5;
+
+/// Standardize the weights to zero mean and unit variance over the input channels and the
+/// kernel of each output channel.
+fn standardize_weight<B: Backend>(weight: Tensor<B, 4>) -> Tensor<B, 4> {
+    let shape = weight.shape();
+    let [channels_out, channels_in, kernel_size_1, kernel_size_2] = shape.dims;
+
+    let weight = weight.reshape([channels_out, channels_in * kernel_size_1 * kernel_size_2]);
+    let (var, mean) = weight.clone().var_mean_bias(1);
+    let weight = weight
+        .sub(mean)
+        .div(var.add_scalar(WEIGHT_STANDARDIZATION_EPSILON).sqrt());
+
+    weight.reshape(shape)
+}
+
 #[cfg(test)]
 mod tests {
-    use burn_tensor::Data;
+    use burn_tensor::{Data, Distribution};
 
     use super::*;
-    use crate::TestBackend;
     use libm::sqrt;
 
+    #[cfg(feature = "std")]
+    use crate::{TestADBackend, TestBackend};
+
+    #[cfg(not(feature = "std"))]
+    use crate::TestBackend;
+
     #[test]
     fn initializer_default() {
         TestBackend::seed(0);
@@ -151,6 +188,22 @@ mod tests {
         conv.weight.to_data().assert_within_range(-k..k);
     }
 
+    #[test]
+    fn initializer_fan_in_with_groups() {
+        TestBackend::seed(0);
+
+        let config = Conv2dConfig::new([4, 64], [3, 3]).with_groups(4);
+        let kernel_size = config.kernel_size[0] * config.kernel_size[1];
+        let k = sqrt(1.0 / (config.channels[0] / config.groups * kernel_size) as f64) as f32;
+        let k_ungrouped = sqrt(1.0 / (config.channels[0] * kernel_size) as f64) as f32;
+        let conv = config.init::<TestBackend>();
+
+        assert_eq!(conv.weight.shape().dims, [64, 1, 3, 3]);
+        let weight = conv.weight.to_data();
+        weight.assert_within_range(-k..k);
+        assert!(weight.value.iter().any(|value| value.abs() > k_ungrouped));
+    }
+
     #[test]
     fn initializer_zeros() {
         TestBackend::seed(0);
@@ -163,4 +216,87 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::zeros(conv.weight.shape()), 3);
     }
+
+    #[test]
+    fn forward_shape() {
+        let config = Conv2dConfig::new([4, 6], [3, 3])
+            .with_stride([2, 1])
+            .with_padding(PaddingConfig2d::Explicit(1, 0))
+            .with_dilation([1, 2])
+            .with_groups(2);
+        let conv = config.init::<TestBackend>();
+
+        let output = conv.forward(Tensor::random([2, 4, 8, 9], Distribution::Default));
+
+        // height: (8 + 2 * 1 - 3) / 2 + 1 = 4, width: (9 - 2 * (3 - 1) - 1) / 1 + 1 = 5
+        assert_eq!(output.dims(), [2, 6, 4, 5]);
+    }
+
+    #[test]
+    fn forward_same_padding() {
+        let config = Conv2dConfig::new([3, 5], [3, 5]).with_padding(PaddingConfig2d::Same);
+        let conv = config.init::<TestBackend>();
+
+        let output = conv.forward(Tensor::random([1, 3, 7, 6], Distribution::Default));
+
+        assert_eq!(output.dims(), [1, 5, 7, 6]);
+    }
+
+    #[test]
+    fn configured_custom() {
+        let config = Conv2dConfig::new([2, 2], [2, 2])
+            .with_padding(PaddingConfig2d::Explicit(2, 1))
+            .with_stride([2, 3])
+            .with_bias(false)
+            .with_dilation([2, 1])
+            .with_groups(2)
+            .with_initializer(Initializer::Zeros);
+
+        let conv = config.init::<TestBackend>();
+
+        assert_eq!(conv.padding, PaddingConfig2d::Explicit(2, 1));
+        assert_eq!(conv.stride, [2, 3]);
+        assert!(conv.bias.is_none());
+        assert_eq!(conv.dilation, [2, 1]);
+        assert_eq!(conv.groups, 2);
+        assert_eq!(conv.weight.shape().dims, [2, 1, 2, 2]);
+        assert_eq!(conv.weight.to_data(), Data::zeros([2, 1, 2, 2]));
+    }
+
+    #[test]
+    fn weight_standardization() {
+        TestBackend::seed(0);
+
+        let conv = Conv2dConfig::new([3, 4], [3, 3])
+            .with_weight_standardization(true)
+            .init::<TestBackend>();
+        let weight = standardize_weight(conv.weight.val()).reshape([4, 27]);
+        let (var, mean) = weight.var_mean_bias(1);
+
+        mean.into_data().assert_approx_eq(&Data::zeros([4, 1]), 3);
+        var.into_data()
+            .assert_approx_eq(&Data::from([[1.0], [1.0], [1.0], [1.0]]), 2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn weight_standardization_gradients() {
+        TestADBackend::seed(0);
+
+        let conv = Conv2dConfig::new([2, 3], [2, 2])
+            .with_weight_standardization(true)
+            .init::<TestADBackend>();
+        let input = Tensor::<TestADBackend, 4>::random([2, 2, 4, 4], Distribution::Default);
+
+        let output = conv.forward(input);
+        let grads = output.powf(2.0).sum().backward();
+        let grad = conv.weight.grad(&grads).unwrap();
+
+        assert_eq!(grad.shape(), conv.weight.shape());
+        assert!(grad
+            .into_data()
+            .value
+            .iter()
+            .any(|value| value.abs() > 1e-6));
+    }
 }
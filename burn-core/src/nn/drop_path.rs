@@ -0,0 +1,106 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Distribution, Shape, Tensor};
+
+/// Configuration to create a [DropPath](DropPath) layer.
+#[derive(Config)]
+pub struct DropPathConfig {
+    /// The probability of randomly dropping the path of each sample during training.
+    pub prob: f64,
+}
+
+/// Randomly drops the whole path of some samples of the batch during training, also known as
+/// stochastic depth.
+///
+/// It's meant to be applied on the residual branch of a block, so that the dropped samples only
+/// go through the skip connection, as described in the paper
+/// [Deep Networks with Stochastic Depth](https://arxiv.org/abs/1603.09382).
+///
+/// The input is also scaled during training to `1 / (1 - prob_keep)`.
+#[derive(Module, Clone, Debug)]
+pub struct DropPath {
+    prob: f64,
+}
+
+impl DropPathConfig {
+    /// Initialize a new [drop path](DropPath) module.
+    pub fn init(&self) -> DropPath {
+        DropPath { prob: self.prob }
+    }
+}
+
+impl DropPath {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, ...]`
+    /// - output: `[batch_size, ...]`
+    pub fn forward<B: Backend, const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        if !B::ad_enabled() || self.prob == 0.0 {
+            return input;
+        }
+
+        let prob_keep = 1.0 - self.prob;
+        let mut dims = [1; D];
+        dims[0] = input.dims()[0];
+        let random = Tensor::from_primitive(B::random(
+            Shape::new(dims),
+            Distribution::Bernoulli(prob_keep),
+            &input.device(),
+        ));
+        let x = input * random;
+
+        x * (1.0 / prob_keep)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    use crate::{TestADBackend, TestBackend};
+
+    #[cfg(not(feature = "std"))]
+    use crate::TestBackend;
+
+    #[test]
+    fn without_ad_backend_should_not_change_input() {
+        let tensor = Tensor::<TestBackend, 3>::random([8, 4, 4], Distribution::Default);
+        let drop_path = DropPathConfig::new(0.5).init();
+
+        let output = drop_path.forward(tensor.clone());
+
+        assert_eq!(tensor.to_data(), output.to_data());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn with_ad_backend_should_drop_whole_samples() {
+        let tensor = Tensor::<TestADBackend, 3>::ones([1000, 4, 2]);
+        let drop_path = DropPathConfig::new(0.3).init();
+
+        let output = drop_path.forward(tensor).into_data();
+
+        let mut num_dropped = 0;
+        for sample in output.value.chunks(8) {
+            let expected = match sample[0] == 0.0 {
+                true => {
+                    num_dropped += 1;
+                    0.0
+                }
+                false => 1.0 / 0.7,
+            };
+            assert!(sample.iter().all(|value| *value == expected));
+        }
+
+        // The expected value is preserved.
+        let mean = output.value.iter().sum::<f32>() / output.value.len() as f32;
+        assert!((mean - 1.0).abs() < 0.1, "{mean}");
+        assert!(num_dropped > 0);
+    }
+}
@@ -25,7 +25,7 @@ pub struct EmbeddingConfig {
 /// # Params
 ///
 /// - weight: Matrix of shape `[n_embedding, d_model]` initialized from a normal distribution:
-///     `N(0, 1)`
+///   `N(0, 1)`
 #[derive(Module, Debug)]
 pub struct Embedding<B: Backend> {
     weight: Param<Tensor<B, 2>>,
@@ -56,8 +56,8 @@ impl<B: Backend> Embedding<B> {
     ///
     /// # Shapes
     ///
-    /// - input: [batch_size, seq_length]
-    /// - output: [batch_size, d_model]
+    /// - input: `[batch_size, seq_length]`
+    /// - output: `[batch_size, seq_length, d_model]`
     pub fn forward(&self, input: Tensor<B, 2, Int>) -> Tensor<B, 3> {
         burn_tensor::module::embedding(self.weight.val(), input)
     }
@@ -66,9 +66,52 @@ impl<B: Backend> Embedding<B> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::TestBackend;
     use burn_tensor::Data;
 
+    #[cfg(feature = "std")]
+    use crate::{TestADBackend, TestBackend};
+
+    #[cfg(not(feature = "std"))]
+    use crate::TestBackend;
+
+    #[test]
+    fn forward_should_select_rows_of_the_indices() {
+        let config = EmbeddingConfig::new(4, 2);
+        let embed = config.init_with::<TestBackend>(EmbeddingRecord {
+            weight: Param::from(Tensor::from_floats([
+                [0.0, 1.0],
+                [2.0, 3.0],
+                [4.0, 5.0],
+                [6.0, 7.0],
+            ])),
+        });
+        let indices = Tensor::<TestBackend, 2, Int>::from_data(Data::from([[3, 0], [1, 3]]));
+
+        let output = embed.forward(indices);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[6.0, 7.0], [0.0, 1.0]], [[2.0, 3.0], [6.0, 7.0]]]),
+            3,
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn backward_should_accumulate_gradients_of_the_selected_rows() {
+        let config = EmbeddingConfig::new(4, 2);
+        let embed = config.init::<TestADBackend>();
+        let indices = Tensor::<TestADBackend, 2, Int>::from_data(Data::from([[3, 0], [1, 3]]));
+
+        let grads = embed.forward(indices).sum().backward();
+        let grad = embed.weight.grad(&grads).unwrap();
+
+        // The last row is selected twice and the third one is never selected.
+        grad.into_data().assert_approx_eq(
+            &Data::from([[1.0, 1.0], [1.0, 1.0], [0.0, 0.0], [2.0, 2.0]]),
+            3,
+        );
+    }
+
     #[test]
     fn initializer_default() {
         TestBackend::seed(0);
@@ -0,0 +1,315 @@
+use alloc::vec::Vec;
+
+use crate as burn;
+
+use super::Initializer;
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::Param;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Data, ElementConversion, Int, Shape, Tensor};
+
+/// How the embedding vectors of a bag are aggregated.
+#[derive(Module, Config, Debug, PartialEq)]
+pub enum EmbeddingBagMode {
+    /// Sum of the vectors.
+    Sum,
+    /// Mean of the vectors.
+    Mean,
+    /// Element-wise maximum of the vectors.
+    Max,
+}
+
+/// Configuration to create an [EmbeddingBag](EmbeddingBag) layer.
+#[derive(Config)]
+pub struct EmbeddingBagConfig {
+    /// The number of embedding vectors.
+    n_embedding: usize,
+    /// The size of each vector.
+    d_model: usize,
+    /// How the embedding vectors of a bag are aggregated.
+    #[config(default = "EmbeddingBagMode::Mean")]
+    pub mode: EmbeddingBagMode,
+    /// The type of function used to initialize neural network parameters
+    #[config(default = "Initializer::Normal{mean:0.0, std:1.0}")]
+    pub initializer: Initializer,
+}
+
+/// Lookup table aggregating variable-length bags of vectors, all the bags being reduced at once
+/// instead of one at a time.
+///
+/// Empty bags are aggregated to zeros.
+///
+/// # Params
+///
+/// - weight: Matrix of shape `[n_embedding, d_model]` initialized from a normal distribution:
+///   `N(0, 1)`
+#[derive(Module, Debug)]
+pub struct EmbeddingBag<B: Backend> {
+    weight: Param<Tensor<B, 2>>,
+    mode: EmbeddingBagMode,
+}
+
+impl EmbeddingBagConfig {
+    /// Initialize a new [embedding bag](EmbeddingBag) module.
+    pub fn init<B: Backend>(&self) -> EmbeddingBag<B> {
+        let weight = self
+            .initializer
+            .init([self.n_embedding, self.d_model])
+            .require_grad();
+
+        EmbeddingBag {
+            weight: Param::from(weight),
+            mode: self.mode.clone(),
+        }
+    }
+
+    /// Initialize a new [embedding bag](EmbeddingBag) module with a
+    /// [record](EmbeddingBagRecord).
+    pub fn init_with<B: Backend>(&self, record: EmbeddingBagRecord<B>) -> EmbeddingBag<B> {
+        EmbeddingBag {
+            weight: record.weight,
+            mode: self.mode.clone(),
+        }
+    }
+}
+
+impl<B: Backend> EmbeddingBag<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// The indices of all the bags are concatenated, each offset being the position of the first
+    /// index of a bag.
+    ///
+    /// # Shapes
+    ///
+    /// - indices: `[num_indices]`
+    /// - offsets: `[num_bags]`
+    /// - output: `[num_bags, d_model]`
+    ///
+    /// # Panics
+    ///
+    /// If the offsets don't start at 0, decrease or exceed the number of indices.
+    pub fn forward(&self, indices: Tensor<B, 1, Int>, offsets: Tensor<B, 1, Int>) -> Tensor<B, 2> {
+        let [num_indices] = indices.dims();
+        let [num_bags] = offsets.dims();
+        let [_, d_model] = self.weight.dims();
+        let device = self.weight.device();
+
+        let offsets = offsets
+            .into_data()
+            .value
+            .into_iter()
+            .map(|offset| offset.elem::<i64>())
+            .collect::<Vec<_>>();
+        check_offsets(&offsets, num_indices);
+
+        if num_indices == 0 {
+            return Tensor::zeros_device([num_bags, d_model], &device);
+        }
+
+        let offsets = offsets
+            .into_iter()
+            .map(|offset| offset as usize)
+            .collect::<Vec<_>>();
+        let bounds = |bag: usize| {
+            let end = offsets.get(bag + 1).copied().unwrap_or(num_indices);
+            offsets[bag]..end
+        };
+
+        let mut segments = Vec::with_capacity(num_indices);
+        for bag in 0..num_bags {
+            segments.extend(bounds(bag).map(|_| bag as i64));
+        }
+        let segments = Tensor::<B, 1, Int>::from_data_device(
+            Data::new(segments, Shape::new([num_indices])).convert(),
+            &device,
+        );
+
+        let embeddings = self.weight.val().select(0, indices);
+        let output = Tensor::zeros_device([num_bags, d_model], &device);
+
+        match self.mode {
+            EmbeddingBagMode::Sum => output.select_assign(0, segments, embeddings),
+            EmbeddingBagMode::Mean => {
+                let counts = (0..num_bags)
+                    .map(|bag| usize::max(bounds(bag).len(), 1) as f32)
+                    .collect::<Vec<_>>();
+                let counts = Tensor::<B, 1>::from_data_device(
+                    Data::new(counts, Shape::new([num_bags])).convert(),
+                    &device,
+                );
+
+                output
+                    .select_assign(0, segments, embeddings)
+                    .div(counts.reshape([num_bags, 1]))
+            }
+            EmbeddingBagMode::Max => {
+                // Start from the first embedding of each bag, empty bags being masked afterward.
+                let firsts = (0..num_bags)
+                    .map(|bag| usize::min(offsets[bag], num_indices - 1) as i64)
+                    .collect::<Vec<_>>();
+                let empty = (0..num_bags)
+                    .map(|bag| if bounds(bag).is_empty() { 0.0 } else { 1.0 })
+                    .collect::<Vec<f32>>();
+                let firsts = Tensor::<B, 1, Int>::from_data_device(
+                    Data::new(firsts, Shape::new([num_bags])).convert(),
+                    &device,
+                );
+                let empty = Tensor::<B, 1>::from_data_device(
+                    Data::new(empty, Shape::new([num_bags])).convert(),
+                    &device,
+                );
+                let segments = segments.reshape([num_indices, 1]).repeat(1, d_model);
+
+                embeddings
+                    .clone()
+                    .select(0, firsts)
+                    .scatter_max(0, segments, embeddings)
+                    .mul(empty.reshape([num_bags, 1]))
+            }
+        }
+    }
+}
+
+fn check_offsets(offsets: &[i64], num_indices: usize) {
+    if num_indices > 0 {
+        assert!(
+            offsets.first() == Some(&0),
+            "The offsets of the embedding bag must start at 0, got {:?}",
+            offsets.first()
+        );
+    }
+
+    for window in offsets.windows(2) {
+        assert!(
+            window[0] <= window[1],
+            "The offsets of the embedding bag must be non-decreasing, got {} followed by {}",
+            window[0],
+            window[1]
+        );
+    }
+
+    if let Some(last) = offsets.last() {
+        assert!(
+            *last >= 0 && *last as usize <= num_indices,
+            "The offsets of the embedding bag must not exceed the number of indices ({num_indices}), got {last}"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[cfg(feature = "std")]
+    use crate::TestADBackend;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_embedding_bag_aggregates_bags_of_different_lengths() {
+        let weight = Tensor::<TestADBackend, 2>::from_data([
+            [1.0, -2.0],
+            [3.0, 0.5],
+            [-1.0, 4.0],
+            [0.0, 1.0],
+        ]);
+        // Bags [0, 2, 1] and [2, 3].
+        let indices = Tensor::<TestADBackend, 1, Int>::from_data(Data::from([0, 2, 1, 2, 3]));
+        let offsets = Tensor::<TestADBackend, 1, Int>::from_data(Data::from([0, 3]));
+
+        let forward = |mode: EmbeddingBagMode| {
+            let embedding_bag = EmbeddingBag {
+                weight: Param::from(weight.clone().require_grad()),
+                mode,
+            };
+            let output = embedding_bag.forward(indices.clone(), offsets.clone());
+            let grads = output.clone().sum().backward();
+
+            (output, embedding_bag.weight.grad(&grads).unwrap())
+        };
+
+        let (output, grad) = forward(EmbeddingBagMode::Sum);
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[3.0, 2.5], [-1.0, 5.0]]), 3);
+        // The row shared by both bags accumulates both gradients.
+        grad.into_data().assert_approx_eq(
+            &Data::from([[1.0, 1.0], [1.0, 1.0], [2.0, 2.0], [1.0, 1.0]]),
+            3,
+        );
+
+        let (output, grad) = forward(EmbeddingBagMode::Mean);
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 0.8333], [-0.5, 2.5]]), 3);
+        grad.into_data().assert_approx_eq(
+            &Data::from([
+                [0.3333, 0.3333],
+                [0.3333, 0.3333],
+                [0.8333, 0.8333],
+                [0.5, 0.5],
+            ]),
+            3,
+        );
+
+        let (output, grad) = forward(EmbeddingBagMode::Max);
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[3.0, 4.0], [0.0, 4.0]]), 3);
+        grad.into_data().assert_approx_eq(
+            &Data::from([[0.0, 0.0], [1.0, 0.0], [0.0, 2.0], [1.0, 0.0]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_embedding_bag_empty_bag() {
+        let embedding_bag = EmbeddingBagConfig::new(4, 3)
+            .with_mode(EmbeddingBagMode::Max)
+            .init::<TestBackend>();
+        let indices = Tensor::<TestBackend, 1, Int>::from_data(Data::from([1, 3]));
+        let offsets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([0, 0]));
+
+        let output = embedding_bag.forward(indices, offsets);
+
+        output
+            .slice([0..1, 0..3])
+            .into_data()
+            .assert_approx_eq(&Data::zeros([1, 3]), 3);
+    }
+
+    #[test]
+    fn test_embedding_bag_no_indices() {
+        let embedding_bag = EmbeddingBagConfig::new(4, 3)
+            .with_mode(EmbeddingBagMode::Max)
+            .init::<TestBackend>();
+        let indices =
+            Tensor::<TestBackend, 1, Int>::from_data(Data::new(Vec::new(), Shape::new([0])));
+        let offsets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([0, 0]));
+
+        let output = embedding_bag.forward(indices, offsets);
+
+        output.into_data().assert_approx_eq(&Data::zeros([2, 3]), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be non-decreasing")]
+    fn test_embedding_bag_decreasing_offsets_panic() {
+        let embedding_bag = EmbeddingBagConfig::new(4, 3).init::<TestBackend>();
+        let indices = Tensor::<TestBackend, 1, Int>::from_data(Data::from([1, 3, 2]));
+        let offsets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([0, 2, 1]));
+
+        embedding_bag.forward(indices, offsets);
+    }
+
+    #[test]
+    #[should_panic(expected = "must not exceed the number of indices")]
+    fn test_embedding_bag_offsets_out_of_range_panic() {
+        let embedding_bag = EmbeddingBagConfig::new(4, 3).init::<TestBackend>();
+        let indices = Tensor::<TestBackend, 1, Int>::from_data(Data::from([1, 3]));
+        let offsets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([0, 3]));
+
+        embedding_bag.forward(indices, offsets);
+    }
+}
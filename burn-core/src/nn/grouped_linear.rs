@@ -0,0 +1,265 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::Param;
+use crate::tensor::{backend::Backend, Tensor};
+use libm::sqrt;
+
+use super::Initializer;
+
+/// Configuration to create a [GroupedLinear](GroupedLinear) layer.
+#[derive(Config, Debug)]
+pub struct GroupedLinearConfig {
+    /// The size of the input features.
+    pub d_input: usize,
+    /// The size of the output features.
+    pub d_output: usize,
+    /// The number of groups the input and output features are split into.
+    pub groups: usize,
+    /// If a bias should be applied during the linear transformation.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The type of function used to initialize neural network parameters
+    #[config(default = "Initializer::KaimingUniform{gain:1.0/sqrt(3.0), fan_out_only:false}")]
+    pub initializer: Initializer,
+}
+
+/// Applies a block-diagonal linear transformation to the input tensor, where each group of input
+/// features only maps to its group of output features:
+///
+/// `O_g = I_g W_g + b_g`
+///
+/// The groups are computed with a single batched matrix multiplication.
+///
+/// # Params
+///
+/// - weight: Tensor of shape `[groups, d_input / groups, d_output / groups]` initialized from a
+///   uniform distribution `U(-k, k)`, where `k = sqrt(groups / d_input)`
+///
+/// - bias (optional): Vector of size `d_output` initialized from a uniform distribution:
+///   `U(-k, k)`, where `k = sqrt(groups / d_input)`
+#[derive(Module, Debug)]
+pub struct GroupedLinear<B: Backend> {
+    pub(crate) weight: Param<Tensor<B, 3>>,
+    pub(crate) bias: Option<Param<Tensor<B, 1>>>,
+}
+
+impl GroupedLinearConfig {
+    /// Initialize a new [grouped linear](GroupedLinear) module.
+    pub fn init<B: Backend>(&self) -> GroupedLinear<B> {
+        self.check_groups();
+
+        let d_input_group = self.d_input / self.groups;
+        let d_output_group = self.d_output / self.groups;
+        let shape = [self.groups, d_input_group, d_output_group];
+        let weight = self
+            .initializer
+            .init_with(shape, Some(d_input_group), Some(d_output_group));
+        let bias = if self.bias {
+            Some(self.initializer.init_with(
+                [self.d_output],
+                Some(d_input_group),
+                Some(d_output_group),
+            ))
+        } else {
+            None
+        };
+
+        GroupedLinear {
+            weight: Param::from(weight),
+            bias: bias.map(Param::from),
+        }
+    }
+
+    /// Initialize a new [grouped linear](GroupedLinear) module with a
+    /// [record](GroupedLinearRecord).
+    pub fn init_with<B: Backend>(&self, record: GroupedLinearRecord<B>) -> GroupedLinear<B> {
+        self.check_groups();
+
+        GroupedLinear {
+            weight: record.weight,
+            bias: record.bias,
+        }
+    }
+
+    fn check_groups(&self) {
+        if self.groups == 0 {
+            panic!("The number of groups should be greater than zero");
+        }
+
+        if !self.d_input.is_multiple_of(self.groups) || !self.d_output.is_multiple_of(self.groups) {
+            panic!(
+                "The input size ({}) and the output size ({}) should be divisible by the number of groups ({})",
+                self.d_input, self.d_output, self.groups
+            );
+        }
+    }
+}
+
+impl<B: Backend> GroupedLinear<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any, d_input]`
+    /// - output: `[..., any, d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let [groups, d_input_group, d_output_group] = self.weight.dims();
+        let mut dims = input.dims();
+        let batch_size = dims[..D - 1].iter().product();
+
+        let output = input
+            .reshape([batch_size, groups, d_input_group])
+            .swap_dims(0, 1)
+            .matmul(self.weight.val())
+            .swap_dims(0, 1);
+
+        dims[D - 1] = groups * d_output_group;
+        let output = output.reshape(dims);
+
+        match &self.bias {
+            Some(bias) => output + bias.val().unsqueeze(),
+            None => output,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_tensor::Shape;
+
+    #[cfg(feature = "std")]
+    use crate::{nn::Linear, TestADBackend, TestBackend};
+    #[cfg(feature = "std")]
+    use burn_tensor::Distribution;
+
+    #[cfg(not(feature = "std"))]
+    use crate::TestBackend;
+
+    #[test]
+    fn initializer_default() {
+        TestBackend::seed(0);
+
+        let config = GroupedLinearConfig::new(8, 4, 2);
+        let k = sqrt(2.0 / config.d_input as f64) as f32;
+        let linear = config.init::<TestBackend>();
+
+        assert_eq!(linear.weight.shape(), Shape::new([2, 4, 2]));
+        linear.weight.to_data().assert_within_range(-k..k);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_features_not_divisible_by_groups() {
+        GroupedLinearConfig::new(8, 5, 2).init::<TestBackend>();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_groups_is_zero() {
+        GroupedLinearConfig::new(0, 0, 0).init::<TestBackend>();
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_grouped_linear_equals_independent_linears() {
+        TestADBackend::seed(0);
+
+        let grouped = GroupedLinearConfig::new(6, 4, 2).init::<TestADBackend>();
+        let linears = (0..2)
+            .map(|group| Linear {
+                weight: Param::from(
+                    grouped
+                        .weight
+                        .val()
+                        .slice([group..group + 1, 0..3, 0..2])
+                        .reshape([3, 2])
+                        .detach()
+                        .require_grad(),
+                ),
+                bias: grouped.bias.as_ref().map(|bias| {
+                    Param::from(
+                        bias.val()
+                            .reshape([2, 2])
+                            .slice([group..group + 1, 0..2])
+                            .reshape([2])
+                            .detach()
+                            .require_grad(),
+                    )
+                }),
+            })
+            .collect::<Vec<_>>();
+
+        let input = Tensor::<TestADBackend, 3>::random([2, 3, 6], Distribution::Default);
+        let input_grouped = input.clone().require_grad();
+        // The inputs of the independent linears are built from the data, so the two backward
+        // passes don't share a graph.
+        let inputs = (0..2)
+            .map(|group| {
+                Tensor::from_data(
+                    input
+                        .clone()
+                        .slice([0..2, 0..3, group * 3..group * 3 + 3])
+                        .into_data(),
+                )
+                .require_grad()
+            })
+            .collect::<Vec<_>>();
+
+        let output = grouped.forward(input_grouped.clone());
+        let outputs = linears
+            .iter()
+            .zip(inputs.iter())
+            .map(|(linear, input)| linear.forward(input.clone()))
+            .collect::<Vec<_>>();
+        let output_expected = Tensor::cat(outputs, 2);
+
+        output
+            .to_data()
+            .assert_approx_eq(&output_expected.to_data(), 3);
+
+        let grads = output.powf(2.0).sum().backward();
+        let grads_expected = output_expected.powf(2.0).sum().backward();
+
+        let grad_input = input_grouped.grad(&grads).unwrap();
+        let grad_input_expected = Tensor::cat(
+            inputs
+                .iter()
+                .map(|input| input.grad(&grads_expected).unwrap())
+                .collect(),
+            2,
+        );
+        grad_input
+            .to_data()
+            .assert_approx_eq(&grad_input_expected.to_data(), 3);
+
+        let grad_weight = grouped.weight.grad(&grads).unwrap();
+        let grad_bias = grouped.bias.unwrap().grad(&grads).unwrap();
+        for (group, linear) in linears.iter().enumerate() {
+            grad_weight
+                .clone()
+                .slice([group..group + 1, 0..3, 0..2])
+                .reshape([3, 2])
+                .to_data()
+                .assert_approx_eq(&linear.weight.grad(&grads_expected).unwrap().to_data(), 3);
+            grad_bias
+                .clone()
+                .reshape([2, 2])
+                .slice([group..group + 1, 0..2])
+                .reshape([2])
+                .to_data()
+                .assert_approx_eq(
+                    &linear
+                        .bias
+                        .as_ref()
+                        .unwrap()
+                        .grad(&grads_expected)
+                        .unwrap()
+                        .to_data(),
+                    3,
+                );
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use crate::nn::loss::reduction::Reduction;
+use core::marker::PhantomData;
+
+use burn_tensor::linalg::{cosine_similarity, DEFAULT_EPSILON};
+use burn_tensor::{backend::Backend, Int, Tensor};
+
+/// Calculate the cosine embedding loss, measuring whether two inputs are similar or dissimilar
+/// using their cosine similarity.
+///
+/// For each pair of inputs, the loss is `1 - cos(x1, x2)` when the target is `1`, and
+/// `max(0, cos(x1, x2) - margin)` when the target is `-1`.
+#[derive(Clone, Debug)]
+pub struct CosineEmbeddingLoss<B: Backend> {
+    margin: f32,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> Default for CosineEmbeddingLoss<B> {
+    fn default() -> Self {
+        Self::new(0.0)
+    }
+}
+
+impl<B: Backend> CosineEmbeddingLoss<B> {
+    /// Create the criterion with the given margin, which should be between -1 and 1.
+    pub fn new(margin: f32) -> Self {
+        Self {
+            margin,
+            backend: PhantomData,
+        }
+    }
+
+    /// Compute the criterion on the input tensors.
+    ///
+    /// # Shapes
+    ///
+    /// - input_1: `[batch_size, d_model]`
+    /// - input_2: `[batch_size, d_model]`
+    /// - targets: `[batch_size]`, with `1` for similar pairs and `-1` for dissimilar pairs.
+    pub fn forward(
+        &self,
+        input_1: Tensor<B, 2>,
+        input_2: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let tensor = self.forward_no_reduction(input_1, input_2, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => tensor.mean(),
+            Reduction::Sum => tensor.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensors without reducing.
+    pub fn forward_no_reduction(
+        &self,
+        input_1: Tensor<B, 2>,
+        input_2: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 1> {
+        let [batch_size] = targets.dims();
+        let similarity =
+            cosine_similarity(input_1, input_2, 1, DEFAULT_EPSILON).reshape([batch_size]);
+
+        let loss_similar = similarity.clone().neg().add_scalar(1.0);
+        let loss_dissimilar = similarity.sub_scalar(self.margin);
+        let mask_negative = loss_dissimilar.clone().lower_elem(0.0);
+        let loss_dissimilar = loss_dissimilar.mask_fill(mask_negative, 0.0);
+
+        let loss_similar = loss_similar.mask_fill(targets.clone().equal_elem(-1), 0.0);
+        let loss_dissimilar = loss_dissimilar.mask_fill(targets.equal_elem(1), 0.0);
+
+        loss_similar.add(loss_dissimilar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[cfg(feature = "std")]
+    use crate::{TestADBackend, TestBackend};
+
+    #[cfg(not(feature = "std"))]
+    use crate::TestBackend;
+
+    #[test]
+    fn test_cosine_embedding_loss() {
+        let input_1 = Tensor::<TestBackend, 2>::from_data(Data::from([
+            [1.0, 0.0],
+            [1.0, 1.0],
+            [1.0, 0.0],
+            [0.0, 2.0],
+        ]));
+        let input_2 = Tensor::<TestBackend, 2>::from_data(Data::from([
+            [2.0, 0.0],
+            [-1.0, 1.0],
+            [1.0, 1.0],
+            [0.0, -1.0],
+        ]));
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([1, 1, -1, -1]));
+
+        let loss = CosineEmbeddingLoss::new(0.5);
+        let loss_no_reduction =
+            loss.forward_no_reduction(input_1.clone(), input_2.clone(), targets.clone());
+        let loss_sum = loss.forward(input_1, input_2, targets, Reduction::Sum);
+
+        let expected = [0.0, 1.0, core::f32::consts::FRAC_1_SQRT_2 - 0.5, 0.0];
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from(expected), 3);
+        loss_sum
+            .into_data()
+            .assert_approx_eq(&Data::from([expected.iter().sum::<f32>()]), 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_cosine_embedding_loss_gradient() {
+        let input_1 = Tensor::<TestADBackend, 2>::from_data(Data::from([[1.0, 1.0], [0.0, 0.0]]))
+            .require_grad();
+        let input_2 = Tensor::<TestADBackend, 2>::from_data(Data::from([[1.0, 0.0], [1.0, 0.0]]));
+        let targets = Tensor::<TestADBackend, 1, Int>::from_data(Data::from([1, 1]));
+
+        let loss = CosineEmbeddingLoss::default().forward(
+            input_1.clone(),
+            input_2,
+            targets,
+            Reduction::Sum,
+        );
+        let grads = loss.backward();
+        let grad = input_1.grad(&grads).unwrap();
+
+        // d(1 - cos)/dx1 = cos * x1 / |x1|^2 - x2 / (|x1| |x2|)
+        grad.clone()
+            .slice([0..1, 0..2])
+            .into_data()
+            .assert_approx_eq(&Data::from([[-0.3536, 0.3536]]), 3);
+        // The epsilon floor keeps the gradient finite for the zero vector.
+        assert!(grad.into_data().value.iter().all(|value| value.is_finite()));
+    }
+}
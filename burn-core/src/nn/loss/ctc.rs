@@ -0,0 +1,378 @@
+use crate::nn::loss::reduction::Reduction;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use burn_tensor::{backend::Backend, Bool, Data, Element, ElementConversion, Int, Tensor};
+
+/// Value used instead of the logarithm of zero, so that the gradients stay finite.
+const LOG_ZERO: f32 = -1e30;
+
+/// Calculate the connectionist temporal classification loss, which is the negative log
+/// likelihood of the targets summed over all their alignments with the input sequences.
+///
+/// The likelihood is computed with the forward recursion over the lattice of the targets
+/// extended with blanks, while the gradients are computed by automatic differentiation.
+///
+/// Introduced in [Connectionist Temporal Classification: Labelling Unsegmented Sequence Data with
+/// Recurrent Neural Networks](https://www.cs.toronto.edu/~graves/icml_2006.pdf).
+#[derive(Clone, Debug)]
+pub struct CtcLoss<B: Backend> {
+    blank: usize,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> Default for CtcLoss<B> {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<B: Backend> CtcLoss<B> {
+    /// Create the criterion with the given index of the blank class.
+    pub fn new(blank: usize) -> Self {
+        Self {
+            blank,
+            backend: PhantomData,
+        }
+    }
+
+    /// Compute the criterion on the input log-probabilities.
+    ///
+    /// With the mean reduction, the loss of each sequence is divided by its target length before
+    /// taking the mean over the batch.
+    ///
+    /// # Shapes
+    ///
+    /// - log_probs: `[batch_size, seq_length, num_classes]`
+    /// - targets: `[batch_size, max_target_length]`, padded with any value.
+    /// - input_lengths: `[batch_size]`
+    /// - target_lengths: `[batch_size]`
+    pub fn forward(
+        &self,
+        log_probs: Tensor<B, 3>,
+        targets: Tensor<B, 2, Int>,
+        input_lengths: Tensor<B, 1, Int>,
+        target_lengths: Tensor<B, 1, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let tensor =
+            self.forward_no_reduction(log_probs, targets, input_lengths, target_lengths.clone());
+
+        match reduction {
+            Reduction::Mean | Reduction::Auto => {
+                let target_lengths = target_lengths
+                    .into_data()
+                    .value
+                    .into_iter()
+                    .map(|length| length.elem::<i64>().max(1) as f32)
+                    .collect::<Vec<_>>();
+                let target_lengths = Tensor::from_data_device(
+                    Data::new(target_lengths, tensor.shape()).convert(),
+                    &tensor.device(),
+                );
+
+                tensor.div(target_lengths).mean()
+            }
+            Reduction::Sum => tensor.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input log-probabilities without reducing.
+    ///
+    /// # Panics
+    ///
+    /// If an input length is larger than the sequence length or if a target length is larger than
+    /// the maximum target length.
+    pub fn forward_no_reduction(
+        &self,
+        log_probs: Tensor<B, 3>,
+        targets: Tensor<B, 2, Int>,
+        input_lengths: Tensor<B, 1, Int>,
+        target_lengths: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 1> {
+        let [batch_size, seq_length, _] = log_probs.dims();
+        let [_, max_target_length] = targets.dims();
+        let num_states = 2 * max_target_length + 1;
+        let device = log_probs.device();
+
+        let input_lengths = lengths(input_lengths, seq_length);
+        let target_lengths = lengths(target_lengths, max_target_length);
+        let lattice = Lattice::new(targets.into_data(), &target_lengths, self.blank);
+
+        let log_probs = log_probs.gather(
+            2,
+            lattice
+                .states(&device)
+                .reshape([batch_size, 1, num_states])
+                .repeat(1, seq_length),
+        );
+        let skip_mask = lattice.skip_mask(&device);
+
+        let log_prob = |time: usize| {
+            log_probs
+                .clone()
+                .slice([0..batch_size, time..time + 1, 0..num_states])
+                .reshape([batch_size, num_states])
+        };
+
+        // Only the first blank and the first label can start an alignment.
+        let start_mask = Tensor::<B, 1, Int>::arange_device(0..num_states, &device)
+            .reshape([1, num_states])
+            .repeat(0, batch_size)
+            .greater_equal_elem(2);
+        let mut alpha = log_prob(0).mask_fill(start_mask, LOG_ZERO);
+
+        for time in 1..seq_length {
+            let transitions = [
+                alpha.clone(),
+                shift(alpha.clone(), 1),
+                shift(alpha.clone(), 2).mask_fill(skip_mask.clone(), LOG_ZERO),
+            ]
+            .into_iter()
+            .map(|tensor| tensor.reshape([batch_size, num_states, 1]))
+            .collect();
+            let alpha_next = log_sum_exp(Tensor::cat(transitions, 2), 2)
+                .reshape([batch_size, num_states])
+                .add(log_prob(time));
+
+            // The sequences that already ended keep their last values.
+            let ended = input_lengths
+                .iter()
+                .map(|length| vec![time >= *length; num_states])
+                .collect::<Vec<_>>()
+                .concat();
+            let ended = Tensor::<B, 2, Bool>::from_bool_device(
+                Data::new(ended, [batch_size, num_states].into()),
+                &device,
+            );
+
+            alpha = alpha_next.mask_where(ended, alpha);
+        }
+
+        // Valid alignments end with the last label or the following blank.
+        let (ends, end_mask): (Vec<_>, Vec<_>) = target_lengths
+            .iter()
+            .map(|length| {
+                let last_blank = (2 * length) as i64;
+                ([last_blank, (last_blank - 1).max(0)], [false, *length == 0])
+            })
+            .unzip();
+        let ends = Tensor::<B, 2, Int>::from_data_device(
+            Data::new(ends.concat(), [batch_size, 2].into()).convert(),
+            &device,
+        );
+        let end_mask = Tensor::<B, 2, Bool>::from_bool_device(
+            Data::new(end_mask.concat(), [batch_size, 2].into()),
+            &device,
+        );
+        let alpha = alpha.gather(1, ends).mask_fill(end_mask, LOG_ZERO);
+
+        log_sum_exp(alpha, 1).reshape([batch_size]).neg()
+    }
+}
+
+/// Lattice of the targets extended with a blank before, between and after each label.
+struct Lattice {
+    states: Vec<i64>,
+    no_skip: Vec<bool>,
+    batch_size: usize,
+    num_states: usize,
+}
+
+impl Lattice {
+    fn new<E: Element>(targets: Data<E, 2>, target_lengths: &[usize], blank: usize) -> Self {
+        let [batch_size, max_target_length] = targets.shape.dims;
+        let num_states = 2 * max_target_length + 1;
+        let blank = blank as i64;
+
+        let mut states = Vec::with_capacity(batch_size * num_states);
+        let mut no_skip = Vec::with_capacity(batch_size * num_states);
+
+        for (batch, length) in target_lengths.iter().enumerate() {
+            let labels = &targets.value[batch * max_target_length..(batch + 1) * max_target_length];
+            let label = |state: usize| {
+                if state.is_multiple_of(2) || state / 2 >= *length {
+                    blank
+                } else {
+                    labels[state / 2].elem::<i64>()
+                }
+            };
+
+            for state in 0..num_states {
+                states.push(label(state));
+                // Skipping the blank is only allowed between two different labels.
+                no_skip
+                    .push(state < 2 || label(state) == blank || label(state) == label(state - 2));
+            }
+        }
+
+        Self {
+            states,
+            no_skip,
+            batch_size,
+            num_states,
+        }
+    }
+
+    fn states<B: Backend>(&self, device: &B::Device) -> Tensor<B, 2, Int> {
+        Tensor::from_data_device(
+            Data::new(
+                self.states.clone(),
+                [self.batch_size, self.num_states].into(),
+            )
+            .convert(),
+            device,
+        )
+    }
+
+    /// Mask of the states that can't be reached from two states before.
+    fn skip_mask<B: Backend>(&self, device: &B::Device) -> Tensor<B, 2, Bool> {
+        Tensor::from_bool_device(
+            Data::new(
+                self.no_skip.clone(),
+                [self.batch_size, self.num_states].into(),
+            ),
+            device,
+        )
+    }
+}
+
+fn lengths<B: Backend>(lengths: Tensor<B, 1, Int>, max: usize) -> Vec<usize> {
+    lengths
+        .into_data()
+        .value
+        .into_iter()
+        .map(|length| {
+            let length = length.elem::<i64>() as usize;
+            assert!(
+                length <= max,
+                "Length {length} should not be larger than {max}"
+            );
+            length
+        })
+        .collect()
+}
+
+/// Shift the states of the lattice, the first ones being unreachable.
+fn shift<B: Backend>(tensor: Tensor<B, 2>, offset: usize) -> Tensor<B, 2> {
+    let [batch_size, num_states] = tensor.dims();
+
+    if offset >= num_states {
+        return Tensor::full_device([batch_size, num_states], LOG_ZERO, &tensor.device());
+    }
+
+    Tensor::cat(
+        vec![
+            Tensor::full_device([batch_size, offset], LOG_ZERO, &tensor.device()),
+            tensor.slice([0..batch_size, 0..num_states - offset]),
+        ],
+        1,
+    )
+}
+
+/// Compute `log(sum(exp(x)))` along the given dimension in a numerically stable way.
+fn log_sum_exp<B: Backend, const D: usize>(tensor: Tensor<B, D>, dim: usize) -> Tensor<B, D> {
+    let max = tensor.clone().max_dim(dim).detach();
+
+    tensor.sub(max.clone()).exp().sum_dim(dim).log().add(max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    use crate::{TestADBackend, TestBackend};
+    #[cfg(feature = "std")]
+    use burn_tensor::activation;
+
+    #[cfg(not(feature = "std"))]
+    use crate::TestBackend;
+
+    #[test]
+    fn test_ctc_loss_variable_lengths() {
+        let probs = Tensor::<TestBackend, 3>::from_floats([
+            [[0.4, 0.6], [0.7, 0.3]],
+            [[0.4, 0.6], [0.7, 0.3]],
+        ]);
+        let targets = Tensor::<TestBackend, 2, Int>::from_data(Data::from([[1], [1]]));
+        let input_lengths = Tensor::<TestBackend, 1, Int>::from_data(Data::from([2, 1]));
+        let target_lengths = Tensor::<TestBackend, 1, Int>::from_data(Data::from([1, 1]));
+
+        let loss = CtcLoss::new(0).forward_no_reduction(
+            probs.log(),
+            targets,
+            input_lengths,
+            target_lengths,
+        );
+
+        // The first sequence has three alignments: "11", "01" and "10", giving
+        // 0.6 * 0.3 + 0.4 * 0.3 + 0.6 * 0.7 = 0.72, while the second one only has "1".
+        loss.into_data()
+            .assert_approx_eq(&Data::from([-f32::ln(0.72), -f32::ln(0.6)]), 3);
+    }
+
+    #[test]
+    fn test_ctc_loss_repeated_labels() {
+        let probs = Tensor::<TestBackend, 3>::from_floats([[
+            [0.2, 0.5, 0.3],
+            [0.6, 0.3, 0.1],
+            [0.1, 0.8, 0.1],
+        ]]);
+        let targets = Tensor::<TestBackend, 2, Int>::from_data(Data::from([[1, 1]]));
+        let lengths = Tensor::<TestBackend, 1, Int>::from_data(Data::from([3]));
+        let target_lengths = Tensor::<TestBackend, 1, Int>::from_data(Data::from([2]));
+
+        let loss = CtcLoss::new(0).forward(
+            probs.log(),
+            targets,
+            lengths,
+            target_lengths,
+            Reduction::Sum,
+        );
+
+        // Repeated labels must be separated by a blank, so "101" is the only alignment.
+        loss.into_data()
+            .assert_approx_eq(&Data::from([-f32::ln(0.5 * 0.6 * 0.8)]), 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_ctc_loss_gradients_are_finite() {
+        let logits = Tensor::<TestADBackend, 3>::from_floats([
+            [
+                [1.0, -2.0, 0.5],
+                [0.0, 3.0, -1.0],
+                [2.0, 0.0, 0.0],
+                [-1.0, 0.5, 4.0],
+            ],
+            [
+                [0.0, 0.0, 0.0],
+                [1.0, 2.0, 3.0],
+                [0.5, -0.5, 0.0],
+                [0.0, 0.0, 0.0],
+            ],
+        ])
+        .require_grad();
+        let targets = Tensor::<TestADBackend, 2, Int>::from_data(Data::from([[1, 2], [2, 0]]));
+        let input_lengths = Tensor::<TestADBackend, 1, Int>::from_data(Data::from([4, 3]));
+        let target_lengths = Tensor::<TestADBackend, 1, Int>::from_data(Data::from([2, 1]));
+
+        let loss = CtcLoss::new(0).forward(
+            activation::log_softmax(logits.clone(), 2),
+            targets,
+            input_lengths,
+            target_lengths,
+            Reduction::Mean,
+        );
+        let grads = loss.backward();
+        let grad = logits.grad(&grads).unwrap();
+
+        assert!(grad.to_data().value.iter().all(|value| value.is_finite()));
+        // The last step of the second sequence isn't part of its input.
+        grad.slice([1..2, 3..4, 0..3])
+            .to_data()
+            .assert_approx_eq(&Data::zeros([1, 1, 3]), 3);
+    }
+}
@@ -0,0 +1,169 @@
+use crate::nn::loss::reduction::Reduction;
+use core::marker::PhantomData;
+
+use burn_tensor::{activation, backend::Backend, Int, Tensor};
+
+/// Calculate the focal loss, which down-weights the well-classified examples to focus the
+/// training on the hard ones.
+///
+/// For each example, the loss is `-alpha * (1 - p)^gamma * log(p)`, where `p` is the predicted
+/// probability of the target class. With `gamma = 0` and `alpha = 1`, it's the cross entropy.
+///
+/// Introduced in [Focal Loss for Dense Object Detection](https://arxiv.org/abs/1708.02002).
+#[derive(Clone, Debug)]
+pub struct FocalLoss<B: Backend> {
+    gamma: f32,
+    alpha: f32,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> Default for FocalLoss<B> {
+    fn default() -> Self {
+        Self::new(2.0, 0.25)
+    }
+}
+
+impl<B: Backend> FocalLoss<B> {
+    /// Create the criterion with the given focusing parameter `gamma` and weighting factor
+    /// `alpha`.
+    pub fn new(gamma: f32, alpha: f32) -> Self {
+        Self {
+            gamma,
+            alpha,
+            backend: PhantomData,
+        }
+    }
+
+    /// Compute the criterion on the input logits, using the softmax probabilities.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[batch_size, num_targets]`
+    /// - targets: `[batch_size]`
+    pub fn forward(
+        &self,
+        logits: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let tensor = self.forward_no_reduction(logits, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => tensor.mean(),
+            Reduction::Sum => tensor.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input logits, using the softmax probabilities, without
+    /// reducing.
+    pub fn forward_no_reduction(
+        &self,
+        logits: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 1> {
+        let [batch_size] = targets.dims();
+        let log_probs = activation::log_softmax(logits, 1)
+            .gather(1, targets.reshape([batch_size, 1]))
+            .reshape([batch_size]);
+
+        self.focal(log_probs).mul_scalar(self.alpha)
+    }
+
+    /// Compute the criterion on the input logits of a binary classification, using the sigmoid
+    /// probabilities.
+    ///
+    /// The weighting factor `alpha` is applied to the positive examples, while `1 - alpha` is
+    /// applied to the negative ones.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[batch_size]`
+    /// - targets: `[batch_size]`, with `1` for positive examples and `0` for negative examples.
+    pub fn forward_binary(
+        &self,
+        logits: Tensor<B, 1>,
+        targets: Tensor<B, 1, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let tensor = self.forward_binary_no_reduction(logits, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => tensor.mean(),
+            Reduction::Sum => tensor.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input logits of a binary classification, using the sigmoid
+    /// probabilities, without reducing.
+    pub fn forward_binary_no_reduction(
+        &self,
+        logits: Tensor<B, 1>,
+        targets: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 1> {
+        let negative = targets.equal_elem(0);
+
+        // log(1 - sigmoid(x)) = log_sigmoid(-x)
+        let log_probs = activation::log_sigmoid(logits.clone())
+            .mask_where(negative.clone(), activation::log_sigmoid(logits.neg()));
+        let alpha = Tensor::full_device(log_probs.shape(), self.alpha, &log_probs.device())
+            .mask_fill(negative, 1.0 - self.alpha);
+
+        self.focal(log_probs).mul(alpha)
+    }
+
+    fn focal(&self, log_probs: Tensor<B, 1>) -> Tensor<B, 1> {
+        let weights = log_probs
+            .clone()
+            .exp()
+            .neg()
+            .add_scalar(1.0)
+            .powf(self.gamma);
+
+        log_probs.mul(weights).neg()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::loss::CrossEntropyLoss;
+    use crate::TestBackend;
+    use burn_tensor::{Data, Distribution};
+
+    #[test]
+    fn test_focal_loss_without_focusing_is_cross_entropy() {
+        let logits = Tensor::<TestBackend, 2>::random([4, 5], Distribution::Normal(0., 1.0));
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([2, 0, 4, 1]));
+
+        let loss_1 =
+            FocalLoss::new(0.0, 1.0).forward(logits.clone(), targets.clone(), Reduction::Mean);
+        let loss_2 = CrossEntropyLoss::new(None).forward(logits, targets);
+
+        loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 3);
+    }
+
+    #[test]
+    fn test_focal_loss_well_classified_examples() {
+        let logits = Tensor::<TestBackend, 2>::from_data([[10.0, 0.0, 0.0], [0.0, 0.0, 10.0]]);
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([0, 2]));
+
+        let loss_focal =
+            FocalLoss::new(5.0, 1.0).forward_no_reduction(logits.clone(), targets.clone());
+        let loss_cross_entropy = FocalLoss::new(0.0, 1.0).forward_no_reduction(logits, targets);
+
+        loss_focal
+            .into_data()
+            .assert_approx_eq(&Data::from([0.0, 0.0]), 6);
+        assert!(loss_cross_entropy.into_data().value[0] > 1e-5);
+    }
+
+    #[test]
+    fn test_focal_loss_binary() {
+        let logits = Tensor::<TestBackend, 1>::from_data([2.0, -1.0, 0.5]);
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([1, 0, 0]));
+
+        let loss = FocalLoss::new(2.0, 0.25).forward_binary_no_reduction(logits, targets);
+
+        // -alpha_t * (1 - p_t)^2 * log(p_t) with p_t = [0.8808, 0.7311, 0.3775]
+        loss.into_data()
+            .assert_approx_eq(&Data::from([0.0005, 0.0170, 0.2831]), 3);
+    }
+}
@@ -0,0 +1,147 @@
+use crate::nn::loss::reduction::Reduction;
+use core::marker::PhantomData;
+
+use burn_tensor::{backend::ADBackend, Tensor};
+
+/// Calculate the gradient penalty, used to enforce the 1-Lipschitz constraint of the critic
+/// when training a Wasserstein GAN.
+///
+/// For each sample, the penalty is `(norm - target_norm)^2`, where `norm` is the L2 norm of the
+/// gradients of the output with respect to the input.
+///
+/// Introduced in [Improved Training of Wasserstein GANs](https://arxiv.org/abs/1704.00028).
+///
+/// # Notes
+///
+/// The gradients are computed with a backward pass of the given autodiff backend, so the
+/// penalty is a tensor of its inner backend. For the penalty to be differentiable with respect
+/// to the parameters, the inner backend must also be an autodiff backend where the parameters
+/// are tracked, e.g. `ADBackendDecorator<ADBackendDecorator<B>>`.
+#[derive(Clone, Debug)]
+pub struct GradientPenalty<B: ADBackend> {
+    target_norm: f32,
+    backend: PhantomData<B>,
+}
+
+impl<B: ADBackend> Default for GradientPenalty<B> {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl<B: ADBackend> GradientPenalty<B> {
+    /// Create the criterion with the given target norm.
+    pub fn new(target_norm: f32) -> Self {
+        Self {
+            target_norm,
+            backend: PhantomData,
+        }
+    }
+
+    /// Compute the criterion on the output of the critic and the input it was computed from.
+    ///
+    /// # Shapes
+    ///
+    /// - output: `[batch_size, ...]`
+    /// - input: `[batch_size, ...]`
+    ///
+    /// # Panics
+    ///
+    /// If the input isn't marked as requiring gradients.
+    pub fn forward<const D_OUT: usize, const D_IN: usize>(
+        &self,
+        output: Tensor<B, D_OUT>,
+        input: Tensor<B, D_IN>,
+        reduction: Reduction,
+    ) -> Tensor<B::InnerBackend, 1> {
+        let tensor = self.forward_no_reduction(output, input);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => tensor.mean(),
+            Reduction::Sum => tensor.sum(),
+        }
+    }
+
+    /// Compute the criterion on the output of the critic and the input it was computed from
+    /// without reducing.
+    pub fn forward_no_reduction<const D_OUT: usize, const D_IN: usize>(
+        &self,
+        output: Tensor<B, D_OUT>,
+        input: Tensor<B, D_IN>,
+    ) -> Tensor<B::InnerBackend, 1> {
+        gradient_norm(output, input)
+            .sub_scalar(self.target_norm)
+            .powf(2.0)
+    }
+}
+
+/// Compute the L2 norm of the gradients of the output with respect to the input for each sample.
+///
+/// The output is summed before the backward pass, so each sample should only depend on its own
+/// input, which is the case for a critic without batch statistics.
+///
+/// # Shapes
+///
+/// - output: `[batch_size, ...]`
+/// - input: `[batch_size, ...]`
+/// - return: `[batch_size]`
+///
+/// # Panics
+///
+/// If the input isn't marked as requiring gradients.
+pub fn gradient_norm<B: ADBackend, const D_OUT: usize, const D_IN: usize>(
+    output: Tensor<B, D_OUT>,
+    input: Tensor<B, D_IN>,
+) -> Tensor<B::InnerBackend, 1> {
+    let grads = output.sum().backward();
+    let grad = input
+        .grad(&grads)
+        .expect("The input should be marked as requiring gradients.");
+
+    let shape = grad.shape();
+    let batch_size = shape.dims[0];
+    let grad = grad.reshape([batch_size, shape.num_elements() / batch_size]);
+
+    grad.powf(2.0).sum_dim(1).sqrt().reshape([batch_size])
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::TestADBackend;
+    use burn_tensor::Data;
+
+    type TestDoubleADBackend = burn_autodiff::ADBackendDecorator<TestADBackend>;
+
+    #[test]
+    fn test_gradient_penalty() {
+        let weight = Tensor::<TestDoubleADBackend, 2>::from_floats([[3.0], [4.0]]);
+        let input =
+            Tensor::<TestDoubleADBackend, 2>::from_floats([[1.0, 2.0], [-1.0, 0.5], [0.0, 0.0]])
+                .require_grad();
+        let output = input.clone().matmul(weight);
+
+        let penalty = GradientPenalty::default().forward_no_reduction(output, input);
+
+        // The gradients of a linear function are its weights, so the norm is 5.
+        penalty
+            .into_data()
+            .assert_approx_eq(&Data::from([16.0, 16.0, 16.0]), 3);
+    }
+
+    #[test]
+    fn test_gradient_penalty_gradient() {
+        let weight_inner = Tensor::<TestADBackend, 2>::from_floats([[3.0], [4.0]]).require_grad();
+        let weight = Tensor::<TestDoubleADBackend, 2>::from_inner(weight_inner.clone());
+        let input =
+            Tensor::<TestDoubleADBackend, 2>::from_floats([[1.0, 2.0], [-1.0, 0.5]]).require_grad();
+        let output = input.clone().matmul(weight);
+
+        let penalty = GradientPenalty::default().forward(output, input, Reduction::Mean);
+        let grads = penalty.backward();
+        let grad = weight_inner.grad(&grads).unwrap();
+
+        // d(|w| - 1)^2/dw = 2 (|w| - 1) w / |w|
+        grad.into_data()
+            .assert_approx_eq(&Data::from([[4.8], [6.4]]), 3);
+    }
+}
@@ -1,7 +1,15 @@
+mod cosine_embedding;
 mod cross_entropy;
+mod ctc;
+mod focal;
+mod gradient_penalty;
 mod mse;
 mod reduction;
 
+pub use cosine_embedding::*;
 pub use cross_entropy::*;
+pub use ctc::*;
+pub use focal::*;
+pub use gradient_penalty::*;
 pub use mse::*;
 pub use reduction::*;
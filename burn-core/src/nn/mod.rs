@@ -16,24 +16,34 @@ pub mod pool;
 /// Transformer module
 pub mod transformer;
 
+mod drop_path;
 mod dropout;
 mod embedding;
+mod embedding_bag;
 mod gelu;
+mod grouped_linear;
 mod initializer;
 mod linear;
 mod norm;
 mod padding;
 mod pos_encoding;
+mod prelu;
 mod relu;
 mod rnn;
+mod rope_encoding;
 
+pub use drop_path::*;
 pub use dropout::*;
 pub use embedding::*;
+pub use embedding_bag::*;
 pub use gelu::*;
+pub use grouped_linear::*;
 pub use initializer::*;
 pub use linear::*;
 pub use norm::*;
 pub use padding::*;
 pub use pos_encoding::*;
+pub use prelu::*;
 pub use relu::*;
 pub use rnn::*;
+pub use rope_encoding::*;
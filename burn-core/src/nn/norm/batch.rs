@@ -74,10 +74,13 @@ impl<const D: usize, B: Backend> BatchNorm<B, D> {
     ///
     /// - input: `[batch_size, channels, ...]`
     /// - output: `[batch_size, channels, ...]`
+    ///
+    /// The statistics are computed over every dimension except the channels, so `BatchNorm1d`
+    /// also accepts inputs without a length dimension (`[batch_size, channels]`).
     pub fn forward<const DI: usize>(&self, input: Tensor<B, DI>) -> Tensor<B, DI> {
         // Should be move to a compilation error when const generic support that kind of
         // validation. https://github.com/rust-lang/rust/issues/76560
-        if D + 2 != DI {
+        if D + 2 != DI && !(D == 1 && DI == 2) {
             panic!("BatchNorm{}D can only be applied on tensors of size {} with the following shape [batch_size, channels, ...], received {}D tensor", D, D+2, DI);
         }
 
@@ -111,21 +114,14 @@ impl<const D: usize, B: Backend> BatchNorm<B, D> {
             flatten_size *= dim;
         }
 
-        let mean = input
+        let (var, mean) = input
             .clone()
             .swap_dims(0, 1)
             .reshape([channels, flatten_size])
-            .mean_dim(1)
-            .reshape(shape_unsqueeze);
+            .var_mean_bias(1);
 
-        let var = input
-            .clone()
-            .sub(mean.clone())
-            .powf(2.0)
-            .swap_dims(0, 1)
-            .reshape([channels, flatten_size])
-            .mean_dim(1)
-            .reshape(shape_unsqueeze);
+        let mean = mean.reshape(shape_unsqueeze);
+        let var = var.reshape(shape_unsqueeze);
 
         let running_mean = self.running_mean.value_sync();
         let running_var = self.running_var.value_sync();
@@ -136,10 +132,16 @@ impl<const D: usize, B: Backend> BatchNorm<B, D> {
                 .mul_scalar(self.momentum)
                 .reshape([channels]),
         );
+        // The running variance is an estimate of the population variance, so it is updated with
+        // the unbiased variance of the batch.
+        let unbiased_factor = match flatten_size {
+            1 => 1.0,
+            size => size as f64 / (size - 1) as f64,
+        };
         let running_var = running_var.mul_scalar(1.0 - self.momentum).add(
             var.clone()
                 .detach()
-                .mul_scalar(self.momentum)
+                .mul_scalar(self.momentum * unbiased_factor)
                 .reshape([channels]),
         );
 
@@ -217,6 +219,87 @@ mod tests_1d {
         );
     }
 
+    #[test]
+    fn batch_norm_forward_train_without_length() {
+        let module = BatchNormConfig::new(3).init::<TestADBackend, 1>();
+
+        let output = module.forward(input_tensor_without_length());
+
+        output.to_data().assert_approx_eq(
+            &Data::from([
+                [1.1483, 0.0016, 1.6204],
+                [0.3752, 0.7507, -0.0452],
+                [0.0689, -1.6318, -0.5337],
+                [-1.5923, 0.8795, -1.0416],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn batch_norm_forward_train_statistics() {
+        let module = BatchNormConfig::new(3).init::<TestADBackend, 1>();
+
+        let output = module
+            .forward(input_tensor())
+            .swap_dims(0, 1)
+            .reshape([3, 4]);
+
+        let mean = output.clone().mean_dim(1);
+        let var = output.powf(2.0).mean_dim(1);
+
+        mean.into_data()
+            .assert_approx_eq(&Data::from([[0.0], [0.0], [0.0]]), 3);
+        var.into_data()
+            .assert_approx_eq(&Data::from([[1.0], [1.0], [1.0]]), 3);
+    }
+
+    #[test]
+    fn batch_norm_forward_inference_without_length() {
+        let module = BatchNormConfig::new(3).init::<TestADBackend, 1>();
+
+        module.forward(input_tensor_without_length());
+        let module = module.valid();
+        let output = module.forward(input_tensor_without_length());
+
+        output.to_data().assert_approx_eq(
+            &Data::from([
+                [0.9409, 0.5892, 0.9106],
+                [0.6976, 0.8774, 0.6844],
+                [0.6012, -0.0394, 0.6181],
+                [0.0782, 0.9270, 0.5492],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn batch_norm_running_stats_without_length() {
+        let module = BatchNormConfig::new(3).init::<TestADBackend, 1>();
+
+        let _output = module.forward(input_tensor_without_length());
+
+        module
+            .running_mean
+            .value_sync()
+            .into_data()
+            .assert_approx_eq(&Data::from([0.0615, 0.0627, 0.0729]), 3);
+        module
+            .running_var
+            .value_sync()
+            .into_data()
+            .assert_approx_eq(&Data::from([0.9120, 0.9181, 0.9022]), 3);
+    }
+
+    fn input_tensor_without_length<B: Backend>() -> Tensor<B, 2> {
+        Tensor::<B, 2>::from_floats([
+            [0.9601, 0.6272, 0.9378],
+            [0.7277, 0.9034, 0.7230],
+            [0.6356, 0.0249, 0.6600],
+            [0.1362, 0.9509, 0.5945],
+        ])
+    }
+
     fn input_tensor<B: Backend>() -> Tensor<B, 3> {
         Tensor::<B, 3>::from_floats([
             [[0.9601, 0.7277], [0.6272, 0.9034], [0.9378, 0.7230]],
@@ -280,6 +363,24 @@ mod tests_2d {
         );
     }
 
+    #[test]
+    fn batch_norm_forward_train_statistics() {
+        let module = BatchNormConfig::new(3).init::<TestADBackend, 2>();
+
+        let output = module
+            .forward(input_tensor())
+            .swap_dims(0, 1)
+            .reshape([3, 8]);
+
+        let mean = output.clone().mean_dim(1);
+        let var = output.powf(2.0).mean_dim(1);
+
+        mean.into_data()
+            .assert_approx_eq(&Data::from([[0.0], [0.0], [0.0]]), 3);
+        var.into_data()
+            .assert_approx_eq(&Data::from([[1.0], [1.0], [1.0]]), 3);
+    }
+
     #[test]
     fn batch_norm_running_mean() {
         let module = BatchNormConfig::new(3).init::<TestADBackend, 2>();
@@ -302,10 +403,11 @@ mod tests_2d {
 
         let running_var = module.running_var.value_sync();
 
+        // Updated with the unbiased variance, the biased one would give [0.9093, 0.9092, 0.9039].
         running_var
             .reshape([3])
             .into_data()
-            .assert_approx_eq(&Data::from([0.9106, 0.9105, 0.9045]), 2);
+            .assert_approx_eq(&Data::from([0.9106, 0.9105, 0.9045]), 3);
     }
 
     #[test]
@@ -0,0 +1,193 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::Param;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Configuration to create a [GroupNorm](GroupNorm) layer.
+#[derive(Config)]
+pub struct GroupNormConfig {
+    /// The number of groups to separate the channels into.
+    pub num_groups: usize,
+    /// The number of channels expected in the input.
+    pub num_channels: usize,
+    /// A value required for numerical stability. Default: 1e-5
+    #[config(default = 1e-5)]
+    pub epsilon: f64,
+}
+
+/// Applies Group Normalization over an input tensor as described in the paper [Group Normalization](https://arxiv.org/abs/1803.08494).
+///
+/// The channels are separated into groups, each one normalized over its channels and the spatial
+/// dimensions.
+///
+/// `Y = norm(X) * γ + β`
+#[derive(Module, Debug)]
+pub struct GroupNorm<B: Backend> {
+    gamma: Param<Tensor<B, 1>>,
+    beta: Param<Tensor<B, 1>>,
+    num_groups: usize,
+    num_channels: usize,
+    epsilon: f64,
+}
+
+impl GroupNormConfig {
+    /// Initialize a new [group norm](GroupNorm) module.
+    pub fn init<B: Backend>(&self) -> GroupNorm<B> {
+        self.check();
+
+        let gamma = Tensor::ones([self.num_channels]);
+        let beta = Tensor::zeros([self.num_channels]);
+
+        GroupNorm {
+            gamma: Param::from(gamma),
+            beta: Param::from(beta),
+            num_groups: self.num_groups,
+            num_channels: self.num_channels,
+            epsilon: self.epsilon,
+        }
+    }
+
+    /// Initialize a new [group norm](GroupNorm) module with a [record](GroupNormRecord).
+    pub fn init_with<B: Backend>(&self, record: GroupNormRecord<B>) -> GroupNorm<B> {
+        self.check();
+
+        GroupNorm {
+            gamma: record.gamma,
+            beta: record.beta,
+            num_groups: self.num_groups,
+            num_channels: self.num_channels,
+            epsilon: self.epsilon,
+        }
+    }
+
+    fn check(&self) {
+        if self.num_groups == 0 || !self.num_channels.is_multiple_of(self.num_groups) {
+            panic!(
+                "The number of channels {} must be divisible by the number of groups {}",
+                self.num_channels, self.num_groups
+            );
+        }
+    }
+}
+
+impl<B: Backend> GroupNorm<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, num_channels, ...]`
+    /// - output: `[batch_size, num_channels, ...]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let shape = input.shape();
+        let batch_size = shape.dims[0];
+        let channels = shape.dims[1];
+
+        if channels != self.num_channels {
+            panic!(
+                "The number of channels in the input tensor should be {}, received {}",
+                self.num_channels, channels
+            );
+        }
+
+        let group_size = shape.num_elements() / (batch_size * self.num_groups);
+        let input = input.reshape([batch_size, self.num_groups, group_size]);
+
+        let (var, mean) = input.clone().var_mean_bias(2);
+        let input_normalized = input
+            .sub(mean)
+            .div(var.add_scalar(self.epsilon).sqrt())
+            .reshape(shape);
+
+        let mut affine_shape = [1; D];
+        affine_shape[1] = channels;
+
+        input_normalized
+            .mul(self.gamma.val().reshape(affine_shape))
+            .add(self.beta.val().reshape(affine_shape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn group_norm_forward() {
+        let module = GroupNormConfig::new(2, 4).init::<TestBackend>();
+
+        let output = module.forward(input_tensor());
+
+        output.to_data().assert_approx_eq(
+            &Data::from([
+                [
+                    [1.3186, 0.3993, -1.9768, -0.3269],
+                    [0.0018, 1.0943, -0.8708, 0.3606],
+                    [1.1702, 0.4754, -0.7168, 1.2390],
+                    [0.1927, -1.4225, -1.4319, 0.4938],
+                ],
+                [
+                    [-1.7658, 1.7913, -0.4052, -0.9084],
+                    [0.6739, 0.4223, 0.2221, -0.0303],
+                    [1.4181, -0.8294, 1.5759, -0.0107],
+                    [0.3745, -0.6815, -0.4202, -1.4266],
+                ],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn group_norm_with_one_channel_per_group_should_match_instance_norm() {
+        let module = GroupNormConfig::new(4, 4).init::<TestBackend>();
+        let input = input_tensor::<TestBackend>().reshape([2, 4, 2, 2]);
+
+        let output = module.forward(input);
+
+        // Each channel of each sample normalized on its own, as with an instance norm.
+        output.into_data().assert_approx_eq(
+            &Data::from([
+                [
+                    [[1.2138, 0.4522], [-1.5164, -0.1495]],
+                    [[-0.2046, 1.3402], [-1.4384, 0.3027]],
+                    [[0.7995, -0.0847], [-1.6019, 0.8871]],
+                    [[0.8239, -0.9875], [-0.9980, 1.1616]],
+                ],
+                [
+                    [[-1.0991, 1.6088], [-0.0633, -0.4464]],
+                    [[1.3581, 0.3870], [-0.3855, -1.3596]],
+                    [[0.8771, -1.3639], [1.0344, -0.5476]],
+                    [[1.4184, -0.2222], [0.1837, -1.3798]],
+                ],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn group_norm_should_panic_when_channels_are_not_divisible_by_groups() {
+        GroupNormConfig::new(3, 4).init::<TestBackend>();
+    }
+
+    fn input_tensor<B: Backend>() -> Tensor<B, 3> {
+        Tensor::<B, 3>::from_floats([
+            [
+                [0.9601, 0.7277, 0.1270, 0.5441],
+                [0.6272, 0.9034, 0.4066, 0.7179],
+                [0.9378, 0.7230, 0.3544, 0.9591],
+                [0.6356, 0.1362, 0.1333, 0.7287],
+            ],
+            [
+                [0.0249, 0.9509, 0.3791, 0.2481],
+                [0.6600, 0.5945, 0.5424, 0.4767],
+                [0.8491, 0.2108, 0.8939, 0.4433],
+                [0.5527, 0.2528, 0.3270, 0.0412],
+            ],
+        ])
+    }
+}
@@ -1,5 +1,9 @@
 mod batch;
+mod group;
 mod layer;
+mod spectral;
 
 pub use batch::*;
+pub use group::*;
 pub use layer::*;
+pub use spectral::*;
@@ -0,0 +1,199 @@
+use crate as burn;
+
+use crate::{
+    config::Config,
+    module::{Module, Param, RunningState},
+    tensor::{backend::Backend, Distribution, Tensor},
+};
+
+/// Configuration to create a [SpectralNorm](SpectralNorm) wrapper.
+#[derive(Config, Debug)]
+pub struct SpectralNormConfig {
+    /// The number of power iterations done on each training forward pass. Default: 1
+    #[config(default = 1)]
+    pub n_power_iterations: usize,
+    /// A value required for numerical stability. Default: 1e-12
+    #[config(default = 1e-12)]
+    pub epsilon: f64,
+}
+
+/// Wraps a weight to apply Spectral Normalization as described in the paper
+/// [Spectral Normalization for Generative Adversarial Networks](https://arxiv.org/abs/1802.05957)
+///
+/// `W_sn = W / σ(W)`
+///
+/// The weight is viewed as a matrix of shape `[dims[0], dims[1] * ... * dims[D - 1]]`, and its
+/// largest singular value `σ(W)` is estimated with power iterations. The iteration vector is kept
+/// as a running state, so it's only updated during training and is saved with the record. The
+/// gradient flows through the normalization.
+///
+/// The normalized weight is meant to be used with the functional operations, e.g.
+/// [conv2d](crate::tensor::module::conv2d) or a matrix multiplication for linear layers.
+#[derive(Module, Debug)]
+pub struct SpectralNorm<B: Backend, const D: usize> {
+    weight: Param<Tensor<B, D>>,
+    u: RunningState<Tensor<B, 1>>,
+    n_power_iterations: usize,
+    epsilon: f64,
+}
+
+impl SpectralNormConfig {
+    /// Initialize a new [spectral norm](SpectralNorm) wrapper around the given weight.
+    pub fn init<B: Backend, const D: usize>(&self, weight: Tensor<B, D>) -> SpectralNorm<B, D> {
+        let u = Tensor::random([weight.dims()[0]], Distribution::Normal(0.0, 1.0))
+            .to_device(&weight.device());
+
+        SpectralNorm {
+            weight: Param::from(weight),
+            u: RunningState::new(normalize(u, self.epsilon)),
+            n_power_iterations: self.n_power_iterations,
+            epsilon: self.epsilon,
+        }
+    }
+
+    /// Initialize a new [spectral norm](SpectralNorm) wrapper with a [record](SpectralNormRecord).
+    pub fn init_with<B: Backend, const D: usize>(
+        &self,
+        record: SpectralNormRecord<B, D>,
+    ) -> SpectralNorm<B, D> {
+        SpectralNorm {
+            weight: record.weight,
+            u: RunningState::from_record(record.u),
+            n_power_iterations: self.n_power_iterations,
+            epsilon: self.epsilon,
+        }
+    }
+}
+
+impl<B: Backend, const D: usize> SpectralNorm<B, D> {
+    /// Returns the normalized weight.
+    ///
+    /// During training, the estimation of the largest singular value is refined with power
+    /// iterations before normalizing.
+    pub fn weight(&self) -> Tensor<B, D> {
+        let weight = self.weight.val();
+        let dims = weight.dims();
+        let num_rows = dims[0];
+        let num_columns = dims.iter().skip(1).product();
+        let matrix = weight.clone().reshape([num_rows, num_columns]);
+        let matrix_detached = matrix.clone().detach();
+
+        let mut u = match B::ad_enabled() {
+            true => self.u.value_sync(),
+            false => self.u.value(),
+        };
+        let mut v = self.power_step(matrix_detached.clone().transpose(), u.clone());
+
+        if B::ad_enabled() {
+            for _ in 0..self.n_power_iterations {
+                u = self.power_step(matrix_detached.clone(), v);
+                v = self.power_step(matrix_detached.clone().transpose(), u.clone());
+            }
+
+            self.u.update(u.clone());
+        }
+
+        // σ(W) = uᵀ W v
+        let sigma = u
+            .reshape([1, num_rows])
+            .matmul(matrix)
+            .matmul(v.reshape([num_columns, 1]))
+            .reshape([1; D]);
+
+        weight.div(sigma)
+    }
+
+    fn power_step(&self, matrix: Tensor<B, 2>, vector: Tensor<B, 1>) -> Tensor<B, 1> {
+        let [num_rows, num_columns] = matrix.dims();
+        let vector = matrix
+            .matmul(vector.reshape([num_columns, 1]))
+            .reshape([num_rows]);
+
+        normalize(vector, self.epsilon)
+    }
+}
+
+fn normalize<B: Backend>(vector: Tensor<B, 1>, epsilon: f64) -> Tensor<B, 1> {
+    let norm = vector.clone().powf(2.0).sum().sqrt().add_scalar(epsilon);
+
+    vector.div(norm)
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        module::ADModule,
+        record::{BinBytesRecorder, FullPrecisionSettings, Recorder},
+        TestADBackend,
+    };
+
+    #[test]
+    fn spectral_norm_weight_has_unit_spectral_norm() {
+        TestADBackend::seed(0);
+        let weight = Tensor::<TestADBackend, 2>::random([8, 6], Distribution::Normal(0.0, 3.0))
+            .require_grad();
+        let module = SpectralNormConfig::new().init(weight.clone());
+
+        for _ in 0..20 {
+            module.weight();
+        }
+        let weight_normalized = module.weight();
+
+        let sigma = spectral_norm(weight_normalized.clone().detach());
+        assert!((sigma - 1.0).abs() < 1e-3, "{sigma}");
+
+        // The normalization is differentiable.
+        let grads = weight_normalized.sum().backward();
+        assert!(module.weight.grad(&grads).is_some());
+    }
+
+    #[test]
+    fn spectral_norm_power_iteration_state_is_checkpointed() {
+        TestADBackend::seed(0);
+        let weight = Tensor::<TestADBackend, 4>::random([4, 2, 3, 3], Distribution::Default);
+        let config = SpectralNormConfig::new().with_n_power_iterations(3);
+        let module = config.init(weight);
+        let u_init = module.u.value_sync();
+
+        // The iteration vector is updated by a training forward pass.
+        module.weight();
+        let u = module.u.value_sync();
+        let distance = u.clone().sub(u_init).powf(2.0).sum().into_scalar();
+        assert!(distance > 1e-6, "{distance}");
+
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let bytes = recorder.record(module.clone().into_record(), ()).unwrap();
+        let module_loaded = config.init_with::<TestADBackend, 4>(recorder.load(bytes).unwrap());
+
+        module_loaded
+            .u
+            .value()
+            .into_data()
+            .assert_approx_eq(&u.into_data(), 5);
+        // The iteration vector isn't updated during inference.
+        module_loaded
+            .valid()
+            .weight()
+            .into_data()
+            .assert_approx_eq(&module.valid().weight().into_data(), 5);
+    }
+
+    /// Largest singular value computed with many power iterations.
+    fn spectral_norm(weight: Tensor<TestADBackend, 2>) -> f32 {
+        let [_, num_columns] = weight.dims();
+        let gram = weight.clone().transpose().matmul(weight);
+        let mut v = Tensor::<TestADBackend, 2>::ones([num_columns, 1]);
+
+        for _ in 0..200 {
+            v = gram.clone().matmul(v);
+            v = v
+                .clone()
+                .div(v.clone().powf(2.0).sum().sqrt().reshape([1, 1]));
+        }
+
+        let eigenvalue = gram.matmul(v.clone()).mul(v).sum().into_scalar();
+        eigenvalue.sqrt()
+    }
+}
@@ -5,6 +5,15 @@ use burn_tensor::ops::conv::calculate_conv_padding;
 use crate::config::Config;
 use crate::module::Module;
 
+/// Values used to fill the padded area of operators supporting it.
+#[derive(Module, Config, Debug, PartialEq)]
+pub enum PadMode {
+    /// Pad with zeros.
+    Zeros,
+    /// Pad by wrapping around the input, applying periodic boundary conditions.
+    Circular,
+}
+
 /// Padding configuration for 1D operators.
 #[derive(Module, Config, Debug, PartialEq)]
 pub enum PaddingConfig1d {
@@ -35,7 +44,7 @@ impl PaddingConfig1d {
 }
 
 /// Padding configuration for 2D operators.
-#[derive(Module, Config, Debug)]
+#[derive(Module, Config, Debug, PartialEq)]
 pub enum PaddingConfig2d {
     /// Dynamically calculate the amount of padding necessary to ensure that the output size will be
     /// the same as the input.
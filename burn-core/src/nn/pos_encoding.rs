@@ -209,6 +209,27 @@ mod tests {
         sinusoids.to_data().assert_approx_eq(&expected.to_data(), 5);
     }
 
+    #[test]
+    fn test_generate_sinusoids_closed_form() {
+        let [length, d_model, max_timescale] = [4, 8, 10_000];
+        let sinusoids = generate_sinusoids::<TestBackend>(length, d_model, max_timescale);
+
+        // PE(pos, 2i) = sin(pos / max_timescale^(2i / d_model))
+        // PE(pos, 2i + 1) = cos(pos / max_timescale^(2i / d_model))
+        let mut expected = Vec::with_capacity(length * d_model);
+        for pos in 0..length {
+            for i in 0..d_model / 2 {
+                let angle =
+                    pos as f64 / libm::pow(max_timescale as f64, (2 * i) as f64 / d_model as f64);
+                expected.push(libm::sin(angle) as f32);
+                expected.push(libm::cos(angle) as f32);
+            }
+        }
+        let expected = Data::new(expected, [length, d_model].into());
+
+        sinusoids.into_data().assert_approx_eq(&expected, 4);
+    }
+
     #[test]
     #[should_panic]
     fn d_model_input_should_match() {
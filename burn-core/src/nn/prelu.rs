@@ -0,0 +1,106 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::Param;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Configuration to create a [PRelu](PRelu) layer.
+#[derive(Config)]
+pub struct PReluConfig {
+    /// The number of learnable slopes, either 1 to share it between all the channels or the
+    /// number of channels. Default: 1
+    #[config(default = 1)]
+    pub num_parameters: usize,
+    /// The initial value of the slopes. Default: 0.25
+    #[config(default = 0.25)]
+    pub alpha: f64,
+}
+
+/// Applies the parametric rectified linear unit function element-wise, as described in the paper
+/// [Delving Deep into Rectifiers](https://arxiv.org/abs/1502.01852).
+///
+/// `y = max(0, x) + α * min(0, x)`
+///
+/// # Params
+///
+/// - alpha: Learnable slopes of shape `[num_parameters]`, applied along the channel dimension.
+#[derive(Module, Debug)]
+pub struct PRelu<B: Backend> {
+    alpha: Param<Tensor<B, 1>>,
+}
+
+impl PReluConfig {
+    /// Initialize a new [prelu](PRelu) module.
+    pub fn init<B: Backend>(&self) -> PRelu<B> {
+        let alpha = Tensor::full([self.num_parameters], self.alpha);
+
+        PRelu {
+            alpha: Param::from(alpha),
+        }
+    }
+
+    /// Initialize a new [prelu](PRelu) module with a [record](PReluRecord).
+    pub fn init_with<B: Backend>(&self, record: PReluRecord<B>) -> PRelu<B> {
+        PRelu {
+            alpha: record.alpha,
+        }
+    }
+}
+
+impl<B: Backend> PRelu<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, ...]`
+    /// - output: `[batch_size, channels, ...]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        crate::tensor::activation::prelu(input, self.alpha.val())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[cfg(feature = "std")]
+    use crate::TestADBackend;
+
+    #[test]
+    fn forward_should_apply_the_slope_of_each_channel() {
+        let config = PReluConfig::new().with_num_parameters(2);
+        let prelu = config.init_with::<TestBackend>(PReluRecord {
+            alpha: Param::from(Tensor::from_floats([0.1, 0.5])),
+        });
+        let input = Tensor::<TestBackend, 3>::from_floats([[[-2.0, 3.0], [-4.0, 0.0]]]);
+
+        let output = prelu.forward(input);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[-0.2, 3.0], [-2.0, 0.0]]]), 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn backward_should_accumulate_the_slope_gradients() {
+        let prelu = PReluConfig::new().init::<TestADBackend>();
+        let input =
+            Tensor::<TestADBackend, 2>::from_floats([[-2.0, 3.0], [-4.0, 0.0]]).require_grad();
+
+        let grads = prelu.forward(input.clone()).sum().backward();
+        let grad_alpha = prelu.alpha.grad(&grads).unwrap();
+        let grad_input = input.grad(&grads).unwrap();
+
+        grad_alpha
+            .into_data()
+            .assert_approx_eq(&Data::from([-6.0]), 3);
+        grad_input
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.25, 1.0], [0.25, 0.25]]), 3);
+    }
+}
@@ -23,8 +23,6 @@ pub struct LstmConfig {
     /// Lstm initializer
     #[config(default = "Initializer::XavierNormal{gain:1.0}")]
     pub initializer: Initializer,
-    /// The batch size.
-    pub batch_size: usize,
 }
 
 /// The Lstm module. This implementation is for a unidirectional, stateless, Lstm.
@@ -34,7 +32,6 @@ pub struct Lstm<B: Backend> {
     forget_gate: GateController<B>,
     output_gate: GateController<B>,
     cell_gate: GateController<B>,
-    batch_size: usize,
     d_hidden: usize,
 }
 
@@ -73,7 +70,6 @@ impl LstmConfig {
             forget_gate,
             output_gate,
             cell_gate,
-            batch_size: self.batch_size,
             d_hidden: self.d_hidden,
         }
     }
@@ -101,46 +97,44 @@ impl LstmConfig {
                 record.output_gate,
             ),
             cell_gate: gate_controller::GateController::new_with(&linear_config, record.cell_gate),
-            batch_size: self.batch_size,
             d_hidden: self.d_hidden,
         }
     }
 }
 
 impl<B: Backend> Lstm<B> {
-    /// Applies the forward pass on the input tensor. This LSTM implementation
-    /// returns the cell state and hidden state for each element in a sequence (i.e., across `seq_length`),
-    /// producing 3-dimensional tensors where the dimensions represent [batch_size, sequence_length, hidden_size].
+    /// Applies the forward pass on the input tensor, iterating over the sequence dimension.
     ///
     /// Parameters:
     ///     batched_input: The input tensor of shape [batch_size, sequence_length, input_size].
-    ///     state: An optional tuple of tensors representing the initial cell state and hidden state.
+    ///     state: An optional tuple of tensors representing the initial hidden state and cell state.
     ///            Each state tensor has shape [batch_size, hidden_size].
     ///            If no initial state is provided, these tensors are initialized to zeros.
     ///
     /// Returns:
-    ///     A tuple of tensors, where the first tensor represents the cell states and
-    ///     the second tensor represents the hidden states for each sequence element.
-    ///     Both output tensors have the shape [batch_size, sequence_length, hidden_size].
+    ///     A tuple with the hidden state of each sequence element, of shape
+    ///     [batch_size, sequence_length, hidden_size], and the final hidden and cell states,
+    ///     each of shape [batch_size, hidden_size].
     pub fn forward(
-        &mut self,
+        &self,
         batched_input: Tensor<B, 3>,
         state: Option<(Tensor<B, 2>, Tensor<B, 2>)>,
-    ) -> (Tensor<B, 3>, Tensor<B, 3>) {
-        let seq_length = batched_input.shape().dims[1];
-        let mut batched_cell_state = Tensor::zeros([self.batch_size, seq_length, self.d_hidden]);
-        let mut batched_hidden_state = Tensor::zeros([self.batch_size, seq_length, self.d_hidden]);
-
-        let (mut cell_state, mut hidden_state) = match state {
-            Some((cell_state, hidden_state)) => (cell_state, hidden_state),
+    ) -> (Tensor<B, 3>, (Tensor<B, 2>, Tensor<B, 2>)) {
+        let [batch_size, seq_length, _] = batched_input.dims();
+        let device = batched_input.device();
+        let mut batched_hidden_state =
+            Tensor::zeros_device([batch_size, seq_length, self.d_hidden], &device);
+
+        let (mut hidden_state, mut cell_state) = match state {
+            Some((hidden_state, cell_state)) => (hidden_state, cell_state),
             None => (
-                Tensor::zeros([self.batch_size, self.d_hidden]),
-                Tensor::zeros([self.batch_size, self.d_hidden]),
+                Tensor::zeros_device([batch_size, self.d_hidden], &device),
+                Tensor::zeros_device([batch_size, self.d_hidden], &device),
             ),
         };
 
         for t in 0..seq_length {
-            let indices = Tensor::arange(t..t + 1);
+            let indices = Tensor::arange_device(t..t + 1, &device);
             let input_t = batched_input.clone().select(1, indices).squeeze(1);
             // f(orget)g(ate) tensors
             let biased_fg_input_sum = self.gate_product(&input_t, &hidden_state, &self.forget_gate);
@@ -158,21 +152,17 @@ impl<B: Backend> Lstm<B> {
             let biased_cg_input_sum = self.gate_product(&input_t, &hidden_state, &self.cell_gate);
             let candidate_cell_values = biased_cg_input_sum.tanh();
 
-            cell_state = forget_values * cell_state.clone() + add_values * candidate_cell_values;
+            cell_state = forget_values * cell_state + add_values * candidate_cell_values;
             hidden_state = output_values * cell_state.clone().tanh();
 
-            // store the state for this timestep
-            batched_cell_state = batched_cell_state.slice_assign(
-                [0..self.batch_size, t..(t + 1), 0..self.d_hidden],
-                cell_state.clone().unsqueeze(),
-            );
+            // store the hidden state for this timestep
             batched_hidden_state = batched_hidden_state.slice_assign(
-                [0..self.batch_size, t..(t + 1), 0..self.d_hidden],
-                hidden_state.clone().unsqueeze(),
+                [0..batch_size, t..(t + 1), 0..self.d_hidden],
+                hidden_state.clone().reshape([batch_size, 1, self.d_hidden]),
             );
         }
 
-        (batched_cell_state, batched_hidden_state)
+        (batched_hidden_state, (hidden_state, cell_state))
     }
 
     /// Helper function for performing weighted matrix product for a gate and adds
@@ -219,13 +209,16 @@ impl<B: Backend> Lstm<B> {
 mod tests {
     use super::*;
     use crate::{module::Param, nn::LinearRecord, TestBackend};
-    use burn_tensor::Data;
+    use burn_tensor::{Data, Distribution};
+
+    #[cfg(feature = "std")]
+    use crate::TestADBackend;
 
     #[test]
     fn test_with_uniform_initializer() {
         TestBackend::seed(0);
 
-        let config = LstmConfig::new(5, 5, false, 2)
+        let config = LstmConfig::new(5, 5, false)
             .with_initializer(Initializer::Uniform { min: 0.0, max: 1.0 });
         let lstm = config.init::<TestBackend>();
 
@@ -250,7 +243,7 @@ mod tests {
     #[test]
     fn test_forward_single_input_single_feature() {
         TestBackend::seed(0);
-        let config = LstmConfig::new(1, 1, false, 1);
+        let config = LstmConfig::new(1, 1, false);
         let mut lstm = config.init::<TestBackend>();
 
         fn create_gate_controller(
@@ -311,11 +304,12 @@ mod tests {
         // single timestep with single feature
         let input = Tensor::<TestBackend, 3>::from_data(Data::from([[[0.1]]]));
 
-        let (cell_state_batch, hidden_state_batch) = lstm.forward(input, None);
-        let cell_state = cell_state_batch.select(0, Tensor::arange(0..1)).squeeze(0);
-        let hidden_state = hidden_state_batch
-            .select(0, Tensor::arange(0..1))
-            .squeeze(0);
+        let (output, (hidden_state, cell_state)) = lstm.forward(input, None);
+
+        output
+            .squeeze(1)
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.024]]), 3);
         cell_state
             .to_data()
             .assert_approx_eq(&Data::from([[0.046]]), 3);
@@ -323,4 +317,54 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::from([[0.024]]), 3)
     }
+
+    #[test]
+    fn test_forward_shapes_with_initial_state() {
+        let config = LstmConfig::new(4, 3, true);
+        let lstm = config.init::<TestBackend>();
+        let input = Tensor::<TestBackend, 3>::random([2, 5, 4], Distribution::Default);
+        let state = (Tensor::zeros([2, 3]), Tensor::ones([2, 3]));
+
+        let (output, (hidden_state, cell_state)) = lstm.forward(input, Some(state));
+
+        assert_eq!(output.shape().dims, [2, 5, 3]);
+        assert_eq!(hidden_state.shape().dims, [2, 3]);
+        assert_eq!(cell_state.shape().dims, [2, 3]);
+        // The output of the last step is the final hidden state.
+        output
+            .slice([0..2, 4..5, 0..3])
+            .squeeze(1)
+            .to_data()
+            .assert_approx_eq(&hidden_state.to_data(), 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_gradients_flow_through_two_steps() {
+        let config = LstmConfig::new(2, 3, true);
+        let lstm = config.init::<TestADBackend>();
+        let input = Tensor::<TestADBackend, 3>::from_data(Data::from([[[0.5, -1.0], [1.0, 0.2]]]))
+            .require_grad();
+
+        // Only the last hidden state, which depends on both steps through the recurrence.
+        let (_, (hidden_state, _)) = lstm.forward(input.clone(), None);
+        let grads = hidden_state.sum().backward();
+
+        let grad_input = input.grad(&grads).unwrap();
+        let grad_hidden_weight = lstm
+            .forget_gate
+            .hidden_transform
+            .weight
+            .grad(&grads)
+            .unwrap();
+
+        let grad_first_step = grad_input.clone().slice([0..1, 0..1, 0..2]);
+        let norm = |tensor: Tensor<TestBackend, 2>| tensor.powf(2.0).sum().into_scalar();
+
+        assert_eq!(grad_input.shape().dims, [1, 2, 2]);
+        assert!(norm(grad_first_step.squeeze(0)) > 0.0);
+        // The hidden weights only contribute from the second step, the initial state being zeros.
+        assert!(norm(grad_hidden_weight.clone()) > 0.0);
+        assert_eq!(grad_hidden_weight.shape().dims, [3, 3]);
+    }
 }
@@ -1,4 +1,5 @@
 mod gate_controller;
+mod sequence;
 
 /// Gated Recurrent Unit module.
 pub mod gru;
@@ -8,3 +9,4 @@ pub mod lstm;
 
 pub use gate_controller::*;
 pub use lstm::*;
+pub use sequence::*;
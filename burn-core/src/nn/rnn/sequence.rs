@@ -0,0 +1,123 @@
+use alloc::{vec, vec::Vec};
+
+use crate::tensor::backend::Backend;
+use crate::tensor::{ElementConversion, Tensor};
+
+/// Pad a list of variable length sequences to the length of the longest one and stack them
+/// into a single batch.
+///
+/// # Arguments
+///
+/// * `sequences` - The sequences, each of shape `[seq_length, d_model]`.
+/// * `pad_value` - The value used to fill the padded positions.
+/// * `batch_first` - If the batch dimension is the first one in the output.
+///
+/// # Returns
+///
+/// The padded batch of shape `[batch_size, max_seq_length, d_model]` when `batch_first` is
+/// true, `[max_seq_length, batch_size, d_model]` otherwise, along with the original length of
+/// each sequence. An empty list of sequences results in an empty batch.
+///
+/// # Panics
+///
+/// If the sequences don't all have the same `d_model`.
+pub fn pad_sequence<B: Backend, E: ElementConversion>(
+    sequences: Vec<Tensor<B, 2>>,
+    pad_value: E,
+    batch_first: bool,
+) -> (Tensor<B, 3>, Vec<usize>) {
+    let lengths: Vec<usize> = sequences.iter().map(|seq| seq.dims()[0]).collect();
+
+    let first = match sequences.first() {
+        Some(first) => first,
+        None => return (Tensor::zeros([0, 0, 0]), lengths),
+    };
+
+    let d_model = first.dims()[1];
+    let device = first.device();
+    let max_length = lengths.iter().copied().max().unwrap_or(0);
+    let pad_value = pad_value.elem::<f64>();
+
+    let sequences = sequences
+        .into_iter()
+        .map(|seq| {
+            let [length, d_model_seq] = seq.dims();
+
+            assert_eq!(
+                d_model, d_model_seq,
+                "All sequences must have the same d_model, got {d_model} and {d_model_seq}",
+            );
+
+            let seq = match length < max_length {
+                true => {
+                    let padding =
+                        Tensor::full_device([max_length - length, d_model], pad_value, &device);
+                    Tensor::cat(vec![seq, padding], 0)
+                }
+                false => seq,
+            };
+
+            seq.unsqueeze::<3>()
+        })
+        .collect();
+
+    let batch = Tensor::cat(sequences, 0);
+
+    match batch_first {
+        true => (batch, lengths),
+        false => (batch.swap_dims(0, 1), lengths),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_pad_sequence_batch_first() {
+        let sequences = vec![
+            Tensor::<TestBackend, 2>::from_floats([[1.0, 1.0]]),
+            Tensor::<TestBackend, 2>::from_floats([[2.0, 2.0], [3.0, 3.0], [4.0, 4.0]]),
+            Tensor::<TestBackend, 2>::from_floats([[5.0, 5.0], [6.0, 6.0]]),
+        ];
+
+        let (batch, lengths) = pad_sequence(sequences, -1.0, true);
+
+        assert_eq!(lengths, vec![1, 3, 2]);
+        assert_eq!(batch.shape().dims, [3, 3, 2]);
+        assert_eq!(
+            batch.into_data(),
+            Data::from([
+                [[1.0, 1.0], [-1.0, -1.0], [-1.0, -1.0]],
+                [[2.0, 2.0], [3.0, 3.0], [4.0, 4.0]],
+                [[5.0, 5.0], [6.0, 6.0], [-1.0, -1.0]],
+            ])
+        );
+    }
+
+    #[test]
+    fn test_pad_sequence_sequence_first() {
+        let sequences = vec![
+            Tensor::<TestBackend, 2>::from_floats([[1.0]]),
+            Tensor::<TestBackend, 2>::from_floats([[2.0], [3.0]]),
+        ];
+
+        let (batch, lengths) = pad_sequence(sequences, 0.0, false);
+
+        assert_eq!(lengths, vec![1, 2]);
+        assert_eq!(
+            batch.into_data(),
+            Data::from([[[1.0], [2.0]], [[0.0], [3.0]]])
+        );
+    }
+
+    #[test]
+    fn test_pad_sequence_empty() {
+        let (batch, lengths) = pad_sequence(Vec::<Tensor<TestBackend, 2>>::new(), 0.0, true);
+
+        assert!(lengths.is_empty());
+        assert_eq!(batch.shape().dims, [0, 0, 0]);
+    }
+}
@@ -0,0 +1,177 @@
+use alloc::{vec, vec::Vec};
+
+use crate as burn;
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use burn_tensor::Data;
+
+use libm::powf;
+
+/// Configuration to create a [RotaryEncoding](RotaryEncoding) layer.
+#[derive(Config)]
+pub struct RotaryEncodingConfig {
+    /// The size of each vector, must be even.
+    pub d_model: usize,
+
+    /// Maximum sequence length to use.
+    #[config(default = "4096")]
+    pub max_len: usize,
+
+    /// Base used to compute the rotation frequencies.
+    #[config(default = "10000.0")]
+    pub theta: f32,
+}
+
+/// Rotary position encoding layer.
+///
+/// This layer encodes the position of each vector by rotating pairs of its features with an
+/// angle proportional to the position, so that the dot product between a query and a key only
+/// depends on their relative position. It is applied to the queries and keys of an attention
+/// layer, not to the input embeddings.
+///
+/// Introduced in [RoFormer: Enhanced Transformer with Rotary Position Embedding
+/// ](https://arxiv.org/abs/2104.09864). The features are paired using the rotate half
+/// convention, the first half of the vector being rotated with the second half.
+#[derive(Module, Debug)]
+pub struct RotaryEncoding<B: Backend> {
+    freq_cos: Tensor<B, 2>,
+    freq_sin: Tensor<B, 2>,
+}
+
+impl RotaryEncodingConfig {
+    /// Initialize a new [RotaryEncoding](RotaryEncoding) module.
+    pub fn init<B: Backend>(&self) -> RotaryEncoding<B> {
+        let angles = generate_angles::<B>(self.max_len, self.d_model, self.theta);
+
+        RotaryEncoding {
+            freq_cos: angles.clone().cos(),
+            freq_sin: angles.sin(),
+        }
+    }
+}
+
+impl<B: Backend> RotaryEncoding<B> {
+    /// Applies the rotary encoding to the input tensor, where the second to last dimension is
+    /// the position in the sequence.
+    ///
+    /// # Shapes
+    ///
+    /// * input: [..., seq_length, d_model]
+    /// * output: [..., seq_length, d_model]
+    ///
+    /// # Panics
+    ///
+    /// * Panics if the input sequence length is greater than the maximum sequence length.
+    /// * Panics if the input d_model is not equal to the d_model of the encoding.
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let dims = input.dims();
+        let seq_length = dims[D - 2];
+        let d_model_input = dims[D - 1];
+
+        let [max_len, d_model] = self.freq_cos.dims();
+
+        assert!(
+            max_len >= seq_length,
+            "max_len({max_len}) must be greater or equal than length({seq_length})",
+        );
+
+        assert!(
+            d_model_input == d_model,
+            "d_model({d_model_input}) of the input must be equal to d_model of encoding({d_model})",
+        );
+
+        let slices = [0..seq_length, 0..d_model];
+        let cos = self.freq_cos.clone().slice(slices.clone()).unsqueeze::<D>();
+        let sin = self.freq_sin.clone().slice(slices).unsqueeze::<D>();
+
+        input.clone() * cos + rotate_half(input) * sin
+    }
+}
+
+/// Swap the two halves of the last dimension, negating the second one: `[x1, x2] -> [-x2, x1]`.
+fn rotate_half<B: Backend, const D: usize>(input: Tensor<B, D>) -> Tensor<B, D> {
+    let dims = input.dims();
+    let half = dims[D - 1] / 2;
+
+    let mut ranges_first = dims.map(|dim| 0..dim);
+    let mut ranges_second = ranges_first.clone();
+    ranges_first[D - 1] = 0..half;
+    ranges_second[D - 1] = half..dims[D - 1];
+
+    let first = input.clone().slice(ranges_first);
+    let second = input.slice(ranges_second);
+
+    Tensor::cat(vec![second.neg(), first], D - 1)
+}
+
+/// Returns the rotation angle of each feature for each position, where the angle of the feature
+/// `i` at the position `p` is `p * theta ^ (-2 (i mod d_model / 2) / d_model)`.
+fn generate_angles<B: Backend>(length: usize, d_model: usize, theta: f32) -> Tensor<B, 2> {
+    let half = d_model / 2;
+    assert!(half * 2 == d_model, "d_model must be even");
+
+    let inv_freq: Vec<f32> = (0..half)
+        .map(|i| 1.0 / powf(theta, (2 * i) as f32 / d_model as f32))
+        .collect();
+
+    let mut angles = Vec::with_capacity(length * d_model);
+
+    for position in 0..length {
+        // Both halves of the vector share the same frequencies.
+        for _ in 0..2 {
+            angles.extend(inv_freq.iter().map(|freq| position as f32 * freq));
+        }
+    }
+
+    let data = Data::new(angles, [length, d_model].into());
+
+    Tensor::<B, 2>::from_data(data.convert())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Distribution;
+
+    #[test]
+    fn test_rotation_should_preserve_norms() {
+        let rope = RotaryEncodingConfig::new(8).init::<TestBackend>();
+        let input = Tensor::<TestBackend, 3>::random([2, 5, 8], Distribution::Default);
+
+        let output = rope.forward(input.clone());
+
+        assert_eq!(output.shape().dims, [2, 5, 8]);
+        let norm = |tensor: Tensor<TestBackend, 3>| tensor.powf(2.0).sum_dim(2).sqrt();
+        norm(output)
+            .into_data()
+            .assert_approx_eq(&norm(input).into_data(), 4);
+    }
+
+    #[test]
+    fn test_dot_product_should_depend_on_relative_position() {
+        let rope = RotaryEncodingConfig::new(2).init::<TestBackend>();
+        let query = rope.forward(Tensor::<TestBackend, 2>::from_floats([[1.0, 0.0]; 4]));
+        let key = rope.forward(Tensor::<TestBackend, 2>::from_floats([[1.0, 0.0]; 4]));
+        let dot = |m: usize, n: usize| {
+            let query = query.clone().slice([m..m + 1, 0..2]);
+            let key = key.clone().slice([n..n + 1, 0..2]);
+            (query * key).sum().into_scalar()
+        };
+
+        // With a single pair of features, the frequency is 1 and the dot product is cos(m - n).
+        assert!((dot(2, 0) - libm::cosf(2.0)).abs() < 1e-5);
+        assert!((dot(3, 1) - dot(2, 0)).abs() < 1e-5);
+        assert!((dot(1, 3) - dot(0, 2)).abs() < 1e-5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn d_model_input_should_match() {
+        let rope = RotaryEncodingConfig::new(8).init::<TestBackend>();
+        let input = Tensor::<TestBackend, 3>::zeros([1, 5, 10]);
+        let _output = rope.forward(input);
+    }
+}
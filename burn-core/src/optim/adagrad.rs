@@ -0,0 +1,347 @@
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::ADModule, record::Record,
+    LearningRate,
+};
+
+use super::{
+    decay::{WeightDecay, WeightDecayConfig, WeightDecayState},
+    SimpleOptimizer,
+};
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{backend::ADBackend, Tensor};
+use burn_tensor::backend::Backend;
+
+/// Adagrad configuration.
+#[derive(Config)]
+pub struct AdagradConfig {
+    /// Decay applied to the learning rate at each step.
+    #[config(default = 0.0)]
+    lr_decay: f64,
+    /// A value added to the accumulated squared gradients, under the square root, for numerical
+    /// stability.
+    #[config(default = 1e-5)]
+    epsilon: f32,
+    /// [Weight decay](WeightDecayConfig) config.
+    weight_decay: Option<WeightDecayConfig>,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// Adagrad optimizer as described in the paper [Adaptive Subgradient Methods for Online Learning
+/// and Stochastic Optimization](https://www.jmlr.org/papers/volume12/duchi11a/duchi11a.pdf).
+///
+/// The update at step `t` is `lr / (1 + (t - 1) * lr_decay) * grad / sqrt(sum + epsilon)`, `sum`
+/// being the accumulated squared gradients. Note that PyTorch adds `epsilon` after the square root
+/// instead.
+pub struct Adagrad<B: Backend> {
+    square_accumulator: SquareAccumulator,
+    weight_decay: Option<WeightDecay<B>>,
+}
+
+/// Adagrad state.
+#[derive(Record, Clone, new)]
+pub struct AdagradState<B: Backend, const D: usize> {
+    weight_decay: Option<WeightDecayState<B, D>>,
+    square_accumulator: SquareAccumulatorState<B, D>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for Adagrad<B> {
+    type State<const D: usize> = AdagradState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        mut grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let mut state_weight_decay = None;
+        let mut state_square_accumulator = None;
+
+        if let Some(state) = state {
+            state_weight_decay = state.weight_decay;
+            state_square_accumulator = Some(state.square_accumulator);
+        }
+
+        if let Some(weight_decay) = &self.weight_decay {
+            let (grad_out, state) = weight_decay.transform(grad, state_weight_decay);
+            state_weight_decay = Some(state);
+            grad = grad_out;
+        }
+
+        let (grad, state_square_accumulator) = self
+            .square_accumulator
+            .transform(grad, state_square_accumulator);
+
+        let state = AdagradState::new(state_weight_decay, state_square_accumulator);
+        let delta = grad.mul_scalar(lr);
+
+        (tensor - delta, Some(state))
+    }
+
+    fn to_device<const D: usize>(
+        mut state: Self::State<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::State<D> {
+        state.weight_decay = state.weight_decay.map(|state| state.to_device(device));
+        state.square_accumulator = state.square_accumulator.to_device(device);
+        state
+    }
+}
+
+impl AdagradConfig {
+    /// Initialize Adagrad optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: ADBackend, M: ADModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<Adagrad<B::InnerBackend>, M, B> {
+        let optim = Adagrad {
+            square_accumulator: SquareAccumulator {
+                lr_decay: self.lr_decay,
+                epsilon: self.epsilon,
+            },
+            weight_decay: self.weight_decay.as_ref().map(WeightDecay::new),
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+/// Square accumulator state, holding the sum of the squared gradients and the current step.
+#[derive(Record, new, Clone)]
+pub struct SquareAccumulatorState<B: Backend, const D: usize> {
+    time: usize,
+    sum: Tensor<B, D>,
+}
+
+struct SquareAccumulator {
+    lr_decay: f64,
+    epsilon: f32,
+}
+
+impl SquareAccumulator {
+    pub fn transform<B: Backend, const D: usize>(
+        &self,
+        grad: Tensor<B, D>,
+        state: Option<SquareAccumulatorState<B, D>>,
+    ) -> (Tensor<B, D>, SquareAccumulatorState<B, D>) {
+        let state = if let Some(mut state) = state {
+            state.sum = state.sum.add(grad.clone().powf(2.0));
+            state.time += 1;
+            state
+        } else {
+            SquareAccumulatorState::new(1, grad.clone().powf(2.0))
+        };
+
+        let new_lr = 1.0 / (1.0 + (state.time - 1) as f64 * self.lr_decay);
+
+        let grad = grad
+            .div(state.sum.clone().add_scalar(self.epsilon).sqrt())
+            .mul_scalar(new_lr);
+
+        (grad, state)
+    }
+}
+
+impl<B: Backend, const D: usize> SquareAccumulatorState<B, D> {
+    /// Move state to device.
+    ///
+    /// # Arguments
+    ///
+    /// * `device` - Device to move state to.
+    ///
+    /// # Returns
+    ///
+    /// Returns state moved to device.
+    pub fn to_device(mut self, device: &B::Device) -> Self {
+        self.sum = self.sum.to_device(device);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::{Module, Param};
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::record::{BinBytesRecorder, FullPrecisionSettings, Recorder};
+    use crate::tensor::{Data, Distribution, Tensor};
+    use crate::{nn, TestADBackend, TestBackend};
+
+    const LEARNING_RATE: LearningRate = 0.01;
+
+    #[test]
+    fn with_updated_params_should_have_state() {
+        let linear = nn::LinearConfig::new(6, 6).init();
+        let x = Tensor::<TestADBackend, 2>::random([2, 6], Distribution::Default);
+        let mut optimizer = create_adagrad();
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let _linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let record = optimizer.to_record();
+
+        assert!(!record.is_empty());
+    }
+
+    #[test]
+    fn without_updated_params_should_not_have_state() {
+        let optimizer = create_adagrad();
+        let record = optimizer.to_record();
+        assert!(record.is_empty());
+    }
+
+    #[test]
+    fn test_adagrad_optimizer_save_load_state() {
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let linear = nn::LinearConfig::new(6, 6).init();
+        let x = Tensor::<TestADBackend, 2>::random([2, 6], Distribution::Default);
+        let mut optimizer = create_adagrad();
+        let grads = linear.forward(x.clone()).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let bytes = recorder.record(optimizer.to_record(), ()).unwrap();
+        let mut optimizer_loaded = create_adagrad().load_record(recorder.load(bytes).unwrap());
+
+        let grads = linear.forward(x.clone()).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear_expected = optimizer.step(LEARNING_RATE, linear.clone(), grads);
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear_actual = optimizer_loaded.step(LEARNING_RATE, linear, grads);
+
+        let (record_expected, record_actual) =
+            (linear_expected.into_record(), linear_actual.into_record());
+        record_actual
+            .weight
+            .to_data()
+            .assert_approx_eq(&record_expected.weight.to_data(), 3);
+        record_actual
+            .bias
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&record_expected.bias.unwrap().to_data(), 3);
+    }
+
+    #[test]
+    fn test_square_accumulator_adds_epsilon_under_the_square_root() {
+        let accumulator = SquareAccumulator {
+            lr_decay: 0.5,
+            epsilon: 1.0,
+        };
+        let grad = Tensor::<TestBackend, 1>::from_floats([0.5, 2.0]);
+
+        let (grad_1, state) = accumulator.transform(grad.clone(), None);
+        let (grad_2, _state) = accumulator.transform(grad, Some(state));
+
+        // 0.5 / sqrt(0.25 + 1) and 2 / sqrt(4 + 1).
+        grad_1
+            .into_data()
+            .assert_approx_eq(&Data::from([0.44721, 0.89443]), 4);
+        // 0.5 / sqrt(0.5 + 1) / 1.5 and 2 / sqrt(8 + 1) / 1.5.
+        grad_2
+            .into_data()
+            .assert_approx_eq(&Data::from([0.27217, 0.44444]), 4);
+    }
+
+    #[test]
+    fn test_adagrad_optimizer_with_numbers() {
+        let linear = given_linear_layer(
+            Data::from([
+                [-0.3206, 0.1374, 0.4043, 0.3200, 0.0859, 0.0671],
+                [0.0777, -0.0185, -0.3667, 0.2550, 0.1955, -0.2922],
+                [-0.0190, 0.0346, -0.2962, 0.2484, -0.2780, 0.3130],
+                [-0.2980, -0.2214, -0.3715, -0.2981, -0.0761, 0.1626],
+                [0.3300, -0.2182, 0.3717, -0.1729, 0.3796, -0.0304],
+                [-0.0159, -0.0120, 0.1258, 0.1921, 0.0293, 0.3833],
+            ]),
+            Data::from([-0.3905, 0.0884, -0.0970, 0.1176, 0.1366, 0.0130]),
+        );
+        let x_1 = Tensor::from_floats([
+            [0.6294, 0.0940, 0.8176, 0.8824, 0.5228, 0.4310],
+            [0.7152, 0.9559, 0.7893, 0.5684, 0.5939, 0.8883],
+        ])
+        .require_grad();
+        let x_2 = Tensor::from_floats([
+            [0.8491, 0.2108, 0.8939, 0.4433, 0.5527, 0.2528],
+            [0.3270, 0.0412, 0.5538, 0.9605, 0.3195, 0.9085],
+        ])
+        .require_grad();
+
+        let mut optimizer = AdagradConfig::new()
+            .with_epsilon(1e-5)
+            .with_lr_decay(0.5)
+            .init();
+
+        let grads = linear.forward(x_1).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let grads = linear.forward(x_2).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let state_updated = linear.into_record();
+        let state_expected = given_linear_record(
+            Data::from([
+                [-0.3350, 0.1230, 0.3899, 0.3056, 0.0715, 0.0527],
+                [0.0661, -0.0301, -0.3783, 0.2434, 0.1839, -0.3038],
+                [-0.0335, 0.0201, -0.3107, 0.2339, -0.2925, 0.2985],
+                [-0.3126, -0.2360, -0.3861, -0.3127, -0.0907, 0.1480],
+                [0.3159, -0.2323, 0.3576, -0.1870, 0.3655, -0.0445],
+                [-0.0303, -0.0264, 0.1114, 0.1777, 0.0149, 0.3689],
+            ]),
+            Data::from([-0.4052, 0.0737, -0.1117, 0.1029, 0.1219, -0.0017]),
+        );
+        let (weight_updated, bias_updated) = (
+            state_updated.weight.to_data(),
+            state_updated.bias.unwrap().to_data(),
+        );
+        let (weight_expected, bias_expected) = (
+            state_expected.weight.to_data(),
+            state_expected.bias.unwrap().to_data(),
+        );
+
+        bias_updated.assert_approx_eq(&bias_expected, 3);
+        weight_updated.assert_approx_eq(&weight_expected, 3);
+    }
+
+    fn given_linear_layer(weight: Data<f32, 2>, bias: Data<f32, 1>) -> nn::Linear<TestADBackend> {
+        let linear = nn::LinearConfig::new(6, 6).init();
+        let record = given_linear_record(weight, bias);
+
+        linear.load_record(record)
+    }
+
+    fn given_linear_record(
+        weight: Data<f32, 2>,
+        bias: Data<f32, 1>,
+    ) -> nn::LinearRecord<TestADBackend> {
+        nn::LinearRecord {
+            weight: Param::from(Tensor::from_data(weight)),
+            bias: Some(Param::from(Tensor::from_data(bias))),
+        }
+    }
+
+    fn create_adagrad(
+    ) -> OptimizerAdaptor<Adagrad<TestBackend>, nn::Linear<TestADBackend>, TestADBackend> {
+        let config = AdagradConfig::new();
+        Adagrad {
+            square_accumulator: SquareAccumulator {
+                lr_decay: config.lr_decay,
+                epsilon: config.epsilon,
+            },
+            weight_decay: config.weight_decay.as_ref().map(WeightDecay::new),
+        }
+        .into()
+    }
+}
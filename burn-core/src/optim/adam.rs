@@ -5,7 +5,7 @@ use crate::{
 
 use super::{
     decay::{WeightDecay, WeightDecayConfig, WeightDecayState},
-    Optimizer, SimpleOptimizer,
+    SimpleOptimizer,
 };
 use crate::config::Config;
 use crate::optim::adaptor::OptimizerAdaptor;
@@ -91,7 +91,9 @@ impl AdamConfig {
     /// # Returns
     ///
     /// Returns an optimizer that can be used to optimize a module.
-    pub fn init<B: ADBackend, M: ADModule<B>>(&self) -> impl Optimizer<M, B> {
+    pub fn init<B: ADBackend, M: ADModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<Adam<B::InnerBackend>, M, B> {
         let optim = Adam {
             momentum: AdaptiveMomentum {
                 beta_1: self.beta_1,
@@ -193,12 +195,36 @@ mod tests {
     use super::*;
     use crate::module::{Module, Param};
     use crate::optim::{GradientsParams, Optimizer};
-    use crate::record::{BinFileRecorder, FullPrecisionSettings, Recorder};
+    use crate::record::{
+        BinBytesRecorder, BinFileRecorder, FullPrecisionSettings, HalfPrecisionSettings,
+        OptimStatePrecision, OptimStatePrecisionSettings, Recorder,
+    };
     use crate::tensor::{Data, Distribution, Tensor};
     use crate::{nn, TestADBackend, TestBackend};
 
     const LEARNING_RATE: LearningRate = 0.01;
 
+    #[test]
+    fn with_updated_params_should_have_state() {
+        let linear = nn::LinearConfig::new(6, 6).init();
+        let x = Tensor::<TestADBackend, 2>::random([2, 6], Distribution::Default);
+        let mut optimizer = create_adam();
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let _linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let record = optimizer.to_record();
+
+        assert!(!record.is_empty());
+    }
+
+    #[test]
+    fn without_updated_params_should_not_have_state() {
+        let optimizer = create_adam();
+        let record = optimizer.to_record();
+        assert!(record.is_empty());
+    }
+
     #[test]
     fn test_adam_optimizer_save_load_state() {
         let linear = nn::LinearConfig::new(6, 6).init();
@@ -220,6 +246,47 @@ mod tests {
         assert_eq!(state_optim_before.len(), state_optim_after.len());
     }
 
+    #[test]
+    fn test_adam_optimizer_save_load_state_half_precision() {
+        type Precision = OptimStatePrecisionSettings<HalfPrecisionSettings>;
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let linear = nn::LinearConfig::new(32, 32).init();
+        let x = Tensor::<TestADBackend, 2>::random([2, 32], Distribution::Default);
+        let mut optimizer = create_adam().with_state_precision::<Precision>();
+        let grads = linear.forward(x.clone()).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let bytes = recorder.record(optimizer.to_record(), ()).unwrap();
+        let bytes_full = recorder
+            .record(Precision::from_record(optimizer.to_record()), ())
+            .unwrap();
+        assert!(bytes.len() < bytes_full.len());
+
+        let mut optimizer_loaded = AdamConfig::new()
+            .init()
+            .with_state_precision::<Precision>()
+            .load_record(recorder.load(bytes).unwrap());
+        let grads = linear.forward(x.clone()).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear_expected = optimizer.step(LEARNING_RATE, linear.clone(), grads);
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear_actual = optimizer_loaded.step(LEARNING_RATE, linear, grads);
+
+        let (record_expected, record_actual) =
+            (linear_expected.into_record(), linear_actual.into_record());
+        record_actual
+            .weight
+            .to_data()
+            .assert_approx_eq(&record_expected.weight.to_data(), 3);
+        record_actual
+            .bias
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&record_expected.bias.unwrap().to_data(), 3);
+    }
+
     #[test]
     fn test_adam_optimizer_with_numbers() {
         let linear = given_linear_layer(
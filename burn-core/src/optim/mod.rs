@@ -4,6 +4,7 @@ pub mod decay;
 /// Momentum module for optimizers.
 pub mod momentum;
 
+mod adagrad;
 mod adam;
 mod base;
 mod grad_accum;
@@ -12,6 +13,7 @@ mod sgd;
 mod simple;
 mod visitor;
 
+pub use adagrad::*;
 pub use adam::*;
 pub use base::*;
 pub use grad_accum::*;
@@ -3,6 +3,7 @@ use crate::{
     grad_clipping::GradientClipping,
     module::{ADModule, ModuleMapper, ParamId},
     optim::{GradientsParams, Optimizer},
+    record::{OptimStatePrecision, RecorderPrecisionSettings},
     LearningRate,
 };
 use burn_tensor::{backend::ADBackend, Tensor};
@@ -11,15 +12,20 @@ use hashbrown::HashMap;
 
 /// Wrapper struct that adapts any [simple optimizer](SimpleOptimizer) into
 /// an [optimizer](Optimizer).
-pub struct OptimizerAdaptor<O, M, B>
+///
+/// The state is recorded with the [precision](OptimStatePrecision) `P`, which defaults to the
+/// precision settings of the recorder.
+pub struct OptimizerAdaptor<O, M, B, P = RecorderPrecisionSettings>
 where
     O: SimpleOptimizer<B::InnerBackend>,
     M: ADModule<B>,
     B: ADBackend,
+    P: OptimStatePrecision,
 {
     optim: O,
     records: HashMap<ParamId, AdaptorRecord<O, B::InnerBackend>>,
     module: PhantomData<M>,
+    precision: PhantomData<P>,
     grad_clipping: Option<GradientClipping>,
 }
 
@@ -34,16 +40,18 @@ where
             optim,
             records: HashMap::new(),
             module: PhantomData,
+            precision: PhantomData,
             grad_clipping: None,
         }
     }
 }
 
-impl<O, M, B> OptimizerAdaptor<O, M, B>
+impl<O, M, B, P> OptimizerAdaptor<O, M, B, P>
 where
     O: SimpleOptimizer<B::InnerBackend>,
     M: ADModule<B>,
     B: ADBackend,
+    P: OptimStatePrecision,
 {
     /// Sets the gradient clipping.
     ///
@@ -59,19 +67,35 @@ where
         self
     }
 
+    /// Sets the precision used to record the optimizer state.
+    ///
+    /// # Returns
+    ///
+    /// The optimizer.
+    pub fn with_state_precision<P2: OptimStatePrecision>(self) -> OptimizerAdaptor<O, M, B, P2> {
+        OptimizerAdaptor {
+            optim: self.optim,
+            records: self.records,
+            module: PhantomData,
+            precision: PhantomData,
+            grad_clipping: self.grad_clipping,
+        }
+    }
+
     #[cfg(test)]
     pub(crate) fn has_gradient_clipping(&self) -> bool {
         self.grad_clipping.is_some()
     }
 }
 
-impl<O, B, M> Optimizer<M, B> for OptimizerAdaptor<O, M, B>
+impl<O, B, M, P> Optimizer<M, B> for OptimizerAdaptor<O, M, B, P>
 where
     B: ADBackend,
     M: ADModule<B>,
     O: SimpleOptimizer<B::InnerBackend>,
+    P: OptimStatePrecision,
 {
-    type Record = HashMap<ParamId, AdaptorRecord<O, B::InnerBackend>>;
+    type Record = P::Record<HashMap<ParamId, AdaptorRecord<O, B::InnerBackend>>>;
 
     fn step(&mut self, lr: LearningRate, module: M, mut grads: GradientsParams) -> M {
         let mut mapper = SimpleOptimizerMapper::<M, B, O>::new(
@@ -85,11 +109,11 @@ where
     }
 
     fn to_record(&self) -> Self::Record {
-        self.records.clone()
+        P::into_record(self.records.clone())
     }
 
     fn load_record(mut self, record: Self::Record) -> Self {
-        self.records = record;
+        self.records = P::from_record(record);
         self
     }
 }
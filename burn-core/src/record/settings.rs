@@ -1,4 +1,6 @@
+use super::Record;
 use burn_tensor::Element;
+use core::marker::PhantomData;
 use serde::{de::DeserializeOwned, Serialize};
 
 /// Settings allowing to control the precision when (de)serializing items.
@@ -40,3 +42,86 @@ impl PrecisionSettings for HalfPrecisionSettings {
     type FloatElem = half::f16;
     type IntElem = i16;
 }
+
+/// Selects the precision settings used to record the state of an
+/// [optimizer](crate::optim::Optimizer), such as the moments of [Adam](crate::optim::Adam).
+pub trait OptimStatePrecision: Send + Sync + 'static {
+    /// Record type of the optimizer state `R`.
+    type Record<R: Record>: Record;
+
+    /// Wraps the optimizer state into its record.
+    fn into_record<R: Record>(state: R) -> Self::Record<R>;
+
+    /// Unwraps the optimizer state from its record.
+    fn from_record<R: Record>(record: Self::Record<R>) -> R;
+}
+
+/// Records the optimizer state with the precision settings of the recorder.
+#[derive(Debug, Default, Clone)]
+pub struct RecorderPrecisionSettings;
+
+/// Records the optimizer state with the precision settings `S`, whatever the settings of the
+/// recorder.
+///
+/// # Example
+///
+/// Keeping the model weights in full precision while halving the size of the optimizer state:
+///
+/// ```rust,ignore
+/// let optim = AdamConfig::new()
+///     .init::<MyBackend, MyModule<MyBackend>>()
+///     .with_state_precision::<OptimStatePrecisionSettings<HalfPrecisionSettings>>();
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct OptimStatePrecisionSettings<S> {
+    _settings: PhantomData<S>,
+}
+
+/// Record of an optimizer state using the [precision settings](OptimStatePrecisionSettings) `S`.
+#[derive(Debug, Clone)]
+pub struct OptimStateRecord<R, S> {
+    state: R,
+    _settings: PhantomData<S>,
+}
+
+impl OptimStatePrecision for RecorderPrecisionSettings {
+    type Record<R: Record> = R;
+
+    fn into_record<R: Record>(state: R) -> Self::Record<R> {
+        state
+    }
+
+    fn from_record<R: Record>(record: Self::Record<R>) -> R {
+        record
+    }
+}
+
+impl<S: PrecisionSettings + 'static> OptimStatePrecision for OptimStatePrecisionSettings<S> {
+    type Record<R: Record> = OptimStateRecord<R, S>;
+
+    fn into_record<R: Record>(state: R) -> Self::Record<R> {
+        OptimStateRecord {
+            state,
+            _settings: PhantomData,
+        }
+    }
+
+    fn from_record<R: Record>(record: Self::Record<R>) -> R {
+        record.state
+    }
+}
+
+impl<R: Record, S: PrecisionSettings> Record for OptimStateRecord<R, S> {
+    type Item<T: PrecisionSettings> = R::Item<S>;
+
+    fn into_item<T: PrecisionSettings>(self) -> Self::Item<T> {
+        self.state.into_item::<S>()
+    }
+
+    fn from_item<T: PrecisionSettings>(item: Self::Item<T>) -> Self {
+        Self {
+            state: R::from_item::<S>(item),
+            _settings: PhantomData,
+        }
+    }
+}
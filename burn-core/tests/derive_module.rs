@@ -1,4 +1,4 @@
-use burn::module::{Module, Param};
+use burn::module::{Module, ModuleMapper, ModuleVisitor, Param, ParamId};
 use burn::tensor::backend::Backend;
 use burn::tensor::{Distribution, Shape, Tensor};
 use burn_core as burn;
@@ -80,6 +80,22 @@ mod state {
     }
 }
 
+mod update_record {
+    use super::*;
+
+    #[test]
+    fn should_override_only_the_updated_params() {
+        let module = ModuleComposed::<TestBackend>::new();
+        let weight_basic = module.basic.weight_basic.to_data();
+        let pretrained = Tensor::<TestBackend, 2>::ones([20, 20]);
+
+        let module = module.update_record(|record| record.weight = Param::from(pretrained.clone()));
+
+        assert_eq!(module.weight.to_data(), pretrained.into_data());
+        assert_eq!(module.basic.weight_basic.to_data(), weight_basic);
+    }
+}
+
 mod num_params {
     use super::*;
 
@@ -96,6 +112,54 @@ mod num_params {
     }
 }
 
+mod visitor_mapper {
+    use super::*;
+
+    struct ZeroMapper;
+
+    impl<B: Backend> ModuleMapper<B> for ZeroMapper {
+        fn map<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+            tensor.zeros_like()
+        }
+    }
+
+    #[derive(Default)]
+    struct CountVisitor {
+        ids: Vec<ParamId>,
+        num_elements: usize,
+    }
+
+    impl<B: Backend> ModuleVisitor<B> for CountVisitor {
+        fn visit<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+            self.ids.push(id.clone());
+            self.num_elements += tensor.shape().num_elements();
+        }
+    }
+
+    #[test]
+    fn should_map_all_params_composed() {
+        let module = ModuleComposed::<TestBackend>::new();
+
+        let module = module.map(&mut ZeroMapper);
+
+        assert_eq!(module.weight.val().sum().into_scalar(), 0.0);
+        assert_eq!(module.basic.weight_basic.val().sum().into_scalar(), 0.0);
+    }
+
+    #[test]
+    fn should_visit_all_params_composed() {
+        let module = ModuleComposed::<TestBackend>::new();
+        let mut visitor = CountVisitor::default();
+
+        module.visit(&mut visitor);
+
+        assert_eq!(visitor.ids.len(), 2);
+        assert!(visitor.ids.contains(module.weight.id()));
+        assert!(visitor.ids.contains(module.basic.weight_basic.id()));
+        assert_eq!(visitor.num_elements, 2 * 20 * 20);
+    }
+}
+
 #[cfg(feature = "std")]
 mod require_grad {
     use burn_tensor::backend::ADBackend;
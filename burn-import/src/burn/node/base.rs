@@ -1,7 +1,13 @@
 use super::{
-    add::AddNode, batch_norm::BatchNormNode, constant::ConstantNode, conv2d::Conv2dNode,
-    equal::EqualNode, flatten::FlattenNode, linear::LinearNode, log_softmax::LogSoftmaxNode,
-    matmul::MatmulNode, max_pool2d::MaxPool2dNode, relu::ReLUNode, sigmoid::SigmoidNode,
+    add::AddNode, batch_norm::BatchNormNode, cast::CastNode, clip::ClipNode, concat::ConcatNode,
+    constant::ConstantNode, conv2d::Conv2dNode, div::DivNode, dropout::DropoutNode,
+    equal::EqualNode, exp::ExpNode, flatten::FlattenNode, gelu::GeluNode, gemm::GemmNode,
+    global_avg_pool2d::GlobalAvgPool2dNode, linear::LinearNode, log::LogNode,
+    log_softmax::LogSoftmaxNode, matmul::MatmulNode, max_pool2d::MaxPool2dNode, mul::MulNode,
+    pow::PowNode, reduce_mean::ReduceMeanNode, reduce_sum::ReduceSumNode, relu::ReLUNode,
+    reshape::ReshapeNode, sigmoid::SigmoidNode, softmax::SoftmaxNode, sqrt::SqrtNode,
+    squeeze::SqueezeNode, sub::SubNode, tanh::TanhNode, transpose::TransposeNode,
+    unsqueeze::UnsqueezeNode,
 };
 use crate::burn::{BurnImports, Scope, Type};
 use burn::record::PrecisionSettings;
@@ -73,16 +79,38 @@ pub trait NodeCodegen<PS: PrecisionSettings>: std::fmt::Debug {
 pub enum Node<PS: PrecisionSettings> {
     Add(AddNode),
     Matmul(MatmulNode),
+    Gemm(GemmNode),
     Conv2d(Conv2dNode<PS>),
     MaxPool2d(MaxPool2dNode),
     Linear(LinearNode<PS>),
     BatchNorm(BatchNormNode<PS>),
     ReLU(ReLUNode),
+    Clip(ClipNode),
     Flatten(FlattenNode),
     LogSoftmax(LogSoftmaxNode),
     Constant(ConstantNode),
     Equal(EqualNode),
     Sigmoid(SigmoidNode),
+    Softmax(SoftmaxNode),
+    Tanh(TanhNode),
+    Gelu(GeluNode),
+    Exp(ExpNode),
+    Log(LogNode),
+    Sqrt(SqrtNode),
+    Pow(PowNode),
+    ReduceMean(ReduceMeanNode),
+    ReduceSum(ReduceSumNode),
+    Squeeze(SqueezeNode),
+    Unsqueeze(UnsqueezeNode),
+    Cast(CastNode),
+    Mul(MulNode),
+    Sub(SubNode),
+    Div(DivNode),
+    Concat(ConcatNode),
+    Reshape(ReshapeNode),
+    Transpose(TransposeNode),
+    GlobalAvgPool2d(GlobalAvgPool2dNode),
+    Dropout(DropoutNode),
 }
 
 macro_rules! match_all {
@@ -90,16 +118,38 @@ macro_rules! match_all {
         match $self {
             Node::Add(node) => $func(node),
             Node::Matmul(node) => $func(node),
+            Node::Gemm(node) => $func(node),
             Node::Conv2d(node) => $func(node),
             Node::MaxPool2d(node) => $func(node),
             Node::Linear(node) => $func(node),
             Node::BatchNorm(node) => $func(node),
             Node::ReLU(node) => $func(node),
+            Node::Clip(node) => $func(node),
             Node::Flatten(node) => $func(node),
             Node::LogSoftmax(node) => $func(node),
             Node::Constant(node) => $func(node),
             Node::Equal(node) => $func(node),
             Node::Sigmoid(node) => $func(node),
+            Node::Softmax(node) => $func(node),
+            Node::Tanh(node) => $func(node),
+            Node::Gelu(node) => $func(node),
+            Node::Exp(node) => $func(node),
+            Node::Log(node) => $func(node),
+            Node::Sqrt(node) => $func(node),
+            Node::Pow(node) => $func(node),
+            Node::ReduceMean(node) => $func(node),
+            Node::ReduceSum(node) => $func(node),
+            Node::Squeeze(node) => $func(node),
+            Node::Unsqueeze(node) => $func(node),
+            Node::Cast(node) => $func(node),
+            Node::Mul(node) => $func(node),
+            Node::Sub(node) => $func(node),
+            Node::Div(node) => $func(node),
+            Node::Concat(node) => $func(node),
+            Node::Reshape(node) => $func(node),
+            Node::Transpose(node) => $func(node),
+            Node::GlobalAvgPool2d(node) => $func(node),
+            Node::Dropout(node) => $func(node),
         }
     }};
 }
@@ -118,16 +168,38 @@ impl<PS: PrecisionSettings> Node<PS> {
         match self {
             Node::Add(_) => "add",
             Node::Matmul(_) => "matmul",
+            Node::Gemm(_) => "gemm",
             Node::Constant(_) => "constant",
             Node::Conv2d(_) => "conv2d",
             Node::MaxPool2d(_) => "max_pool2d",
             Node::Linear(_) => "linear",
             Node::BatchNorm(_) => "batch_norm",
             Node::ReLU(_) => "relu",
+            Node::Clip(_) => "clip",
             Node::Flatten(_) => "flatten",
             Node::LogSoftmax(_) => "log_softmax",
             Node::Equal(_) => "equal",
             Node::Sigmoid(_) => "sigmoid",
+            Node::Softmax(_) => "softmax",
+            Node::Tanh(_) => "tanh",
+            Node::Gelu(_) => "gelu",
+            Node::Exp(_) => "exp",
+            Node::Log(_) => "log",
+            Node::Sqrt(_) => "sqrt",
+            Node::Pow(_) => "pow",
+            Node::ReduceMean(_) => "reduce_mean",
+            Node::ReduceSum(_) => "reduce_sum",
+            Node::Squeeze(_) => "squeeze",
+            Node::Unsqueeze(_) => "unsqueeze",
+            Node::Cast(_) => "cast",
+            Node::Mul(_) => "mul",
+            Node::Sub(_) => "sub",
+            Node::Div(_) => "div",
+            Node::Concat(_) => "concat",
+            Node::Reshape(_) => "reshape",
+            Node::Transpose(_) => "transpose",
+            Node::GlobalAvgPool2d(_) => "global_avg_pool2d",
+            Node::Dropout(_) => "dropout",
         }
     }
 }
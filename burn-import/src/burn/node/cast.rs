@@ -0,0 +1,143 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, Scope, TensorKind, TensorType, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Casts the input tensor to the tensor kind of the output.
+///
+/// The element precision is chosen by the backend, so casts between float types (e.g. float32 to
+/// float16) are identities, while casts between floats and ints convert the tensor kind.
+#[derive(Debug, Clone, new)]
+pub struct CastNode {
+    pub input: TensorType,
+    pub output: TensorType,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for CastNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(&self.output)]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(&self.input)]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+
+        let tokens = match (&self.input.kind, &self.output.kind) {
+            (TensorKind::Float, TensorKind::Float)
+            | (TensorKind::Int, TensorKind::Int)
+            | (TensorKind::Bool, TensorKind::Bool) => quote! { #input },
+            (TensorKind::Float, TensorKind::Int) | (TensorKind::Bool, TensorKind::Int) => {
+                quote! { #input.into_int() }
+            }
+            (TensorKind::Int, TensorKind::Float) => quote! { #input.into_float() },
+            (TensorKind::Bool, TensorKind::Float) => quote! { #input.into_int().into_float() },
+            (_, TensorKind::Bool) => {
+                unreachable!("Cast: casting to bool is rejected during the conversion")
+            }
+        };
+
+        quote! {
+            let #output = #tokens;
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        for tensor in [&self.input, &self.output] {
+            match tensor.kind {
+                TensorKind::Int => imports.register("burn::tensor::Int"),
+                TensorKind::Bool => imports.register("burn::tensor::Bool"),
+                TensorKind::Float => {}
+            }
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Cast(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{cast::CastNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_float32_to_float16() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(CastNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+        ));
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend>{}
+
+            impl<B: Backend> Model <B> {
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self { }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1;
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_float_to_int() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(CastNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_int("tensor2", 4),
+        ));
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::tensor::Int;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend>{}
+
+            impl<B: Backend> Model <B> {
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self { }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4, Int> {
+                    let tensor2 = tensor1.into_int();
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
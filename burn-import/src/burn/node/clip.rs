@@ -0,0 +1,167 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone, new)]
+pub struct ClipNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ClipNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(&self.output)]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(&self.input)]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+
+        match (self.min, self.max) {
+            (Some(min), Some(max)) => quote! {
+                let #output = #input.clamp(#min, #max);
+            },
+            (Some(min), None) => quote! {
+                let #output = #input.clamp_min(#min);
+            },
+            (None, Some(max)) => quote! {
+                let #output = #input.clamp_max(#max);
+            },
+            (None, None) => quote! {
+                let #output = #input;
+            },
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Clip(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{clip::ClipNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn codegen_nodes_min_max() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ClipNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+            Some(0.0),
+            Some(6.0),
+        ));
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend>{}
+
+            impl<B: Backend> Model <B> {
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self { }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.clamp(0f64, 6f64);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn codegen_nodes_min_only() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ClipNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+            Some(0.0),
+            None,
+        ));
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend>{}
+
+            impl<B: Backend> Model <B> {
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self { }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.clamp_min(0f64);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn codegen_nodes_max_only() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ClipNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+            None,
+            Some(6.0),
+        ));
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend>{}
+
+            impl<B: Backend> Model <B> {
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self { }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.clamp_max(6f64);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
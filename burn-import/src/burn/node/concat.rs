@@ -0,0 +1,95 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone, new)]
+pub struct ConcatNode {
+    pub inputs: Vec<TensorType>,
+    pub output: TensorType,
+    pub dim: usize,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ConcatNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(&self.output)]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        self.inputs.iter().map(Type::Tensor).collect()
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|input| scope.tensor_use_owned(input, node_position));
+        let output = &self.output.name;
+        let dim = self.dim.to_tokens();
+
+        quote! {
+            let #output = burn::tensor::Tensor::cat(vec![#(#inputs),*], #dim);
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Concat(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{concat::ConcatNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_concat() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ConcatNode::new(
+            vec![
+                TensorType::new_float("tensor1", 4),
+                TensorType::new_float("tensor2", 4),
+                TensorType::new_float("tensor3", 4),
+            ],
+            TensorType::new_float("tensor4", 4),
+            1,
+        ));
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend>{}
+
+            impl<B: Backend> Model <B> {
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self { }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 4>,
+                    tensor2: Tensor<B, 4>,
+                    tensor3: Tensor<B, 4>
+                ) -> Tensor<B, 4> {
+                    let tensor4 = burn::tensor::Tensor::cat(vec![tensor1, tensor2, tensor3], 1);
+
+                    tensor4
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
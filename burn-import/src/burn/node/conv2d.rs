@@ -103,6 +103,7 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for Conv2dNode<PS> {
             dilation: [ConstantRecord::new(); 2],
             groups: ConstantRecord::new(),
             padding: ConstantRecord::new(),
+            weight_standardization: ConstantRecord::new(),
         };
 
         let item = Record::into_item::<PS>(record);
@@ -4,12 +4,13 @@ use burn::record::PrecisionSettings;
 use proc_macro2::TokenStream;
 use quote::quote;
 
+/// Flattens the input into a matrix, where the dimensions before `axis` are collapsed into the
+/// rows and the remaining ones into the columns.
 #[derive(Debug, Clone, new)]
 pub struct FlattenNode {
     pub input: TensorType,
     pub output: TensorType,
-    pub start_dim: usize,
-    pub end_dim: usize,
+    pub axis: usize,
 }
 
 impl<PS: PrecisionSettings> NodeCodegen<PS> for FlattenNode {
@@ -24,11 +25,33 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for FlattenNode {
     fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
         let input = scope.tensor_use_owned(&self.input, node_position);
         let output = &self.output.name;
-        let start_dim = self.start_dim.to_tokens();
-        let end_dim = self.end_dim.to_tokens();
+        let axis = self.axis;
+        let last_dim = self.input.dim - 1;
+
+        let tokens = if axis == 0 {
+            // A single row, where all the dimensions are collapsed into the columns.
+            let end_dim = last_dim.to_tokens();
+            quote! { #input.flatten::<1>(0, #end_dim).unsqueeze() }
+        } else if axis > last_dim {
+            // A single column, where all the dimensions are collapsed into the rows.
+            let end_dim = last_dim.to_tokens();
+            quote! { #input.flatten::<1>(0, #end_dim).unsqueeze_dim(1) }
+        } else if axis == 1 {
+            let end_dim = last_dim.to_tokens();
+            quote! { #input.flatten(1, #end_dim) }
+        } else if axis == last_dim {
+            let end_dim = (axis - 1).to_tokens();
+            quote! { #input.flatten(0, #end_dim) }
+        } else {
+            let rank = (axis + 1).to_tokens();
+            let start_dim = axis.to_tokens();
+            let end_dim = last_dim.to_tokens();
+            let rows_end_dim = (axis - 1).to_tokens();
+            quote! { #input.flatten::<#rank>(#start_dim, #end_dim).flatten(0, #rows_end_dim) }
+        };
 
         quote! {
-            let #output = #input.flatten(#start_dim, #end_dim);
+            let #output = #tokens;
         }
     }
 
@@ -36,3 +59,85 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for FlattenNode {
         Node::Flatten(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{flatten::FlattenNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_axis_1() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(FlattenNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 2),
+            1,
+        ));
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend>{}
+
+            impl<B: Backend> Model <B> {
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self { }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 2> {
+                    let tensor2 = tensor1.flatten(1, 3);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_axis_2() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(FlattenNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 2),
+            2,
+        ));
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend>{}
+
+            impl<B: Backend> Model <B> {
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self { }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 2> {
+                    let tensor2 = tensor1.flatten::<3>(2, 3).flatten(0, 1);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
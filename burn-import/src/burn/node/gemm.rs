@@ -0,0 +1,181 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// General matrix multiplication: `output = alpha * op(lhs) * op(rhs) + beta * bias`, where `op`
+/// optionally transposes its operand.
+#[derive(Debug, Clone, new)]
+pub struct GemmNode {
+    pub lhs: TensorType,
+    pub rhs: TensorType,
+    pub bias: Option<TensorType>,
+    pub output: TensorType,
+    pub config: GemmConfig,
+}
+
+/// Scaling factors and transpositions of the Gemm operands.
+#[derive(Debug, Clone, new)]
+pub struct GemmConfig {
+    pub alpha: f32,
+    pub beta: f32,
+    pub trans_a: bool,
+    pub trans_b: bool,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for GemmNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(&self.output)]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        let mut types = vec![Type::Tensor(&self.lhs), Type::Tensor(&self.rhs)];
+
+        if let Some(bias) = &self.bias {
+            types.push(Type::Tensor(bias));
+        }
+
+        types
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let lhs = scope.tensor_use_owned(&self.lhs, node_position);
+        let rhs = scope.tensor_use_owned(&self.rhs, node_position);
+        let output = &self.output.name;
+
+        let lhs = match self.config.trans_a {
+            true => quote! { #lhs.transpose() },
+            false => lhs,
+        };
+        let rhs = match self.config.trans_b {
+            true => quote! { #rhs.transpose() },
+            false => rhs,
+        };
+
+        let mut tokens = quote! { #lhs.matmul(#rhs) };
+
+        if self.config.alpha != 1.0 {
+            let alpha = self.config.alpha;
+            tokens = quote! { #tokens.mul_scalar(#alpha) };
+        }
+
+        if let Some(bias_type) = &self.bias {
+            let mut bias = scope.tensor_use_owned(bias_type, node_position);
+
+            // The bias is broadcasted to the output, which requires the same rank.
+            if bias_type.dim < self.output.dim {
+                bias = quote! { #bias.unsqueeze() };
+            }
+
+            if self.config.beta != 1.0 {
+                let beta = self.config.beta;
+                bias = quote! { #bias.mul_scalar(#beta) };
+            }
+
+            tokens = quote! { #tokens.add(#bias) };
+        }
+
+        quote! {
+            let #output = #tokens;
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Gemm(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{
+            gemm::{GemmConfig, GemmNode},
+            test::assert_tokens,
+        },
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_trans_b_with_bias() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GemmNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_float("tensor2", 2),
+            Some(TensorType::new_float("tensor3", 1)),
+            TensorType::new_float("tensor4", 2),
+            GemmConfig::new(1.0, 1.0, false, true),
+        ));
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend>{}
+
+            impl<B: Backend> Model <B> {
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self { }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 2>,
+                    tensor2: Tensor<B, 2>,
+                    tensor3: Tensor<B, 1>
+                ) -> Tensor<B, 2> {
+                    let tensor4 = tensor1.matmul(tensor2.transpose()).add(tensor3.unsqueeze());
+
+                    tensor4
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_scaled_trans_a_without_bias() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GemmNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_float("tensor2", 2),
+            None,
+            TensorType::new_float("tensor3", 2),
+            GemmConfig::new(0.5, 1.0, true, false),
+        ));
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend>{}
+
+            impl<B: Backend> Model <B> {
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self { }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>, tensor2: Tensor<B, 2>) -> Tensor<B, 2> {
+                    let tensor3 = tensor1.transpose().matmul(tensor2).mul_scalar(0.5f32);
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
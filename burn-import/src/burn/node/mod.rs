@@ -2,16 +2,38 @@ mod base;
 
 pub(crate) mod add;
 pub(crate) mod batch_norm;
+pub(crate) mod cast;
+pub(crate) mod clip;
+pub(crate) mod concat;
 pub(crate) mod constant;
 pub(crate) mod conv2d;
+pub(crate) mod div;
+pub(crate) mod dropout;
 pub(crate) mod equal;
+pub(crate) mod exp;
 pub(crate) mod flatten;
+pub(crate) mod gelu;
+pub(crate) mod gemm;
+pub(crate) mod global_avg_pool2d;
 pub(crate) mod linear;
+pub(crate) mod log;
 pub(crate) mod log_softmax;
 pub(crate) mod matmul;
 pub(crate) mod max_pool2d;
+pub(crate) mod mul;
+pub(crate) mod pow;
+pub(crate) mod reduce_mean;
+pub(crate) mod reduce_sum;
 pub(crate) mod relu;
+pub(crate) mod reshape;
 pub(crate) mod sigmoid;
+pub(crate) mod softmax;
+pub(crate) mod sqrt;
+pub(crate) mod squeeze;
+pub(crate) mod sub;
+pub(crate) mod tanh;
+pub(crate) mod transpose;
+pub(crate) mod unsqueeze;
 
 pub(crate) use base::*;
 
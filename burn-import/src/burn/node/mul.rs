@@ -0,0 +1,177 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{BinaryInput, Scope, TensorType, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone, new)]
+pub struct MulNode {
+    pub lhs: BinaryInput,
+    pub rhs: BinaryInput,
+    pub output: TensorType,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for MulNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(&self.output)]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![self.lhs.ty(), self.rhs.ty()]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let output = &self.output.name;
+
+        match (&self.lhs, &self.rhs) {
+            (BinaryInput::Tensor(lhs), BinaryInput::Tensor(rhs)) => {
+                let lhs = scope.tensor_use_owned(lhs, node_position);
+                let rhs = scope.tensor_use_owned(rhs, node_position);
+
+                quote! {
+                    let #output = #lhs.mul(#rhs);
+                }
+            }
+            (BinaryInput::Tensor(lhs), BinaryInput::Scalar(rhs)) => {
+                let lhs = scope.tensor_use_owned(lhs, node_position);
+                let rhs = &rhs.name;
+
+                quote! {
+                    let #output = #lhs.mul_scalar(#rhs);
+                }
+            }
+            (BinaryInput::Scalar(lhs), BinaryInput::Tensor(rhs)) => {
+                let lhs = &lhs.name;
+                let rhs = scope.tensor_use_owned(rhs, node_position);
+
+                quote! {
+                    let #output = #rhs.mul_scalar(#lhs);
+                }
+            }
+            (BinaryInput::Scalar(_), BinaryInput::Scalar(_)) => {
+                panic!("Mul: at least one input should be a tensor.")
+            }
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Mul(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{mul::MulNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_tensor_tensor() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(MulNode::new(
+            TensorType::new_float("tensor1", 4).into(),
+            TensorType::new_float("tensor2", 4).into(),
+            TensorType::new_float("tensor3", 4),
+        ));
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend>{}
+
+            impl<B: Backend> Model <B> {
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self { }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>, tensor2: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor3 = tensor1.mul(tensor2);
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_tensor_scalar() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(MulNode::new(
+            TensorType::new_float("tensor1", 4).into(),
+            BinaryInput::new_scalar("scalar1"),
+            TensorType::new_float("tensor2", 4),
+        ));
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend>{}
+
+            impl<B: Backend> Model <B> {
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self { }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>, scalar1: f32) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.mul_scalar(scalar1);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_scalar_tensor() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(MulNode::new(
+            BinaryInput::new_scalar("scalar1"),
+            TensorType::new_float("tensor1", 4).into(),
+            TensorType::new_float("tensor2", 4),
+        ));
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend>{}
+
+            impl<B: Backend> Model <B> {
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self { }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, scalar1: f32, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.mul_scalar(scalar1);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
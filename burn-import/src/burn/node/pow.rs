@@ -0,0 +1,329 @@
+use super::{Node, NodeCodegen, SerializationBackend};
+use crate::burn::{BinaryInput, BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+use burn::{
+    module::{Param, ParamId},
+    record::{PrecisionSettings, Record},
+    tensor::{DataSerialize, Tensor},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::Serialize;
+
+/// Raises the base tensor to the power of the exponent.
+///
+/// A scalar exponent maps to [powf](burn::tensor::Tensor::powf), while a tensor exponent maps to
+/// [powf_tensor](burn::tensor::Tensor::powf_tensor). The base must be a tensor.
+///
+/// An exponent stored as an initializer is a parameter of the model that doesn't require gradients,
+/// loaded from the record.
+#[derive(Debug, Clone)]
+pub struct PowNode {
+    pub lhs: TensorType,
+    pub rhs: BinaryInput,
+    pub output: TensorType,
+    pub rhs_field: Option<OtherType>,
+    pub rhs_data: Option<DataSerialize<f32>>,
+}
+
+impl PowNode {
+    pub fn new(lhs: TensorType, rhs: BinaryInput, output: TensorType) -> Self {
+        Self {
+            lhs,
+            rhs,
+            output,
+            rhs_field: None,
+            rhs_data: None,
+        }
+    }
+
+    /// Create a node raising the base to a constant tensor exponent.
+    ///
+    /// The shape of the exponent is padded with leading ones to match the rank of the base.
+    pub fn new_with_constant_exponent(
+        lhs: TensorType,
+        rhs_name: &str,
+        mut rhs_data: DataSerialize<f32>,
+        output: TensorType,
+    ) -> Self {
+        if rhs_data.shape.len() > lhs.dim {
+            panic!(
+                "Pow: the exponent has a rank of {}, higher than the rank of the base ({})",
+                rhs_data.shape.len(),
+                lhs.dim
+            );
+        }
+
+        let mut shape = vec![1; lhs.dim - rhs_data.shape.len()];
+        shape.append(&mut rhs_data.shape);
+        rhs_data.shape = shape;
+
+        let dim = lhs.dim.to_tokens();
+
+        Self {
+            rhs: BinaryInput::Tensor(TensorType::new_float(rhs_name, lhs.dim)),
+            rhs_field: Some(OtherType::new(
+                rhs_name,
+                quote! {
+                    Param<Tensor<B, #dim>>
+                },
+            )),
+            lhs,
+            output,
+            rhs_data: Some(rhs_data),
+        }
+    }
+}
+
+fn serialize_exponent<PS: PrecisionSettings, S: serde::Serializer, const D: usize>(
+    data: &DataSerialize<f32>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let record = Param::new(
+        ParamId::new(),
+        Tensor::<SerializationBackend, D>::from_data(data.clone().convert()),
+    );
+    let item = Record::into_item::<PS>(record);
+
+    item.serialize(serializer)
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for PowNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(&self.output)]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        match self.rhs_field {
+            Some(_) => vec![Type::Tensor(&self.lhs)],
+            None => vec![Type::Tensor(&self.lhs), self.rhs.ty()],
+        }
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        self.rhs_field.as_ref().map(Type::Other)
+    }
+
+    fn field_init(&self, with_record: bool) -> Option<TokenStream> {
+        let name = &self.rhs_field.as_ref()?.name;
+        let data = self.rhs_data.as_ref()?;
+        let dim = data.shape.len().to_tokens();
+        let shape = data.shape.iter().map(|dim| dim.to_tokens());
+
+        // The exponent is a constant of the model, so it doesn't require gradients.
+        let tokens = match with_record {
+            true => quote! {
+                let #name = record.#name.no_grad();
+            },
+            false => quote! {
+                let #name = Param::from(Tensor::<B, #dim>::zeros([#(#shape),*])).no_grad();
+            },
+        };
+
+        Some(tokens)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let data = self
+            .rhs_data
+            .as_ref()
+            .expect("The exponent should be serialized only when it is a constant.");
+
+        match data.shape.len() {
+            1 => serialize_exponent::<PS, S, 1>(data, serializer),
+            2 => serialize_exponent::<PS, S, 2>(data, serializer),
+            3 => serialize_exponent::<PS, S, 3>(data, serializer),
+            4 => serialize_exponent::<PS, S, 4>(data, serializer),
+            5 => serialize_exponent::<PS, S, 5>(data, serializer),
+            6 => serialize_exponent::<PS, S, 6>(data, serializer),
+            dim => panic!("Pow: unsupported exponent rank {dim}"),
+        }
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let lhs = scope.tensor_use_owned(&self.lhs, node_position);
+        let output = &self.output.name;
+
+        match (&self.rhs, &self.rhs_field) {
+            (BinaryInput::Tensor(_), Some(field)) => {
+                let field = &field.name;
+
+                quote! {
+                    let #output = #lhs.powf_tensor(self.#field.val());
+                }
+            }
+            (BinaryInput::Tensor(rhs), None) => {
+                let rhs = scope.tensor_use_owned(rhs, node_position);
+
+                quote! {
+                    let #output = #lhs.powf_tensor(#rhs);
+                }
+            }
+            (BinaryInput::Scalar(rhs), _) => {
+                let rhs = &rhs.name;
+
+                quote! {
+                    let #output = #lhs.powf(#rhs);
+                }
+            }
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        if self.rhs_field.is_some() {
+            imports.register("burn::module::Param");
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Pow(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{pow::PowNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_tensor_tensor() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(PowNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 4).into(),
+            TensorType::new_float("tensor3", 4),
+        ));
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend>{}
+
+            impl<B: Backend> Model <B> {
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self { }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>, tensor2: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor3 = tensor1.powf_tensor(tensor2);
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_tensor_scalar() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(PowNode::new(
+            TensorType::new_float("tensor1", 4),
+            BinaryInput::new_scalar("scalar1"),
+            TensorType::new_float("tensor2", 4),
+        ));
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend>{}
+
+            impl<B: Backend> Model <B> {
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self { }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>, scalar1: f32) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.powf(scalar1);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_tensor_constant_exponent() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(PowNode::new_with_constant_exponent(
+            TensorType::new_float("tensor1", 2),
+            "exponent",
+            DataSerialize::new(vec![2.0, 3.0], vec![2]),
+            TensorType::new_float("tensor2", 2),
+        ));
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::module::Param;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                exponent: Param<Tensor<B, 2>>,
+            }
+
+            impl<B: Backend> Model <B> {
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    let exponent = record.exponent.no_grad();
+
+                    Self {
+                        exponent,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> Tensor<B, 2> {
+                    let tensor2 = tensor1.powf_tensor(self.exponent.val());
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_constant_exponent_serializes_as_a_param_record() {
+        let node = PowNode::new_with_constant_exponent(
+            TensorType::new_float("tensor1", 2),
+            "exponent",
+            DataSerialize::new(vec![2.0, 3.0], vec![2]),
+            TensorType::new_float("tensor2", 2),
+        );
+
+        let value = NodeCodegen::<FullPrecisionSettings>::field_serialize(
+            &node,
+            serde_json::value::Serializer,
+        )
+        .unwrap();
+        let item: <Param<Tensor<SerializationBackend, 2>> as Record>::Item<FullPrecisionSettings> =
+            serde_json::from_value(value).unwrap();
+        let exponent: Param<Tensor<SerializationBackend, 2>> = Record::from_item(item);
+
+        assert_eq!(
+            exponent.val().into_data(),
+            burn::tensor::Data::from([[2.0, 3.0]])
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone, new)]
+pub struct ReduceSumNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub axes: Vec<usize>,
+    pub keepdims: bool,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ReduceSumNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(&self.output)]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(&self.input)]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+
+        // Reducing every axis without keeping them is the sum of all elements.
+        if !self.keepdims && self.axes.len() == self.input.dim {
+            return quote! {
+                let #output = #input.sum();
+            };
+        }
+
+        let mut tokens = quote! { #input };
+
+        for axis in self.axes.iter() {
+            let axis = axis.to_tokens();
+            tokens = quote! { #tokens.sum_dim(#axis) };
+        }
+
+        if !self.keepdims {
+            // Squeeze from the last axis so that the remaining ones stay valid.
+            let mut rank = self.input.dim;
+
+            for axis in self.axes.iter().rev() {
+                rank -= 1;
+                let rank_tokens = rank.to_tokens();
+                let axis = axis.to_tokens();
+                tokens = quote! { #tokens.squeeze::<#rank_tokens>(#axis) };
+            }
+        }
+
+        quote! {
+            let #output = #tokens;
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::ReduceSum(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{reduce_sum::ReduceSumNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_keepdims() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ReduceSumNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+            vec![1, 2],
+            true,
+        ));
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend>{}
+
+            impl<B: Backend> Model <B> {
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self { }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.sum_dim(1).sum_dim(2);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_without_keepdims() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ReduceSumNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 2),
+            vec![1, 2],
+            false,
+        ));
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend>{}
+
+            impl<B: Backend> Model <B> {
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self { }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 2> {
+                    let tensor2 = tensor1
+                        .sum_dim(1)
+                        .sum_dim(2)
+                        .squeeze::<3>(2)
+                        .squeeze::<2>(1);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
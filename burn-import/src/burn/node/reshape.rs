@@ -0,0 +1,182 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Reshape node following the ONNX semantic, where a `0` copies the input dimension at the same
+/// position and a single `-1` is inferred from the number of elements.
+#[derive(Debug, Clone, new)]
+pub struct ReshapeNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub shape: Vec<i64>,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ReshapeNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(&self.output)]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(&self.input)]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+
+        if self.shape.iter().all(|dim| *dim > 0) {
+            let shape = self.shape.iter().map(|dim| (*dim as usize).to_tokens());
+
+            return quote! {
+                let #output = #input.reshape([#(#shape),*]);
+            };
+        }
+
+        let num_inferred = self.shape.iter().filter(|dim| **dim == -1).count();
+        if num_inferred > 1 {
+            panic!("Reshape: only one dimension can be inferred, got {num_inferred}");
+        }
+
+        let shape = self
+            .shape
+            .iter()
+            .enumerate()
+            .map(|(i, dim)| match *dim {
+                -1 => quote! { inferred },
+                0 => {
+                    let i = i.to_tokens();
+                    quote! { dims[#i] }
+                }
+                dim if dim > 0 => (dim as usize).to_tokens(),
+                dim => panic!("Reshape: invalid dimension {dim}"),
+            })
+            .collect::<Vec<_>>();
+        let known_dims = self
+            .shape
+            .iter()
+            .zip(shape.iter())
+            .filter(|(dim, _)| **dim != -1)
+            .map(|(_, tokens)| tokens)
+            .collect::<Vec<_>>();
+
+        let inferred = if num_inferred == 1 {
+            let num_elems = quote! { dims.iter().product::<usize>() };
+
+            match known_dims.split_first() {
+                Some((first, others)) => {
+                    let known_product = others
+                        .iter()
+                        .fold((*first).clone(), |acc, dim| quote! { #acc * #dim });
+
+                    quote! { let inferred = #num_elems / (#known_product); }
+                }
+                None => quote! { let inferred = #num_elems; },
+            }
+        } else {
+            quote! {}
+        };
+
+        quote! {
+            let #output = {
+                let input = #input;
+                let dims = input.dims();
+                #inferred
+                input.reshape([#(#shape),*])
+            };
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Reshape(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{reshape::ReshapeNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_static_shape() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ReshapeNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 2),
+            vec![2, 48],
+        ));
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend>{}
+
+            impl<B: Backend> Model <B> {
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self { }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 2> {
+                    let tensor2 = tensor1.reshape([2, 48]);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_inferred_shape() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        // [N, C, H, W] -> [N, C * H * W]
+        graph.register(ReshapeNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 2),
+            vec![0, -1],
+        ));
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend>{}
+
+            impl<B: Backend> Model <B> {
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self { }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 2> {
+                    let tensor2 = {
+                        let input = tensor1;
+                        let dims = input.dims();
+                        let inferred = dims.iter().product::<usize>() / (dims[0]);
+                        input.reshape([dims[0], inferred])
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,85 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Transpose node permuting the input dimensions, where the output dimension `i` is the input
+/// dimension `perm[i]`.
+#[derive(Debug, Clone, new)]
+pub struct TransposeNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub perm: Vec<usize>,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for TransposeNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(&self.output)]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(&self.input)]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let perm = self.perm.iter().map(|dim| dim.to_tokens());
+
+        quote! {
+            let #output = #input.permute([#(#perm),*]);
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Transpose(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{test::assert_tokens, transpose::TransposeNode},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_perm() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(TransposeNode::new(
+            TensorType::new_float("tensor1", 3),
+            TensorType::new_float("tensor2", 3),
+            vec![0, 2, 1],
+        ));
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend>{}
+
+            impl<B: Backend> Model <B> {
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self { }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 3>) -> Tensor<B, 3> {
+                    let tensor2 = tensor1.permute([0, 2, 1]);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,90 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone, new)]
+pub struct UnsqueezeNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub axes: Vec<usize>,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for UnsqueezeNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(&self.output)]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(&self.input)]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let mut tokens = quote! { #input };
+
+        // The axes refer to the output, inserting them in increasing order keeps them valid.
+        for (index, axis) in self.axes.iter().enumerate() {
+            let rank = (self.input.dim + index + 1).to_tokens();
+            let axis = axis.to_tokens();
+            tokens = quote! { #tokens.unsqueeze_dim::<#rank>(#axis) };
+        }
+
+        quote! {
+            let #output = #tokens;
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Unsqueeze(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{test::assert_tokens, unsqueeze::UnsqueezeNode},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_nodes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(UnsqueezeNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_float("tensor2", 4),
+            vec![0, 3],
+        ));
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend>{}
+
+            impl<B: Backend> Model <B> {
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self { }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.unsqueeze_dim::<3>(0).unsqueeze_dim::<4>(3);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -12,9 +12,10 @@ pub struct TensorType {
     pub kind: TensorKind,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub enum TensorKind {
     Int,
+    #[default]
     Float,
     Bool,
 }
@@ -25,6 +26,13 @@ pub struct OtherType {
     pub ty: TokenStream,
 }
 
+/// Input of an element-wise binary operation, either a tensor or a scalar constant.
+#[derive(Debug, Clone)]
+pub enum BinaryInput {
+    Tensor(TensorType),
+    Scalar(OtherType),
+}
+
 pub enum Type<'a> {
     Tensor(&'a TensorType),
     Other(&'a OtherType),
@@ -68,12 +76,33 @@ impl TensorType {
     pub fn ty(&self) -> TokenStream {
         let dim = self.dim.to_tokens();
 
-        quote! {
-            Tensor<B, #dim>
+        match self.kind {
+            TensorKind::Float => quote! { Tensor<B, #dim> },
+            TensorKind::Int => quote! { Tensor<B, #dim, Int> },
+            TensorKind::Bool => quote! { Tensor<B, #dim, Bool> },
+        }
+    }
+}
+
+impl BinaryInput {
+    pub fn new_scalar<S: AsRef<str>>(name: S) -> Self {
+        Self::Scalar(OtherType::new(name, quote! { f32 }))
+    }
+
+    pub fn ty(&self) -> Type<'_> {
+        match self {
+            BinaryInput::Tensor(tensor) => Type::Tensor(tensor),
+            BinaryInput::Scalar(scalar) => Type::Other(scalar),
         }
     }
 }
 
+impl From<TensorType> for BinaryInput {
+    fn from(tensor: TensorType) -> Self {
+        Self::Tensor(tensor)
+    }
+}
+
 impl OtherType {
     pub fn new<S: AsRef<str>>(name: S, tokens: TokenStream) -> Self {
         Self {
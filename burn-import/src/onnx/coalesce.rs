@@ -1,7 +1,10 @@
 use burn::tensor::Tensor;
 use burn_ndarray::NdArrayBackend;
 
-use super::ir::{AttributeValue, Node, NodeType, StateType, TensorData};
+use super::{
+    ir::{Node, NodeType, StateType, TensorData},
+    op_configuration::gemm_config,
+};
 
 type B = NdArrayBackend<f32>;
 
@@ -15,58 +18,203 @@ pub fn coalesce(nodes: &mut Vec<Node>) {
     }
 }
 
-/// This function converts a Gemm node into a Linear node
+/// This function converts a Gemm node with constant weights into a Linear node.
 ///
-///  Warning: This function is not complete yet.
-///  It only supports the case where the Gemm node is a straight linear transformation.
+/// The weights are transposed when `transB` is set and scaled by `alpha`, and the bias is scaled
+/// by `beta`. A Gemm node without constant weights is left as is and converted to a matrix
+/// multiplication later on.
 fn convert_gemm(node: &mut Node) {
-    if node.inputs.len() != 1 {
-        panic!("Gemm node must have 1 input");
-    }
-
     if node.outputs.len() != 1 {
         panic!("Gemm node must have 1 output");
     }
-    let straight_linear = match (
-        node.attrs.get("alpha"),
-        node.attrs.get("beta"),
-        node.attrs.get("transB"),
-    ) {
-        (
-            Some(AttributeValue::Float32(alpha)),
-            Some(AttributeValue::Float32(beta)),
-            Some(AttributeValue::Int64(trans_b)),
-        ) => *alpha == 1.0 && *beta == 1.0 && *trans_b == 1,
-        _ => false,
-    };
 
-    if straight_linear {
-        node.node_type = NodeType::Linear;
-        node.attrs.remove("alpha");
-        node.attrs.remove("beta");
-        node.attrs.remove("transB");
+    if node.states.is_empty() {
+        return;
+    }
 
-        // Transpose the weights
-        transpose_linear_node_weights(node);
-    } else {
-        panic!("Full Gemm node not supported yet.");
+    // The initializers are moved to the states, so a runtime second operand with a constant bias
+    // leaves two inputs. The bias alone can't be kept since the matrix multiplication has none.
+    if node.inputs.len() != 1 {
+        panic!(
+            "Gemm: initializers are only supported for the weights and the bias, with a runtime \
+             first operand (got {} runtime inputs and {} initializers)",
+            node.inputs.len(),
+            node.states.len()
+        );
     }
-}
 
-// Transpose linear weights (required for Gemm -> Linear conversion)
-fn transpose_linear_node_weights(node: &mut Node) {
-    if node.states.is_empty() {
-        panic!("Linear node must have at least 1 state.");
+    let config = gemm_config(node);
+
+    if config.trans_a {
+        panic!("Gemm: transA is not supported with constant weights");
+    }
+
+    node.node_type = NodeType::Linear;
+    node.attrs.remove("alpha");
+    node.attrs.remove("beta");
+    node.attrs.remove("transA");
+    node.attrs.remove("transB");
+
+    update_linear_node_weights(node, config.alpha, config.trans_b);
+
+    if node.states.len() == 2 {
+        update_linear_node_bias(node, config.beta);
     }
+}
 
+/// Transpose and scale the linear weights (required for Gemm -> Linear conversion)
+fn update_linear_node_weights(node: &mut Node, alpha: f32, transpose: bool) {
     let StateType::Tensor(node_weight) = &node.states[0].ty;
 
-    let weight: Tensor<B, 2> = node_weight.try_into().unwrap();
+    let mut weight: Tensor<B, 2> = node_weight.try_into().unwrap();
 
-    let weight = weight.transpose();
+    if transpose {
+        weight = weight.transpose();
+    }
+
+    if alpha != 1.0 {
+        weight = weight.mul_scalar(alpha);
+    }
 
     let StateType::Tensor(node_weight) = &mut node.states[0].ty;
 
     node_weight.data = Some(TensorData::Float32(weight.clone().into_data().value));
     node_weight.shape = Some(weight.shape().dims.to_vec());
 }
+
+/// Flatten and scale the linear bias (required for Gemm -> Linear conversion)
+fn update_linear_node_bias(node: &mut Node, beta: f32) {
+    let StateType::Tensor(node_weight) = &node.states[0].ty;
+    let out_size = node_weight.shape.as_ref().unwrap()[1];
+
+    let StateType::Tensor(node_bias) = &mut node.states[1].ty;
+    let shape = node_bias.shape.clone().unwrap();
+
+    // The bias is broadcasted along the rows of the output, so it has either a single value or
+    // one value per output in its last dimension. A bias of shape `[M, 1]` varies per row instead.
+    let num_elements = shape.iter().product::<usize>();
+    let per_output = shape.last() == Some(&out_size) && num_elements == out_size;
+    if num_elements != 1 && !per_output {
+        panic!("Gemm: only a bias broadcasted along the rows is supported (got shape {shape:?})");
+    }
+
+    let TensorData::Float32(mut bias) = node_bias.data.clone().unwrap() else {
+        panic!(
+            "Gemm: the bias must be stored as float32 values to be converted to a linear bias (got {:?})",
+            node_bias.elem_type
+        );
+    };
+
+    if num_elements == 1 {
+        bias = vec![bias[0]; out_size];
+    }
+
+    if beta != 1.0 {
+        bias.iter_mut().for_each(|value| *value *= beta);
+    }
+
+    node_bias.data = Some(TensorData::Float32(bias));
+    node_bias.shape = Some(vec![out_size]);
+    node_bias.dim = 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::onnx::ir::{ArgType, Argument, AttributeValue, ElementType, State, TensorArg};
+
+    fn state(name: &str, shape: Vec<usize>, values: Vec<f32>) -> State {
+        State {
+            name: name.to_string(),
+            ty: StateType::Tensor(crate::onnx::ir::Tensor {
+                elem_type: ElementType::Float32,
+                dim: shape.len(),
+                data: Some(TensorData::Float32(values)),
+                shape: Some(shape),
+            }),
+        }
+    }
+
+    fn gemm(inputs: &[&str], states: Vec<State>) -> Node {
+        let arg = |name: &str| Argument {
+            name: name.to_string(),
+            ty: ArgType::Tensor(TensorArg::new(2)),
+        };
+
+        Node {
+            node_type: NodeType::Gemm,
+            name: "gemm1".to_string(),
+            inputs: inputs.iter().map(|name| arg(name)).collect(),
+            outputs: vec![arg("gemm1_out1")],
+            states,
+            attrs: Default::default(),
+        }
+    }
+
+    fn weights() -> State {
+        state("b", vec![2, 3], vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0])
+    }
+
+    fn bias_of(node: &Node) -> (Vec<f32>, Vec<usize>) {
+        let StateType::Tensor(bias) = &node.states[1].ty;
+        let TensorData::Float32(values) = bias.data.clone().unwrap() else {
+            panic!("The bias should be float32s");
+        };
+
+        (values, bias.shape.clone().unwrap())
+    }
+
+    #[test]
+    fn convert_gemm_flattens_a_bias_per_output() {
+        let mut node = gemm(
+            &["a"],
+            vec![weights(), state("c", vec![1, 3], vec![1.0, 2.0, 3.0])],
+        );
+
+        convert_gemm(&mut node);
+
+        assert_eq!(node.node_type, NodeType::Linear);
+        assert_eq!(bias_of(&node), (vec![1.0, 2.0, 3.0], vec![3]));
+    }
+
+    #[test]
+    fn convert_gemm_expands_a_single_value_bias() {
+        let mut node = gemm(&["a"], vec![weights(), state("c", vec![1], vec![0.5])]);
+        node.attrs
+            .insert("beta".to_string(), AttributeValue::Float32(2.0));
+
+        convert_gemm(&mut node);
+
+        assert_eq!(bias_of(&node), (vec![1.0, 1.0, 1.0], vec![3]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Gemm: only a bias broadcasted along the rows is supported")]
+    fn convert_gemm_rejects_a_bias_per_row() {
+        // Three rows, which matches the number of outputs by coincidence.
+        let mut node = gemm(
+            &["a"],
+            vec![weights(), state("c", vec![3, 1], vec![1.0, 2.0, 3.0])],
+        );
+
+        convert_gemm(&mut node);
+    }
+
+    #[test]
+    #[should_panic(expected = "Gemm: initializers are only supported for the weights and the bias")]
+    fn convert_gemm_rejects_runtime_weights_with_a_constant_bias() {
+        let mut node = gemm(&["a", "b"], vec![state("c", vec![3], vec![1.0, 2.0, 3.0])]);
+
+        convert_gemm(&mut node);
+    }
+
+    #[test]
+    #[should_panic(expected = "Gemm: transA is not supported with constant weights")]
+    fn convert_gemm_rejects_trans_a_with_constant_weights() {
+        let mut node = gemm(&["a"], vec![weights()]);
+        node.attrs
+            .insert("transA".to_string(), AttributeValue::Int64(1));
+
+        convert_gemm(&mut node);
+    }
+}
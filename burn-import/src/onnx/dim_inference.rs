@@ -1,8 +1,11 @@
 use std::collections::HashMap;
 
 use super::{
-    ir::{ArgType, Argument, AttributeValue, Node, NodeType, TensorArg},
-    op_configuration::flatten_config,
+    ir::{ArgType, Argument, Node, NodeType, TensorArg},
+    op_configuration::{
+        cast_config, flatten_config, reduce_config, reshape_config, squeeze_config,
+        unsqueeze_config,
+    },
 };
 
 struct TensorDimUpdater {
@@ -62,35 +65,43 @@ pub fn dim_inference(
             NodeType::Conv2d => conv2d_update_outputs(node),
             NodeType::MaxPool2d => max_pool2d_update_outputs(node),
             NodeType::Linear => linear_update_outputs(node),
+            NodeType::Gemm => gemm_update_outputs(node),
             NodeType::Flatten => flatten_update_outputs(node),
             NodeType::Relu => same_as_input(node),
+            NodeType::Clip => same_as_input(node),
             NodeType::LogSoftmax => same_as_input(node),
             NodeType::BatchNormalization => same_as_input(node),
             NodeType::Add => same_as_input(node),
-            NodeType::Sub => same_as_input(node),
+            NodeType::Sub => elementwise_binary_update_outputs(node),
             NodeType::Pow => same_as_input(node),
-            NodeType::Mul => same_as_input(node),
-            NodeType::Cast => same_as_input(node),
-            NodeType::Div => same_as_input(node),
+            NodeType::Mul => elementwise_binary_update_outputs(node),
+            NodeType::Cast => cast_update_outputs(node),
+            NodeType::Div => elementwise_binary_update_outputs(node),
             NodeType::Sqrt => same_as_input(node),
+            NodeType::Exp => same_as_input(node),
+            NodeType::Log => same_as_input(node),
             NodeType::Softmax => same_as_input(node),
             NodeType::Erf => same_as_input(node),
-            NodeType::ReduceMean => mean_update_outputs(node),
+            NodeType::ReduceMean => reduce_update_outputs(node),
+            NodeType::ReduceSum => reduce_update_outputs(node),
             NodeType::Constant => {
                 node.outputs[0].ty = ArgType::Constant;
             }
             NodeType::Equal => same_as_input(node),
             NodeType::Shape => shape_update_outputs(node),
+            NodeType::Squeeze => squeeze_update_outputs(node),
             NodeType::Unsqueeze => unsqueeze_update_outputs(node),
             NodeType::Slice => slice_update_outputs(node),
             NodeType::MatMul => same_as_input(node),
             NodeType::Sigmoid => same_as_input(node),
+            NodeType::Tanh => same_as_input(node),
+            NodeType::Gelu => same_as_input(node),
+            NodeType::Transpose => same_as_input(node),
+            NodeType::Dropout => same_as_input(node),
+            NodeType::GlobalAveragePool => global_avg_pool2d_update_outputs(node),
             NodeType::Concat => concat_update_outputs(node),
             NodeType::Reshape => reshape_update_outputs(node),
-            _ => todo!(
-                "shape inference for {:?} is not implemented",
-                node.node_type
-            ),
+            _ => unsupported_update_outputs(node),
         }
 
         updater.update_tensor_outputs(node);
@@ -110,12 +121,21 @@ fn linear_update_outputs(node: &mut Node) {
 
     if let ArgType::Tensor(tensor) = node_input.clone().ty {
         // Update the output tensor
-        node.outputs[0].ty = ArgType::Tensor(TensorArg { dim: tensor.dim });
+        node.outputs[0].ty = ArgType::Tensor(TensorArg::new(tensor.dim));
     } else {
         panic!("Only tensor input is valid");
     }
 }
 
+/// Infer the shape of the output tensor of a Gemm node, always a matrix
+fn gemm_update_outputs(node: &mut Node) {
+    if node.inputs.len() < 2 {
+        panic!("Gemm: the first two operands must be runtime inputs");
+    }
+
+    node.outputs[0].ty = ArgType::Tensor(TensorArg::new(2));
+}
+
 fn concat_update_outputs(node: &mut Node) {
     let tensor = node
         .inputs
@@ -126,10 +146,26 @@ fn concat_update_outputs(node: &mut Node) {
         })
         .unwrap();
 
-    node.outputs[0].ty = ArgType::Tensor(TensorArg { dim: tensor.dim });
+    node.outputs[0].ty = ArgType::Tensor(TensorArg {
+        dim: tensor.dim,
+        kind: tensor.kind.clone(),
+    });
 }
 
 fn reshape_update_outputs(node: &mut Node) {
+    let kind = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.kind.clone(),
+        _ => Default::default(),
+    };
+
+    if let Some(shape) = reshape_config(node) {
+        node.outputs[0].ty = ArgType::Tensor(TensorArg {
+            dim: shape.len(),
+            kind,
+        });
+        return;
+    }
+
     let dim = *node
         .inputs
         .iter()
@@ -141,35 +177,30 @@ fn reshape_update_outputs(node: &mut Node) {
         .last()
         .unwrap();
 
-    node.outputs[0].ty = ArgType::Tensor(TensorArg { dim });
+    node.outputs[0].ty = ArgType::Tensor(TensorArg { dim, kind });
 }
 
-fn mean_update_outputs(node: &mut Node) {
-    if node.inputs.len() != 1 {
-        panic!("Mean: multiple inputs are not supported");
-    }
-
-    // Extract the configuration of the linear layer (inputs are known)
-    let node_input = &mut node.inputs[0];
-    let tensor = match node_input.clone().ty {
+/// Infers the shape of a reduction node (ReduceMean, ReduceSum), dropping the reduced axes when
+/// `keepdims` is false
+fn reduce_update_outputs(node: &mut Node) {
+    let tensor = match node.inputs[0].clone().ty {
         ArgType::Tensor(tensor) => tensor,
         _ => panic!("Only tensor input is valid"),
     };
 
-    let dim_only = match node.attrs.get("axes") {
-        Some(value) => match &value {
-            AttributeValue::Int64(_) => true,
-            AttributeValue::Int64s(ints) => ints.len() == 1,
-            _ => false,
-        },
-        None => false,
-    };
+    let (axes, keepdims) = reduce_config(node);
 
-    if dim_only {
-        node.outputs[0].ty = ArgType::Tensor(TensorArg { dim: tensor.dim });
+    let dim = if keepdims {
+        tensor.dim
     } else {
-        node.outputs[0].ty = ArgType::Tensor(TensorArg { dim: 1 });
-    }
+        // Reducing every axis still produces a tensor with a single element
+        usize::max(tensor.dim - axes.len(), 1)
+    };
+
+    node.outputs[0].ty = ArgType::Tensor(TensorArg {
+        dim,
+        kind: tensor.kind,
+    });
 }
 
 fn unsqueeze_update_outputs(node: &mut Node) {
@@ -178,13 +209,30 @@ fn unsqueeze_update_outputs(node: &mut Node) {
     }
 
     let node_input = &mut node.inputs[0];
-    let dim = match node_input.clone().ty {
-        ArgType::Tensor(tensor) => tensor.dim,
-        ArgType::Shape(dim) => dim,
+    let tensor = match node_input.clone().ty {
+        ArgType::Tensor(tensor) => TensorArg {
+            dim: tensor.dim + unsqueeze_config(node).len(),
+            kind: tensor.kind,
+        },
+        ArgType::Shape(dim) => TensorArg::new(dim + 1),
         ArgType::Constant => panic!("Needs shape or tensor"),
     };
 
-    node.outputs[0].ty = ArgType::Tensor(TensorArg { dim: dim + 1 });
+    node.outputs[0].ty = ArgType::Tensor(tensor);
+}
+
+fn squeeze_update_outputs(node: &mut Node) {
+    let tensor = match node.inputs[0].clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("Only tensor input is valid"),
+    };
+
+    let axes = squeeze_config(node);
+
+    node.outputs[0].ty = ArgType::Tensor(TensorArg {
+        dim: tensor.dim - axes.len(),
+        kind: tensor.kind,
+    });
 }
 
 fn slice_update_outputs(node: &mut Node) {
@@ -201,13 +249,68 @@ fn slice_update_outputs(node: &mut Node) {
         })
         .unwrap();
 
-    node.outputs[0].ty = ArgType::Tensor(TensorArg { dim: tensor.dim });
+    node.outputs[0].ty = ArgType::Tensor(tensor.clone());
+}
+
+/// The spatial dimensions are reduced to one, so the output keeps the `[N, C, 1, 1]` rank.
+fn global_avg_pool2d_update_outputs(node: &mut Node) {
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("Only tensor input is valid"),
+    };
+
+    if tensor.dim != 4 {
+        panic!(
+            "GlobalAveragePool: only 4D inputs are supported (got {:?})",
+            tensor.dim
+        );
+    }
+
+    node.outputs[0].ty = ArgType::Tensor(tensor);
+}
+
+/// The output of a Cast node keeps the rank of the input, with the kind of the target type.
+fn cast_update_outputs(node: &mut Node) {
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("Cast: only tensor input is valid"),
+    };
+
+    node.outputs[0].ty = ArgType::Tensor(TensorArg {
+        dim: tensor.dim,
+        kind: cast_config(node),
+    });
 }
 
 fn same_as_input(node: &mut Node) {
     node.outputs[0].ty = node.inputs[0].ty.clone();
 }
 
+/// Nodes without shape inference keep the type of their first input, so the remaining nodes can
+/// still be processed and every unsupported node reported during the conversion.
+fn unsupported_update_outputs(node: &mut Node) {
+    log::warn!("Shape inference for {} is not implemented", node.node_type);
+
+    if let Some(input) = node.inputs.first() {
+        let ty = input.ty.clone();
+        node.outputs
+            .iter_mut()
+            .for_each(|output| output.ty = ty.clone());
+    }
+}
+
+/// The output of an element-wise binary operation has the type of its tensor input, since the
+/// other input can be a scalar constant.
+fn elementwise_binary_update_outputs(node: &mut Node) {
+    let input = node
+        .inputs
+        .iter()
+        .find(|input| matches!(input.ty, ArgType::Tensor(_)))
+        .unwrap_or_else(|| panic!("{}: at least one input should be a tensor", node.node_type));
+
+    node.outputs[0].ty = input.ty.clone();
+}
+
 fn shape_update_outputs(node: &mut Node) {
     if node.inputs.len() != 1 {
         panic!("Gather: multiple inputs are not supported: {:?}", node);
@@ -223,17 +326,17 @@ fn shape_update_outputs(node: &mut Node) {
     }
 }
 
-/// Infers the shape of a Flatten node and replaces the shape of the output tensor.
+/// Infers the shape of a Flatten node and replaces the shape of the output tensor, always a
+/// matrix.
 fn flatten_update_outputs(node: &mut Node) {
     if node.inputs.len() != 1 {
         panic!("Flatten: multiple inputs are not supported");
     }
 
-    let (start_dim, end_dim) = flatten_config(node);
+    // validates the axis
+    flatten_config(node);
 
-    node.outputs[0].ty = ArgType::Tensor(TensorArg {
-        dim: end_dim - start_dim,
-    });
+    node.outputs[0].ty = ArgType::Tensor(TensorArg::new(2));
 }
 
 /// Infers the shape of a Conv2d node and replaces the shape of the output tensor.
@@ -247,7 +350,7 @@ fn conv2d_update_outputs(node: &mut Node) {
 
     // extract the channels from the weight tensor's shape [out_channels, in_channels, ...]
     if let ArgType::Tensor(tensor) = node.inputs[0].clone().ty {
-        node.outputs[0].ty = ArgType::Tensor(TensorArg { dim: tensor.dim });
+        node.outputs[0].ty = ArgType::Tensor(TensorArg::new(tensor.dim));
     } else {
         panic!("Only tensor input is valid");
     }
@@ -264,7 +367,7 @@ fn max_pool2d_update_outputs(node: &mut Node) {
 
     // extract the channels from the weight tensor's shape [out_channels, in_channels, ...]
     if let ArgType::Tensor(tensor) = node.inputs[0].clone().ty {
-        node.outputs[0].ty = ArgType::Tensor(TensorArg { dim: tensor.dim });
+        node.outputs[0].ty = ArgType::Tensor(TensorArg::new(tensor.dim));
     } else {
         panic!("Only tensor input is valid");
     }
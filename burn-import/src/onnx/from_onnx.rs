@@ -21,10 +21,34 @@ use bytemuck::cast_slice;
 use protobuf::{Enum, Message};
 use topological_sort::TopologicalSort;
 
+/// Minimum ONNX opset version supported by the importer.
+pub const MIN_OPSET_VERSION: i64 = 13;
+
+/// Maximum ONNX opset version supported by the importer, which is the first one defining `Gelu`.
+pub const MAX_OPSET_VERSION: i64 = 20;
+
 /// Error type for parsing ONNX model
 #[derive(Debug)]
 pub enum ParseError {
     VariantNotFound,
+    /// The default domain opset version is outside the supported range.
+    UnsupportedOpsetVersion(i64),
+}
+
+/// Check that the opset version of the default ONNX domain is supported.
+pub fn check_opset_version(model: &ModelProto) -> Result<(), ParseError> {
+    let version = model
+        .opset_import
+        .iter()
+        .find(|opset| opset.domain.is_empty() || opset.domain == "ai.onnx")
+        .map(|opset| opset.version);
+
+    match version {
+        Some(version) if !(MIN_OPSET_VERSION..=MAX_OPSET_VERSION).contains(&version) => {
+            Err(ParseError::UnsupportedOpsetVersion(version))
+        }
+        _ => Ok(()),
+    }
 }
 
 /// Open an onnx file and convert it to a Graph (intermediate representation)
@@ -34,14 +58,28 @@ pub fn parse_onnx(onnx_path: &Path) -> ONNXGraph {
     let onnx_model: ModelProto =
         Message::parse_from_reader(&mut file).expect("Unable to parse ONNX file");
 
+    // Nodes from an unsupported opset may still convert, but their semantics can differ
+    if let Err(ParseError::UnsupportedOpsetVersion(version)) = check_opset_version(&onnx_model) {
+        log::warn!(
+            "ONNX opset version {} is outside the supported range ({}..={}), \
+             the generated model may be incorrect",
+            version,
+            MIN_OPSET_VERSION,
+            MAX_OPSET_VERSION
+        );
+    }
+
     // Convert the nodes
     let mut nodes: Vec<Node> = vec![];
     for onnx_node in onnx_model.graph.node.iter() {
         nodes.push(convert_node_proto(onnx_node));
     }
 
-    // Move inputs to initializers
+    // Lift scalar operands of element-wise operations, then move inputs to initializers
+    lift_scalar_initializers(&mut nodes, &onnx_model.graph.initializer);
+    move_clip_bounds_to_attrs(&mut nodes, &onnx_model.graph.initializer);
     move_inputs_to_state(&mut nodes, &onnx_model.graph.initializer);
+    move_constant_axes_to_state(&mut nodes);
 
     // Get the topological sort of the nodes and the top nodes
     let (ts, top_nodes) = get_top_nodes(&nodes);
@@ -344,7 +382,8 @@ pub fn convert_node_proto(node: &NodeProto) -> Node {
     let attrs = convert_vec_attrs_proto(node.attribute.clone());
 
     log::debug!("Found ONNX node type => {}", node.op_type.as_str());
-    let node_type = NodeType::from_str(node.op_type.as_str()).expect("Unknown node type");
+    // Unknown operators are reported as unsupported when converting the graph
+    let node_type = NodeType::from_str(node.op_type.as_str()).unwrap();
 
     let mut node = Node {
         node_type,
@@ -427,6 +466,121 @@ impl TryFrom<ValueInfoProto> for State {
     }
 }
 
+/// Replaces the single element initializers used by element-wise binary operations and by the
+/// exponent of Pow with constant nodes, so they are used as scalars instead of being moved into the
+/// node states, which would lose the position of the operand.
+fn lift_scalar_initializers(nodes: &mut Vec<Node>, initializer: &[TensorProto]) {
+    let mut constants: Vec<Node> = Vec::new();
+
+    for node in nodes.iter_mut() {
+        if !matches!(
+            node.node_type,
+            NodeType::Mul | NodeType::Sub | NodeType::Div | NodeType::Pow
+        ) {
+            continue;
+        }
+
+        for (position, input) in node.inputs.iter_mut().enumerate() {
+            let init = match initializer.iter().find(|init| init.name == input.name) {
+                Some(init) => init,
+                None => continue,
+            };
+
+            // Only the exponent is known by position once the initializers are moved into the
+            // states, the base has to be provided at runtime.
+            if node.node_type == NodeType::Pow && position == 0 {
+                panic!(
+                    "{}: a base stored as an initializer is unsupported",
+                    node.node_type
+                );
+            }
+
+            let value = match Tensor::try_from(init.clone()).unwrap().data {
+                Some(TensorData::Float32(val)) if val.len() == 1 => val[0],
+                Some(TensorData::Float64(val)) if val.len() == 1 => val[0] as f32,
+                Some(TensorData::Int32(val)) if val.len() == 1 => val[0] as f32,
+                Some(TensorData::Int64(val)) if val.len() == 1 => val[0] as f32,
+                _ => continue,
+            };
+
+            input.name = format!("{}_scalar", input.name);
+
+            if constants.iter().any(|constant| constant.name == input.name) {
+                continue;
+            }
+
+            constants.push(Node {
+                node_type: NodeType::Constant,
+                name: input.name.clone(),
+                inputs: vec![],
+                outputs: vec![Argument {
+                    name: input.name.clone(),
+                    ty: ArgType::Constant,
+                }],
+                states: vec![],
+                attrs: Attributes::from([("value".to_string(), AttributeValue::Float32(value))]),
+            });
+        }
+    }
+
+    nodes.splice(0..0, constants);
+}
+
+/// Moves the min and max inputs of the Clip nodes, given as initializers or outputs of Constant
+/// nodes from opset 11, into the attributes used before, since their position is lost once the
+/// initializers are moved into the node states.
+fn move_clip_bounds_to_attrs(nodes: &mut Vec<Node>, initializer: &[TensorProto]) {
+    let constants: HashMap<String, AttributeValue> = nodes
+        .iter()
+        .filter(|node| node.node_type == NodeType::Constant)
+        .filter_map(|node| {
+            let value = node.attrs.get("value")?;
+            Some((node.outputs[0].name.clone(), value.clone()))
+        })
+        .collect();
+    let mut moved = HashSet::new();
+
+    for node in nodes.iter_mut() {
+        if node.node_type != NodeType::Clip {
+            continue;
+        }
+
+        for (position, key) in [(1, "min"), (2, "max")] {
+            let input = match node.inputs.get(position) {
+                // An omitted min followed by a max is an input without name
+                Some(input) if !input.name.is_empty() => input,
+                _ => continue,
+            };
+
+            let scalar = |tensor: Tensor| match tensor.data {
+                Some(TensorData::Float32(values)) if values.len() == 1 => Some(values[0]),
+                Some(TensorData::Float64(values)) if values.len() == 1 => Some(values[0] as f32),
+                _ => None,
+            };
+            let value = match initializer.iter().find(|init| init.name == input.name) {
+                Some(init) => scalar(Tensor::try_from(init.clone()).unwrap()),
+                None => match constants.get(&input.name) {
+                    Some(AttributeValue::Tensor(tensor)) => scalar(tensor.clone()),
+                    Some(AttributeValue::Float32(value)) => Some(*value),
+                    _ => panic!(
+                        "Clip: {key} computed at runtime is unsupported, it must be an initializer \
+                         or the output of a Constant node"
+                    ),
+                },
+            };
+            let value = value.unwrap_or_else(|| panic!("Clip: {key} must be a float scalar"));
+
+            node.attrs
+                .insert(key.to_string(), AttributeValue::Float32(value));
+            moved.insert(input.name.clone());
+        }
+
+        node.inputs.truncate(1);
+    }
+
+    remove_unused_constants(nodes, &moved);
+}
+
 fn move_inputs_to_state(nodes: &mut Vec<Node>, initializer: &[TensorProto]) {
     nodes.iter_mut().for_each(|node| {
         let mut node_states = Vec::new();
@@ -460,6 +614,79 @@ fn move_inputs_to_state(nodes: &mut Vec<Node>, initializer: &[TensorProto]) {
     });
 }
 
+/// Moves the axes given by the output of a Constant node, as done from opset 13, into the states of
+/// the node using them like the axes stored as initializers, and removes the Constant nodes left
+/// unused. The target shape of a Reshape, usually given by a Constant node too, is moved the same
+/// way.
+fn move_constant_axes_to_state(nodes: &mut Vec<Node>) {
+    let constants: HashMap<String, Tensor> = nodes
+        .iter()
+        .filter(|node| node.node_type == NodeType::Constant)
+        .filter_map(|node| {
+            let tensor = match (node.attrs.get("value"), node.attrs.get("value_ints")) {
+                (Some(AttributeValue::Tensor(tensor)), _) => tensor.clone(),
+                (_, Some(AttributeValue::Int64s(values))) => Tensor {
+                    elem_type: ElementType::Int64,
+                    dim: 1,
+                    data: Some(TensorData::Int64(values.clone())),
+                    shape: Some(vec![values.len()]),
+                },
+                _ => return None,
+            };
+
+            Some((node.outputs[0].name.clone(), tensor))
+        })
+        .collect();
+
+    if constants.is_empty() {
+        return;
+    }
+
+    for node in nodes.iter_mut() {
+        if !matches!(
+            node.node_type,
+            NodeType::Squeeze
+                | NodeType::Unsqueeze
+                | NodeType::ReduceMean
+                | NodeType::ReduceSum
+                | NodeType::Reshape
+        ) {
+            continue;
+        }
+
+        let tensor = match node.inputs.get(1) {
+            Some(axes) => match constants.get(&axes.name) {
+                Some(tensor) => tensor.clone(),
+                None => continue,
+            },
+            None => continue,
+        };
+
+        let axes = node.inputs.remove(1);
+        node.states.push(State {
+            name: axes.name,
+            ty: StateType::Tensor(tensor),
+        });
+    }
+
+    let constants = constants.into_keys().collect();
+    remove_unused_constants(nodes, &constants);
+}
+
+/// Removes the given Constant nodes when their output isn't used anymore.
+fn remove_unused_constants(nodes: &mut Vec<Node>, constants: &HashSet<String>) {
+    let used: HashSet<String> = nodes
+        .iter()
+        .flat_map(|node| node.inputs.iter().map(|input| input.name.clone()))
+        .collect();
+
+    nodes.retain(|node| {
+        node.node_type != NodeType::Constant
+            || !constants.contains(&node.outputs[0].name)
+            || used.contains(&node.outputs[0].name)
+    });
+}
+
 /// Rename the nodes in the graph to be unique and return a map of the old names to the new names.
 fn rename_nodes(nodes: &mut Vec<Node>) -> HashMap<String, String> {
     let mut old_names = HashMap::new();
@@ -565,3 +792,287 @@ pub fn topsort(nodes: &Vec<Node>) -> TopologicalSort<Node> {
 
     ts
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::onnx::{
+        op_configuration::{clip_config, reshape_config, unsqueeze_config},
+        protos::OperatorSetIdProto,
+    };
+
+    fn model_with_opset(domain: &str, version: i64) -> ModelProto {
+        let mut opset = OperatorSetIdProto::new();
+        opset.domain = domain.to_string();
+        opset.version = version;
+
+        let mut model = ModelProto::new();
+        model.opset_import.push(opset);
+        model
+    }
+
+    #[test]
+    fn check_opset_version_accepts_supported_version() {
+        assert!(check_opset_version(&model_with_opset("", 16)).is_ok());
+        assert!(check_opset_version(&model_with_opset("ai.onnx", 13)).is_ok());
+        assert!(check_opset_version(&model_with_opset("", 20)).is_ok());
+    }
+
+    #[test]
+    fn check_opset_version_rejects_unsupported_version() {
+        let result = check_opset_version(&model_with_opset("", 7));
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnsupportedOpsetVersion(7))
+        ));
+    }
+
+    #[test]
+    fn check_opset_version_ignores_other_domains() {
+        assert!(check_opset_version(&model_with_opset("com.microsoft", 1)).is_ok());
+    }
+
+    fn initializer(name: &str, values: Vec<f32>) -> TensorProto {
+        let mut tensor = TensorProto::new();
+        tensor.name = name.to_string();
+        tensor.data_type = DataType::FLOAT.value();
+        tensor.dims = vec![values.len() as i64];
+        tensor.float_data = values;
+        tensor
+    }
+
+    fn binary_node(node_type: NodeType, lhs: &str, rhs: &str) -> Node {
+        let arg = |name: &str| Argument {
+            name: name.to_string(),
+            ty: ArgType::Tensor(TensorArg::default()),
+        };
+
+        Node {
+            node_type,
+            name: "binary".to_string(),
+            inputs: vec![arg(lhs), arg(rhs)],
+            outputs: vec![arg("output")],
+            states: vec![],
+            attrs: Default::default(),
+        }
+    }
+
+    fn constant_node(name: &str, values: Vec<i64>) -> Node {
+        let tensor = Tensor {
+            elem_type: ElementType::Int64,
+            dim: 1,
+            shape: Some(vec![values.len()]),
+            data: Some(TensorData::Int64(values)),
+        };
+
+        Node {
+            node_type: NodeType::Constant,
+            name: name.to_string(),
+            inputs: vec![],
+            outputs: vec![Argument {
+                name: name.to_string(),
+                ty: ArgType::Tensor(TensorArg::default()),
+            }],
+            states: vec![],
+            attrs: Attributes::from([("value".to_string(), AttributeValue::Tensor(tensor))]),
+        }
+    }
+
+    #[test]
+    fn move_constant_axes_to_state_resolves_the_unsqueeze_axes() {
+        let mut unsqueeze = binary_node(NodeType::Unsqueeze, "x", "axes");
+        unsqueeze.inputs[0].ty = ArgType::Tensor(TensorArg::new(2));
+        let mut nodes = vec![constant_node("axes", vec![0, -1]), unsqueeze];
+
+        move_constant_axes_to_state(&mut nodes);
+
+        assert_eq!(nodes.len(), 1);
+        assert_eq!(nodes[0].inputs.len(), 1);
+        assert_eq!(nodes[0].states.len(), 1);
+        assert_eq!(unsqueeze_config(&nodes[0]), vec![0, 3]);
+    }
+
+    #[test]
+    fn move_constant_axes_to_state_resolves_the_reshape_shape() {
+        let mut nodes = vec![
+            constant_node("shape", vec![2, -1]),
+            binary_node(NodeType::Reshape, "x", "shape"),
+        ];
+
+        move_constant_axes_to_state(&mut nodes);
+
+        assert_eq!(nodes.len(), 1);
+        assert_eq!(nodes[0].inputs.len(), 1);
+        assert_eq!(reshape_config(&nodes[0]), Some(vec![2, -1]));
+    }
+
+    #[test]
+    fn move_constant_axes_to_state_keeps_constants_used_elsewhere() {
+        let mut nodes = vec![
+            constant_node("axes", vec![0]),
+            binary_node(NodeType::Squeeze, "x", "axes"),
+            binary_node(NodeType::Add, "y", "axes"),
+        ];
+
+        move_constant_axes_to_state(&mut nodes);
+
+        assert_eq!(nodes.len(), 3);
+        assert_eq!(nodes[1].states.len(), 1);
+        assert_eq!(nodes[2].inputs[1].name, "axes");
+    }
+
+    #[test]
+    fn lift_scalar_initializers_keeps_the_operand_position() {
+        let initializers = [initializer("one", vec![1.0])];
+        let mut nodes = vec![binary_node(NodeType::Sub, "one", "x")];
+
+        lift_scalar_initializers(&mut nodes, &initializers);
+        move_inputs_to_state(&mut nodes, &initializers);
+
+        assert_eq!(nodes.len(), 2);
+        assert_eq!(nodes[0].node_type, NodeType::Constant);
+        assert_eq!(nodes[0].outputs[0].name, "one_scalar");
+        assert!(matches!(
+            nodes[0].attrs.get("value"),
+            Some(AttributeValue::Float32(val)) if *val == 1.0
+        ));
+        let sub = &nodes[1];
+        assert_eq!(sub.inputs[0].name, "one_scalar");
+        assert_eq!(sub.inputs[1].name, "x");
+        assert!(sub.states.is_empty());
+    }
+
+    #[test]
+    fn lift_scalar_initializers_ignores_tensor_initializers() {
+        let initializers = [initializer("weights", vec![1.0, 2.0])];
+        let mut nodes = vec![binary_node(NodeType::Mul, "x", "weights")];
+
+        lift_scalar_initializers(&mut nodes, &initializers);
+        move_inputs_to_state(&mut nodes, &initializers);
+
+        assert_eq!(nodes.len(), 1);
+        assert_eq!(nodes[0].inputs.len(), 1);
+        assert_eq!(nodes[0].states.len(), 1);
+    }
+
+    #[test]
+    fn lift_scalar_initializers_lifts_the_pow_exponent() {
+        let initializers = [initializer("two", vec![2.0])];
+        let mut nodes = vec![binary_node(NodeType::Pow, "x", "two")];
+
+        lift_scalar_initializers(&mut nodes, &initializers);
+        move_inputs_to_state(&mut nodes, &initializers);
+
+        assert_eq!(nodes.len(), 2);
+        assert_eq!(nodes[0].node_type, NodeType::Constant);
+        let pow = &nodes[1];
+        assert_eq!(pow.inputs[0].name, "x");
+        assert_eq!(pow.inputs[1].name, "two_scalar");
+        assert!(pow.states.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Pow: a base stored as an initializer is unsupported")]
+    fn lift_scalar_initializers_rejects_a_pow_base_initializer() {
+        let initializers = [initializer("base", vec![1.0, 2.0])];
+        let mut nodes = vec![binary_node(NodeType::Pow, "base", "x")];
+
+        lift_scalar_initializers(&mut nodes, &initializers);
+    }
+
+    fn clip_node(inputs: &[&str]) -> Node {
+        let arg = |name: &str| Argument {
+            name: name.to_string(),
+            ty: ArgType::Tensor(TensorArg::default()),
+        };
+
+        Node {
+            node_type: NodeType::Clip,
+            name: "clip".to_string(),
+            inputs: inputs.iter().map(|name| arg(name)).collect(),
+            outputs: vec![arg("output")],
+            states: vec![],
+            attrs: Default::default(),
+        }
+    }
+
+    fn float_constant_node(name: &str, value: f32) -> Node {
+        let tensor = Tensor {
+            elem_type: ElementType::Float32,
+            dim: 0,
+            shape: Some(vec![]),
+            data: Some(TensorData::Float32(vec![value])),
+        };
+        let mut node = constant_node(name, vec![]);
+        node.attrs = Attributes::from([("value".to_string(), AttributeValue::Tensor(tensor))]);
+        node
+    }
+
+    #[test]
+    fn clip_config_reads_the_attributes() {
+        let mut clip = clip_node(&["x"]);
+        clip.attrs = Attributes::from([
+            ("min".to_string(), AttributeValue::Float32(-1.0)),
+            ("max".to_string(), AttributeValue::Float32(2.0)),
+        ]);
+        let mut nodes = vec![clip];
+
+        move_clip_bounds_to_attrs(&mut nodes, &[]);
+
+        assert_eq!(clip_config(&nodes[0]), (Some(-1.0), Some(2.0)));
+    }
+
+    #[test]
+    fn move_clip_bounds_to_attrs_resolves_the_constant_inputs() {
+        let mut nodes = vec![
+            float_constant_node("min", -1.0),
+            float_constant_node("max", 2.0),
+            clip_node(&["x", "min", "max"]),
+        ];
+
+        move_clip_bounds_to_attrs(&mut nodes, &[]);
+        move_inputs_to_state(&mut nodes, &[]);
+
+        assert_eq!(nodes.len(), 1);
+        assert_eq!(nodes[0].inputs.len(), 1);
+        assert_eq!(clip_config(&nodes[0]), (Some(-1.0), Some(2.0)));
+    }
+
+    #[test]
+    fn move_clip_bounds_to_attrs_keeps_the_bounds_order() {
+        let initializers = [initializer("max", vec![2.0])];
+        let mut nodes = vec![
+            float_constant_node("min", -1.0),
+            clip_node(&["x", "min", "max"]),
+        ];
+
+        move_clip_bounds_to_attrs(&mut nodes, &initializers);
+        move_inputs_to_state(&mut nodes, &initializers);
+
+        assert_eq!(nodes.len(), 1);
+        assert_eq!(clip_config(&nodes[0]), (Some(-1.0), Some(2.0)));
+    }
+
+    #[test]
+    fn move_clip_bounds_to_attrs_handles_an_omitted_min() {
+        let initializers = [initializer("max", vec![2.0])];
+        let mut nodes = vec![clip_node(&["x", "", "max"])];
+
+        move_clip_bounds_to_attrs(&mut nodes, &initializers);
+        move_inputs_to_state(&mut nodes, &initializers);
+
+        assert_eq!(nodes[0].inputs.len(), 1);
+        assert!(nodes[0].states.is_empty());
+        assert_eq!(clip_config(&nodes[0]), (None, Some(2.0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Clip: max computed at runtime is unsupported")]
+    fn move_clip_bounds_to_attrs_rejects_runtime_bounds() {
+        let mut nodes = vec![clip_node(&["x", "min", "max"])];
+        let initializers = [initializer("min", vec![-1.0])];
+
+        move_clip_bounds_to_attrs(&mut nodes, &initializers);
+    }
+}
@@ -1,8 +1,9 @@
+use crate::burn::TensorKind;
 use burn_ndarray::NdArrayBackend;
 use core::fmt;
 use half::f16;
 use std::{collections::HashMap, fmt::Formatter};
-use strum_macros::{Display, EnumString};
+use strum_macros::EnumString;
 
 pub type Shape = Vec<usize>;
 
@@ -22,6 +23,9 @@ pub enum ArgType {
 #[derive(new, Default, Debug, Clone)]
 pub struct TensorArg {
     pub dim: usize,
+    /// The kind of the elements, float unless changed by a Cast.
+    #[new(default)]
+    pub kind: TensorKind,
 }
 
 #[derive(Debug, Clone)]
@@ -143,7 +147,7 @@ impl core::hash::Hash for Argument {
 }
 
 /// The list of supported node types (ONNX operators and some extra ones to map easily to Burn's ops)
-#[derive(Debug, Hash, Eq, PartialEq, EnumString, Clone, Display)]
+#[derive(Debug, Hash, Eq, PartialEq, EnumString, Clone)]
 pub enum NodeType {
     Abs,
     Acos,
@@ -337,6 +341,18 @@ pub enum NodeType {
     Upsample,
     Where,
     Xor,
+    /// An operator unknown to the importer, keeping the name of the ONNX operator.
+    #[strum(default)]
+    Unknown(String),
+}
+
+impl fmt::Display for NodeType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            NodeType::Unknown(op_type) => f.write_str(op_type),
+            node_type => fmt::Debug::fmt(node_type, f),
+        }
+    }
 }
 
 /// Truncate the vector display for debug display
@@ -2,7 +2,10 @@ use burn::nn::{
     conv::Conv2dConfig, pool::MaxPool2dConfig, BatchNormConfig, LinearConfig, PaddingConfig2d,
 };
 
-use super::ir::{ArgType, AttributeValue, Node, StateType};
+use super::ir::{ArgType, AttributeValue, Node, StateType, TensorData};
+use super::protos::tensor_proto::DataType;
+use crate::burn::{node::gemm::GemmConfig, TensorKind};
+use protobuf::Enum;
 
 #[inline(always)]
 pub fn attr_value_vec_i64(value: &AttributeValue, target: &mut Vec<i64>) {
@@ -25,6 +28,14 @@ pub fn attr_value_f32(value: &AttributeValue, target: &mut f32) {
     }
 }
 
+#[inline(always)]
+pub fn attr_value_f64(value: &AttributeValue) -> Option<f64> {
+    match value {
+        AttributeValue::Float32(val) => Some(*val as f64),
+        _ => None,
+    }
+}
+
 /// Create a Conv2dConfig from the attributes of the node
 pub fn conv2d_config(curr: &Node) -> Conv2dConfig {
     let mut kernel_shape = Vec::new();
@@ -104,9 +115,9 @@ pub fn max_pool2d_config(curr: &Node) -> MaxPool2dConfig {
 }
 
 /// Create a FlattenConfig from the attributes of the node
-pub fn flatten_config(curr: &Node) -> (usize, usize) {
-    // the begin dimension is the first dimension (Default: 1 per ONNX spec)
-    let mut start_dim: i64 = 1;
+pub fn flatten_config(curr: &Node) -> usize {
+    // the dimensions before the axis are collapsed into the rows (Default: 1 per ONNX spec)
+    let mut axis: i64 = 1;
 
     // check if the node has only one input
     if curr.inputs.len() != 1 {
@@ -130,23 +141,27 @@ pub fn flatten_config(curr: &Node) -> (usize, usize) {
         );
     }
 
-    // the end dimension is the last dimension
-    let end_dim = tensor.dim - 1;
-
     // extract the attributes
     for (key, value) in curr.attrs.iter() {
         match key.as_str() {
-            "axis" => attr_value_i64(value, &mut start_dim),
+            "axis" => attr_value_i64(value, &mut axis),
             _ => {}
         }
     }
 
-    // if beg_dim is negative, it is counted from the end
-    if start_dim < 0 {
-        start_dim += tensor.dim as i64;
+    // if the axis is negative, it is counted from the end
+    if axis < 0 {
+        axis += tensor.dim as i64;
     }
 
-    (start_dim as usize, end_dim)
+    if axis < 0 || axis > tensor.dim as i64 {
+        panic!(
+            "Flatten: axis must be in the range [-{rank}, {rank}] (got {axis})",
+            rank = tensor.dim
+        );
+    }
+
+    axis as usize
 }
 
 /// Create a LinearConfig from the attributes of the node
@@ -182,15 +197,218 @@ pub fn linear_config(node: &Node) -> LinearConfig {
     LinearConfig::new(in_size, out_size).with_bias(bias)
 }
 
+/// Create a GemmConfig from the attributes of the node
+pub fn gemm_config(node: &Node) -> GemmConfig {
+    // Default values per ONNX spec
+    let mut alpha: f32 = 1.0;
+    let mut beta: f32 = 1.0;
+    let mut trans_a: i64 = 0;
+    let mut trans_b: i64 = 0;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "alpha" => attr_value_f32(value, &mut alpha),
+            "beta" => attr_value_f32(value, &mut beta),
+            "transA" => attr_value_i64(value, &mut trans_a),
+            "transB" => attr_value_i64(value, &mut trans_b),
+            _ => {}
+        }
+    }
+
+    GemmConfig::new(alpha, beta, trans_a != 0, trans_b != 0)
+}
+
+/// Create a Clip config from the attributes or the constant inputs of the node, returning the
+/// optional `(min, max)` bounds
+pub fn clip_config(node: &Node) -> (Option<f64>, Option<f64>) {
+    let mut min = None;
+    let mut max = None;
+
+    // The bounds are attributes before opset 11, and constant inputs moved to the attributes
+    // while parsing the graph afterwards
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "min" => min = attr_value_f64(value),
+            "max" => max = attr_value_f64(value),
+            _ => {}
+        }
+    }
+
+    (min, max)
+}
+
+/// Read the axes of the node, given as an attribute before opset 13 and as an input afterwards,
+/// moved to the states when it is an initializer or the output of a Constant node
+fn axes_config(node: &Node) -> Vec<i64> {
+    let mut axes: Vec<i64> = Vec::new();
+
+    if let Some(value) = node.attrs.get("axes") {
+        attr_value_vec_i64(value, &mut axes);
+    }
+
+    if node.inputs.len() > 1 {
+        panic!(
+            "{}: axes computed at runtime are unsupported, they must be an initializer or the \
+             output of a Constant node",
+            node.node_type
+        );
+    }
+
+    if let Some(state) = node.states.get(0) {
+        let StateType::Tensor(state) = &state.ty;
+        match state.data.as_ref() {
+            Some(TensorData::Int64(values)) => axes = values.clone(),
+            _ => panic!("{}: axes must be int64 values", node.node_type),
+        }
+    }
+
+    axes
+}
+
+/// Normalize the axes against the given rank, counting negative axes from the end, and sort them
+fn normalize_axes(node: &Node, axes: Vec<i64>, rank: usize) -> Vec<usize> {
+    let mut axes: Vec<usize> = axes
+        .into_iter()
+        .map(|axis| {
+            let axis = if axis < 0 { axis + rank as i64 } else { axis };
+
+            if axis < 0 || axis >= rank as i64 {
+                panic!(
+                    "{}: axis must be in the range [-{}, {}) (got {:?})",
+                    node.node_type, rank, rank, axis
+                );
+            }
+
+            axis as usize
+        })
+        .collect();
+
+    axes.sort();
+    axes.dedup();
+
+    axes
+}
+
+/// Create a reduce config (ReduceMean, ReduceSum) from the attributes or the constant axes input
+/// of the node, returning the normalized `(axes, keepdims)`
+pub fn reduce_config(node: &Node) -> (Vec<usize>, bool) {
+    let mut keepdims: i64 = 1;
+
+    let tensor = match node.inputs.get(0).unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("{}: only tensor input is valid", node.node_type),
+    };
+
+    if let Some(value) = node.attrs.get("keepdims") {
+        attr_value_i64(value, &mut keepdims);
+    }
+
+    // An empty list of axes reduces all of them
+    let mut axes = axes_config(node);
+    if axes.is_empty() {
+        axes = (0..tensor.dim as i64).collect();
+    }
+
+    (normalize_axes(node, axes, tensor.dim), keepdims != 0)
+}
+
+/// Create a squeeze config from the attributes or the constant axes input of the node, returning
+/// the normalized axes of the input to remove
+pub fn squeeze_config(node: &Node) -> Vec<usize> {
+    let tensor = match node.inputs.get(0).unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("Squeeze: only tensor input is valid"),
+    };
+
+    let axes = axes_config(node);
+
+    // Without axes, every dimension of size one is removed, which requires static shapes
+    if axes.is_empty() {
+        panic!("Squeeze: the axes must be provided");
+    }
+
+    normalize_axes(node, axes, tensor.dim)
+}
+
+/// Create an unsqueeze config from the attributes or the constant axes input of the node,
+/// returning the normalized axes of the output to insert
+pub fn unsqueeze_config(node: &Node) -> Vec<usize> {
+    let tensor = match node.inputs.get(0).unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("Unsqueeze: only tensor input is valid"),
+    };
+
+    let axes = axes_config(node);
+
+    if axes.is_empty() {
+        panic!("Unsqueeze: the axes must be provided");
+    }
+
+    // The axes refer to the dimensions of the output
+    let rank = tensor.dim + axes.len();
+    normalize_axes(node, axes, rank)
+}
+
+/// Create a cast config from the `to` attribute of the node, returning the target tensor kind
+pub fn cast_config(node: &Node) -> TensorKind {
+    let mut to: i64 = DataType::UNDEFINED as i64;
+
+    if let Some(value) = node.attrs.get("to") {
+        attr_value_i64(value, &mut to);
+    }
+
+    match DataType::from_i32(to as i32) {
+        Some(DataType::FLOAT)
+        | Some(DataType::FLOAT16)
+        | Some(DataType::BFLOAT16)
+        | Some(DataType::DOUBLE) => TensorKind::Float,
+        Some(DataType::UINT8)
+        | Some(DataType::INT8)
+        | Some(DataType::UINT16)
+        | Some(DataType::INT16)
+        | Some(DataType::INT32)
+        | Some(DataType::INT64)
+        | Some(DataType::UINT32)
+        | Some(DataType::UINT64) => TensorKind::Int,
+        Some(DataType::BOOL) => TensorKind::Bool,
+        _ => panic!("Cast: unsupported target type {to}"),
+    }
+}
+
+/// Check the `approximate` attribute of a Gelu node, only the exact form (`"none"`, the default)
+/// being supported
+pub fn gelu_config(node: &Node) {
+    if let Some(value) = node.attrs.get("approximate") {
+        match value {
+            AttributeValue::String(approximate) if approximate == "none" => {}
+            AttributeValue::String(approximate) => {
+                panic!("Gelu: approximate = {approximate:?} is not supported")
+            }
+            _ => panic!("Gelu: approximate must be a string"),
+        }
+    }
+}
+
 /// Create log_softmax config from the attributes of the node
 pub fn log_softmax_config(node: &Node) -> usize {
-    // the axis is the last dimension (Default: 1 per ONNX spec)
+    softmax_axis_config(node)
+}
+
+/// Create softmax config from the attributes of the node
+pub fn softmax_config(node: &Node) -> usize {
+    softmax_axis_config(node)
+}
+
+/// Read the normalized axis of a softmax-like node (Softmax, LogSoftmax)
+fn softmax_axis_config(node: &Node) -> usize {
+    // the axis defaults to the last dimension (-1 per ONNX spec since opset 13)
     let mut axis: i64 = -1;
 
     // check if the node has only one input
     if node.inputs.len() != 1 {
         panic!(
-            "LogSoftmax: multiple inputs are not supported (got {:?})",
+            "{}: multiple inputs are not supported (got {:?})",
+            node.node_type,
             node.inputs.len()
         );
     }
@@ -217,6 +435,81 @@ pub fn log_softmax_config(node: &Node) -> usize {
     axis as usize
 }
 
+/// Create concat config from the attributes of the node
+pub fn concat_config(node: &Node) -> usize {
+    // the axis is required by the ONNX spec
+    let mut axis: Option<i64> = None;
+
+    // extract the rank of the first input tensor
+    let tensor = match node.inputs.get(0).unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("Only tensor input is valid"),
+    };
+
+    // extract the attributes
+    for (key, value) in node.attrs.iter() {
+        if key.as_str() == "axis" {
+            let mut value_i64 = 0;
+            attr_value_i64(value, &mut value_i64);
+            axis = Some(value_i64);
+        }
+    }
+
+    let mut axis = axis.expect("Concat: the axis attribute is required");
+
+    // if axis is negative, it is counted from the end
+    if axis < 0 {
+        axis += tensor.dim as i64;
+    }
+
+    axis as usize
+}
+
+/// Create reshape config from the shape initializer or the attributes of the node, returns
+/// `None` when the shape is only known at runtime
+pub fn reshape_config(node: &Node) -> Option<Vec<i64>> {
+    // the shape is usually provided as a constant initializer, moved to the node states
+    if let Some(state) = node.states.get(0) {
+        let StateType::Tensor(tensor) = &state.ty;
+
+        return match &tensor.data {
+            Some(TensorData::Int64(shape)) => Some(shape.clone()),
+            _ => panic!("Reshape: the shape should be a constant int64 tensor"),
+        };
+    }
+
+    // older opsets provide the shape as an attribute
+    match node.attrs.get("shape") {
+        Some(AttributeValue::Int64s(shape)) => Some(shape.clone()),
+        _ => None,
+    }
+}
+
+/// Create transpose config from the attributes of the node
+pub fn transpose_config(node: &Node) -> Vec<usize> {
+    // extract the rank of the input tensor
+    let tensor = match node.inputs.get(0).unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("Only tensor input is valid"),
+    };
+
+    // the dimensions are reversed by default
+    let mut perm: Vec<i64> = (0..tensor.dim as i64).rev().collect();
+
+    if let Some(value) = node.attrs.get("perm") {
+        attr_value_vec_i64(value, &mut perm);
+    }
+
+    if perm.len() != tensor.dim {
+        panic!(
+            "Transpose: perm should have one entry per input dimension (got {:?} for rank {})",
+            perm, tensor.dim
+        );
+    }
+
+    perm.into_iter().map(|dim| dim as usize).collect()
+}
+
 /// Create a BatchNormConfig from the attributes of the node
 pub fn batch_norm_config(node: &Node) -> BatchNormConfig {
     // extract the shape of the weight tensor
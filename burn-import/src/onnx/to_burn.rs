@@ -15,26 +15,50 @@ use crate::{
         node::{
             add::AddNode,
             batch_norm::BatchNormNode,
+            cast::CastNode,
+            clip::ClipNode,
+            concat::ConcatNode,
             constant::{ConstantNode, ConstantValue},
             conv2d::Conv2dNode,
+            div::DivNode,
+            dropout::DropoutNode,
             equal::EqualNode,
+            exp::ExpNode,
             flatten::FlattenNode,
+            gelu::GeluNode,
+            gemm::GemmNode,
+            global_avg_pool2d::GlobalAvgPool2dNode,
             linear::LinearNode,
+            log::LogNode,
             log_softmax::LogSoftmaxNode,
             matmul::MatmulNode,
             max_pool2d::MaxPool2dNode,
+            mul::MulNode,
+            pow::PowNode,
+            reduce_mean::ReduceMeanNode,
+            reduce_sum::ReduceSumNode,
             relu::ReLUNode,
+            reshape::ReshapeNode,
             sigmoid::SigmoidNode,
+            softmax::SoftmaxNode,
+            sqrt::SqrtNode,
+            squeeze::SqueezeNode,
+            sub::SubNode,
+            tanh::TanhNode,
+            transpose::TransposeNode,
+            unsqueeze::UnsqueezeNode,
         },
-        TensorType,
+        BinaryInput, TensorKind, TensorType,
     },
     format_tokens,
     logger::init_log,
     onnx::{
         ir::{AttributeValue, Node, NodeType},
         op_configuration::{
-            batch_norm_config, conv2d_config, flatten_config, linear_config, log_softmax_config,
-            max_pool2d_config,
+            batch_norm_config, clip_config, concat_config, conv2d_config, flatten_config,
+            gelu_config, gemm_config, linear_config, log_softmax_config, max_pool2d_config,
+            reduce_config, reshape_config, softmax_config, squeeze_config, transpose_config,
+            unsqueeze_config,
         },
     },
 };
@@ -44,6 +68,30 @@ use super::{
     ir::{ArgType, Argument, ONNXGraph, State, StateType, Tensor, TensorData},
 };
 
+/// Error type for converting an ONNX graph to a Burn graph.
+#[derive(Debug)]
+pub enum ConversionError {
+    /// The graph contains node types without a Burn conversion.
+    UnsupportedNodes(Vec<NodeType>),
+}
+
+impl core::fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnsupportedNodes(node_types) => {
+                let names = node_types
+                    .iter()
+                    .map(|node_type| node_type.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, "Unsupported node conversion: {}", names)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
 /// Generate code and states from `.onnx` files and save them to the `out_dir`.
 #[derive(Debug, Default)]
 pub struct ModelGen {
@@ -149,8 +197,20 @@ impl ModelGen {
 
 impl ONNXGraph {
     /// Converts ONNX graph to Burn graph.
+    ///
+    /// # Panics
+    ///
+    /// If the graph contains nodes that can't be converted, see [try_into_burn](Self::try_into_burn).
     pub fn into_burn<PS: PrecisionSettings + 'static>(self) -> BurnGraph<PS> {
+        self.try_into_burn().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Converts ONNX graph to Burn graph, returning every unsupported node type at once.
+    pub fn try_into_burn<PS: PrecisionSettings + 'static>(
+        self,
+    ) -> Result<BurnGraph<PS>, ConversionError> {
         let mut graph = BurnGraph::<PS>::default();
+        let mut unsupported = Vec::new();
 
         for node in self.nodes {
             match node.node_type {
@@ -158,21 +218,53 @@ impl ONNXGraph {
                 NodeType::Conv2d => graph.register(Self::conv2d_conversion::<PS>(node)),
                 NodeType::MaxPool2d => graph.register(Self::max_pool2d_conversion(node)),
                 NodeType::MatMul => graph.register(Self::matmul_conversion(node)),
+                NodeType::Gemm => graph.register(Self::gemm_conversion(node)),
+                NodeType::Mul => graph.register(Self::mul_conversion(node)),
+                NodeType::Sub => graph.register(Self::sub_conversion(node)),
+                NodeType::Div => graph.register(Self::div_conversion(node)),
+                NodeType::Concat => graph.register(Self::concat_conversion(node)),
+                NodeType::Reshape => graph.register(Self::reshape_conversion(node)),
+                NodeType::Transpose => graph.register(Self::transpose_conversion(node)),
+                NodeType::Dropout => graph.register(Self::dropout_conversion(node)),
+                NodeType::GlobalAveragePool => {
+                    graph.register(Self::global_avg_pool2d_conversion(node))
+                }
                 NodeType::Linear => graph.register(Self::linear_conversion::<PS>(node)),
                 NodeType::BatchNormalization => {
                     graph.register(Self::batch_norm_conversion::<PS>(node))
                 }
                 NodeType::Relu => graph.register(Self::relu_conversion(node)),
+                NodeType::Clip => graph.register(Self::clip_conversion(node)),
                 NodeType::Flatten => graph.register(Self::flatten_conversion(node)),
                 NodeType::LogSoftmax => graph.register(Self::log_softmax_conversion(node)),
+                NodeType::Softmax => graph.register(Self::softmax_conversion(node)),
                 NodeType::Constant => graph.register(Self::constant_conversion(node)),
                 NodeType::Equal => graph.register(Self::equal_conversion(node)),
                 NodeType::Sigmoid => graph.register(Self::sigmoid_conversion(node)),
-                _ => panic!("Unsupported node conversion {}", node.node_type),
+                NodeType::Tanh => graph.register(Self::tanh_conversion(node)),
+                NodeType::Gelu => graph.register(Self::gelu_conversion(node)),
+                NodeType::Exp => graph.register(Self::exp_conversion(node)),
+                NodeType::Log => graph.register(Self::log_conversion(node)),
+                NodeType::Sqrt => graph.register(Self::sqrt_conversion(node)),
+                NodeType::Pow => graph.register(Self::pow_conversion(node)),
+                NodeType::ReduceMean => graph.register(Self::reduce_mean_conversion(node)),
+                NodeType::ReduceSum => graph.register(Self::reduce_sum_conversion(node)),
+                NodeType::Squeeze => graph.register(Self::squeeze_conversion(node)),
+                NodeType::Unsqueeze => graph.register(Self::unsqueeze_conversion(node)),
+                NodeType::Cast => graph.register(Self::cast_conversion(node)),
+                node_type => {
+                    if !unsupported.contains(&node_type) {
+                        unsupported.push(node_type);
+                    }
+                }
             }
         }
 
-        graph
+        if !unsupported.is_empty() {
+            return Err(ConversionError::UnsupportedNodes(unsupported));
+        }
+
+        Ok(graph)
     }
 
     fn constant_conversion(mut node: Node) -> ConstantNode {
@@ -198,6 +290,27 @@ impl ONNXGraph {
         AddNode::new(lhs, rhs, output)
     }
 
+    fn mul_conversion(node: Node) -> MulNode {
+        let (lhs, rhs) = binary_inputs(&node);
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+
+        MulNode::new(lhs, rhs, output)
+    }
+
+    fn sub_conversion(node: Node) -> SubNode {
+        let (lhs, rhs) = binary_inputs(&node);
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+
+        SubNode::new(lhs, rhs, output)
+    }
+
+    fn div_conversion(node: Node) -> DivNode {
+        let (lhs, rhs) = binary_inputs(&node);
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+
+        DivNode::new(lhs, rhs, output)
+    }
+
     fn matmul_conversion(node: Node) -> MatmulNode {
         let lhs = node.inputs.get(0).unwrap().to_tensor_type();
         let rhs = node.inputs.get(1).unwrap().to_tensor_type();
@@ -206,6 +319,16 @@ impl ONNXGraph {
         MatmulNode::new(lhs, rhs, output)
     }
 
+    fn gemm_conversion(node: Node) -> GemmNode {
+        let lhs = node.inputs.get(0).unwrap().to_tensor_type();
+        let rhs = node.inputs.get(1).unwrap().to_tensor_type();
+        let bias = node.inputs.get(2).map(|input| input.to_tensor_type());
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+        let config = gemm_config(&node);
+
+        GemmNode::new(lhs, rhs, bias, output, config)
+    }
+
     fn equal_conversion(node: Node) -> EqualNode {
         let lhs = node.inputs.get(0).unwrap().to_tensor_type();
         let rhs = node.inputs.get(1).unwrap().to_tensor_type();
@@ -221,12 +344,20 @@ impl ONNXGraph {
         ReLUNode::new(input, output)
     }
 
+    fn clip_conversion(node: Node) -> ClipNode {
+        let input = node.inputs.get(0).unwrap().to_tensor_type();
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+        let (min, max) = clip_config(&node);
+
+        ClipNode::new(input, output, min, max)
+    }
+
     fn flatten_conversion(node: Node) -> FlattenNode {
         let input = node.inputs.get(0).unwrap().to_tensor_type();
         let output = node.outputs.get(0).unwrap().to_tensor_type();
-        let (start_dim, end_dim) = flatten_config(&node);
+        let axis = flatten_config(&node);
 
-        FlattenNode::new(input, output, start_dim, end_dim)
+        FlattenNode::new(input, output, axis)
     }
 
     fn sigmoid_conversion(node: Node) -> SigmoidNode {
@@ -236,6 +367,114 @@ impl ONNXGraph {
         SigmoidNode::new(input, output)
     }
 
+    fn tanh_conversion(node: Node) -> TanhNode {
+        let input = node.inputs.get(0).unwrap().to_tensor_type();
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+
+        TanhNode::new(input, output)
+    }
+
+    fn gelu_conversion(node: Node) -> GeluNode {
+        let input = node.inputs.get(0).unwrap().to_tensor_type();
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+        gelu_config(&node);
+
+        GeluNode::new(input, output)
+    }
+
+    fn exp_conversion(node: Node) -> ExpNode {
+        let input = node.inputs.get(0).unwrap().to_tensor_type();
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+
+        ExpNode::new(input, output)
+    }
+
+    fn log_conversion(node: Node) -> LogNode {
+        let input = node.inputs.get(0).unwrap().to_tensor_type();
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+
+        LogNode::new(input, output)
+    }
+
+    fn sqrt_conversion(node: Node) -> SqrtNode {
+        let input = node.inputs.get(0).unwrap().to_tensor_type();
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+
+        SqrtNode::new(input, output)
+    }
+
+    fn pow_conversion(mut node: Node) -> PowNode {
+        let lhs = node.inputs.get(0).unwrap().to_tensor_type();
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+
+        // Single element exponents are lifted to constants before the other initializers are
+        // moved into the states, so a remaining state is a tensor exponent.
+        match node.states.is_empty() {
+            true => {
+                let rhs = node.inputs.get(1).unwrap().to_binary_input();
+
+                PowNode::new(lhs, rhs, output)
+            }
+            false => {
+                let state = node.states.remove(0);
+                let name = state.name.clone();
+
+                PowNode::new_with_constant_exponent(
+                    lhs,
+                    &name,
+                    state.into_data_serialize::<f32>(),
+                    output,
+                )
+            }
+        }
+    }
+
+    fn reduce_mean_conversion(node: Node) -> ReduceMeanNode {
+        let input = node.inputs.get(0).unwrap().to_tensor_type();
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+        let (axes, keepdims) = reduce_config(&node);
+
+        ReduceMeanNode::new(input, output, axes, keepdims)
+    }
+
+    fn reduce_sum_conversion(node: Node) -> ReduceSumNode {
+        let input = node.inputs.get(0).unwrap().to_tensor_type();
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+        let (axes, keepdims) = reduce_config(&node);
+
+        ReduceSumNode::new(input, output, axes, keepdims)
+    }
+
+    fn squeeze_conversion(node: Node) -> SqueezeNode {
+        let input = node.inputs.get(0).unwrap().to_tensor_type();
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+        let axes = squeeze_config(&node);
+
+        SqueezeNode::new(input, output, axes)
+    }
+
+    fn unsqueeze_conversion(node: Node) -> UnsqueezeNode {
+        let input = node.inputs.get(0).unwrap().to_tensor_type();
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+        let axes = unsqueeze_config(&node);
+
+        UnsqueezeNode::new(input, output, axes)
+    }
+
+    fn cast_conversion(node: Node) -> CastNode {
+        // The kind of the output is set to the target type during the dim inference
+        let input = node.inputs.get(0).unwrap().to_tensor_type();
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+
+        match (&input.kind, &output.kind) {
+            (TensorKind::Bool, TensorKind::Bool) => {}
+            (kind, TensorKind::Bool) => panic!("Cast: casting {kind:?} to bool is not supported"),
+            _ => {}
+        }
+
+        CastNode::new(input, output)
+    }
+
     fn log_softmax_conversion(node: Node) -> LogSoftmaxNode {
         let input = node.inputs.get(0).unwrap().to_tensor_type();
         let output = node.outputs.get(0).unwrap().to_tensor_type();
@@ -244,6 +483,56 @@ impl ONNXGraph {
         LogSoftmaxNode::new(input, output, dim)
     }
 
+    fn softmax_conversion(node: Node) -> SoftmaxNode {
+        let input = node.inputs.get(0).unwrap().to_tensor_type();
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+        let dim = softmax_config(&node);
+
+        SoftmaxNode::new(input, output, dim)
+    }
+
+    fn concat_conversion(node: Node) -> ConcatNode {
+        let inputs = node
+            .inputs
+            .iter()
+            .map(|input| input.to_tensor_type())
+            .collect();
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+        let dim = concat_config(&node);
+
+        ConcatNode::new(inputs, output, dim)
+    }
+
+    fn reshape_conversion(node: Node) -> ReshapeNode {
+        let input = node.inputs.get(0).unwrap().to_tensor_type();
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+        let shape = reshape_config(&node).expect("Reshape: only a static shape is supported");
+
+        ReshapeNode::new(input, output, shape)
+    }
+
+    fn transpose_conversion(node: Node) -> TransposeNode {
+        let input = node.inputs.get(0).unwrap().to_tensor_type();
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+        let perm = transpose_config(&node);
+
+        TransposeNode::new(input, output, perm)
+    }
+
+    fn global_avg_pool2d_conversion(node: Node) -> GlobalAvgPool2dNode {
+        let input = node.inputs.get(0).unwrap().to_tensor_type();
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+
+        GlobalAvgPool2dNode::new(input, output)
+    }
+
+    fn dropout_conversion(node: Node) -> DropoutNode {
+        let input = node.inputs.get(0).unwrap().to_tensor_type();
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+
+        DropoutNode::new(input, output)
+    }
+
     fn linear_conversion<PS: PrecisionSettings>(mut node: Node) -> LinearNode<PS> {
         let name = &node.name;
         let input = node.inputs.get(0).unwrap().to_tensor_type();
@@ -325,6 +614,26 @@ fn extract_next_data_serialize<E: Element>(node: &mut Node) -> Option<DataSerial
     Some(node.states.remove(0).into_data_serialize::<E>())
 }
 
+/// Gets both operands of an element-wise binary operation.
+///
+/// # Panics
+///
+/// If an operand is a tensor initializer, since only the single element ones are lifted to
+/// scalars when parsing the graph.
+fn binary_inputs(node: &Node) -> (BinaryInput, BinaryInput) {
+    if !node.states.is_empty() {
+        panic!(
+            "{}: operands stored as tensor initializers are unsupported, only single element ones are",
+            node.node_type
+        );
+    }
+
+    let lhs = node.inputs.get(0).unwrap().to_binary_input();
+    let rhs = node.inputs.get(1).unwrap().to_binary_input();
+
+    (lhs, rhs)
+}
+
 impl State {
     pub fn into_data_serialize<E: Element>(self) -> DataSerialize<E> {
         match self.ty {
@@ -336,11 +645,21 @@ impl State {
 impl Argument {
     pub fn to_tensor_type(&self) -> TensorType {
         match &self.ty {
-            ArgType::Tensor(tensor) => TensorType::new_float(self.name.clone(), tensor.dim),
+            ArgType::Tensor(tensor) => {
+                TensorType::new(self.name.clone(), tensor.dim, tensor.kind.clone())
+            }
             ArgType::Shape(_shape) => panic!("Can't transform shape to tensor."),
             ArgType::Constant => panic!("Can't transform constant to tensor."),
         }
     }
+
+    pub fn to_binary_input(&self) -> BinaryInput {
+        match &self.ty {
+            ArgType::Tensor(_) => BinaryInput::Tensor(self.to_tensor_type()),
+            ArgType::Shape(_shape) => panic!("Can't use shape as a binary operation input."),
+            ArgType::Constant => BinaryInput::new_scalar(&self.name),
+        }
+    }
 }
 
 impl Tensor {
@@ -358,3 +677,190 @@ impl Tensor {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::onnx::{
+        dim_inference::dim_inference,
+        from_onnx::convert_node_proto,
+        ir::{ElementType, TensorArg},
+        protos::{tensor_proto::DataType, NodeProto},
+    };
+    use protobuf::Enum;
+
+    fn node(name: &str, node_type: NodeType, input: &str, output: &str) -> Node {
+        let arg = |name: &str| Argument {
+            name: name.to_string(),
+            ty: ArgType::Tensor(TensorArg::new(2)),
+        };
+
+        Node {
+            node_type,
+            name: name.to_string(),
+            inputs: vec![arg(input)],
+            outputs: vec![arg(output)],
+            states: vec![],
+            attrs: Default::default(),
+        }
+    }
+
+    #[test]
+    fn try_into_burn_reports_every_unsupported_node_once() {
+        let graph = ONNXGraph {
+            nodes: vec![
+                node("abs1", NodeType::Abs, "input1", "abs1_out1"),
+                node("relu1", NodeType::Relu, "abs1_out1", "relu1_out1"),
+                node("abs2", NodeType::Abs, "relu1_out1", "abs2_out1"),
+                node("floor1", NodeType::Floor, "abs2_out1", "floor1_out1"),
+            ],
+            inputs: vec![],
+            outputs: vec![],
+            states: vec![],
+            old_node_names: Default::default(),
+            old_input_names: Default::default(),
+        };
+
+        let err = graph
+            .try_into_burn::<FullPrecisionSettings>()
+            .expect_err("Abs and Floor should be unsupported");
+
+        match &err {
+            ConversionError::UnsupportedNodes(node_types) => {
+                assert_eq!(node_types, &vec![NodeType::Abs, NodeType::Floor])
+            }
+        }
+        assert_eq!(err.to_string(), "Unsupported node conversion: Abs, Floor");
+    }
+
+    #[test]
+    fn try_into_burn_reports_unknown_operators() {
+        let mut proto = NodeProto::new();
+        proto.op_type = "MyCustomOp".to_string();
+        proto.input = vec!["input1".to_string()];
+        proto.output = vec!["custom_out1".to_string()];
+        let mut node = convert_node_proto(&proto);
+        node.name = "custom1".to_string();
+        let graph = ONNXGraph {
+            nodes: vec![node],
+            inputs: vec![],
+            outputs: vec![],
+            states: vec![],
+            old_node_names: Default::default(),
+            old_input_names: Default::default(),
+        };
+
+        let err = graph
+            .try_into_burn::<FullPrecisionSettings>()
+            .expect_err("MyCustomOp should be unsupported");
+
+        match &err {
+            ConversionError::UnsupportedNodes(node_types) => assert_eq!(
+                node_types,
+                &vec![NodeType::Unknown("MyCustomOp".to_string())]
+            ),
+        }
+        assert_eq!(err.to_string(), "Unsupported node conversion: MyCustomOp");
+    }
+
+    #[test]
+    #[should_panic(expected = "Mul: operands stored as tensor initializers are unsupported")]
+    fn binary_conversion_rejects_tensor_initializers() {
+        let mut node = node("mul1", NodeType::Mul, "input1", "mul1_out1");
+        node.states.push(State {
+            name: "weights".to_string(),
+            ty: StateType::Tensor(Tensor {
+                elem_type: ElementType::Float32,
+                dim: 1,
+                data: Some(TensorData::Float32(vec![1.0, 2.0])),
+                shape: Some(vec![2]),
+            }),
+        });
+
+        ONNXGraph::mul_conversion(node);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cast: casting Float to bool is not supported")]
+    fn cast_conversion_rejects_a_bool_target() {
+        let mut cast = node("cast1", NodeType::Cast, "input1", "cast1_out1");
+        cast.attrs.insert(
+            "to".to_string(),
+            AttributeValue::Int64(DataType::BOOL.value() as i64),
+        );
+        let mut nodes = vec![cast];
+        let inputs = vec![Argument {
+            name: "input1".to_string(),
+            ty: ArgType::Tensor(TensorArg::new(2)),
+        }];
+
+        dim_inference(&mut nodes, &inputs, &mut vec![]);
+
+        ONNXGraph::cast_conversion(nodes.remove(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Gelu: approximate = \"tanh\" is not supported")]
+    fn gelu_conversion_rejects_the_tanh_approximation() {
+        let mut gelu = node("gelu1", NodeType::Gelu, "input1", "gelu1_out1");
+        gelu.attrs.insert(
+            "approximate".to_string(),
+            AttributeValue::String("tanh".to_string()),
+        );
+
+        ONNXGraph::gelu_conversion(gelu);
+    }
+
+    #[test]
+    fn cast_output_is_consumed_with_the_target_kind() {
+        let mut cast = node("cast1", NodeType::Cast, "input1", "cast1_out1");
+        cast.attrs.insert(
+            "to".to_string(),
+            AttributeValue::Int64(DataType::INT64.value() as i64),
+        );
+        let mut unsqueeze = node(
+            "unsqueeze1",
+            NodeType::Unsqueeze,
+            "cast1_out1",
+            "unsqueeze1_out1",
+        );
+        unsqueeze
+            .attrs
+            .insert("axes".to_string(), AttributeValue::Int64s(vec![0]));
+        let mut nodes = vec![cast, unsqueeze];
+        let inputs = vec![Argument {
+            name: "input1".to_string(),
+            ty: ArgType::Tensor(TensorArg::new(2)),
+        }];
+
+        dim_inference(&mut nodes, &inputs, &mut vec![]);
+
+        let cast = ONNXGraph::cast_conversion(nodes[0].clone());
+        assert!(matches!(cast.input.kind, TensorKind::Float));
+        assert!(matches!(cast.output.kind, TensorKind::Int));
+        let unsqueeze = ONNXGraph::unsqueeze_conversion(nodes[1].clone());
+        assert!(matches!(unsqueeze.input.kind, TensorKind::Int));
+        assert!(matches!(unsqueeze.output.kind, TensorKind::Int));
+        assert_eq!(unsqueeze.output.dim, 3);
+    }
+
+    #[test]
+    fn pow_conversion_uses_a_tensor_exponent_initializer_as_constant() {
+        let mut node = node("pow1", NodeType::Pow, "input1", "pow1_out1");
+        node.states.push(State {
+            name: "exponent".to_string(),
+            ty: StateType::Tensor(Tensor {
+                elem_type: ElementType::Float32,
+                dim: 1,
+                data: Some(TensorData::Float32(vec![2.0, 3.0])),
+                shape: Some(vec![2]),
+            }),
+        });
+
+        let pow = ONNXGraph::pow_conversion(node);
+
+        let data = pow.rhs_data.expect("The exponent to be a constant");
+        assert_eq!(data.value, vec![2.0, 3.0]);
+        assert_eq!(data.shape, vec![1, 2]);
+    }
+}
@@ -0,0 +1,49 @@
+use burn_ndarray::NdArrayBackend;
+use burn_tensor::{Distribution, Tensor};
+use std::time::{Duration, Instant};
+
+type B = NdArrayBackend<f32>;
+
+const NUM_SAMPLES: usize = 10;
+
+/// Run the operation on a tensor with the given number of rows and report the time per element,
+/// tensors with fewer than 32 * 1024 elements being computed serially.
+fn bench<F>(name: &str, num_rows: usize, func: F)
+where
+    F: Fn(Tensor<B, 2>),
+{
+    let num_cols = 1024;
+    let tensor = Tensor::<B, 2>::random([num_rows, num_cols], Distribution::Default);
+    let mut total = Duration::ZERO;
+
+    for _ in 0..NUM_SAMPLES {
+        let tensor = tensor.clone();
+        let start = Instant::now();
+        func(tensor);
+        total += start.elapsed();
+    }
+
+    let num_elems = num_rows * num_cols;
+    let mean = total / NUM_SAMPLES as u32;
+    println!(
+        "{name} [{num_rows}, {num_cols}]: {mean:?} ({:.3} ns/elem)",
+        mean.as_nanos() as f64 / num_elems as f64
+    );
+}
+
+fn main() {
+    for num_rows in [16, 4096] {
+        bench("exp", num_rows, |tensor| {
+            tensor.exp();
+        });
+        bench("relu", num_rows, |tensor| {
+            burn_tensor::activation::relu(tensor);
+        });
+        bench("argmax", num_rows, |tensor| {
+            tensor.argmax(1);
+        });
+        bench("max_dim", num_rows, |tensor| {
+            tensor.max_dim(0);
+        });
+    }
+}
@@ -3,7 +3,8 @@ use libm::{exp, log, log1p, pow, sqrt};
 use libm::{expf, log1pf, logf, powf, sqrtf};
 use ndarray::LinalgScalar;
 
-pub(crate) trait FloatNdArrayElement: NdArrayElement + LinalgScalar
+pub(crate) trait FloatNdArrayElement:
+    NdArrayElement + LinalgScalar + num_traits::Float
 where
     Self: Sized,
 {
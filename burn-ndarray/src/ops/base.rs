@@ -1,19 +1,24 @@
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 use burn_tensor::Data;
 use burn_tensor::ElementConversion;
 use core::cmp::Ordering;
 use core::{marker::PhantomData, ops::Range};
 use ndarray::s;
 use ndarray::Array2;
+use ndarray::ArrayD;
+use ndarray::ArrayView1;
 
 use burn_tensor::Shape;
 use ndarray::Axis;
 use ndarray::Dim;
 use ndarray::IxDyn;
 use ndarray::SliceInfoElem;
+use ndarray::Zip;
 
 use crate::element::NdArrayElement;
 use crate::ops::macros::{keepdim, mean_dim, sum_dim};
+#[cfg(feature = "rayon")]
+use crate::parallel::PARALLEL_THRESHOLD;
 use crate::{reshape, tensor::NdArrayTensor};
 
 pub struct NdArrayOps<E> {
@@ -76,6 +81,30 @@ where
         NdArrayTensor { array }
     }
 
+    pub fn repeat<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        times: usize,
+    ) -> NdArrayTensor<E, D> {
+        // Concatenating no array fails, the result being the input emptied along the dim instead.
+        if times == 0 {
+            let array = tensor
+                .array
+                .slice_axis(Axis(dim), (0..0).into())
+                .to_owned()
+                .into_shared();
+
+            return NdArrayTensor { array };
+        }
+
+        let arrays = vec![tensor.array.view(); times];
+        let array = ndarray::concatenate(Axis(dim), &arrays)
+            .unwrap()
+            .into_shared();
+
+        NdArrayTensor { array }
+    }
+
     fn to_slice_args<const D1: usize, const D2: usize>(
         ranges: [Range<usize>; D2],
     ) -> [SliceInfoElem; D1] {
@@ -293,6 +322,166 @@ where
         output
     }
 
+    pub fn maximum<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        Self::elementwise_op(lhs, rhs, |a, b| if b > a { *b } else { *a })
+    }
+
+    pub fn minimum<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        Self::elementwise_op(lhs, rhs, |a, b| if b < a { *b } else { *a })
+    }
+
+    pub fn max_dim_with_indices<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        Self::select_dim_with_indices(tensor, dim, |a, b| !is_nan(a) && (b > a || is_nan(b)))
+    }
+
+    /// Select the `k` largest elements of each lane along the dimension with their indices.
+    pub fn topk<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        k: usize,
+        dim: usize,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        Self::sort_lanes(tensor, k, dim, true)
+    }
+
+    /// Stable sort of each lane along the dimension, returning the indices of the sorted elements.
+    pub fn sort<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        let size = tensor.shape().dims[dim];
+
+        Self::sort_lanes(tensor, size, dim, descending)
+    }
+
+    /// Sort each lane along the dimension and keep its first `k` elements with their indices,
+    /// using a partial sort so only the kept elements get fully sorted.
+    fn sort_lanes<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        k: usize,
+        dim: usize,
+        descending: bool,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        let mut shape = tensor.shape();
+        shape.dims[dim] = k;
+
+        let mut values = ArrayD::from_elem(IxDyn(&shape.dims), 0.elem::<E>());
+        let mut indices = ArrayD::from_elem(IxDyn(&shape.dims), 0);
+
+        Zip::from(values.lanes_mut(Axis(dim)))
+            .and(indices.lanes_mut(Axis(dim)))
+            .and(tensor.array.lanes(Axis(dim)))
+            .for_each(|mut values, mut indices, lane| {
+                // Ties are ordered by increasing index, which makes the sort stable.
+                let cmp = |a: &usize, b: &usize| {
+                    let ordering = match descending {
+                        true => cmp_nan_greatest(&lane[*b], &lane[*a]),
+                        false => cmp_nan_greatest(&lane[*a], &lane[*b]),
+                    };
+
+                    ordering.then(a.cmp(b))
+                };
+
+                let mut selected = (0..lane.len()).collect::<Vec<_>>();
+                if k > 0 && k < selected.len() {
+                    selected.select_nth_unstable_by(k - 1, cmp);
+                }
+                selected.truncate(k);
+                selected.sort_unstable_by(cmp);
+
+                for (i, index) in selected.into_iter().enumerate() {
+                    values[i] = lane[index];
+                    indices[i] = index as i64;
+                }
+            });
+
+        (
+            NdArrayTensor::new(values.into_shared()),
+            NdArrayTensor::new(indices.into_shared()),
+        )
+    }
+
+    pub fn min_dim_with_indices<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        Self::select_dim_with_indices(tensor, dim, |a, b| !is_nan(a) && (b < a || is_nan(b)))
+    }
+
+    /// Select an element of each lane along the dimension with its index, the element being
+    /// replaced each time the predicate is true, so the first one is kept on ties.
+    fn select_dim_with_indices<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        select: fn(&E, &E) -> bool,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        let mut shape = tensor.shape();
+        shape.dims[dim] = 1;
+
+        let select_lane = |lane: ArrayView1<E>| {
+            let mut index = 0;
+            for (i, value) in lane.iter().enumerate().skip(1) {
+                if select(&lane[index], value) {
+                    index = i;
+                }
+            }
+
+            (lane[index], index as i64)
+        };
+        let lanes = tensor.array.lanes(Axis(dim));
+
+        #[cfg(feature = "rayon")]
+        let (values, indices): (Vec<E>, Vec<i64>) = if tensor.array.len() >= PARALLEL_THRESHOLD {
+            Zip::from(lanes)
+                .par_map_collect(select_lane)
+                .into_iter()
+                .unzip()
+        } else {
+            lanes.into_iter().map(select_lane).unzip()
+        };
+
+        #[cfg(not(feature = "rayon"))]
+        let (values, indices): (Vec<E>, Vec<i64>) = lanes.into_iter().map(select_lane).unzip();
+
+        (
+            NdArrayTensor::from_data(Data::new(values, shape.clone())),
+            NdArrayTensor::from_data(Data::new(indices, shape)),
+        )
+    }
+
+    /// Apply a binary function element-wise, broadcasting both tensors to their common shape.
+    pub fn elementwise_op<const D: usize, F>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+        func: F,
+    ) -> NdArrayTensor<E, D>
+    where
+        F: Fn(&E, &E) -> E,
+    {
+        let shape = lhs
+            .array
+            .shape()
+            .iter()
+            .zip(rhs.array.shape())
+            .map(|(a, b)| usize::max(*a, *b))
+            .collect::<Vec<_>>();
+        let lhs = lhs.array.broadcast(shape.clone()).unwrap();
+        let rhs = rhs.array.broadcast(shape).unwrap();
+
+        let array = Zip::from(&lhs).and(&rhs).map_collect(func).into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
     pub fn mask_where<const D: usize>(
         tensor: NdArrayTensor<E, D>,
         mask: NdArrayTensor<bool, D>,
@@ -345,6 +534,71 @@ where
         batch_size
     }
 
+    /// Scatter the values into the tensor, replacing the current element at each position when
+    /// `replace(current, candidate)` is true.
+    ///
+    /// Returns the output along with the index along `dim` in the value tensor of the element kept
+    /// at each position, or `-1` when the element of the original tensor is kept.
+    pub fn scatter_reduce_with_indices<const D: usize>(
+        dim: usize,
+        mut tensor: NdArrayTensor<E, D>,
+        mut indices: NdArrayTensor<i64, D>,
+        mut value: NdArrayTensor<E, D>,
+        replace: fn(&E, &E) -> bool,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        if dim != D - 1 {
+            tensor.array.swap_axes(D - 1, dim);
+            indices.array.swap_axes(D - 1, dim);
+            value.array.swap_axes(D - 1, dim);
+        }
+
+        let (shape_tensor, shape_indices, shape_value) =
+            (tensor.shape(), indices.shape(), value.shape());
+        let (size_tensor, size_index, size_value) = (
+            shape_tensor.dims[D - 1],
+            shape_indices.dims[D - 1],
+            shape_value.dims[D - 1],
+        );
+        let batch_size = Self::gather_batch_size(&shape_tensor, &shape_indices);
+
+        if shape_value != shape_indices {
+            panic!("Invalid dimension: the shape of the index tensor should be the same as the value tensor: Index {:?} value {:?}", shape_indices.dims, shape_value.dims);
+        }
+
+        let indices = NdArrayOps::reshape(indices, Shape::new([batch_size, size_index])).array;
+        let value = NdArrayOps::reshape(value, Shape::new([batch_size, size_value])).array;
+        let mut tensor = NdArrayOps::reshape(tensor, Shape::new([batch_size, size_tensor])).array;
+        let mut sources = Array2::from_elem((batch_size, size_tensor), -1);
+
+        for b in 0..batch_size {
+            let indices = indices.slice(s!(b, ..));
+
+            for (i, index) in indices.iter().enumerate() {
+                let index = *index as usize;
+                let candidate = value[[b, i]];
+
+                if replace(&tensor[[b, index]], &candidate) {
+                    tensor[[b, index]] = candidate;
+                    sources[[b, index]] = i as i64;
+                }
+            }
+        }
+
+        let mut output = NdArrayOps::reshape(
+            NdArrayTensor::<E, 2>::new(tensor.into_shared().into_dyn()),
+            shape_tensor.clone(),
+        );
+        let mut sources = NdArrayOps::reshape(
+            NdArrayTensor::<i64, 2>::new(sources.into_shared().into_dyn()),
+            shape_tensor,
+        );
+        if dim != D - 1 {
+            output.array.swap_axes(D - 1, dim);
+            sources.array.swap_axes(D - 1, dim);
+        }
+        (output, sources)
+    }
+
     pub fn select<const D: usize>(
         tensor: NdArrayTensor<E, D>,
         dim: usize,
@@ -379,90 +633,113 @@ where
 
         NdArrayTensor::new(output_array.into_shared())
     }
+
+    /// Index of the largest element of each lane along the dimension, the first one being kept on
+    /// ties. NaN is propagated like in PyTorch, so the index of the first NaN is returned.
     pub fn argmax<const D: usize>(
-        mut tensor: NdArrayTensor<E, D>,
+        tensor: NdArrayTensor<E, D>,
         dim: usize,
     ) -> NdArrayTensor<i64, D> {
-        if dim == D - 1 {
-            return arg(tensor, cmp_min);
-        }
-
-        tensor.array.swap_axes(dim, D - 1);
-        let mut tensor = arg(tensor, cmp_min);
-        tensor.array.swap_axes(dim, D - 1);
-
-        tensor
+        Self::max_dim_with_indices(tensor, dim).1
     }
 
+    /// Index of the smallest element of each lane along the dimension, the first one being kept
+    /// on ties. NaN is propagated like in PyTorch, so the index of the first NaN is returned.
     pub fn argmin<const D: usize>(
-        mut tensor: NdArrayTensor<E, D>,
+        tensor: NdArrayTensor<E, D>,
         dim: usize,
     ) -> NdArrayTensor<i64, D> {
-        if dim == D - 1 {
-            return arg(tensor, cmp_max);
-        }
+        Self::min_dim_with_indices(tensor, dim).1
+    }
+}
 
-        tensor.array.swap_axes(dim, D - 1);
-        let mut tensor = arg(tensor, cmp_max);
-        tensor.array.swap_axes(dim, D - 1);
+/// NaN is the only value that can't be compared to itself.
+fn is_nan<E: PartialOrd>(value: &E) -> bool {
+    value.partial_cmp(value).is_none()
+}
 
-        tensor
+/// Total order where NaN is greater than any other value, as with PyTorch.
+fn cmp_nan_greatest<E: PartialOrd>(lhs: &E, rhs: &E) -> Ordering {
+    match (is_nan(lhs), is_nan(rhs)) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => lhs.partial_cmp(rhs).unwrap(),
     }
 }
 
-fn arg<E: NdArrayElement, F, const D: usize>(
-    tensor: NdArrayTensor<E, D>,
-    cmp: F,
-) -> NdArrayTensor<i64, D>
-where
-    F: Fn(&f64, &f64) -> Ordering,
-{
-    let mut shape = tensor.shape();
-    let batch_size = shape.dims[D - 1];
-    let mut end = shape.dims[D - 1];
-
-    let mut values = tensor.array.into_iter().collect::<Vec<_>>();
-    let mut start = 0;
-    let mut output = Vec::new();
-
-    while end <= values.len() {
-        let data_dim = &mut values[start..end];
-        let mut sorted: Vec<f64> = data_dim.iter().map(|a| a.elem()).collect();
-        sorted.sort_by(&cmp);
-
-        let max = sorted[0];
-
-        let data_dim = &mut values[start..end];
-        let mut index: i64 = 0;
-        for elem in data_dim {
-            let as_float: f64 = elem.elem();
-            if as_float == max {
-                break;
-            }
-            index += 1;
-        }
-        output.push(index);
-        start += batch_size;
-        end += batch_size;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Ops = NdArrayMathOps<f32>;
+
+    #[test]
+    fn into_int_should_map_nan_to_zero_and_saturate() {
+        use crate::NdArrayBackend;
+        use burn_tensor::ops::TensorOps;
+
+        let tensor = NdArrayTensor::from_data(Data::from([f32::NAN, 1e30, -1e30, -2.5]));
+
+        let output = NdArrayBackend::<f32>::into_int(tensor);
+
+        assert_eq!(
+            output.array.into_iter().collect::<Vec<_>>(),
+            vec![0, i64::MAX, i64::MIN, -2]
+        );
     }
-    shape.dims[D - 1] = 1;
-    NdArrayTensor::from_data(Data::new(output, shape))
-}
 
-fn cmp_max(a: &f64, b: &f64) -> Ordering {
-    if a < b {
-        return Ordering::Less;
-    } else if a > b {
-        return Ordering::Greater;
+    #[test]
+    fn powf_tensor_should_keep_the_f64_exponent() {
+        use crate::NdArrayBackend;
+        use burn_tensor::ops::TensorOps;
+
+        let lhs = NdArrayTensor::from_data(Data::from([10.0]));
+        let rhs = NdArrayTensor::from_data(Data::from([0.1]));
+
+        let output = NdArrayBackend::<f64>::powf_tensor(lhs, rhs);
+
+        assert_eq!(
+            output.array.into_iter().collect::<Vec<_>>(),
+            vec![10f64.powf(0.1)]
+        );
     }
-    Ordering::Equal
-}
 
-fn cmp_min(a: &f64, b: &f64) -> Ordering {
-    if a > b {
-        return Ordering::Less;
-    } else if a < b {
-        return Ordering::Greater;
+    #[test]
+    fn argmax_should_return_the_first_index_on_ties() {
+        let tensor = NdArrayTensor::from_data(Data::from([[1.0, 3.0, 3.0, 2.0]]));
+
+        let indices = Ops::argmax(tensor, 1);
+
+        assert_eq!(indices.array.into_iter().collect::<Vec<_>>(), vec![1]);
+    }
+
+    #[test]
+    fn argmin_should_return_the_first_index_on_ties() {
+        let tensor = NdArrayTensor::from_data(Data::from([[2.0, 1.0, 3.0, 1.0]]));
+
+        let indices = Ops::argmin(tensor, 1);
+
+        assert_eq!(indices.array.into_iter().collect::<Vec<_>>(), vec![1]);
+    }
+
+    #[test]
+    fn argmax_and_argmin_should_propagate_nan() {
+        let data = Data::from([[1.0, f32::NAN, 3.0, f32::NAN], [-1.0, 0.0, 2.0, 1.0]]);
+
+        let argmax = Ops::argmax(NdArrayTensor::from_data(data.clone()), 1);
+        let argmin = Ops::argmin(NdArrayTensor::from_data(data), 1);
+
+        assert_eq!(argmax.array.into_iter().collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(argmin.array.into_iter().collect::<Vec<_>>(), vec![1, 0]);
+    }
+
+    #[test]
+    fn argmax_should_propagate_nan_along_the_first_dim() {
+        let data = Data::from([[1.0, 5.0], [f32::NAN, 2.0], [3.0, f32::NAN]]);
+
+        let argmax = Ops::argmax(NdArrayTensor::from_data(data), 0);
+
+        assert_eq!(argmax.array.into_iter().collect::<Vec<_>>(), vec![1, 2]);
     }
-    Ordering::Equal
 }
@@ -100,6 +100,14 @@ impl<E: FloatNdArrayElement> BoolTensorOps<NdArrayBackend<E>> for NdArrayBackend
         NdArrayOps::cat(tensors, dim)
     }
 
+    fn bool_repeat<const D: usize>(
+        tensor: <NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D>,
+        dim: usize,
+        times: usize,
+    ) -> <NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D> {
+        NdArrayOps::repeat(tensor, dim, times)
+    }
+
     fn bool_equal<const D: usize>(
         lhs: <NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D>,
         rhs: <NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D>,
@@ -0,0 +1,205 @@
+use crate::{
+    element::FloatNdArrayElement, iter_par, run_par, sharing::UnsafeSharedRef,
+    tensor::NdArrayTensor,
+};
+
+use alloc::vec::Vec;
+use burn_tensor::{
+    ops::{GridSampleMode, GridSamplePadding},
+    ElementConversion,
+};
+use ndarray::Array4;
+
+pub(crate) fn grid_sample<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 4>,
+    grid: NdArrayTensor<E, 4>,
+    mode: GridSampleMode,
+    padding: GridSamplePadding,
+) -> NdArrayTensor<E, 4> {
+    let [batch_size, channels, height, width] = x.shape().dims;
+    let [_, height_out, width_out, _] = grid.shape().dims;
+
+    let x = x.array;
+    let grid = grid.array;
+
+    let mut output = Array4::from_elem((batch_size, channels, height_out, width_out), 0.elem());
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_par!(0, batch_size * channels).for_each(|k| unsafe {
+            let b = k / channels;
+            let c = k % channels;
+
+            let output = unsafe_shared_out.get();
+
+            for h in 0..height_out {
+                for w in 0..width_out {
+                    let sample = Sample::new(
+                        grid[[b, h, w, 0]].elem(),
+                        grid[[b, h, w, 1]].elem(),
+                        [height, width],
+                        padding,
+                    );
+
+                    let mut value = 0.0;
+                    for corner in sample.corners(mode) {
+                        value += x[[b, c, corner.y, corner.x]].elem::<f64>() * corner.weight;
+                    }
+
+                    output[[b, c, h, w]] = value.elem();
+                }
+            }
+        })
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
+
+pub(crate) fn grid_sample_backward<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 4>,
+    grid: NdArrayTensor<E, 4>,
+    output_grad: NdArrayTensor<E, 4>,
+    mode: GridSampleMode,
+    padding: GridSamplePadding,
+) -> (NdArrayTensor<E, 4>, NdArrayTensor<E, 4>) {
+    let [batch_size, channels, height, width] = x.shape().dims;
+    let [_, height_out, width_out, _] = grid.shape().dims;
+
+    let x = x.array;
+    let grid = grid.array;
+    let output_grad = output_grad.array;
+
+    let mut x_grad = Array4::from_elem((batch_size, channels, height, width), 0.elem());
+    let mut grid_grad = Array4::from_elem((batch_size, height_out, width_out, 2), 0.elem());
+    let unsafe_shared_x_grad = UnsafeSharedRef::new(&mut x_grad);
+    let unsafe_shared_grid_grad = UnsafeSharedRef::new(&mut grid_grad);
+
+    // The samples of different batches don't share any input pixel or grid coordinate.
+    run_par!(|| {
+        iter_par!(0, batch_size).for_each(|b| unsafe {
+            let x_grad = unsafe_shared_x_grad.get();
+            let grid_grad = unsafe_shared_grid_grad.get();
+
+            for h in 0..height_out {
+                for w in 0..width_out {
+                    let sample = Sample::new(
+                        grid[[b, h, w, 0]].elem(),
+                        grid[[b, h, w, 1]].elem(),
+                        [height, width],
+                        padding,
+                    );
+                    let (mut grad_x, mut grad_y) = (0.0, 0.0);
+
+                    for corner in sample.corners(mode) {
+                        for c in 0..channels {
+                            let grad = output_grad[[b, c, h, w]].elem::<f64>();
+                            let value = x[[b, c, corner.y, corner.x]].elem::<f64>();
+
+                            x_grad[[b, c, corner.y, corner.x]] += (grad * corner.weight).elem();
+                            grad_x += grad * value * corner.weight_grad_x;
+                            grad_y += grad * value * corner.weight_grad_y;
+                        }
+                    }
+
+                    grid_grad[[b, h, w, 0]] = (grad_x * sample.scale_x).elem();
+                    grid_grad[[b, h, w, 1]] = (grad_y * sample.scale_y).elem();
+                }
+            }
+        })
+    });
+
+    (
+        NdArrayTensor::new(x_grad.into_dyn().into_shared()),
+        NdArrayTensor::new(grid_grad.into_dyn().into_shared()),
+    )
+}
+
+/// Pixel coordinates of a sample, with their gradient with respect to the grid coordinates.
+struct Sample {
+    x: f64,
+    y: f64,
+    scale_x: f64,
+    scale_y: f64,
+    height: usize,
+    width: usize,
+}
+
+/// Input pixel used by a sample, with its interpolation weight.
+struct Corner {
+    x: usize,
+    y: usize,
+    weight: f64,
+    weight_grad_x: f64,
+    weight_grad_y: f64,
+}
+
+impl Sample {
+    fn new(x: f64, y: f64, [height, width]: [usize; 2], padding: GridSamplePadding) -> Self {
+        let (x, scale_x) = unnormalize(x, width, padding);
+        let (y, scale_y) = unnormalize(y, height, padding);
+
+        Self {
+            x,
+            y,
+            scale_x,
+            scale_y,
+            height,
+            width,
+        }
+    }
+
+    /// The input pixels used by the sample, the pixels outside of the input being zeros and
+    /// therefore skipped.
+    fn corners(&self, mode: GridSampleMode) -> Vec<Corner> {
+        let mut corners = Vec::with_capacity(4);
+        let mut push = |x: f64, y: f64, weight, weight_grad_x, weight_grad_y| {
+            if x >= 0.0 && x < self.width as f64 && y >= 0.0 && y < self.height as f64 {
+                corners.push(Corner {
+                    x: x as usize,
+                    y: y as usize,
+                    weight,
+                    weight_grad_x,
+                    weight_grad_y,
+                });
+            }
+        };
+
+        match mode {
+            GridSampleMode::Nearest => push(libm::rint(self.x), libm::rint(self.y), 1.0, 0.0, 0.0),
+            GridSampleMode::Bilinear => {
+                let (x0, y0) = (libm::floor(self.x), libm::floor(self.y));
+                let (wx, wy) = (self.x - x0, self.y - y0);
+
+                push(x0, y0, (1.0 - wx) * (1.0 - wy), wy - 1.0, wx - 1.0);
+                push(x0 + 1.0, y0, wx * (1.0 - wy), 1.0 - wy, -wx);
+                push(x0, y0 + 1.0, (1.0 - wx) * wy, -wy, 1.0 - wx);
+                push(x0 + 1.0, y0 + 1.0, wx * wy, wy, wx);
+            }
+        }
+
+        corners
+    }
+}
+
+/// Convert a normalized coordinate from `[-1, 1]` to a pixel coordinate, `-1` and `1` being the
+/// outer borders of the first and the last pixels, returning its gradient as well.
+fn unnormalize(coordinate: f64, size: usize, padding: GridSamplePadding) -> (f64, f64) {
+    let scale = size as f64 / 2.0;
+    let coordinate = ((coordinate + 1.0) * size as f64 - 1.0) / 2.0;
+
+    match padding {
+        GridSamplePadding::Zeros => (coordinate, scale),
+        GridSamplePadding::Border => {
+            let max = (size - 1) as f64;
+
+            // The gradient is zero on the border as well, as with PyTorch.
+            if coordinate <= 0.0 {
+                (0.0, 0.0)
+            } else if coordinate >= max {
+                (max, 0.0)
+            } else {
+                (coordinate, scale)
+            }
+        }
+    }
+}
@@ -6,11 +6,12 @@ use core::ops::Range;
 
 // Current crate
 use crate::element::FloatNdArrayElement;
-use crate::NdArrayDevice;
 use crate::{tensor::NdArrayTensor, NdArrayBackend};
+use crate::{NdArrayDevice, SEED};
 
 // Workspace crates
-use burn_tensor::{backend::Backend, Data, Shape};
+use burn_common::rand::get_seeded_rng;
+use burn_tensor::{backend::Backend, Data, Distribution, ElementConversion, Shape};
 
 use super::{NdArrayMathOps, NdArrayOps};
 
@@ -22,6 +23,22 @@ impl<E: FloatNdArrayElement> IntTensorOps<NdArrayBackend<E>> for NdArrayBackend<
         NdArrayTensor::from_data(data)
     }
 
+    fn int_random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution<i64>,
+        device: &NdArrayDevice,
+    ) -> NdArrayTensor<i64, D> {
+        let mut seed = SEED.lock().unwrap();
+        let mut rng = if let Some(rng_seeded) = seed.as_ref() {
+            rng_seeded.clone()
+        } else {
+            get_seeded_rng()
+        };
+        let tensor = Self::int_from_data(Data::random(shape, distribution, &mut rng), device);
+        *seed = Some(rng);
+        tensor
+    }
+
     fn int_shape<const D: usize>(tensor: &NdArrayTensor<i64, D>) -> Shape<D> {
         tensor.shape()
     }
@@ -44,6 +61,12 @@ impl<E: FloatNdArrayElement> IntTensorOps<NdArrayBackend<E>> for NdArrayBackend<
         tensor
     }
 
+    fn int_into_float<const D: usize>(tensor: NdArrayTensor<i64, D>) -> NdArrayTensor<E, D> {
+        let array = tensor.array.mapv(|a| a.elem()).into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
     fn int_reshape<const D1: usize, const D2: usize>(
         tensor: NdArrayTensor<i64, D1>,
         shape: Shape<D2>,
@@ -103,6 +126,14 @@ impl<E: FloatNdArrayElement> IntTensorOps<NdArrayBackend<E>> for NdArrayBackend<
         NdArrayOps::cat(tensors, dim)
     }
 
+    fn int_repeat<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+        times: usize,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayOps::repeat(tensor, dim, times)
+    }
+
     fn int_equal<const D: usize>(
         lhs: NdArrayTensor<i64, D>,
         rhs: NdArrayTensor<i64, D>,
@@ -334,4 +365,32 @@ impl<E: FloatNdArrayElement> IntTensorOps<NdArrayBackend<E>> for NdArrayBackend<
     ) -> NdArrayTensor<i64, D> {
         NdArrayMathOps::argmin(tensor, dim)
     }
+
+    fn int_max_dim<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::max_dim_with_indices(tensor, dim).0
+    }
+
+    fn int_max_dim_with_indices<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> (NdArrayTensor<i64, D>, NdArrayTensor<i64, D>) {
+        NdArrayMathOps::max_dim_with_indices(tensor, dim)
+    }
+
+    fn int_min_dim<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::min_dim_with_indices(tensor, dim).0
+    }
+
+    fn int_min_dim_with_indices<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> (NdArrayTensor<i64, D>, NdArrayTensor<i64, D>) {
+        NdArrayMathOps::min_dim_with_indices(tensor, dim)
+    }
 }
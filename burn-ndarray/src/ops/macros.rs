@@ -32,7 +32,12 @@ pub(crate) fn mean_dim<E: NdArrayElement, const D1: usize, const D2: usize>(
     tensor: NdArrayTensor<E, D1>,
     dim: usize,
 ) -> NdArrayTensor<E, D2> {
-    let array = tensor.array.mean_axis(Axis(dim)).unwrap().into_shared();
+    // The mean of an empty axis is a division by zero, NaN for floats.
+    let array = match tensor.array.mean_axis(Axis(dim)) {
+        Some(array) => array,
+        None => tensor.array.sum_axis(Axis(dim)) / E::zero(),
+    }
+    .into_shared();
 
     NdArrayTensor { array }
 }
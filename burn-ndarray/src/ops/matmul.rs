@@ -1,5 +1,6 @@
 use crate::{element::FloatNdArrayElement, tensor::NdArrayTensor, NdArrayBackend};
 use crate::{iter_par, run_par, UnsafeSharedRef};
+use alloc::vec::Vec;
 use burn_tensor::ElementConversion;
 use burn_tensor::{ops::TensorOps, Shape};
 use ndarray::s;
@@ -11,43 +12,71 @@ pub(crate) fn matmul<E, const D: usize>(
 where
     E: FloatNdArrayElement,
 {
-    let shape_ori_lhs = lhs.shape();
-    let shape_ori_rhs = rhs.shape();
+    let shape_lhs = lhs.shape();
+    let shape_rhs = rhs.shape();
+    let m = shape_lhs.dims[D - 2];
+    let n = shape_rhs.dims[D - 1];
 
-    let lhs = reshape(lhs);
-    let rhs = reshape(rhs);
+    // Each batch dimension is broadcasted independently.
+    let mut shape_out = shape_lhs.clone();
+    for i in 0..D - 2 {
+        let (size_lhs, size_rhs) = (shape_lhs.dims[i], shape_rhs.dims[i]);
 
-    let [batch_size_lhs, m, _] = lhs.shape().dims;
-    let [batch_size_rhs, _, n] = rhs.shape().dims;
+        if size_lhs != size_rhs && size_lhs != 1 && size_rhs != 1 {
+            panic!(
+                "Can't broadcast batch dimension {i} of sizes {size_lhs} and {size_rhs} in matmul"
+            );
+        }
 
-    let mut shape_out = match batch_size_lhs > batch_size_rhs {
-        true => shape_ori_lhs,
-        false => shape_ori_rhs,
-    };
+        shape_out.dims[i] = usize::max(size_lhs, size_rhs);
+    }
     shape_out.dims[D - 2] = m;
     shape_out.dims[D - 1] = n;
 
-    let out = general_matmul(lhs, rhs);
+    let batch_indices_lhs = broadcast_batch_indices(&shape_lhs, &shape_out);
+    let batch_indices_rhs = broadcast_batch_indices(&shape_rhs, &shape_out);
+
+    let lhs = reshape(lhs);
+    let rhs = reshape(rhs);
+
+    let out = general_matmul(lhs, rhs, batch_indices_lhs, batch_indices_rhs);
 
     NdArrayBackend::<E>::reshape(out, shape_out)
 }
 
+/// Index of the batch of the input used for each batch of the output.
+fn broadcast_batch_indices<const D: usize>(shape: &Shape<D>, shape_out: &Shape<D>) -> Vec<usize> {
+    (0..batch_size(shape_out))
+        .map(|batch_out| {
+            let mut remainder = batch_out;
+            let mut index = 0;
+            let mut stride = 1;
+
+            for i in (0..D - 2).rev() {
+                let index_dim = remainder % shape_out.dims[i];
+                remainder /= shape_out.dims[i];
+
+                if shape.dims[i] != 1 {
+                    index += index_dim * stride;
+                }
+                stride *= shape.dims[i];
+            }
+
+            index
+        })
+        .collect()
+}
+
 fn general_matmul<E: FloatNdArrayElement>(
     lhs: NdArrayTensor<E, 3>,
     rhs: NdArrayTensor<E, 3>,
+    batch_indices_lhs: Vec<usize>,
+    batch_indices_rhs: Vec<usize>,
 ) -> NdArrayTensor<E, 3> {
     run_par!(|| {
         let [batch_size_lhs, m, _] = lhs.shape().dims;
         let [batch_size_rhs, k, n] = rhs.shape().dims;
-        let batch_size = usize::max(batch_size_rhs, batch_size_lhs);
-
-        if batch_size_lhs > batch_size && batch_size_lhs != 1 {
-            panic!("Broadcast on multiple dimensions is not yet supported");
-        }
-
-        if batch_size_rhs > batch_size && batch_size_rhs != 1 {
-            panic!("Broadcast on multiple dimensions is not yet supported");
-        }
+        let batch_size = batch_indices_lhs.len();
 
         let alpha: E = 1.0.elem();
         let beta: E = 0.0.elem();
@@ -59,14 +88,8 @@ fn general_matmul<E: FloatNdArrayElement>(
         let rhs_array = rhs.array.into_shape((batch_size_rhs, k, n)).unwrap();
 
         iter_par!(0, batch_size).for_each(|b| {
-            let lhs_slice = match batch_size_lhs == 1 {
-                true => lhs_array.slice(s!(0, .., ..)),
-                false => lhs_array.slice(s!(b, .., ..)),
-            };
-            let rhs_slice = match batch_size_rhs == 1 {
-                true => rhs_array.slice(s!(0, .., ..)),
-                false => rhs_array.slice(s!(b, .., ..)),
-            };
+            let lhs_slice = lhs_array.slice(s!(batch_indices_lhs[b], .., ..));
+            let rhs_slice = rhs_array.slice(s!(batch_indices_rhs[b], .., ..));
 
             unsafe {
                 let mut out_slice = unsafe_shared_out_array.get().slice_mut(s!(b, .., ..));
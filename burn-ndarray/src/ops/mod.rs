@@ -5,8 +5,10 @@ mod int_tensor;
 mod module;
 mod tensor;
 
+pub(crate) mod adaptive_avgpool;
 pub(crate) mod avgpool;
 pub(crate) mod conv;
+pub(crate) mod grid_sample;
 pub(crate) mod macros;
 pub(crate) mod matmul;
 pub(crate) mod maxpool;
@@ -1,6 +1,8 @@
 use super::{
+    adaptive_avgpool::{adaptive_avg_pool2d, adaptive_avg_pool2d_backward},
     avgpool::{avg_pool2d, avg_pool2d_backward},
     conv::{conv2d, conv_transpose2d},
+    grid_sample::{grid_sample, grid_sample_backward},
     maxpool::{max_pool2d, max_pool2d_backward, max_pool2d_with_indices},
 };
 use crate::{element::FloatNdArrayElement, tensor::NdArrayTensor, NdArrayBackend};
@@ -44,6 +46,38 @@ impl<E: FloatNdArrayElement> ModuleOps<NdArrayBackend<E>> for NdArrayBackend<E>
         avg_pool2d_backward(x, grad, kernel_size, stride, padding)
     }
 
+    fn adaptive_avg_pool2d(x: NdArrayTensor<E, 4>, output_size: [usize; 2]) -> NdArrayTensor<E, 4> {
+        adaptive_avg_pool2d(x, output_size)
+    }
+
+    fn adaptive_avg_pool2d_backward(
+        x: NdArrayTensor<E, 4>,
+        grad: NdArrayTensor<E, 4>,
+    ) -> NdArrayTensor<E, 4> {
+        adaptive_avg_pool2d_backward(x, grad)
+    }
+
+    fn grid_sample(
+        x: NdArrayTensor<E, 4>,
+        grid: NdArrayTensor<E, 4>,
+        mode: GridSampleMode,
+        padding: GridSamplePadding,
+    ) -> NdArrayTensor<E, 4> {
+        grid_sample(x, grid, mode, padding)
+    }
+
+    fn grid_sample_backward(
+        x: NdArrayTensor<E, 4>,
+        grid: NdArrayTensor<E, 4>,
+        output_grad: NdArrayTensor<E, 4>,
+        mode: GridSampleMode,
+        padding: GridSamplePadding,
+    ) -> GridSampleBackward<NdArrayBackend<E>> {
+        let (x_grad, grid_grad) = grid_sample_backward(x, grid, output_grad, mode, padding);
+
+        GridSampleBackward::new(x_grad, grid_grad)
+    }
+
     fn max_pool2d(
         x: NdArrayTensor<E, 4>,
         kernel_size: [usize; 2],
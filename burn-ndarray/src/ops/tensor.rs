@@ -5,7 +5,7 @@ use core::ops::Range;
 // Current crate
 use super::{matmul::matmul, NdArrayMathOps, NdArrayOps};
 use crate::element::FloatNdArrayElement;
-use crate::{tensor::NdArrayTensor, NdArrayBackend};
+use crate::{parallel::mapv_into_par, tensor::NdArrayTensor, NdArrayBackend};
 use crate::{NdArrayDevice, SEED};
 
 // Workspace crates
@@ -14,7 +14,8 @@ use burn_tensor::Distribution;
 use burn_tensor::{backend::Backend, ops::TensorOps, Data, ElementConversion, Shape};
 
 // External crates
-use libm::{cos, erf, sin, tanh};
+use libm::{ceil, cos, erf, floor, rint, sin, tanh};
+use ndarray::Axis;
 
 #[cfg(not(feature = "std"))]
 #[allow(unused_imports)]
@@ -144,6 +145,22 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         NdArrayTensor::new(array)
     }
 
+    fn permute<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        axes: [usize; D],
+    ) -> NdArrayTensor<E, D> {
+        let array = tensor.array.permuted_axes(axes.as_slice());
+
+        NdArrayTensor::new(array)
+    }
+
+    fn flip<const D: usize>(tensor: NdArrayTensor<E, D>, axes: &[usize]) -> NdArrayTensor<E, D> {
+        let mut array = tensor.array;
+        axes.iter().for_each(|axis| array.invert_axis(Axis(*axis)));
+
+        NdArrayTensor::new(array)
+    }
+
     fn reshape<const D1: usize, const D2: usize>(
         tensor: NdArrayTensor<E, D1>,
         shape: Shape<D2>,
@@ -168,6 +185,24 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         NdArrayMathOps::scatter(dim, tensor, indices, value)
     }
 
+    fn scatter_max_with_indices<const D: usize>(
+        dim: usize,
+        tensor: NdArrayTensor<E, D>,
+        indices: NdArrayTensor<i64, D>,
+        value: NdArrayTensor<E, D>,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        NdArrayMathOps::scatter_reduce_with_indices(dim, tensor, indices, value, |a, b| b > a)
+    }
+
+    fn scatter_min_with_indices<const D: usize>(
+        dim: usize,
+        tensor: NdArrayTensor<E, D>,
+        indices: NdArrayTensor<i64, D>,
+        value: NdArrayTensor<E, D>,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        NdArrayMathOps::scatter_reduce_with_indices(dim, tensor, indices, value, |a, b| b < a)
+    }
+
     fn select<const D: usize>(
         tensor: NdArrayTensor<E, D>,
         dim: usize,
@@ -200,6 +235,43 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         NdArrayOps::slice_assign(tensor, ranges, value)
     }
 
+    fn maximum<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::maximum(lhs, rhs)
+    }
+
+    fn minimum<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::minimum(lhs, rhs)
+    }
+
+    fn clamp<const D: usize>(tensor: NdArrayTensor<E, D>, min: E, max: E) -> NdArrayTensor<E, D> {
+        let array = mapv_into_par!(tensor.array, |a| match a {
+            a if a < min => min,
+            a if a > max => max,
+            a => a,
+        })
+        .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn clamp_min<const D: usize>(tensor: NdArrayTensor<E, D>, min: E) -> NdArrayTensor<E, D> {
+        let array = mapv_into_par!(tensor.array, |a| if a < min { min } else { a }).into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn clamp_max<const D: usize>(tensor: NdArrayTensor<E, D>, max: E) -> NdArrayTensor<E, D> {
+        let array = mapv_into_par!(tensor.array, |a| if a > max { max } else { a }).into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
     fn mask_where<const D: usize>(
         tensor: NdArrayTensor<E, D>,
         mask: NdArrayTensor<bool, D>,
@@ -318,6 +390,13 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         NdArrayMathOps::sum_dim(tensor, dim)
     }
 
+    fn cumsum<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        let mut array = tensor.array.into_owned();
+        array.accumulate_axis_inplace(Axis(dim), |&prev, curr| *curr += prev);
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
     fn to_full_precision<const D: usize>(tensor: &NdArrayTensor<E, D>) -> NdArrayTensor<f32, D> {
         let array = tensor.array.mapv(|a| a.elem()).into_shared();
 
@@ -330,6 +409,16 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         NdArrayTensor::new(array)
     }
 
+    fn into_int<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<i64, D> {
+        // The cast saturates the values out of range and maps NaN to zero, where `elem` panics.
+        let array = tensor
+            .array
+            .mapv(|a| a.to_f64().unwrap() as i64)
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
     fn argmax<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<i64, D> {
         NdArrayMathOps::argmax(tensor, dim)
     }
@@ -338,20 +427,58 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         NdArrayMathOps::argmin(tensor, dim)
     }
 
+    fn max_dim<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::max_dim_with_indices(tensor, dim).0
+    }
+
+    fn max_dim_with_indices<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        NdArrayMathOps::max_dim_with_indices(tensor, dim)
+    }
+
+    fn topk<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        k: usize,
+        dim: usize,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        NdArrayMathOps::topk(tensor, k, dim)
+    }
+
+    fn sort<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        NdArrayMathOps::sort(tensor, dim, descending)
+    }
+
+    fn min_dim<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::min_dim_with_indices(tensor, dim).0
+    }
+
+    fn min_dim_with_indices<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        NdArrayMathOps::min_dim_with_indices(tensor, dim)
+    }
+
     fn exp<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
-        let array = tensor.array.mapv_into(|a| a.exp_elem()).into_shared();
+        let array = mapv_into_par!(tensor.array, |a| a.exp_elem()).into_shared();
 
         NdArrayTensor::new(array)
     }
 
     fn log<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
-        let array = tensor.array.mapv_into(|a| a.log_elem()).into_shared();
+        let array = mapv_into_par!(tensor.array, |a| a.log_elem()).into_shared();
 
         NdArrayTensor::new(array)
     }
 
     fn log1p<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
-        let array = tensor.array.mapv_into(|a| a.log1p_elem()).into_shared();
+        let array = mapv_into_par!(tensor.array, |a| a.log1p_elem()).into_shared();
 
         NdArrayTensor::new(array)
     }
@@ -359,59 +486,74 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
     fn powf<const D: usize>(tensor: NdArrayTensor<E, D>, value: f32) -> NdArrayTensor<E, D> {
         let array = if value == 2.0 {
             // Happens often and is faster.
-            tensor.array.mapv_into(|a| a * a).into_shared()
+            mapv_into_par!(tensor.array, |a| a * a).into_shared()
         } else if value.floor() == value {
             // Is faster then powf
-            tensor
-                .array
-                .mapv_into(|a| a.powi_elem(value as i32))
-                .into_shared()
+            mapv_into_par!(tensor.array, |a| a.powi_elem(value as i32)).into_shared()
         } else {
             // Default
-            tensor.array.mapv_into(|a| a.powf_elem(value)).into_shared()
+            mapv_into_par!(tensor.array, |a| a.powf_elem(value)).into_shared()
         };
 
         NdArrayTensor::new(array)
     }
 
+    fn powf_tensor<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::elementwise_op(lhs, rhs, |a, b| a.powf(*b))
+    }
+
     fn sqrt<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
-        let array = tensor.array.mapv_into(|a| a.sqrt_elem()).into_shared();
+        let array = mapv_into_par!(tensor.array, |a| a.sqrt_elem()).into_shared();
 
         NdArrayTensor::new(array)
     }
 
     fn cos<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
-        let array = tensor
-            .array
-            .mapv_into(|a| cos(a.to_f64().unwrap()).elem())
-            .into_shared();
+        let array = mapv_into_par!(tensor.array, |a| cos(a.to_f64().unwrap()).elem()).into_shared();
 
         NdArrayTensor::new(array)
     }
 
     fn sin<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
-        let array = tensor
-            .array
-            .mapv_into(|a| sin(a.to_f64().unwrap()).elem())
-            .into_shared();
+        let array = mapv_into_par!(tensor.array, |a| sin(a.to_f64().unwrap()).elem()).into_shared();
 
         NdArrayTensor::new(array)
     }
 
     fn tanh<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
-        let array = tensor
-            .array
-            .mapv_into(|a| tanh(a.to_f64().unwrap()).elem())
-            .into_shared();
+        let array =
+            mapv_into_par!(tensor.array, |a| tanh(a.to_f64().unwrap()).elem()).into_shared();
 
         NdArrayTensor::new(array)
     }
 
     fn erf<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
-        let array = tensor
-            .array
-            .mapv_into(|a| erf(a.to_f64().unwrap()).elem())
-            .into_shared();
+        let array = mapv_into_par!(tensor.array, |a| erf(a.to_f64().unwrap()).elem()).into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn floor<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array =
+            mapv_into_par!(tensor.array, |a| floor(a.to_f64().unwrap()).elem()).into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn ceil<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array =
+            mapv_into_par!(tensor.array, |a| ceil(a.to_f64().unwrap()).elem()).into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn round<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        // `rint` rounds half-way cases to even, unlike `round`.
+        let array =
+            mapv_into_par!(tensor.array, |a| rint(a.to_f64().unwrap()).elem()).into_shared();
 
         NdArrayTensor::new(array)
     }
@@ -419,4 +561,12 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
     fn cat<const D: usize>(tensors: Vec<NdArrayTensor<E, D>>, dim: usize) -> NdArrayTensor<E, D> {
         NdArrayOps::cat(tensors, dim)
     }
+
+    fn repeat<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        times: usize,
+    ) -> NdArrayTensor<E, D> {
+        NdArrayOps::repeat(tensor, dim, times)
+    }
 }
@@ -32,3 +32,99 @@ macro_rules! iter_par {
         output
     }};
 }
+
+/// Number of elements from which element-wise operations and reductions are executed in
+/// parallel, smaller tensors don't amortize the scheduling overhead.
+#[cfg(feature = "rayon")]
+pub(crate) const PARALLEL_THRESHOLD: usize = 32 * 1024;
+
+/// Macro for mapping every element of an array in place, in parallel for large arrays when the
+/// `rayon` feature is enabled.
+macro_rules! mapv_into_par {
+    (
+        $array:expr, $func:expr
+    ) => {{
+        let mut array = $array;
+
+        #[cfg(feature = "rayon")]
+        {
+            if array.len() >= $crate::parallel::PARALLEL_THRESHOLD {
+                array.par_mapv_inplace($func);
+            } else {
+                array.mapv_inplace($func);
+            }
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        array.mapv_inplace($func);
+
+        array
+    }};
+}
+
+pub(crate) use mapv_into_par;
+
+#[cfg(all(test, feature = "rayon"))]
+mod tests {
+    use super::PARALLEL_THRESHOLD;
+    use crate::NdArrayBackend;
+    use alloc::vec::Vec;
+    use burn_tensor::{Distribution, Int, Tensor};
+
+    type TestTensor<const D: usize> = Tensor<NdArrayBackend<f32>, D>;
+    type TestTensorInt<const D: usize> = Tensor<NdArrayBackend<f32>, D, Int>;
+
+    // Each row is below the threshold, so it is computed serially.
+    const NUM_ROWS: usize = 64;
+    const ROW_SIZE: usize = PARALLEL_THRESHOLD / 32;
+
+    #[test]
+    fn elementwise_ops_should_match_serial_results() {
+        let tensor = TestTensor::<2>::random([NUM_ROWS, ROW_SIZE], Distribution::Default);
+
+        let rows = (0..NUM_ROWS)
+            .map(|i| tensor.clone().slice([i..i + 1]).exp())
+            .collect::<Vec<_>>();
+        let expected = TestTensor::cat(rows, 0);
+        let output = tensor.exp();
+
+        assert_eq!(output.into_data(), expected.into_data());
+    }
+
+    #[test]
+    fn argmax_should_match_serial_results() {
+        let tensor = TestTensor::<2>::random([NUM_ROWS, ROW_SIZE], Distribution::Default);
+
+        let rows = (0..NUM_ROWS)
+            .map(|i| tensor.clone().slice([i..i + 1]).argmax(1))
+            .collect::<Vec<_>>();
+        let expected = TestTensorInt::cat(rows, 0);
+        let output = tensor.argmax(1);
+
+        assert_eq!(output.into_data(), expected.into_data());
+    }
+
+    #[test]
+    fn max_dim_with_indices_should_match_serial_results() {
+        let tensor = TestTensor::<2>::random([ROW_SIZE, NUM_ROWS], Distribution::Default);
+
+        let (values, indices): (Vec<_>, Vec<_>) = (0..NUM_ROWS)
+            .map(|i| {
+                tensor
+                    .clone()
+                    .slice([0..ROW_SIZE, i..i + 1])
+                    .max_dim_with_indices(0)
+            })
+            .unzip();
+        let (output_values, output_indices) = tensor.max_dim_with_indices(0);
+
+        assert_eq!(
+            output_values.into_data(),
+            TestTensor::cat(values, 1).into_data()
+        );
+        assert_eq!(
+            output_indices.into_data(),
+            TestTensorInt::cat(indices, 1).into_data()
+        );
+    }
+}
@@ -59,12 +59,9 @@ macro_rules! reshape {
         array $array:expr
     ) => {{
         let dim = $crate::to_typed_dims!($n, $shape.dims, justdim);
-        let safe_into_shape =
-            $array.is_standard_layout() ||
-            (
-                $array.ndim() > 1 &&
-                $array.raw_view().reversed_axes().is_standard_layout()
-            );
+        // Only arrays in standard layout can be reshaped without copying, since the elements of
+        // other layouts would be reinterpreted in their memory order.
+        let safe_into_shape = $array.is_standard_layout();
 
         let array: ndarray::ArcArray<$ty, Dim<[usize; $n]>> = match safe_into_shape {
             true => $array
@@ -20,4 +20,8 @@ impl<E: TchElement> ActivationOps<TchBackend<E>> for TchBackend<E> {
 
         TchTensor::from_existing(tensor, storage)
     }
+
+    fn log_sigmoid<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(tensor.tensor.log_sigmoid())
+    }
 }
@@ -1,6 +1,6 @@
 use std::ops::Range;
 
-use burn_tensor::{backend::Backend, ops::IntTensorOps, Data, Shape};
+use burn_tensor::{backend::Backend, ops::IntTensorOps, Data, Distribution, Shape};
 
 use crate::{element::TchElement, TchBackend, TchDevice, TchShape, TchTensor};
 
@@ -11,6 +11,42 @@ impl<E: TchElement> IntTensorOps<TchBackend<E>> for TchBackend<E> {
         TchTensor::from_data(data, (*device).into())
     }
 
+    fn int_random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution<i64>,
+        device: &TchDevice,
+    ) -> TchTensor<i64, D> {
+        match distribution {
+            Distribution::Default => {
+                // Sampling from the smallest value without an end covers the whole range of i64,
+                // as with the other backends, while `random_` only samples non-negative values.
+                let mut tensor = TchTensor::<i64, D>::empty(shape, *device);
+                tensor
+                    .mut_ops(|tensor| tensor.random_from_(i64::MIN, None::<i64>))
+                    .unwrap()
+            }
+            Distribution::Bernoulli(prob) => {
+                let mut tensor = TchTensor::<i64, D>::empty(shape, *device);
+                tensor
+                    .mut_ops(|tensor| tensor.f_bernoulli_float_(prob).unwrap())
+                    .unwrap()
+            }
+            Distribution::Uniform(from, to) => {
+                let mut tensor = TchTensor::<i64, D>::empty(shape, *device);
+                tensor
+                    .mut_ops(|tensor| tensor.random_from_(from, Some(to)))
+                    .unwrap()
+            }
+            Distribution::Normal(mean, std) => {
+                // The normal distribution is only supported by float tensors.
+                let mut tensor = TchTensor::<f64, D>::empty(shape, *device);
+                let tensor = tensor.mut_ops(|tensor| tensor.normal_(mean, std)).unwrap();
+
+                TchTensor::new(tensor.tensor.round().to_kind(tch::Kind::Int64))
+            }
+        }
+    }
+
     fn int_shape<const D: usize>(tensor: &TchTensor<i64, D>) -> Shape<D> {
         tensor.shape()
     }
@@ -42,6 +78,11 @@ impl<E: TchElement> IntTensorOps<TchBackend<E>> for TchBackend<E> {
         TchTensor::new(tensor.tensor.to((*device).into()))
     }
 
+    fn int_into_float<const D: usize>(tensor: TchTensor<i64, D>) -> TchTensor<E, D> {
+        let tensor = tensor.tensor.to_kind(E::KIND);
+        TchTensor::new(tensor)
+    }
+
     fn int_reshape<const D1: usize, const D2: usize>(
         tensor: TchTensor<i64, D1>,
         shape: Shape<D2>,
@@ -190,13 +231,20 @@ impl<E: TchElement> IntTensorOps<TchBackend<E>> for TchBackend<E> {
         lhs: TchTensor<i64, D>,
         rhs: TchTensor<i64, D>,
     ) -> TchTensor<i64, D> {
-        TchOps::div(lhs, rhs)
+        // Torch uses true division by default, which would produce floats.
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.f_div_tensor_mode_(rhs, "trunc").unwrap(),
+            |lhs, rhs| lhs.f_div_tensor_mode(rhs, "trunc").unwrap(),
+            |lhs, rhs| lhs.f_div_tensor_mode(rhs, "trunc").unwrap(),
+        )
     }
 
     fn int_div_scalar<const D: usize>(lhs: TchTensor<i64, D>, rhs: i64) -> TchTensor<i64, D> {
         lhs.unary_ops(
-            |mut tensor| tensor.f_div_scalar_(rhs).unwrap(),
-            |tensor| tensor.f_div_scalar(rhs).unwrap(),
+            |mut tensor| tensor.f_div_scalar_mode_(rhs, "trunc").unwrap(),
+            |tensor| tensor.f_div_scalar_mode(rhs, "trunc").unwrap(),
         )
     }
 
@@ -1,6 +1,7 @@
 use crate::{element::TchElement, TchBackend, TchTensor};
 use burn_tensor::ops::{
-    ConvOptions, ConvTransposeOptions, MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
+    ConvOptions, ConvTransposeOptions, GridSampleBackward, GridSampleMode, GridSamplePadding,
+    MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
 };
 
 impl<E: TchElement> ModuleOps<TchBackend<E>> for TchBackend<E> {
@@ -163,6 +164,21 @@ impl<E: TchElement> ModuleOps<TchBackend<E>> for TchBackend<E> {
         TchTensor::new(tensor)
     }
 
+    fn adaptive_avg_pool2d(x: TchTensor<E, 4>, output_size: [usize; 2]) -> TchTensor<E, 4> {
+        let tensor = tch::Tensor::adaptive_avg_pool2d(
+            &x.tensor,
+            [output_size[0] as i64, output_size[1] as i64],
+        );
+
+        TchTensor::new(tensor)
+    }
+
+    fn adaptive_avg_pool2d_backward(x: TchTensor<E, 4>, grad: TchTensor<E, 4>) -> TchTensor<E, 4> {
+        let tensor = tch::Tensor::internal_adaptive_avg_pool2d_backward(&x.tensor, &grad.tensor);
+
+        TchTensor::new(tensor)
+    }
+
     fn max_pool2d(
         x: TchTensor<E, 4>,
         kernel_size: [usize; 2],
@@ -220,4 +236,51 @@ impl<E: TchElement> ModuleOps<TchBackend<E>> for TchBackend<E> {
 
         MaxPool2dBackward::new(TchTensor::new(grad))
     }
+
+    fn grid_sample(
+        x: TchTensor<E, 4>,
+        grid: TchTensor<E, 4>,
+        mode: GridSampleMode,
+        padding: GridSamplePadding,
+    ) -> TchTensor<E, 4> {
+        let (mode, padding) = grid_sampler_modes(mode, padding);
+        let tensor = tch::Tensor::grid_sampler(&x.tensor, &grid.tensor, mode, padding, false);
+
+        TchTensor::new(tensor)
+    }
+
+    fn grid_sample_backward(
+        x: TchTensor<E, 4>,
+        grid: TchTensor<E, 4>,
+        output_grad: TchTensor<E, 4>,
+        mode: GridSampleMode,
+        padding: GridSamplePadding,
+    ) -> GridSampleBackward<TchBackend<E>> {
+        let (mode, padding) = grid_sampler_modes(mode, padding);
+        let (x_grad, grid_grad) = tch::Tensor::grid_sampler_2d_backward(
+            &output_grad.tensor,
+            &x.tensor,
+            &grid.tensor,
+            mode,
+            padding,
+            false,
+            &[true, true],
+        );
+
+        GridSampleBackward::new(TchTensor::new(x_grad), TchTensor::new(grid_grad))
+    }
+}
+
+/// The interpolation and padding modes of the LibTorch grid sampler.
+fn grid_sampler_modes(mode: GridSampleMode, padding: GridSamplePadding) -> (i64, i64) {
+    let mode = match mode {
+        GridSampleMode::Bilinear => 0,
+        GridSampleMode::Nearest => 1,
+    };
+    let padding = match padding {
+        GridSamplePadding::Zeros => 0,
+        GridSamplePadding::Border => 1,
+    };
+
+    (mode, padding)
 }
@@ -182,6 +182,18 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         TchTensor::new(tensor)
     }
 
+    fn permute<const D: usize>(tensor: TchTensor<E, D>, axes: [usize; D]) -> TchTensor<E, D> {
+        let axes = axes.map(|axis| axis as i64);
+        let tensor = tensor.tensor.permute(axes.as_slice());
+        TchTensor::new(tensor)
+    }
+
+    fn flip<const D: usize>(tensor: TchTensor<E, D>, axes: &[usize]) -> TchTensor<E, D> {
+        let axes = axes.iter().map(|axis| *axis as i64).collect::<Vec<_>>();
+        let tensor = tensor.tensor.flip(axes.as_slice());
+        TchTensor::new(tensor)
+    }
+
     fn reshape<const D1: usize, const D2: usize>(
         tensor: TchTensor<E, D1>,
         shape: Shape<D2>,
@@ -238,6 +250,42 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         TchOps::slice_assign(tensor, ranges, value)
     }
 
+    fn maximum<const D: usize>(lhs: TchTensor<E, D>, rhs: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(lhs.tensor.maximum(&rhs.tensor))
+    }
+
+    fn minimum<const D: usize>(lhs: TchTensor<E, D>, rhs: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(lhs.tensor.minimum(&rhs.tensor))
+    }
+
+    fn clamp<const D: usize>(tensor: TchTensor<E, D>, min: E, max: E) -> TchTensor<E, D> {
+        let min: f64 = min.elem();
+        let max: f64 = max.elem();
+
+        tensor.unary_ops(
+            |mut tensor| tensor.f_clamp_(min, max).unwrap(),
+            |tensor| tensor.f_clamp(min, max).unwrap(),
+        )
+    }
+
+    fn clamp_min<const D: usize>(tensor: TchTensor<E, D>, min: E) -> TchTensor<E, D> {
+        let min: f64 = min.elem();
+
+        tensor.unary_ops(
+            |mut tensor| tensor.f_clamp_min_(min).unwrap(),
+            |tensor| tensor.f_clamp_min(min).unwrap(),
+        )
+    }
+
+    fn clamp_max<const D: usize>(tensor: TchTensor<E, D>, max: E) -> TchTensor<E, D> {
+        let max: f64 = max.elem();
+
+        tensor.unary_ops(
+            |mut tensor| tensor.f_clamp_max_(max).unwrap(),
+            |tensor| tensor.f_clamp_max(max).unwrap(),
+        )
+    }
+
     fn mask_where<const D: usize>(
         tensor: TchTensor<E, D>,
         mask: TchTensor<bool, D>,
@@ -323,6 +371,39 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         TchOps::sum_dim(tensor, dim)
     }
 
+    fn var_mean<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+        unbiased: bool,
+    ) -> (TchTensor<E, D>, TchTensor<E, D>) {
+        let (var, mean) = tensor
+            .tensor
+            .var_mean_dim(Some([dim as i64].as_slice()), unbiased, true);
+
+        (TchTensor::new(var), TchTensor::new(mean))
+    }
+
+    fn cumsum<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        tensor.unary_ops(
+            |mut tensor| tensor.cumsum_(dim as i64, E::KIND),
+            |tensor| tensor.cumsum(dim as i64, E::KIND),
+        )
+    }
+
+    fn tril<const D: usize>(tensor: TchTensor<E, D>, diagonal: i64) -> TchTensor<E, D> {
+        tensor.unary_ops(
+            |mut tensor| tensor.tril_(diagonal),
+            |tensor| tensor.tril(diagonal),
+        )
+    }
+
+    fn triu<const D: usize>(tensor: TchTensor<E, D>, diagonal: i64) -> TchTensor<E, D> {
+        tensor.unary_ops(
+            |mut tensor| tensor.triu_(diagonal),
+            |tensor| tensor.triu(diagonal),
+        )
+    }
+
     fn to_full_precision<const D: usize>(tensor: &TchTensor<E, D>) -> TchTensor<f32, D> {
         let storage = tensor.storage.clone();
         let tensor = tensor.tensor.to_kind(tch::Kind::Float);
@@ -337,6 +418,11 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         TchTensor::from_existing(tensor, storage)
     }
 
+    fn into_int<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<i64, D> {
+        let tensor = tensor.tensor.to_kind(tch::Kind::Int64);
+        TchTensor::new(tensor)
+    }
+
     fn argmax<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<i64, D> {
         TchOps::argmax(tensor, dim)
     }
@@ -367,6 +453,30 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         TchOps::min_dim_with_indices(tensor, dim)
     }
 
+    fn topk<const D: usize>(
+        tensor: TchTensor<E, D>,
+        k: usize,
+        dim: usize,
+    ) -> (TchTensor<E, D>, TchTensor<i64, D>) {
+        // The order of equal elements isn't defined by topk, a stable sort keeps the lowest index
+        // first.
+        let (values, indices) = tensor.tensor.sort_stable(true, dim as i64, true);
+        let values = values.narrow(dim as i64, 0, k as i64);
+        let indices = indices.narrow(dim as i64, 0, k as i64);
+
+        (TchTensor::new(values), TchTensor::new(indices))
+    }
+
+    fn sort<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (TchTensor<E, D>, TchTensor<i64, D>) {
+        let (values, indices) = tensor.tensor.sort_stable(true, dim as i64, descending);
+
+        (TchTensor::new(values), TchTensor::new(indices))
+    }
+
     fn exp<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
         tensor.unary_ops(|mut tensor| tensor.exp_(), |tensor| tensor.exp())
     }
@@ -386,6 +496,16 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         )
     }
 
+    fn powf_tensor<const D: usize>(lhs: TchTensor<E, D>, rhs: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.f_pow_tensor_(rhs).unwrap(),
+            |lhs, rhs| lhs.f_pow(rhs).unwrap(),
+            |lhs, rhs| lhs.f_pow(rhs).unwrap(),
+        )
+    }
+
     fn sqrt<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
         tensor.unary_ops(|mut tensor| tensor.sqrt_(), |tensor| tensor.sqrt())
     }
@@ -406,6 +526,18 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         tensor.unary_ops(|mut tensor| tensor.erf_(), |tensor| tensor.erf())
     }
 
+    fn floor<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        tensor.unary_ops(|mut tensor| tensor.floor_(), |tensor| tensor.floor())
+    }
+
+    fn ceil<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        tensor.unary_ops(|mut tensor| tensor.ceil_(), |tensor| tensor.ceil())
+    }
+
+    fn round<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        tensor.unary_ops(|mut tensor| tensor.round_(), |tensor| tensor.round())
+    }
+
     fn cat<const D: usize>(tensors: Vec<TchTensor<E, D>>, dim: usize) -> TchTensor<E, D> {
         TchOps::cat(tensors, dim)
     }
@@ -225,7 +225,8 @@ impl<E: tch::kind::Element + Default + Copy + std::fmt::Debug, const D: usize> T
 #[cfg(test)]
 mod tests {
     use super::*;
-    use burn_tensor::{Distribution, Tensor};
+    use burn_tensor::{Distribution, Int, Tensor};
+    use half::f16;
     use rand::prelude::StdRng;
     use rand::SeedableRng;
 
@@ -276,4 +277,53 @@ mod tests {
 
         assert_ne!(tensor_3.to_data().value, tensor_1.to_data().value);
     }
+
+    #[test]
+    fn should_accumulate_half_precision_in_full_precision() {
+        // Adding one to 2048 is rounded away in f16, while the true sum (2064) is representable.
+        let mut values = vec![f16::from_f32(2048.0)];
+        values.extend([f16::ONE; 16]);
+        let half_sum = values.iter().fold(f16::ZERO, |sum, value| sum + *value);
+        let tensor = Tensor::<TchBackend<f16>, 1>::from_data(Data::new(values, Shape::new([17])));
+
+        let sum = tensor.sum_full_precision().into_scalar();
+
+        assert_eq!(half_sum, f16::from_f32(2048.0));
+        assert_eq!(sum, f16::from_f32(2064.0));
+    }
+
+    #[test]
+    fn should_not_overflow_half_precision_mean_in_full_precision() {
+        // The true sum (131072) is over the f16 range (65504).
+        let tensor = Tensor::<TchBackend<f16>, 1>::full([4096], 32.0);
+
+        let naive = tensor.clone().sum().into_scalar();
+        let mean = tensor.mean_full_precision().into_scalar();
+
+        assert!(naive.is_infinite());
+        assert_eq!(mean, f16::from_f32(32.0));
+    }
+
+    #[test]
+    fn should_convert_cuda_device_index_both_ways() {
+        let device: tch::Device = TchDevice::Cuda(1).into();
+        assert_eq!(device, tch::Device::Cuda(1));
+
+        let device: TchDevice = tch::Device::Cuda(1).into();
+        assert_eq!(device, TchDevice::Cuda(1));
+    }
+
+    #[test]
+    fn should_report_cuda_device_index() {
+        if !tch::Cuda::is_available() {
+            return;
+        }
+
+        let device = TchDevice::Cuda(0);
+        let tensor = Tensor::<TchBackend<f32>, 1>::from_floats([4.0, 4.0]).to_device(&device);
+        let tensor_int = Tensor::<TchBackend<f32>, 1, Int>::zeros_device([2], &device);
+
+        assert_eq!(tensor.device(), device);
+        assert_eq!(tensor_int.device(), device);
+    }
 }
@@ -1,7 +1,6 @@
 use crate::backend::Backend;
 use crate::check::TensorCheck;
-use crate::{check, Tensor};
-use crate::{ElementPrecision, Precision};
+use crate::{check, Distribution, Tensor};
 
 /// Applies the rectified linear unit function.
 pub fn relu<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
@@ -13,6 +12,50 @@ pub fn gelu<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
     Tensor::from_primitive(B::gelu(tensor.primitive))
 }
 
+/// Applies the leaky rectified linear unit function.
+///
+/// `y = max(0, x) + negative_slope * min(0, x)`
+///
+/// # Notes
+///
+/// The gradient is `1` for positive inputs and `negative_slope` otherwise.
+pub fn leaky_relu<const D: usize, B: Backend>(
+    tensor: Tensor<B, D>,
+    negative_slope: f64,
+) -> Tensor<B, D> {
+    let mask = tensor.clone().lower_equal_elem(0.0);
+
+    tensor
+        .clone()
+        .mask_where(mask, tensor.mul_scalar(negative_slope))
+}
+
+/// Applies the parametric rectified linear unit function, with a learnable slope for the
+/// negative inputs, as described in the paper [Delving Deep into Rectifiers](https://arxiv.org/abs/1502.01852).
+///
+/// `y = max(0, x) + alpha * min(0, x)`
+///
+/// # Notes
+///
+/// The slope `alpha` either has a single value shared by all the channels, or one value for each
+/// channel of the second dimension.
+pub fn prelu<const D: usize, B: Backend>(
+    tensor: Tensor<B, D>,
+    alpha: Tensor<B, 1>,
+) -> Tensor<B, D> {
+    check!(TensorCheck::prelu::<D>(&tensor.shape(), &alpha.shape()));
+
+    let [num_parameters] = alpha.dims();
+    let mut dims = [1; D];
+    if num_parameters > 1 {
+        dims[1] = num_parameters;
+    }
+    let mask = tensor.clone().lower_equal_elem(0.0);
+    let negatives = tensor.clone().mul(alpha.reshape(dims));
+
+    tensor.mask_where(mask, negatives)
+}
+
 /// Applies the softmax function on the input tensor along the given dimension.
 ///
 /// `softmax(x_i) = exp(x_i) / sum_j(exp(x_j))`
@@ -48,21 +91,61 @@ pub fn log_softmax<const D: usize, B: Backend>(tensor: Tensor<B, D>, dim: usize)
     tensor.sub(tensor_tmp)
 }
 
+/// Samples from the Gumbel-softmax distribution, a differentiable relaxation of the categorical
+/// distribution parameterized by the unnormalized log-probabilities along the given dimension.
+///
+/// `gumbel_softmax(x_i) = softmax((x_i + g_i) / tau)`, where `g_i = -log(-log(u_i))` is Gumbel
+/// noise sampled with the backend's random number generator.
+///
+/// When `hard` is true, the samples are discretized to one-hot vectors in the forward pass, while
+/// the gradient is computed as if the soft samples were returned (straight-through estimator).
+///
+/// # Notes
+///
+/// The dimension argument `dim` specifies the dimension along which the function will be computed.
+/// It must in the range of `0` and `D-1`.
+pub fn gumbel_softmax<const D: usize, B: Backend>(
+    logits: Tensor<B, D>,
+    tau: f64,
+    hard: bool,
+    dim: usize,
+) -> Tensor<B, D> {
+    check!(TensorCheck::dim_ops::<D>("gumbel softmax", dim));
+
+    let gumbels = logits
+        .random_like(Distribution::Default)
+        .log()
+        .neg()
+        .log()
+        .neg();
+    let soft = softmax(logits.add(gumbels).div_scalar(tau), dim);
+
+    if !hard {
+        return soft;
+    }
+
+    let indices = soft.clone().argmax(dim);
+    let ones = Tensor::ones_device(indices.shape(), &indices.device());
+    let hard = soft.zeros_like().scatter(dim, indices, ones);
+
+    hard - soft.clone().detach() + soft
+}
+
 /// Applies the sigmoid function.
 pub fn sigmoid<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
     log_sigmoid(tensor).exp()
 }
 
 /// Applies the log sigmoid function.
+///
+/// `log_sigmoid(x) = log(1 / (1 + exp(-x)))`
+///
+/// # Notes
+///
+/// The function is computed as `min(x, 0) - log(1 + exp(-|x|))`, so it stays finite for inputs
+/// of large magnitude.
 pub fn log_sigmoid<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
-    match B::FloatElem::precision() {
-        Precision::Half => {
-            let tensor_full = tensor.to_full_precision();
-            let tensor_tmp = tensor_full.neg().exp().add_scalar(1.0_f32).log().neg();
-            Tensor::from_full_precision(tensor_tmp)
-        }
-        _ => tensor.neg().exp().add_scalar(1.0_f32).log().neg(),
-    }
+    Tensor::from_primitive(B::log_sigmoid(tensor.primitive))
 }
 
 /// Applies the silu function
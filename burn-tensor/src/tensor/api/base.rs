@@ -165,6 +165,53 @@ where
         Tensor::new(K::reshape::<D, D2>(self.primitive, new_dims.into()))
     }
 
+    /// Squeeze the tensor along the given dimensions, removing each specified dimension
+    /// of size one, and effectively reducing the rank of the tensor by the number of dimensions.
+    ///
+    /// # Arguments
+    ///
+    /// - `dims`: The dimensions to be squeezed.
+    ///
+    /// # Type Parameters
+    ///
+    ///  - 'D2': The resulting number of dimensions in the squeezed tensor.
+    ///
+    /// # Returns
+    ///
+    /// A new `Tensor<B, D2, K>` instance with the specified dimensions removed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 4>::ones(Shape::new([2, 1, 4, 1]));
+    ///
+    ///     // Given a 4D tensor with dimensions (2, 1, 4, 1), squeeze the dimensions 1 and 3
+    ///     let squeezed_tensor: Tensor::<B, 2> = tensor.squeeze_dims(&[1, 3]);
+    ///
+    ///     // Resulting tensor will have dimensions (2, 4)
+    ///     println!("{:?}", squeezed_tensor.shape());
+    /// }
+    /// ```
+    pub fn squeeze_dims<const D2: usize>(self, dims: &[usize]) -> Tensor<B, D2, K> {
+        let current_dims = self.shape().dims;
+        check!(TensorCheck::squeeze_dims::<D, D2>(dims, &current_dims));
+
+        let mut new_dims: [usize; D2] = [0; D2];
+        current_dims
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !dims.contains(index))
+            .zip(new_dims.iter_mut())
+            .for_each(|((_, current), new)| *new = *current);
+
+        Tensor::new(K::reshape::<D, D2>(self.primitive, new_dims.into()))
+    }
+
     /// Unsqueeze the current tensor. Create new dimensions to fit the given size.
     ///
     /// # Panics
@@ -197,6 +244,38 @@ where
         self.reshape(shape)
     }
 
+    /// Insert a new dimension of size one at the given position.
+    ///
+    /// # Panics
+    ///
+    /// If the output size isn't one more than the current tensor, or if the dimension is
+    /// greater than the current number of dimensions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::ones(Shape::new([3, 3]));
+    ///     let tensor = tensor.unsqueeze_dim::<3>(2);
+    ///     println!("{:?}", tensor.shape());
+    ///     // Shape { dims: [3, 3, 1] }
+    /// }
+    /// ```
+    pub fn unsqueeze_dim<const D2: usize>(self, dim: usize) -> Tensor<B, D2, K> {
+        check!(TensorCheck::unsqueeze_dim::<D, D2>(dim));
+
+        let current_dims = self.shape().dims;
+        let mut dims = [1; D2];
+
+        dims[..dim].copy_from_slice(&current_dims[..dim]);
+        dims[dim + 1..].copy_from_slice(&current_dims[dim..]);
+
+        self.reshape(Shape::new(dims))
+    }
+
     /// Returns a tensor containing the elements selected from the given ranges.
     ///
     /// # Panics
@@ -255,6 +334,78 @@ where
         Self::new(K::slice_assign(self.primitive, ranges, values.primitive))
     }
 
+    /// Splits the tensor into chunks of equal size along the given dimension.
+    ///
+    /// Each chunk has a size of `ceil(size / chunks)` on the dimension, except for the last one
+    /// which holds the remaining elements. Fewer chunks are returned when the size of the
+    /// dimension doesn't allow `chunks` non-empty chunks.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is greater than the number of dimensions of the tensor or if the number
+    /// of chunks is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::ones(Shape::new([2, 5]));
+    ///     let chunks = tensor.chunk(2, 1);
+    ///     println!("{:?}", chunks[0].dims()); // [2, 3]
+    ///     println!("{:?}", chunks[1].dims()); // [2, 2]
+    /// }
+    /// ```
+    pub fn chunk(self, chunks: usize, dim: usize) -> Vec<Self> {
+        check!(TensorCheck::chunk::<D>(chunks, dim));
+
+        let size = self.dims()[dim];
+        let split_size = usize::max(size.div_ceil(chunks), 1);
+
+        self.split(split_size, dim)
+    }
+
+    /// Splits the tensor into chunks of the given size along the given dimension.
+    ///
+    /// The last chunk is smaller when the size of the dimension isn't divisible by `split_size`.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is greater than the number of dimensions of the tensor or if the split
+    /// size is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::ones(Shape::new([2, 5]));
+    ///     let chunks = tensor.split(2, 1);
+    ///     println!("{:?}", chunks.len()); // 3
+    ///     println!("{:?}", chunks[2].dims()); // [2, 1]
+    /// }
+    /// ```
+    pub fn split(self, split_size: usize, dim: usize) -> Vec<Self> {
+        check!(TensorCheck::split::<D>(split_size, dim));
+
+        let dims = self.dims();
+        let size = dims[dim];
+
+        (0..size)
+            .step_by(split_size)
+            .map(|start| {
+                let mut ranges = dims.map(|size| 0..size);
+                ranges[dim] = start..usize::min(start + split_size, size);
+
+                self.clone().slice(ranges)
+            })
+            .collect()
+    }
+
     /// Returns the device of the current tensor.
     pub fn device(&self) -> B::Device {
         K::device(&self.primitive)
@@ -293,10 +444,29 @@ where
 
     /// Repeat the tensor along the given dimension.
     ///
+    /// The copies are laid out one after the other, so a dimension of size `n` becomes a
+    /// dimension of size `n * times`.
+    ///
     /// # Panics
     ///
-    /// If the selected dimension more than one item.
+    /// If the dimension is greater than the number of dimensions of the tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::ones(Shape::new([2, 3]));
+    ///     let tensor = tensor.repeat(1, 2);
+    ///     println!("{:?}", tensor.shape());
+    ///     // Shape { dims: [2, 6] }
+    /// }
+    /// ```
     pub fn repeat(self, dim: usize, times: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("Repeat", dim));
+
         Self::new(K::repeat(self.primitive, dim, times))
     }
 
@@ -323,6 +493,40 @@ where
             dim,
         ))
     }
+
+    /// Stacks all tensors into a new one along a new dimension inserted at the given index.
+    ///
+    /// Unlike [cat](Tensor::cat), the output has one more dimension than the inputs, its size
+    /// being the number of tensors.
+    ///
+    /// # Panics
+    ///
+    /// If all tensors don't have the same shape or if the output doesn't have one more dimension
+    /// than the inputs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor_1 = Tensor::<B, 2>::ones(Shape::new([2, 3]));
+    ///     let tensor_2 = Tensor::<B, 2>::zeros(Shape::new([2, 3]));
+    ///     let tensor = Tensor::stack::<3>(vec![tensor_1, tensor_2], 0);
+    ///     println!("{:?}", tensor.dims()); // [2, 2, 3]
+    /// }
+    /// ```
+    pub fn stack<const D2: usize>(tensors: Vec<Self>, dim: usize) -> Tensor<B, D2, K> {
+        check!(TensorCheck::stack::<B, D, D2, K>(&tensors, dim));
+
+        let tensors = tensors
+            .into_iter()
+            .map(|tensor| tensor.unsqueeze_dim::<D2>(dim))
+            .collect();
+
+        Tensor::cat(tensors, dim)
+    }
 }
 
 impl<B, const D: usize, K> Tensor<B, D, K>
@@ -66,6 +66,119 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn diff<const D: usize>(dim: usize, n: usize, shape: &Shape<D>) -> Self {
+        let mut check = Self::dim_ops::<D>("Diff", dim);
+
+        if dim < D && n > shape.dims[dim] {
+            check = check.register(
+                "Diff",
+                TensorError::new(
+                    "The order of the difference can't be higher than the size of the dimension.",
+                )
+                .details(format!(
+                    "Order: '{n}', size of dimension '{dim}': '{}'.",
+                    shape.dims[dim]
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn moving_average<const D: usize>(
+        window: usize,
+        dim: usize,
+        shape: &Shape<D>,
+    ) -> Self {
+        let mut check = Self::dim_ops::<D>("MovingAverage", dim);
+
+        if dim < D && (window == 0 || window > shape.dims[dim]) {
+            check = check.register(
+                "MovingAverage",
+                TensorError::new("The window should be between one and the size of the dimension.")
+                    .details(format!(
+                        "Window: '{window}', size of dimension '{dim}': '{}'.",
+                        shape.dims[dim]
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn triangular<const D: usize>(ops: &str) -> Self {
+        let mut check = Self::Ok;
+
+        if D < 2 {
+            check = check.register(
+                ops,
+                TensorError::new("The tensor rank must be at least two.")
+                    .details(format!("Tensor rank: '{D}'.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn arange_float(step: f64) -> Self {
+        let mut check = Self::Ok;
+
+        if step == 0.0 || !step.is_finite() {
+            check = check.register(
+                "Arange Float",
+                TensorError::new("The step size must be finite and different from zero.")
+                    .details(format!("Step: '{step}'.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn topk<const D: usize>(k: usize, dim: usize, shape: &Shape<D>) -> Self {
+        let mut check = Self::dim_ops::<D>("TopK", dim);
+
+        if dim < D && k > shape.dims[dim] {
+            check = check.register(
+                "TopK",
+                TensorError::new(
+                    "The number of elements to keep is higher than the dimension size.",
+                )
+                .details(format!(
+                    "K: '{k}', dimension: '{dim}', size of dimension: '{}'.",
+                    shape.dims[dim]
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn chunk<const D: usize>(chunks: usize, dim: usize) -> Self {
+        let mut check = Self::dim_ops::<D>("Chunk", dim);
+
+        if chunks == 0 {
+            check = check.register(
+                "Chunk",
+                TensorError::new("The number of chunks should be greater than zero."),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn split<const D: usize>(split_size: usize, dim: usize) -> Self {
+        let mut check = Self::dim_ops::<D>("Split", dim);
+
+        if split_size == 0 {
+            check = check.register(
+                "Split",
+                TensorError::new("The split size should be greater than zero."),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn dim_ops<const D: usize>(ops: &str, dim: usize) -> Self {
         let mut check = Self::Ok;
 
@@ -130,6 +243,17 @@ impl TensorCheck {
             );
         }
 
+        if start_dim <= end_dim && D1 > end_dim && D2 != D1 - (end_dim - start_dim) {
+            check = check.register(
+                "Flatten",
+                TensorError::new(format!(
+                    "Result dim ({D2}) must be ({}) when flattening dims {start_dim} to {end_dim} \
+                     of a tensor with ({D1}) dimensions",
+                    D1 - (end_dim - start_dim)
+                )),
+            );
+        }
+
         check
     }
 
@@ -150,6 +274,52 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn squeeze_dims<const D1: usize, const D2: usize>(
+        dims: &[usize],
+        tensor_dims: &[usize],
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        for (index, dim) in dims.iter().enumerate() {
+            if *dim >= D1 {
+                check = check.register(
+                    "Squeeze Dims",
+                    TensorError::new(format!(
+                        "Can't squeeze dimension {dim} of a tensor with ({D1}) dimensions"
+                    )),
+                );
+            } else if tensor_dims[*dim] != 1 {
+                check = check.register(
+                    "Squeeze Dims",
+                    TensorError::new(format!(
+                        "Can't squeeze dimension {dim} because its size is not 1"
+                    )),
+                );
+            }
+
+            if dims[..index].contains(dim) {
+                check = check.register(
+                    "Squeeze Dims",
+                    TensorError::new(format!("Dimension {dim} is squeezed more than once")),
+                );
+            }
+        }
+
+        if D2 + dims.len() != D1 {
+            check = check.register(
+                "Squeeze Dims",
+                TensorError::new(format!(
+                    "Squeezing {} dimensions of a tensor with ({D1}) dimensions gives ({}) \
+                     dimensions, got ({D2})",
+                    dims.len(),
+                    D1.saturating_sub(dims.len())
+                )),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn unsqueeze<const D1: usize, const D2: usize>() -> Self {
         let mut check = Self::Ok;
         if D2 < D1 {
@@ -164,6 +334,31 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn unsqueeze_dim<const D1: usize, const D2: usize>(dim: usize) -> Self {
+        let mut check = Self::Ok;
+
+        if D2 != D1 + 1 {
+            check = check.register(
+                "Unsqueeze Dim",
+                TensorError::new(format!(
+                    "Can't unsqueeze a single dim, got dim {D2}, expected {}",
+                    D1 + 1
+                )),
+            );
+        }
+
+        if dim > D1 {
+            check = check.register(
+                "Unsqueeze Dim",
+                TensorError::new(format!(
+                    "Can't insert a dim at position {dim} on a tensor with ({D1}) dimensions"
+                )),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn swap_dims<const D: usize>(dim1: usize, dim2: usize) -> Self {
         let mut check = Self::Ok;
 
@@ -180,6 +375,52 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn permute<const D: usize>(axes: [usize; D]) -> Self {
+        let mut check = Self::Ok;
+        let mut seen = [false; D];
+
+        for axis in axes {
+            if axis >= D || seen[axis] {
+                check = check.register(
+                    "Permute",
+                    TensorError::new("The axes must be a permutation of the tensor dimensions")
+                        .details(format!("Axes {axes:?} on tensor with ({D}) dimensions.")),
+                );
+                break;
+            }
+
+            seen[axis] = true;
+        }
+
+        check
+    }
+
+    pub(crate) fn flip<const D: usize>(axes: &[usize]) -> Self {
+        let mut check = Self::Ok;
+
+        for (index, axis) in axes.iter().enumerate() {
+            if *axis >= D {
+                check = check.register(
+                    "Flip",
+                    TensorError::new("The axes must be lower than the tensor rank")
+                        .details(format!("Axes {axes:?} on tensor with ({D}) dimensions.")),
+                );
+                break;
+            }
+
+            if axes[..index].contains(axis) {
+                check = check.register(
+                    "Flip",
+                    TensorError::new("The axes must not contain duplicates")
+                        .details(format!("Axes {axes:?} on tensor with ({D}) dimensions.")),
+                );
+                break;
+            }
+        }
+
+        check
+    }
+
     pub(crate) fn matmul<B: Backend, const D: usize>(
         lhs: &Tensor<B, D>,
         rhs: &Tensor<B, D>,
@@ -211,6 +452,24 @@ impl TensorCheck {
             );
         }
 
+        for i in 0..D - 2 {
+            let size_lhs = shape_lhs.dims[i];
+            let size_rhs = shape_rhs.dims[i];
+
+            if size_lhs != size_rhs && size_lhs != 1 && size_rhs != 1 {
+                check = check.register(
+                    "Matmul",
+                    TensorError::new(format!(
+                        "The batch dimension {i} of matmul can't be broadcasted, got {size_lhs} and {size_rhs}."
+                    ))
+                    .details(format!(
+                        "Batch dimensions should be equal or one of them should be 1. Lhs shape {:?}, rhs shape {:?}.",
+                        shape_lhs.dims, shape_rhs.dims
+                    )),
+                );
+            }
+        }
+
         check
     }
 
@@ -264,6 +523,59 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn stack<B: Backend, const D1: usize, const D2: usize, K: BasicOps<B>>(
+        tensors: &[Tensor<B, D1, K>],
+        dim: usize,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if D2 != D1 + 1 {
+            check = check.register(
+                "Stack",
+                TensorError::new(
+                    "The output tensor should have one more dimension than the stacked tensors.",
+                )
+                .details(format!(
+                    "Stacked tensors dimensions: '{D1}', output dimensions: '{D2}'."
+                )),
+            );
+        }
+
+        if dim >= D2 {
+            check = check.register(
+                "Stack",
+                TensorError::new("Can't stack tensors on a dim that exceeds the output dimension")
+                    .details(format!(
+                        "Trying to stack tensors on axis {dim} of an output with {D2} dimensions."
+                    )),
+            );
+        }
+
+        if tensors.is_empty() {
+            return check.register(
+                "Stack",
+                TensorError::new("Can't stack an empty list of tensors."),
+            );
+        }
+
+        let shape_reference = tensors[0].shape();
+
+        if tensors
+            .iter()
+            .any(|tensor| tensor.shape() != shape_reference)
+        {
+            check = check.register(
+                "Stack",
+                TensorError::new("Can't stack tensors with different shapes.").details(format!(
+                    "Tensors shapes: {:?}",
+                    tensors.iter().map(Tensor::shape).collect::<Vec<_>>()
+                )),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn slice<const D1: usize, const D2: usize>(
         shape: &Shape<D1>,
         ranges: &[Range<usize>; D2],
@@ -440,6 +752,93 @@ impl TensorCheck {
         Self::check_select_basic::<D>(Self::Ok, "select_assign", dim)
     }
 
+    pub(crate) fn select_by_mask<const D: usize>(shape: &Shape<D>, shape_mask: &Shape<D>) -> Self {
+        let mut check = Self::Ok;
+
+        if shape != shape_mask {
+            check = check.register(
+                "SelectByMask",
+                TensorError::new("The mask shape should be the same as the tensor shape.").details(
+                    format!(
+                        "The shape differs: {:?} != {:?}",
+                        shape.dims, shape_mask.dims
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn batched_index_select<const D: usize>(
+        dim: usize,
+        shape: &Shape<D>,
+        shape_indices: &Shape<2>,
+    ) -> Self {
+        let ops = "BatchedIndexSelect";
+        let mut check = Self::Ok;
+
+        if dim == 0 || dim >= D {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "The first dimension is the batch, the selected dimension should come after it.",
+                )
+                .details(format!("Dimension: '{dim}', number of dimensions: '{D}'.")),
+            );
+        }
+
+        if shape.dims[0] != shape_indices.dims[0] {
+            check = check.register(
+                ops,
+                TensorError::new("The indices should have the same batch size as the tensor.")
+                    .details(format!(
+                        "Tensor batch size: '{}', indices batch size: '{}'.",
+                        shape.dims[0], shape_indices.dims[0]
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn quantize_per_channel<const D: usize>(
+        ops: &str,
+        axis: usize,
+        shape: &Shape<D>,
+        shape_scale: &Shape<1>,
+        shape_zero_point: &Shape<1>,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if axis >= D {
+            check = check.register(
+                ops,
+                TensorError::new("The channel axis should be smaller than the tensor rank.")
+                    .details(format!("Axis: '{axis}', number of dimensions: '{D}'.")),
+            );
+
+            return check;
+        }
+
+        let channels = shape.dims[axis];
+
+        if shape_scale.dims[0] != channels || shape_zero_point.dims[0] != channels {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "The scale and the zero point should have one value for each channel.",
+                )
+                .details(format!(
+                    "Channels: '{}', scale size: '{}', zero point size: '{}'.",
+                    channels, shape_scale.dims[0], shape_zero_point.dims[0]
+                )),
+            );
+        }
+
+        check
+    }
+
     fn check_select_basic<const D: usize>(mut check: Self, ops: &str, dim: usize) -> Self {
         if dim > D {
             check = check.register(
@@ -493,6 +892,51 @@ impl TensorCheck {
         check
     }
 
+    /// Checks that the grid of a grid sample matches the batch size of the input and contains
+    /// (x, y) coordinates.
+    pub(crate) fn grid_sample(shape: &Shape<4>, shape_grid: &Shape<4>) -> Self {
+        let mut check = Self::Ok;
+
+        if shape.dims[0] != shape_grid.dims[0] || shape_grid.dims[3] != 2 {
+            check = check.register(
+                "Grid Sample",
+                TensorError::new(
+                    "The grid should have the same batch size as the input and contain (x, y) coordinates.",
+                )
+                .details(format!(
+                    "Input shape: {:?}, grid shape: {:?}, expected grid shape: [{}, height_out, width_out, 2].",
+                    shape.dims, shape_grid.dims, shape.dims[0]
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn prelu<const D: usize>(shape: &Shape<D>, shape_alpha: &Shape<1>) -> Self {
+        let mut check = Self::Ok;
+        let [num_parameters] = shape_alpha.dims;
+
+        if num_parameters == 1 {
+            return check;
+        }
+
+        if D < 2 || shape.dims[1] != num_parameters {
+            check = check.register(
+                "PReLU",
+                TensorError::new(
+                    "The number of parameters should be one or the number of channels of the second dimension.",
+                )
+                .details(format!(
+                    "Tensor shape {:?}, number of parameters: '{num_parameters}'.",
+                    shape.dims
+                )),
+            );
+        }
+
+        check
+    }
+
     /// Checks aggregate dimension such as mean and sum.
     pub(crate) fn aggregate_dim<const D: usize>(ops: &str, dim: usize) -> Self {
         let mut check = Self::Ok;
@@ -509,6 +953,45 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn pixel_shuffle(shape: &Shape<4>, upscale_factor: usize) -> Self {
+        let mut check = Self::Ok;
+        let channels = shape.dims[1];
+
+        if upscale_factor == 0 || !channels.is_multiple_of(upscale_factor * upscale_factor) {
+            check = check.register(
+                "Pixel Shuffle",
+                TensorError::new(
+                    "The number of channels should be divisible by the square of the upscale factor.",
+                )
+                .details(format!(
+                    "Channels: '{channels}', upscale factor: '{upscale_factor}'."
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn pixel_unshuffle(shape: &Shape<4>, downscale_factor: usize) -> Self {
+        let mut check = Self::Ok;
+        let [_, _, height, width] = shape.dims;
+
+        if downscale_factor == 0 || height % downscale_factor != 0 || width % downscale_factor != 0
+        {
+            check = check.register(
+                "Pixel Unshuffle",
+                TensorError::new(
+                    "The height and the width should be divisible by the downscale factor.",
+                )
+                .details(format!(
+                    "Height: '{height}', width: '{width}', downscale factor: '{downscale_factor}'."
+                )),
+            );
+        }
+
+        check
+    }
+
     /// The goal is to minimize the cost of checks when there are no error, but it's way less
     /// important when an error occured, crafting a comprehensive error message is more important
     /// than optimizing string manipulation.
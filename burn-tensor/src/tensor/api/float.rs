@@ -6,8 +6,8 @@ use crate::check;
 use crate::check::TensorCheck;
 use crate::tensor::backend::Backend;
 use crate::tensor::stats;
-use crate::tensor::{Data, Distribution, Shape};
-use crate::Tensor;
+use crate::tensor::{Data, Distribution, ElementConversion, Shape};
+use crate::{Int, Tensor};
 
 impl<const D: usize, B> Tensor<B, D>
 where
@@ -59,6 +59,28 @@ where
         Self::new(B::erf(self.primitive))
     }
 
+    /// Applies element wise floor operation.
+    ///
+    /// `y = floor(x)`
+    pub fn floor(self) -> Self {
+        Self::new(B::floor(self.primitive))
+    }
+
+    /// Applies element wise ceil operation.
+    ///
+    /// `y = ceil(x)`
+    pub fn ceil(self) -> Self {
+        Self::new(B::ceil(self.primitive))
+    }
+
+    /// Applies element wise round operation, half-way cases are rounded to the nearest even
+    /// integer.
+    ///
+    /// `y = round(x)`
+    pub fn round(self) -> Self {
+        Self::new(B::round(self.primitive))
+    }
+
     /// Applies element wise power operation.
     ///
     /// `y = x^a`
@@ -66,6 +88,14 @@ where
         Self::new(B::powf(self.primitive, value))
     }
 
+    /// Applies element wise power operation with another tensor as the exponent.
+    ///
+    /// `y = x^e`
+    pub fn powf_tensor(self, exponent: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("PowfTensor", &self, &exponent));
+        Self::new(B::powf_tensor(self.primitive, exponent.primitive))
+    }
+
     /// Applies element wise root square operation.
     pub fn sqrt(self) -> Self {
         Self::new(B::sqrt(self.primitive))
@@ -81,6 +111,20 @@ where
         Self::new(B::sin(self.primitive))
     }
 
+    /// Applies the softmax function along the given dimension.
+    ///
+    /// See [softmax](crate::activation::softmax).
+    pub fn softmax(self, dim: usize) -> Self {
+        crate::activation::softmax(self, dim)
+    }
+
+    /// Applies the log softmax function along the given dimension.
+    ///
+    /// See [log_softmax](crate::activation::log_softmax).
+    pub fn log_softmax(self, dim: usize) -> Self {
+        crate::activation::log_softmax(self, dim)
+    }
+
     /// Applies element wise hyperbolic tangent operation.
     pub fn tanh(self) -> Self {
         Self::new(B::tanh(self.primitive))
@@ -166,6 +210,293 @@ where
         Self::new(B::swap_dims(self.primitive, dim1, dim2))
     }
 
+    /// Permute the dimensions of the tensor, where the output dimension `i` is the input
+    /// dimension `axes[i]`.
+    ///
+    /// # Panics
+    ///
+    /// If the axes are not a permutation of the tensor dimensions.
+    pub fn permute(self, axes: [usize; D]) -> Self {
+        check!(TensorCheck::permute::<D>(axes));
+        Self::new(B::permute(self.primitive, axes))
+    }
+
+    /// Reverse the order of the elements along each of the given axes.
+    ///
+    /// # Panics
+    ///
+    /// If an axis exceeds the tensor rank or appears more than once.
+    pub fn flip(self, axes: &[usize]) -> Self {
+        check!(TensorCheck::flip::<D>(axes));
+        Self::new(B::flip(self.primitive, axes))
+    }
+
+    /// Assign the gathered elements corresponding to the given indices along the specified
+    /// dimension from the value tensor to the original tensor, keeping the maximum at each
+    /// position.
+    ///
+    /// Example using a 3D tensor with `dim = 0`:
+    ///
+    /// `input[indices[i, j, k], j, k] = max(input[indices[i, j, k], j, k], values[i, j, k])`
+    ///
+    /// # Notes
+    ///
+    /// The gradient only flows to the element kept at each position, the existing element being
+    /// kept on ties.
+    pub fn scatter_max(self, dim: usize, indices: Tensor<B, D, Int>, values: Self) -> Self {
+        check!(TensorCheck::scatter::<D>(
+            dim,
+            &self.shape(),
+            &indices.shape(),
+            &values.shape()
+        ));
+
+        Self::new(B::scatter_max(
+            dim,
+            self.primitive,
+            indices.primitive,
+            values.primitive,
+        ))
+    }
+
+    /// Assign the gathered elements corresponding to the given indices along the specified
+    /// dimension from the value tensor to the original tensor, keeping the minimum at each
+    /// position.
+    ///
+    /// Example using a 3D tensor with `dim = 0`:
+    ///
+    /// `input[indices[i, j, k], j, k] = min(input[indices[i, j, k], j, k], values[i, j, k])`
+    ///
+    /// # Notes
+    ///
+    /// The gradient only flows to the element kept at each position, the existing element being
+    /// kept on ties.
+    pub fn scatter_min(self, dim: usize, indices: Tensor<B, D, Int>, values: Self) -> Self {
+        check!(TensorCheck::scatter::<D>(
+            dim,
+            &self.shape(),
+            &indices.shape(),
+            &values.shape()
+        ));
+
+        Self::new(B::scatter_min(
+            dim,
+            self.primitive,
+            indices.primitive,
+            values.primitive,
+        ))
+    }
+
+    /// Applies element wise maximum between two tensors.
+    ///
+    /// `y = max(x1, x2)`
+    ///
+    /// # Notes
+    ///
+    /// The gradient flows to the first tensor on ties.
+    pub fn max_pair(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Max Pair", &self, &other));
+        Self::new(B::maximum(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise minimum between two tensors.
+    ///
+    /// `y = min(x1, x2)`
+    ///
+    /// # Notes
+    ///
+    /// The gradient flows to the first tensor on ties.
+    pub fn min_pair(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Min Pair", &self, &other));
+        Self::new(B::minimum(self.primitive, other.primitive))
+    }
+
+    /// Clamps the tensor between the given minimum and maximum values.
+    ///
+    /// `y = min(max(x, min), max)`
+    ///
+    /// # Notes
+    ///
+    /// The gradient is zero where the input falls outside of `[min, max]`.
+    pub fn clamp<E: ElementConversion>(self, min: E, max: E) -> Self {
+        Self::new(B::clamp(self.primitive, min.elem(), max.elem()))
+    }
+
+    /// Clamps the tensor under the given minimum value.
+    ///
+    /// `y = max(x, min)`
+    pub fn clamp_min<E: ElementConversion>(self, min: E) -> Self {
+        Self::new(B::clamp_min(self.primitive, min.elem()))
+    }
+
+    /// Clamps the tensor over the given maximum value.
+    ///
+    /// `y = min(x, max)`
+    pub fn clamp_max<E: ElementConversion>(self, max: E) -> Self {
+        Self::new(B::clamp_max(self.primitive, max.elem()))
+    }
+
+    /// Computes the n-th order discrete difference along the given dimension.
+    ///
+    /// `y[i] = x[i + 1] - x[i]`, applied `n` times, so the size of the dimension is reduced
+    /// by `n`.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension exceeds the tensor rank or if `n` is higher than its size.
+    pub fn diff(self, dim: usize, n: usize) -> Self {
+        check!(TensorCheck::diff::<D>(dim, n, &self.shape()));
+        Self::new(B::diff(self.primitive, dim, n))
+    }
+
+    /// Computes the cumulative sum of the elements along the given dimension.
+    ///
+    /// `y[i] = x[0] + x[1] + ... + x[i]`, so the shape of the tensor is unchanged.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension exceeds the tensor rank.
+    pub fn cumsum(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("Cumsum", dim));
+        Self::new(B::cumsum(self.primitive, dim))
+    }
+
+    /// Returns the lower triangular part of the matrices formed by the last two dimensions,
+    /// setting the elements above the given diagonal to zero.
+    ///
+    /// A `diagonal` of `0` keeps the main diagonal, positive values keep diagonals above it and
+    /// negative values exclude diagonals below it.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor has less than two dimensions.
+    pub fn tril(self, diagonal: i64) -> Self {
+        check!(TensorCheck::triangular::<D>("Tril"));
+        Self::new(B::tril(self.primitive, diagonal))
+    }
+
+    /// Returns the upper triangular part of the matrices formed by the last two dimensions,
+    /// setting the elements below the given diagonal to zero.
+    ///
+    /// A `diagonal` of `0` keeps the main diagonal, negative values keep diagonals below it and
+    /// positive values exclude diagonals above it.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor has less than two dimensions.
+    pub fn triu(self, diagonal: i64) -> Self {
+        check!(TensorCheck::triangular::<D>("Triu"));
+        Self::new(B::triu(self.primitive, diagonal))
+    }
+
+    /// Returns the `k` largest elements along the given dimension, sorted in descending order,
+    /// with their indices.
+    ///
+    /// When multiple elements are equal, the one with the lowest index comes first.
+    pub fn topk(self, k: usize, dim: usize) -> (Self, Tensor<B, D, Int>) {
+        check!(TensorCheck::topk::<D>(k, dim, &self.shape()));
+
+        let (values, indices) = B::topk(self.primitive, k, dim);
+
+        (Self::new(values), Tensor::new(indices))
+    }
+
+    /// Sorts the elements along the given dimension and returns them with their indices in the
+    /// original tensor.
+    ///
+    /// The sort is stable, equal elements keep their original order.
+    pub fn sort(self, dim: usize, descending: bool) -> (Self, Tensor<B, D, Int>) {
+        check!(TensorCheck::dim_ops::<D>("Sort", dim));
+
+        let (values, indices) = B::sort(self.primitive, dim, descending);
+
+        (Self::new(values), Tensor::new(indices))
+    }
+
+    /// Computes the average of each window of the given size along the given dimension.
+    ///
+    /// `y[i] = (x[i] + ... + x[i + window - 1]) / window`, so the size of the dimension is
+    /// reduced by `window - 1`.
+    ///
+    /// # Notes
+    ///
+    /// The window sums are computed from the difference of a cumulative sum, so the cost
+    /// doesn't depend on the window size.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension exceeds the tensor rank or if the window is empty or larger than the
+    /// size of the dimension.
+    pub fn moving_average(self, window: usize, dim: usize) -> Self {
+        check!(TensorCheck::moving_average::<D>(window, dim, &self.shape()));
+        stats::moving_average(self, window, dim)
+    }
+
+    /// Quantize the tensor to the int8 range with a scale and a zero point for each channel
+    /// along the given axis.
+    ///
+    /// `q = clamp(round(x / scale) + zero_point, -128, 127)`, rounding half to even.
+    ///
+    /// # Panics
+    ///
+    /// If the axis exceeds the tensor rank or if the scale and the zero point don't have one
+    /// value for each channel.
+    pub fn quantize_per_channel(
+        self,
+        scale: Tensor<B, 1>,
+        zero_point: Tensor<B, 1, Int>,
+        axis: usize,
+    ) -> Tensor<B, D, Int> {
+        check!(TensorCheck::quantize_per_channel::<D>(
+            "QuantizePerChannel",
+            axis,
+            &self.shape(),
+            &scale.shape(),
+            &zero_point.shape()
+        ));
+
+        Tensor::new(B::quantize_per_channel(
+            self.primitive,
+            scale.primitive,
+            zero_point.primitive,
+            axis,
+        ))
+    }
+
+    /// Quantize and dequantize the tensor with a scale and a zero point for each channel along
+    /// the given axis, which is used for quantization-aware training.
+    ///
+    /// # Notes
+    ///
+    /// The gradient is passed through unchanged (straight-through estimator) for the elements
+    /// within the range that can be represented, and is zero for the clamped elements.
+    ///
+    /// # Panics
+    ///
+    /// If the axis exceeds the tensor rank or if the scale and the zero point don't have one
+    /// value for each channel.
+    pub fn fake_quantize_per_channel(
+        self,
+        scale: Tensor<B, 1>,
+        zero_point: Tensor<B, 1, Int>,
+        axis: usize,
+    ) -> Self {
+        check!(TensorCheck::quantize_per_channel::<D>(
+            "FakeQuantizePerChannel",
+            axis,
+            &self.shape(),
+            &scale.shape(),
+            &zero_point.shape()
+        ));
+
+        Self::new(B::fake_quantize_per_channel(
+            self.primitive,
+            scale.primitive,
+            zero_point.primitive,
+            axis,
+        ))
+    }
+
     /// Applies the matrix multiplication operation.
     ///
     /// `C = AB`
@@ -190,23 +521,24 @@ where
 
     /// Calculate the variance along the given dimension and also returns the mean.
     pub fn var_mean(self, dim: usize) -> (Self, Self) {
-        let mean = self.clone().mean_dim(dim);
-        let var = stats::var_with_mean(self, mean.clone(), dim);
-        (var, mean)
+        let (var, mean) = B::var_mean(self.primitive, dim, true);
+        (Self::new(var), Self::new(mean))
     }
 
     /// Calculate the variance along the given dimension without applying the Bessel’s correction and also returns the mean.
     pub fn var_mean_bias(self, dim: usize) -> (Self, Self) {
-        let mean = self.clone().mean_dim(dim);
-        let var = stats::var_with_mean_bias(self, mean.clone(), dim);
-        (var, mean)
+        let (var, mean) = B::var_mean(self.primitive, dim, false);
+        (Self::new(var), Self::new(mean))
     }
 
-    /// Create a random tensor of the given shape where each element is sampled from the given
-    /// distribution.
-    pub fn random<S: Into<Shape<D>>>(shape: S, distribution: Distribution<B::FloatElem>) -> Self {
-        let tensor = B::random(shape.into(), distribution, &B::Device::default());
-        Self::new(tensor)
+    /// Calculate the standard deviation along the given dimension.
+    pub fn std(self, dim: usize) -> Self {
+        stats::std(self, dim)
+    }
+
+    /// Calculate the standard deviation along the given dimension without applying the Bessel’s correction.
+    pub fn std_bias(self, dim: usize) -> Self {
+        stats::std_bias(self, dim)
     }
 
     /// Returns a tensor with full precision based on the selected backend.
@@ -219,6 +551,44 @@ where
         Self::new(B::from_full_precision(tensor.primitive))
     }
 
+    /// Converts the float tensor into an int tensor, truncating the values toward zero.
+    pub fn into_int(self) -> Tensor<B, D, Int> {
+        Tensor::new(B::into_int(self.primitive))
+    }
+
+    /// Aggregate all elements in the tensor with the sum operation, accumulating in full precision.
+    ///
+    /// # Notes
+    ///
+    /// This is useful with half precision backends, where the accumulation may overflow or lose
+    /// precision. The result is cast back to the backend precision.
+    pub fn sum_full_precision(self) -> Tensor<B, 1> {
+        Tensor::from_full_precision(self.to_full_precision().sum())
+    }
+
+    /// Aggregate all elements in the tensor with the mean operation, accumulating in full precision.
+    ///
+    /// See [sum_full_precision](Tensor::sum_full_precision) for more details.
+    pub fn mean_full_precision(self) -> Tensor<B, 1> {
+        Tensor::from_full_precision(self.to_full_precision().mean())
+    }
+
+    /// Aggregate all elements along the given *dimension* or *axis* in the tensor with the sum
+    /// operation, accumulating in full precision.
+    ///
+    /// See [sum_full_precision](Tensor::sum_full_precision) for more details.
+    pub fn sum_dim_full_precision(self, dim: usize) -> Self {
+        Self::from_full_precision(self.to_full_precision().sum_dim(dim))
+    }
+
+    /// Aggregate all elements along the given *dimension* or *axis* in the tensor with the mean
+    /// operation, accumulating in full precision.
+    ///
+    /// See [sum_full_precision](Tensor::sum_full_precision) for more details.
+    pub fn mean_dim_full_precision(self, dim: usize) -> Self {
+        Self::from_full_precision(self.to_full_precision().mean_dim(dim))
+    }
+
     /// Detach the current tensor from the autodiff graph.
     /// This function does nothing when autodiff is not enabled.
     /// This can be used in batchers or elsewere to ensure that previous operations are not
@@ -252,6 +622,74 @@ where
     }
 }
 
+impl<B> Tensor<B, 1>
+where
+    B: Backend,
+{
+    /// Returns a new float tensor on the default device with values from `start` (inclusive) to
+    /// `end` (exclusive) spaced by `step`.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The first value.
+    /// * `end` - The bound of the values, never included.
+    /// * `step` - The step between each value, can be negative but not zero.
+    pub fn arange_float(start: f64, end: f64, step: f64) -> Self {
+        Self::arange_float_device(start, end, step, &B::Device::default())
+    }
+
+    /// Returns a new float tensor on the specified device with values from `start` (inclusive) to
+    /// `end` (exclusive) spaced by `step`.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The first value.
+    /// * `end` - The bound of the values, never included.
+    /// * `step` - The step between each value, can be negative but not zero.
+    /// * `device` - The device to create the tensor on.
+    pub fn arange_float_device(start: f64, end: f64, step: f64, device: &B::Device) -> Self {
+        check!(TensorCheck::arange_float(step));
+
+        Self::new(B::arange_float(start, end, step, device))
+    }
+
+    /// Returns a new float tensor on the default device with `steps` evenly spaced values from
+    /// `start` to `end`, both inclusive.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The first value.
+    /// * `end` - The last value.
+    /// * `steps` - The number of values to generate.
+    pub fn linspace(start: f64, end: f64, steps: usize) -> Self {
+        Self::linspace_device(start, end, steps, &B::Device::default())
+    }
+
+    /// Returns a new float tensor on the specified device with `steps` evenly spaced values from
+    /// `start` to `end`, both inclusive.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The first value.
+    /// * `end` - The last value.
+    /// * `steps` - The number of values to generate.
+    /// * `device` - The device to create the tensor on.
+    ///
+    /// # Remarks
+    ///
+    /// When `steps` is 1, the tensor only contains `start`.
+    pub fn linspace_device(start: f64, end: f64, steps: usize, device: &B::Device) -> Self {
+        let step = match steps {
+            0 | 1 => 0.0,
+            _ => (end - start) / (steps - 1) as f64,
+        };
+
+        Self::arange_float_device(0.0, steps as f64, 1.0, device)
+            .mul_scalar(step)
+            .add_scalar(start)
+    }
+}
+
 impl<const D: usize, B: ADBackend> Tensor<B, D> {
     /// Backward pass of the tensor.
     pub fn backward(&self) -> B::Gradients {
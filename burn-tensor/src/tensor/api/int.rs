@@ -1,3 +1,5 @@
+use crate::check;
+use crate::check::TensorCheck;
 use crate::{backend::Backend, Data, Int, Tensor};
 use core::ops::Range;
 
@@ -43,6 +45,46 @@ where
     pub fn arange_step_device(range: Range<usize>, step: usize, device: &B::Device) -> Self {
         Tensor::new(B::arange_step(range, step, device))
     }
+
+    /// Encodes each index as a one-hot row of `num_classes` float values.
+    ///
+    /// Indices outside of `0..num_classes` are encoded as rows of zeros.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let indices = Tensor::<B, 1, Int>::from_ints([0, 2]);
+    ///     let one_hot = indices.one_hot(3);
+    ///     println!("{}", one_hot.to_data());
+    ///     // [[1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]
+    /// }
+    /// ```
+    pub fn one_hot(self, num_classes: usize) -> Tensor<B, 2> {
+        let [num_indices] = self.dims();
+        let device = self.device();
+        let num_classes_elem = num_classes as i64;
+
+        // Negative indices are moved above the number of classes, so a single comparison finds
+        // every out of range index.
+        let indices = self.clone().mask_fill(self.lower_elem(0), num_classes_elem);
+        let valid = indices.clone().lower_elem(num_classes_elem);
+        let indices = indices
+            .clone()
+            .mask_fill(indices.greater_equal_elem(num_classes_elem), 0);
+
+        let values = Tensor::<B, 2>::zeros_device([num_indices, 1], &device)
+            .mask_fill(valid.reshape([num_indices, 1]), 1.0);
+
+        Tensor::zeros_device([num_indices, num_classes], &device).scatter(
+            1,
+            indices.reshape([num_indices, 1]),
+            values,
+        )
+    }
 }
 
 impl<const D: usize, B> Tensor<B, D, Int>
@@ -65,4 +107,44 @@ where
     pub fn from_ints<A: Into<Data<i32, D>>>(ints: A) -> Self {
         Self::from_data(ints.into().convert())
     }
+
+    /// Converts the int tensor into a float tensor.
+    pub fn into_float(self) -> Tensor<B, D> {
+        Tensor::new(B::int_into_float(self.primitive))
+    }
+
+    /// Dequantize the tensor with a scale and a zero point for each channel along the given axis.
+    ///
+    /// `x = (q - zero_point) * scale`
+    ///
+    /// # Notes
+    ///
+    /// The gradient of the scale of each channel is `grad * (q - zero_point)` summed over the
+    /// elements of the channel.
+    ///
+    /// # Panics
+    ///
+    /// If the axis exceeds the tensor rank or if the scale and the zero point don't have one
+    /// value for each channel.
+    pub fn dequantize_per_channel(
+        self,
+        scale: Tensor<B, 1>,
+        zero_point: Tensor<B, 1, Int>,
+        axis: usize,
+    ) -> Tensor<B, D> {
+        check!(TensorCheck::quantize_per_channel::<D>(
+            "DequantizePerChannel",
+            axis,
+            &self.shape(),
+            &scale.shape(),
+            &zero_point.shape()
+        ));
+
+        Tensor::new(B::dequantize_per_channel(
+            self.primitive,
+            scale.primitive,
+            zero_point.primitive,
+            axis,
+        ))
+    }
 }
@@ -1,6 +1,8 @@
+use alloc::vec::Vec;
+
 use crate::{
-    backend::Backend, check, check::TensorCheck, BasicOps, Bool, Element, ElementConversion, Float,
-    Int, Shape, Tensor, TensorKind,
+    backend::Backend, check, check::TensorCheck, BasicOps, Bool, Data, Distribution, Element,
+    ElementConversion, Float, Int, Shape, Tensor, TensorKind,
 };
 
 impl<B, const D: usize, K> Tensor<B, D, K>
@@ -91,6 +93,13 @@ where
         Self::new(K::neg(self.primitive))
     }
 
+    /// Create a random tensor of the given shape where each element is sampled from the given
+    /// distribution.
+    pub fn random<S: Into<Shape<D>>>(shape: S, distribution: Distribution<K::Elem>) -> Self {
+        let tensor = K::random(shape.into(), distribution, &B::Device::default());
+        Self::new(tensor)
+    }
+
     /// Create a tensor of the given shape where each element is zero.
     pub fn zeros<S: Into<Shape<D>>>(shape: S) -> Self {
         Self::zeros_device(shape, &B::Device::default())
@@ -215,7 +224,9 @@ where
     /// Update the given tensor with the value tensor where the mask is true.
     ///
     /// This is similar to [mask_fill](Tensor::mask_fill), however the value is a tensor instead of
-    /// a scalar.
+    /// a scalar. It selects elements from `value` where the mask is true and from the current
+    /// tensor otherwise, so `a.mask_where(cond, b)` is equivalent to `where(cond, b, a)`. The mask
+    /// and the value can be broadcasted to the shape of the tensor.
     pub fn mask_where(self, mask: Tensor<B, D, Bool>, value: Self) -> Self {
         Self::new(K::mask_where(self.primitive, mask, value.primitive))
     }
@@ -312,6 +323,78 @@ where
         ))
     }
 
+    /// Select the tensor elements along the given dimension using different indices for each
+    /// element of the batch, the batch being the first dimension.
+    ///
+    /// Example using a 3D tensor:
+    ///
+    /// `output[i, j, k] = input[i, indices[i, j], k]; // dim = 1`
+    /// `output[i, j, k] = input[i, j, indices[i, k]]; // dim = 2`
+    ///
+    /// # Notes
+    ///
+    /// The indices are broadcasted to the other dimensions and used with [gather](Tensor::gather),
+    /// so the gradients are scattered back to the selected positions of each batch element.
+    pub fn batched_index_select(self, dim: usize, indices: Tensor<B, 2, Int>) -> Self {
+        check!(TensorCheck::batched_index_select::<D>(
+            dim,
+            &self.shape(),
+            &indices.shape()
+        ));
+
+        let dims = self.dims();
+        let [batch_size, num_indices] = indices.dims();
+
+        let mut shape = [1; D];
+        shape[0] = batch_size;
+        shape[dim] = num_indices;
+        let mut indices = indices.reshape(shape);
+
+        for (i, size) in dims.into_iter().enumerate().skip(1) {
+            if i != dim && size > 1 {
+                indices = indices.repeat(i, size);
+            }
+        }
+
+        self.gather(dim, indices)
+    }
+
+    /// Select the tensor elements where the mask is true.
+    ///
+    /// Returns the selected values along with their indices in the flattened tensor, in
+    /// row-major order. Since the selection is done with [select](Tensor::select), gradients of
+    /// the values are propagated back to the selected positions.
+    ///
+    /// # Notes
+    ///
+    /// The number of selected elements depends on the values of the mask, which is therefore read
+    /// back from the device.
+    pub fn select_by_mask(self, mask: Tensor<B, D, Bool>) -> (Tensor<B, 1, K>, Tensor<B, 1, Int>) {
+        check!(TensorCheck::select_by_mask::<D>(
+            &self.shape(),
+            &mask.shape()
+        ));
+
+        let device = self.device();
+        let num_elements = self.shape().num_elements();
+        let indices: Vec<i64> = mask
+            .into_data()
+            .value
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, selected)| selected.then_some(index as i64))
+            .collect();
+        let num_selected = indices.len();
+        let indices = Tensor::from_data_device(
+            Data::new(indices, Shape::new([num_selected])).convert(),
+            &device,
+        );
+
+        let values = self.reshape([num_elements]).select(0, indices.clone());
+
+        (values, indices)
+    }
+
     /// Applies the argmax function along the given dimension and returns an integer tensor.
     ///
     /// # Example
@@ -679,6 +762,32 @@ where
         device: &B::Device,
     ) -> Self::Primitive<D>;
 
+    /// Creates a tensor with random values sampled from the given distribution.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - The shape of the tensor.
+    /// * `distribution` - The distribution to sample from.
+    /// * `device` - The device on which the tensor will be allocated.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with random values.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For creating a random tensor, users should prefer the [Tensor::random](Tensor::random) function,
+    /// which is more high-level and designed for public use.
+    fn random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution<Self::Elem>,
+        device: &B::Device,
+    ) -> Self::Primitive<D>;
+
     /// Sums all the elements of the tensor.
     ///
     /// # Arguments
@@ -1403,6 +1512,13 @@ impl<B: Backend> Numeric<B> for Int {
     ) -> Self::Primitive<D> {
         B::int_full(shape, fill_value.elem(), device)
     }
+    fn random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution<Self::Elem>,
+        device: &B::Device,
+    ) -> Self::Primitive<D> {
+        B::int_random(shape, distribution, device)
+    }
     fn sum<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1> {
         B::int_sum(tensor)
     }
@@ -1631,6 +1747,13 @@ impl<B: Backend> Numeric<B> for Float {
     ) -> Self::Primitive<D> {
         B::full(shape, fill_value.elem(), device)
     }
+    fn random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution<Self::Elem>,
+        device: &B::Device,
+    ) -> Self::Primitive<D> {
+        B::random(shape, distribution, device)
+    }
     fn sum<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1> {
         B::sum(tensor)
     }
@@ -28,7 +28,8 @@ pub struct Data<E, const D: usize> {
 /// Distribution for random value of a tensor.
 #[derive(Clone, Copy)]
 pub enum Distribution<E> {
-    /// Uniform distribution from 0 (inclusive) to 1 (exclusive).
+    /// Uniform distribution from 0 (inclusive) to 1 (exclusive) for floats, and over the whole
+    /// range of the element type for ints.
     Default,
 
     /// Bernoulli distribution with the given probability.
@@ -0,0 +1,48 @@
+use crate::backend::Backend;
+use crate::Tensor;
+
+/// Default epsilon used by [cosine similarity](cosine_similarity) to avoid dividing by zero.
+pub const DEFAULT_EPSILON: f64 = 1e-8;
+
+/// Computes the cosine similarity between two tensors along the given dimension.
+///
+/// `similarity = sum(a * b) / (max(||a||, eps) * max(||b||, eps))`
+///
+/// # Arguments
+///
+/// * `a` - The first tensor.
+/// * `b` - The second tensor, with the same shape as the first one.
+/// * `dim` - The dimension along which the vectors are compared.
+/// * `eps` - The minimum value of each norm, avoiding a division by zero for zero vectors.
+///
+/// # Returns
+///
+/// The cosine similarity, with the given dimension reduced to a size of one.
+pub fn cosine_similarity<B: Backend, const D: usize>(
+    a: Tensor<B, D>,
+    b: Tensor<B, D>,
+    dim: usize,
+    eps: f64,
+) -> Tensor<B, D> {
+    let dot = a.clone().mul(b.clone()).sum_dim(dim);
+    let norm_a = clamped_norm(a, dim, eps);
+    let norm_b = clamped_norm(b, dim, eps);
+
+    dot.div(norm_a.mul(norm_b))
+}
+
+/// L2 norm along the given dimension, with a minimum value of `eps`.
+///
+/// The squared norm is clamped before taking the square root, so that the gradient stays finite
+/// for zero vectors.
+fn clamped_norm<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    dim: usize,
+    eps: f64,
+) -> Tensor<B, D> {
+    let min = eps * eps;
+    let norm_squared = tensor.powf(2.0).sum_dim(dim);
+    let mask = norm_squared.clone().lower_elem(min);
+
+    norm_squared.mask_fill(mask, min).sqrt()
+}
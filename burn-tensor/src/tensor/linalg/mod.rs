@@ -0,0 +1,3 @@
+mod cosine_similarity;
+
+pub use cosine_similarity::*;
@@ -0,0 +1,80 @@
+use super::Reduction;
+use crate::backend::Backend;
+use crate::Tensor;
+
+/// Computes the Kullback-Leibler divergence `KL(q || p)` between the distributions along the given
+/// dimension.
+///
+/// `divergence = sum(q * (log(q) - log_p))`
+///
+/// The input is expected as log-probabilities for numerical stability, while the target is
+/// expected as probabilities, following the convention of PyTorch's `kl_div`. Target
+/// probabilities of zero don't contribute to the divergence.
+///
+/// # Arguments
+///
+/// * `log_p` - The log-probabilities of the input distributions.
+/// * `q` - The probabilities of the target distributions, with the same shape as the input.
+/// * `dim` - The dimension along which the distributions are defined.
+/// * `reduction` - How the divergences of the distributions are reduced. The mean is taken over
+///   the distributions rather than the elements, matching PyTorch's `batchmean` reduction.
+///
+/// # Returns
+///
+/// The reduced divergence.
+pub fn kl_divergence<B: Backend, const D: usize>(
+    log_p: Tensor<B, D>,
+    q: Tensor<B, D>,
+    dim: usize,
+    reduction: Reduction,
+) -> Tensor<B, 1> {
+    let zeros = q.clone().lower_equal_elem(0.0);
+    let log_q = q.clone().mask_fill(zeros, 1.0).log();
+    let divergence = q.mul(log_q.sub(log_p)).sum_dim(dim);
+
+    reduce(divergence, reduction)
+}
+
+/// Computes the Jensen-Shannon divergence between the distributions along the given dimension.
+///
+/// `divergence = (KL(p || m) + KL(q || m)) / 2`, where `m = (p + q) / 2`
+///
+/// Probabilities of zero don't contribute to the divergence.
+///
+/// # Arguments
+///
+/// * `log_p` - The log-probabilities of the first distributions.
+/// * `log_q` - The log-probabilities of the second distributions, with the same shape as the
+///   first ones.
+/// * `dim` - The dimension along which the distributions are defined.
+/// * `reduction` - How the divergences of the distributions are reduced. The mean is taken over
+///   the distributions rather than the elements.
+///
+/// # Returns
+///
+/// The reduced divergence.
+pub fn js_divergence<B: Backend, const D: usize>(
+    log_p: Tensor<B, D>,
+    log_q: Tensor<B, D>,
+    dim: usize,
+    reduction: Reduction,
+) -> Tensor<B, 1> {
+    let p = log_p.clone().exp();
+    let q = log_q.clone().exp();
+    let log_m = p.clone().add(q.clone()).div_scalar(2.0).log();
+
+    let zeros_p = p.clone().lower_equal_elem(0.0);
+    let zeros_q = q.clone().lower_equal_elem(0.0);
+    let divergence_p = p.mul(log_p.sub(log_m.clone()).mask_fill(zeros_p, 0.0));
+    let divergence_q = q.mul(log_q.sub(log_m).mask_fill(zeros_q, 0.0));
+    let divergence = divergence_p.add(divergence_q).sum_dim(dim).div_scalar(2.0);
+
+    reduce(divergence, reduction)
+}
+
+fn reduce<B: Backend, const D: usize>(tensor: Tensor<B, D>, reduction: Reduction) -> Tensor<B, 1> {
+    match reduction {
+        Reduction::Mean | Reduction::Auto => tensor.mean(),
+        Reduction::Sum => tensor.sum(),
+    }
+}
@@ -1,3 +1,9 @@
+mod divergence;
+mod reduction;
+
+pub use divergence::*;
+pub use reduction::*;
+
 use crate::backend::Backend;
 use crate::{activation, Tensor};
 
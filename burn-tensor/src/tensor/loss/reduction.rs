@@ -0,0 +1,12 @@
+/// The reduction type for the loss.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Reduction {
+    /// The mean of the losses will be returned.
+    Mean,
+
+    /// The sum of the losses will be returned.
+    Sum,
+
+    /// The mean of the losses will be returned.
+    Auto,
+}
@@ -19,6 +19,9 @@ pub mod backend;
 /// The container module.
 pub mod container;
 
+/// The linear algebra module.
+pub mod linalg;
+
 /// The loss module.
 pub mod loss;
 
@@ -1,6 +1,8 @@
 use crate::{
     backend::Backend,
-    ops::{ConvOptions, ConvTransposeOptions},
+    check,
+    check::TensorCheck,
+    ops::{ConvOptions, ConvTransposeOptions, GridSampleMode, GridSamplePadding, InterpolateMode},
     Int, Tensor,
 };
 
@@ -84,6 +86,19 @@ where
     ))
 }
 
+/// Applies a [1D max pooling](crate::ops::ModuleOps::max_pool1d).
+pub fn max_pool1d<B>(
+    x: Tensor<B, 3>,
+    kernel_size: usize,
+    stride: usize,
+    padding: usize,
+) -> Tensor<B, 3>
+where
+    B: Backend,
+{
+    Tensor::new(B::max_pool1d(x.primitive, kernel_size, stride, padding))
+}
+
 /// Applies a [2D max pooling](crate::ops::ModuleOps::max_pool2d).
 pub fn max_pool2d<B>(
     x: Tensor<B, 4>,
@@ -110,6 +125,14 @@ where
     Tensor::new(B::avg_pool2d(x.primitive, kernel_size, stride, padding))
 }
 
+/// Applies a [2D adaptive avg pooling](crate::ops::ModuleOps::adaptive_avg_pool2d).
+pub fn adaptive_avg_pool2d<B>(x: Tensor<B, 4>, output_size: [usize; 2]) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    Tensor::new(B::adaptive_avg_pool2d(x.primitive, output_size))
+}
+
 /// Applies a [1D avg pooling](crate::ops::ModuleOps::avg_pool1d).
 pub fn avg_pool1d<B>(
     x: Tensor<B, 3>,
@@ -123,6 +146,29 @@ where
     Tensor::new(B::avg_pool1d(x.primitive, kernel_size, stride, padding))
 }
 
+/// Applies a [1D interpolation](crate::ops::ModuleOps::interpolate1d).
+pub fn interpolate1d<B>(x: Tensor<B, 3>, output_size: usize, mode: InterpolateMode) -> Tensor<B, 3>
+where
+    B: Backend,
+{
+    Tensor::new(B::interpolate1d(x.primitive, output_size, mode))
+}
+
+/// Applies a [1D max pooling with indices](crate::ops::ModuleOps::max_pool1d_with_indices).
+pub fn max_pool1d_with_indices<B>(
+    x: Tensor<B, 3>,
+    kernel_size: usize,
+    stride: usize,
+    padding: usize,
+) -> (Tensor<B, 3>, Tensor<B, 3, Int>)
+where
+    B: Backend,
+{
+    let output = B::max_pool1d_with_indices(x.primitive, kernel_size, stride, padding);
+
+    (Tensor::new(output.output), Tensor::new(output.indices))
+}
+
 /// Applies a [2D max pooling with indices](crate::ops::ModuleOps::max_pool2d_with_indices).
 pub fn max_pool2d_with_indices<B>(
     x: Tensor<B, 4>,
@@ -137,3 +183,46 @@ where
 
     (Tensor::new(output.output), Tensor::new(output.indices))
 }
+
+/// Applies a [pixel shuffle](crate::ops::ModuleOps::pixel_shuffle).
+///
+/// # Panics
+///
+/// If the number of channels isn't divisible by the square of the upscale factor.
+pub fn pixel_shuffle<B>(x: Tensor<B, 4>, upscale_factor: usize) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    check!(TensorCheck::pixel_shuffle(&x.shape(), upscale_factor));
+
+    Tensor::new(B::pixel_shuffle(x.primitive, upscale_factor))
+}
+
+/// Applies a [pixel unshuffle](crate::ops::ModuleOps::pixel_unshuffle).
+///
+/// # Panics
+///
+/// If the height or the width isn't divisible by the downscale factor.
+pub fn pixel_unshuffle<B>(x: Tensor<B, 4>, downscale_factor: usize) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    check!(TensorCheck::pixel_unshuffle(&x.shape(), downscale_factor));
+
+    Tensor::new(B::pixel_unshuffle(x.primitive, downscale_factor))
+}
+
+/// Applies a [grid sampling](crate::ops::ModuleOps::grid_sample).
+pub fn grid_sample<B>(
+    x: Tensor<B, 4>,
+    grid: Tensor<B, 4>,
+    mode: GridSampleMode,
+    padding: GridSamplePadding,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    check!(TensorCheck::grid_sample(&x.shape(), &grid.shape()));
+
+    Tensor::new(B::grid_sample(x.primitive, grid.primitive, mode, padding))
+}
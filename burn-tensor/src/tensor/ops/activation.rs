@@ -100,4 +100,44 @@ pub trait ActivationOps<B: Backend> {
 
         B::mul(y, grad)
     }
+
+    /// Applies the log sigmoid activation function.
+    ///
+    /// `log_sigmoid(x) = -softplus(-x) = min(x, 0) - log(1 + exp(-|x|))`, which doesn't overflow
+    /// for inputs of large magnitude.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The output tensor.
+    fn log_sigmoid<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        let mask_positive = B::greater_elem(tensor.clone(), 0.elem());
+        let min = B::mask_fill(tensor.clone(), mask_positive.clone(), 0.elem());
+        let abs_neg = B::mask_where(tensor.clone(), mask_positive, B::neg(tensor));
+
+        B::sub(min, B::log1p(B::exp(abs_neg)))
+    }
+
+    /// Applies the log sigmoid activation function backward.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The tensor.
+    /// * `grad` - The gradient.
+    ///
+    /// # Returns
+    ///
+    /// The output tensor.
+    fn log_sigmoid_backward<const D: usize>(
+        x: B::TensorPrimitive<D>,
+        grad: B::TensorPrimitive<D>,
+    ) -> B::TensorPrimitive<D> {
+        // d/dx log_sigmoid(x) = sigmoid(-x) = exp(log_sigmoid(-x))
+        let sigmoid_neg = B::exp(B::log_sigmoid(B::neg(x)));
+
+        B::mul(sigmoid_neg, grad)
+    }
 }
@@ -163,10 +163,8 @@ pub trait BoolTensorOps<B: Backend> {
         times: usize,
     ) -> B::BoolTensorPrimitive<D> {
         let mut shape = Self::bool_shape(&tensor);
-        if shape.dims[dim] != 1 {
-            panic!("Can only repeat dimension with dim=1");
-        }
-        shape.dims[dim] = times;
+        let size = shape.dims[dim];
+        shape.dims[dim] = size * times;
 
         let mut i = 0;
         let ranges_select_all = [0; D].map(|_| {
@@ -179,7 +177,7 @@ pub trait BoolTensorOps<B: Backend> {
         let mut tensor_output = Self::bool_empty(shape, &Self::bool_device(&tensor));
         for i in 0..times {
             let mut ranges = ranges_select_all.clone();
-            ranges[dim] = i..i + 1;
+            ranges[dim] = i * size..(i + 1) * size;
             tensor_output = Self::bool_slice_assign(tensor_output, ranges, tensor.clone());
         }
 
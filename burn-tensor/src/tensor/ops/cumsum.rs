@@ -0,0 +1,29 @@
+use alloc::vec;
+
+use crate::{Data, Element, ElementConversion};
+
+/// Computes the cumulative sum of each lane along the given dimension.
+///
+/// The sum is accumulated in `f64` before being converted back to the element type.
+pub(crate) fn cumsum_data<E: Element, const D: usize>(data: Data<E, D>, dim: usize) -> Data<E, D> {
+    let size = data.shape.dims[dim];
+    let batch_size: usize = data.shape.dims[..dim].iter().product();
+    let inner_size: usize = data.shape.dims[dim + 1..].iter().product();
+
+    let mut values = vec![E::default(); data.value.len()];
+
+    for b in 0..batch_size {
+        for i in 0..inner_size {
+            let offset = b * size * inner_size + i;
+            let mut sum = 0.0;
+
+            for j in 0..size {
+                let index = offset + j * inner_size;
+                sum += data.value[index].elem::<f64>();
+                values[index] = sum.elem();
+            }
+        }
+    }
+
+    Data::new(values, data.shape)
+}
@@ -1,7 +1,8 @@
 use alloc::vec::Vec;
 use core::ops::Range;
 
-use crate::{backend::Backend, tensor::Shape, Data, ElementConversion};
+use crate::{backend::Backend, tensor::Shape, Data, Distribution, ElementConversion};
+use burn_common::rand::get_seeded_rng;
 
 /// Int Tensor API for basic and numeric operations, see [tensor](crate::Tensor)
 /// for documentation on each function.
@@ -68,6 +69,28 @@ pub trait IntTensorOps<B: Backend> {
         device: &B::Device,
     ) -> B::IntTensorPrimitive<D>;
 
+    /// Creates a new int tensor with random values.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - The shape of the tensor.
+    /// * `distribution` - The distribution to sample from, the uniform range excluding its end.
+    ///   The default distribution samples uniformly over the whole range of the int element type.
+    /// * `device` - The device to create the tensor on.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the given shape and random values.
+    fn int_random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution<B::IntElem>,
+        device: &B::Device,
+    ) -> B::IntTensorPrimitive<D> {
+        let mut rng = get_seeded_rng();
+
+        B::int_from_data(Data::random(shape, distribution, &mut rng), device)
+    }
+
     /// Gets the device of the tensor.
     ///
     /// # Arguments
@@ -85,6 +108,21 @@ pub trait IntTensorOps<B: Backend> {
         device: &B::Device,
     ) -> B::IntTensorPrimitive<D>;
 
+    /// Converts an int tensor to a float tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to convert.
+    ///
+    /// # Returns
+    ///
+    /// A float tensor with the values of `tensor`.
+    fn int_into_float<const D: usize>(tensor: B::IntTensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        let device = B::int_device(&tensor);
+
+        B::from_data(B::int_into_data(tensor).convert(), &device)
+    }
+
     /// Reshapes the tensor.
     ///
     /// # Arguments
@@ -252,10 +290,8 @@ pub trait IntTensorOps<B: Backend> {
         times: usize,
     ) -> B::IntTensorPrimitive<D> {
         let mut shape = Self::int_shape(&tensor);
-        if shape.dims[dim] != 1 {
-            panic!("Can only repeat dimension with dim=1");
-        }
-        shape.dims[dim] = times;
+        let size = shape.dims[dim];
+        shape.dims[dim] = size * times;
 
         let mut i = 0;
         let indices_select_all = [0; D].map(|_| {
@@ -268,7 +304,7 @@ pub trait IntTensorOps<B: Backend> {
         let mut tensor_output = Self::int_empty(shape, &Self::int_device(&tensor));
         for i in 0..times {
             let mut indices = indices_select_all.clone();
-            indices[dim] = i..i + 1;
+            indices[dim] = i * size..(i + 1) * size;
             tensor_output = Self::int_slice_assign(tensor_output, indices, tensor.clone());
         }
 
@@ -734,7 +770,7 @@ pub trait IntTensorOps<B: Backend> {
     ) -> B::IntTensorPrimitive<D> {
         let index = B::int_argmax(tensor.clone(), dim);
 
-        B::int_gather(D - 1, tensor, index)
+        B::int_gather(dim, tensor, index)
     }
 
     /// Gets the maximum elements and corresponding indices along a dimension.
@@ -752,7 +788,7 @@ pub trait IntTensorOps<B: Backend> {
         dim: usize,
     ) -> (B::IntTensorPrimitive<D>, B::IntTensorPrimitive<D>) {
         let index = B::int_argmax(tensor.clone(), dim);
-        let values = B::int_gather(D - 1, tensor, index.clone());
+        let values = B::int_gather(dim, tensor, index.clone());
 
         (values, index)
     }
@@ -789,7 +825,7 @@ pub trait IntTensorOps<B: Backend> {
     ) -> B::IntTensorPrimitive<D> {
         let index = B::int_argmin(tensor.clone(), dim);
 
-        B::int_gather(D - 1, tensor, index)
+        B::int_gather(dim, tensor, index)
     }
 
     /// Gets the minimum elements and corresponding indices along a dimension.
@@ -807,7 +843,7 @@ pub trait IntTensorOps<B: Backend> {
         dim: usize,
     ) -> (B::IntTensorPrimitive<D>, B::IntTensorPrimitive<D>) {
         let indices = B::int_argmin(tensor.clone(), dim);
-        let values = B::int_gather(D - 1, tensor, indices.clone());
+        let values = B::int_gather(dim, tensor, indices.clone());
 
         (values, indices)
     }
@@ -1,11 +1,17 @@
 mod activation;
 mod bool_tensor;
+mod cumsum;
 mod int_tensor;
 mod modules;
+mod quantization;
+mod scatter;
+mod sort;
 mod tensor;
+mod triangular;
 
 pub use activation::*;
 pub use bool_tensor::*;
 pub use int_tensor::*;
 pub use modules::*;
+pub use quantization::{QUANTIZATION_MAX, QUANTIZATION_MIN};
 pub use tensor::*;
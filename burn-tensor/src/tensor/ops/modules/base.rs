@@ -1,4 +1,4 @@
-use super::{conv, pool};
+use super::{conv, grid_sample, interpolate, pool};
 use crate::{backend::Backend, Shape};
 
 /// Gradient computed during the backward pass for each tensor used by [conv2d](ModuleOps::conv2d).
@@ -21,6 +21,16 @@ pub struct MaxPool2dBackward<B: Backend> {
     pub x_grad: B::TensorPrimitive<4>,
 }
 
+/// Gradient computed during the backward pass for each tensor used by [grid_sample](ModuleOps::grid_sample).
+#[derive(new)]
+pub struct GridSampleBackward<B: Backend> {
+    /// Gradient.
+    pub x_grad: B::TensorPrimitive<4>,
+
+    /// Grid gradient.
+    pub grid_grad: B::TensorPrimitive<4>,
+}
+
 /// Results from [max_pool2d](ModuleOps::max_pool2d_with_indices).
 #[derive(new)]
 pub struct MaxPool2dWithIndices<B: Backend> {
@@ -31,6 +41,23 @@ pub struct MaxPool2dWithIndices<B: Backend> {
     pub indices: B::IntTensorPrimitive<4>,
 }
 
+/// Gradient computed during the backward pass for each tensor used by [max_pool1d](ModuleOps::max_pool1d).
+#[derive(new)]
+pub struct MaxPool1dBackward<B: Backend> {
+    /// Gradient.
+    pub x_grad: B::TensorPrimitive<3>,
+}
+
+/// Results from [max_pool1d](ModuleOps::max_pool1d_with_indices).
+#[derive(new)]
+pub struct MaxPool1dWithIndices<B: Backend> {
+    /// The output tensor.
+    pub output: B::TensorPrimitive<3>,
+
+    /// The indices tensor.
+    pub indices: B::IntTensorPrimitive<3>,
+}
+
 /// Gradient computed during the backward pass for each tensor used by [conv1d](ModuleOps::conv1d).
 #[derive(new)]
 pub struct Conv1dBackward<B: Backend> {
@@ -79,6 +106,33 @@ pub struct ConvTransposeOptions<const N: usize> {
     pub groups: usize,
 }
 
+/// Interpolation mode used to resize a tensor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterpolateMode {
+    /// Uses the value of the nearest input element.
+    Nearest,
+    /// Linearly interpolates between the two nearest input elements.
+    Linear,
+}
+
+/// Interpolation mode used to sample a tensor with a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridSampleMode {
+    /// Uses the value of the nearest input pixel.
+    Nearest,
+    /// Bilinearly interpolates between the four nearest input pixels.
+    Bilinear,
+}
+
+/// Padding mode used to sample a tensor with a grid outside of its borders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridSamplePadding {
+    /// Uses zeros for the pixels outside of the input.
+    Zeros,
+    /// Clamps the coordinates to the border of the input.
+    Border,
+}
+
 /// Module operations trait.
 pub trait ModuleOps<B: Backend> {
     /// Embedding operation.
@@ -231,6 +285,160 @@ pub trait ModuleOps<B: Backend> {
     ) -> B::TensorPrimitive<3> {
         pool::avg_pool1d_backward_from_avg_pool2d::<B>(x, grad, kernel_size, stride, padding)
     }
+    /// One dimensional max pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, length],
+    fn max_pool1d(
+        x: B::TensorPrimitive<3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+    ) -> B::TensorPrimitive<3> {
+        pool::max_pool1d_from_max_pool2d::<B>(x, kernel_size, stride, padding)
+    }
+    /// One dimensional max pooling with indices.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, length],
+    fn max_pool1d_with_indices(
+        x: B::TensorPrimitive<3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+    ) -> MaxPool1dWithIndices<B> {
+        pool::max_pool1d_with_indices_from_max_pool2d::<B>(x, kernel_size, stride, padding)
+    }
+    /// Backward pass for the [max pooling 1d](ModuleOps::max_pool1d_with_indices) operation.
+    fn max_pool1d_with_indices_backward(
+        x: B::TensorPrimitive<3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        output_grad: B::TensorPrimitive<3>,
+        indices: B::IntTensorPrimitive<3>,
+    ) -> MaxPool1dBackward<B> {
+        pool::max_pool1d_with_indices_backward_from_max_pool2d::<B>(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            output_grad,
+            indices,
+        )
+    }
+    /// One dimensional interpolation, resizing the length of the input to `output_size`.
+    ///
+    /// Input and output elements are aligned on their centers, the border values being repeated
+    /// when sampling outside of the input. An empty input is interpolated to zeros.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, length],
+    fn interpolate1d(
+        x: B::TensorPrimitive<3>,
+        output_size: usize,
+        mode: InterpolateMode,
+    ) -> B::TensorPrimitive<3> {
+        interpolate::interpolate1d::<B>(x, output_size, mode)
+    }
+    /// Backward pass for the [interpolate 1d](ModuleOps::interpolate1d) operation.
+    fn interpolate1d_backward(
+        x: B::TensorPrimitive<3>,
+        grad: B::TensorPrimitive<3>,
+        mode: InterpolateMode,
+    ) -> B::TensorPrimitive<3> {
+        interpolate::interpolate1d_backward::<B>(x, grad, mode)
+    }
+    /// Samples the input at the normalized coordinates of the grid.
+    ///
+    /// The coordinates are `(x, y)` pairs, where `-1` and `1` are the outer borders of the first
+    /// and the last pixels of each dimension, i.e. the pixels are aligned on their centers.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, height, width],
+    /// grid: [batch_size, height_out, width_out, 2],
+    /// output: [batch_size, channels, height_out, width_out],
+    fn grid_sample(
+        x: B::TensorPrimitive<4>,
+        grid: B::TensorPrimitive<4>,
+        mode: GridSampleMode,
+        padding: GridSamplePadding,
+    ) -> B::TensorPrimitive<4> {
+        grid_sample::grid_sample::<B>(x, grid, mode, padding)
+    }
+    /// Backward pass for the [grid sample](ModuleOps::grid_sample) operation.
+    ///
+    /// The gradient of the grid is zero with the nearest mode.
+    fn grid_sample_backward(
+        x: B::TensorPrimitive<4>,
+        grid: B::TensorPrimitive<4>,
+        output_grad: B::TensorPrimitive<4>,
+        mode: GridSampleMode,
+        padding: GridSamplePadding,
+    ) -> GridSampleBackward<B> {
+        grid_sample::grid_sample_backward::<B>(x, grid, output_grad, mode, padding)
+    }
+    /// Rearranges the channels into blocks of the spatial dimensions, increasing the height and
+    /// the width by the upscale factor.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels * upscale_factor^2, height, width],
+    /// output: [batch_size, channels, height * upscale_factor, width * upscale_factor],
+    fn pixel_shuffle(x: B::TensorPrimitive<4>, upscale_factor: usize) -> B::TensorPrimitive<4> {
+        let [batch_size, channels, height, width] = B::shape(&x).dims;
+        let factor = upscale_factor;
+        let channels_out = channels / (factor * factor);
+
+        let x = B::reshape(
+            x,
+            Shape::new([batch_size, channels_out, factor, factor, height, width]),
+        );
+        // [batch_size, channels, height, factor, width, factor]
+        let x = B::swap_dims(x, 2, 4);
+        let x = B::swap_dims(x, 3, 4);
+        let x = B::swap_dims(x, 4, 5);
+
+        B::reshape(
+            x,
+            Shape::new([batch_size, channels_out, height * factor, width * factor]),
+        )
+    }
+    /// Inverse of the [pixel shuffle](ModuleOps::pixel_shuffle) operation, rearranging blocks of
+    /// the spatial dimensions into channels.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, height * downscale_factor, width * downscale_factor],
+    /// output: [batch_size, channels * downscale_factor^2, height, width],
+    fn pixel_unshuffle(x: B::TensorPrimitive<4>, downscale_factor: usize) -> B::TensorPrimitive<4> {
+        let [batch_size, channels, height, width] = B::shape(&x).dims;
+        let factor = downscale_factor;
+        let (height_out, width_out) = (height / factor, width / factor);
+
+        let x = B::reshape(
+            x,
+            Shape::new([batch_size, channels, height_out, factor, width_out, factor]),
+        );
+        // [batch_size, channels, factor, factor, height, width]
+        let x = B::swap_dims(x, 4, 5);
+        let x = B::swap_dims(x, 3, 4);
+        let x = B::swap_dims(x, 2, 4);
+
+        B::reshape(
+            x,
+            Shape::new([
+                batch_size,
+                channels * factor * factor,
+                height_out,
+                width_out,
+            ]),
+        )
+    }
     /// Two dimensional avg pooling.
     ///
     /// # Shapes
@@ -251,6 +459,26 @@ pub trait ModuleOps<B: Backend> {
         padding: [usize; 2],
     ) -> B::TensorPrimitive<4>;
 
+    /// Two dimensional adaptive avg pooling.
+    ///
+    /// Each output cell averages the window `[floor(i * in / out), ceil((i + 1) * in / out))` of
+    /// its spatial dimension, so windows may overlap when the input size isn't divisible by the
+    /// output size.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, height, width],
+    /// output: [batch_size, channels, height_out, width_out],
+    fn adaptive_avg_pool2d(
+        x: B::TensorPrimitive<4>,
+        output_size: [usize; 2],
+    ) -> B::TensorPrimitive<4>;
+    /// Backward pass for the [adaptive avg pooling 2d](ModuleOps::adaptive_avg_pool2d) operation.
+    fn adaptive_avg_pool2d_backward(
+        x: B::TensorPrimitive<4>,
+        grad: B::TensorPrimitive<4>,
+    ) -> B::TensorPrimitive<4>;
+
     /// Two dimensional max pooling.
     ///
     /// # Shapes
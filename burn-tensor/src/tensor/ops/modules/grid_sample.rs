@@ -0,0 +1,410 @@
+use super::{GridSampleBackward, GridSampleMode, GridSamplePadding};
+use crate::{backend::Backend, Data, ElementConversion, Shape};
+use alloc::vec;
+use alloc::vec::Vec;
+
+pub(crate) fn grid_sample<B: Backend>(
+    x: B::TensorPrimitive<4>,
+    grid: B::TensorPrimitive<4>,
+    mode: GridSampleMode,
+    padding: GridSamplePadding,
+) -> B::TensorPrimitive<4> {
+    let sampler = Sampler::new::<B>(&x, &grid);
+    let x = sampler.flatten::<B>(x);
+
+    let mut output = None;
+    for corner in sampler.corners::<B>(grid, mode, padding) {
+        let values = B::mul(
+            sampler.gather::<B>(x.clone(), &corner),
+            sampler.expand::<B>(corner.weights),
+        );
+
+        output = Some(match output {
+            Some(output) => B::add(output, values),
+            None => values,
+        });
+    }
+
+    sampler.unflatten::<B>(output.unwrap())
+}
+
+pub(crate) fn grid_sample_backward<B: Backend>(
+    x: B::TensorPrimitive<4>,
+    grid: B::TensorPrimitive<4>,
+    output_grad: B::TensorPrimitive<4>,
+    mode: GridSampleMode,
+    padding: GridSamplePadding,
+) -> GridSampleBackward<B> {
+    let sampler = Sampler::new::<B>(&x, &grid);
+    let device = B::device(&x);
+    let x = sampler.flatten::<B>(x);
+    let output_grad = B::reshape(
+        output_grad,
+        Shape::new([sampler.batch_size, sampler.channels, sampler.num_points]),
+    );
+    let (scale_x, scale_y) = (sampler.width as f32 / 2.0, sampler.height as f32 / 2.0);
+    let (clamp_grad_x, clamp_grad_y) = sampler.clamp_grads::<B>(grid.clone(), padding);
+
+    let mut x_grad = B::zeros(
+        Shape::new([
+            sampler.batch_size,
+            sampler.channels,
+            sampler.height * sampler.width,
+        ]),
+        &device,
+    );
+    let mut coordinates_grad: Option<(B::TensorPrimitive<2>, B::TensorPrimitive<2>)> = None;
+
+    for corner in sampler.corners::<B>(grid, mode, padding) {
+        // The values outside of the input are zeros, so they don't receive any gradient.
+        let indices = sampler.indices::<B>(&corner);
+        let values = B::mul(
+            output_grad.clone(),
+            sampler.expand::<B>(B::mul(corner.weights.clone(), corner.mask.clone())),
+        );
+        x_grad = B::scatter(2, x_grad, indices, values);
+
+        let (weights_grad_x, weights_grad_y) = match corner.weights_grad.clone() {
+            Some(weights_grad) => weights_grad,
+            None => continue,
+        };
+        let values = B::mul(sampler.gather::<B>(x.clone(), &corner), output_grad.clone());
+        let grad = |weights_grad: B::TensorPrimitive<2>| {
+            let grad = B::mul(values.clone(), sampler.expand::<B>(weights_grad));
+            let grad = B::sum_dim(grad, 1);
+
+            B::reshape(grad, Shape::new([sampler.batch_size, sampler.num_points]))
+        };
+        let (grad_x, grad_y) = (grad(weights_grad_x), grad(weights_grad_y));
+
+        coordinates_grad = Some(match coordinates_grad {
+            Some((x, y)) => (B::add(x, grad_x), B::add(y, grad_y)),
+            None => (grad_x, grad_y),
+        });
+    }
+
+    let (grad_x, grad_y) = coordinates_grad.unwrap_or_else(|| {
+        let zeros = B::zeros(
+            Shape::new([sampler.batch_size, sampler.num_points]),
+            &device,
+        );
+        (zeros.clone(), zeros)
+    });
+    let grad_x = B::mul(B::mul_scalar(grad_x, scale_x.elem()), clamp_grad_x);
+    let grad_y = B::mul(B::mul_scalar(grad_y, scale_y.elem()), clamp_grad_y);
+    let shape = Shape::new([sampler.batch_size, sampler.num_points, 1]);
+    let grid_grad = B::cat(
+        vec![B::reshape(grad_x, shape.clone()), B::reshape(grad_y, shape)],
+        2,
+    );
+
+    GridSampleBackward::new(
+        sampler.unflatten_input::<B>(x_grad),
+        B::reshape(
+            grid_grad,
+            Shape::new([sampler.batch_size, sampler.height_out, sampler.width_out, 2]),
+        ),
+    )
+}
+
+struct Sampler {
+    batch_size: usize,
+    channels: usize,
+    height: usize,
+    width: usize,
+    height_out: usize,
+    width_out: usize,
+    num_points: usize,
+}
+
+/// Input pixel used by each sample, with its interpolation weight.
+struct Corner<B: Backend> {
+    positions_x: Vec<i64>,
+    positions_y: Vec<i64>,
+    /// Ones for the positions inside of the input, zeros otherwise.
+    mask: B::TensorPrimitive<2>,
+    weights: B::TensorPrimitive<2>,
+    /// Gradients of the weights with respect to the pixel coordinates, when differentiable.
+    weights_grad: Option<(B::TensorPrimitive<2>, B::TensorPrimitive<2>)>,
+}
+
+impl Sampler {
+    fn new<B: Backend>(x: &B::TensorPrimitive<4>, grid: &B::TensorPrimitive<4>) -> Self {
+        let [batch_size, channels, height, width] = B::shape(x).dims;
+        let [_, height_out, width_out, _] = B::shape(grid).dims;
+
+        Self {
+            batch_size,
+            channels,
+            height,
+            width,
+            height_out,
+            width_out,
+            num_points: height_out * width_out,
+        }
+    }
+
+    /// The pixel coordinates of the samples along the x and y axes.
+    fn coordinates<B: Backend>(
+        &self,
+        grid: B::TensorPrimitive<4>,
+        padding: GridSamplePadding,
+    ) -> (B::TensorPrimitive<2>, B::TensorPrimitive<2>) {
+        let coordinates = |index: usize, size: usize| {
+            let coordinates = B::slice(
+                grid.clone(),
+                [
+                    0..self.batch_size,
+                    0..self.height_out,
+                    0..self.width_out,
+                    index..index + 1,
+                ],
+            );
+            let coordinates =
+                B::reshape(coordinates, Shape::new([self.batch_size, self.num_points]));
+            let coordinates = unnormalize::<B>(coordinates, size);
+
+            match padding {
+                GridSamplePadding::Zeros => coordinates,
+                GridSamplePadding::Border => clamp::<B>(coordinates, (size - 1) as f32),
+            }
+        };
+
+        (coordinates(0, self.width), coordinates(1, self.height))
+    }
+
+    /// Gradients of the pixel coordinates with respect to the unnormalized ones, zero where they
+    /// are clamped to the border, including on the border itself as with PyTorch.
+    fn clamp_grads<B: Backend>(
+        &self,
+        grid: B::TensorPrimitive<4>,
+        padding: GridSamplePadding,
+    ) -> (B::TensorPrimitive<2>, B::TensorPrimitive<2>) {
+        let (coordinates_x, coordinates_y) = self.coordinates::<B>(grid, GridSamplePadding::Zeros);
+        let clamp_grad = |coordinates: B::TensorPrimitive<2>, size: usize| {
+            let ones = B::ones(B::shape(&coordinates), &B::device(&coordinates));
+
+            match padding {
+                GridSamplePadding::Zeros => ones,
+                GridSamplePadding::Border => {
+                    let mask = B::lower_equal_elem(coordinates.clone(), 0.0.elem());
+                    let ones = B::mask_fill(ones, mask, 0.0.elem());
+                    let mask = B::greater_equal_elem(coordinates, ((size - 1) as f32).elem());
+
+                    B::mask_fill(ones, mask, 0.0.elem())
+                }
+            }
+        };
+
+        (
+            clamp_grad(coordinates_x, self.width),
+            clamp_grad(coordinates_y, self.height),
+        )
+    }
+
+    /// The input pixels used by the samples.
+    fn corners<B: Backend>(
+        &self,
+        grid: B::TensorPrimitive<4>,
+        mode: GridSampleMode,
+        padding: GridSamplePadding,
+    ) -> Vec<Corner<B>> {
+        let device = B::device(&grid);
+        let (coordinates_x, coordinates_y) = self.coordinates::<B>(grid, padding);
+
+        match mode {
+            GridSampleMode::Nearest => {
+                let positions_x = host_values::<B>(coordinates_x, libm::rint);
+                let positions_y = host_values::<B>(coordinates_y, libm::rint);
+                let mask = self.mask::<B>(&positions_x, &positions_y, &device);
+
+                vec![Corner {
+                    positions_x,
+                    positions_y,
+                    weights: mask.clone(),
+                    mask,
+                    weights_grad: None,
+                }]
+            }
+            GridSampleMode::Bilinear => {
+                let positions_x = host_values::<B>(coordinates_x.clone(), libm::floor);
+                let positions_y = host_values::<B>(coordinates_y.clone(), libm::floor);
+
+                // Weights of the second corner along each axis.
+                let weights_x =
+                    B::sub(coordinates_x, self.float_tensor::<B>(&positions_x, &device));
+                let weights_y =
+                    B::sub(coordinates_y, self.float_tensor::<B>(&positions_y, &device));
+                // The weights of the first and the second corners along an axis with their
+                // gradient.
+                let weights = |weights: &B::TensorPrimitive<2>, offset: i64| match offset {
+                    0 => (B::add_scalar(B::neg(weights.clone()), 1.0.elem()), -1.0),
+                    _ => (weights.clone(), 1.0),
+                };
+
+                [(0, 0), (1, 0), (0, 1), (1, 1)]
+                    .into_iter()
+                    .map(|(offset_x, offset_y)| {
+                        let (weights_x, sign_x) = weights(&weights_x, offset_x);
+                        let (weights_y, sign_y) = weights(&weights_y, offset_y);
+                        let positions_x =
+                            positions_x.iter().map(|x| x + offset_x).collect::<Vec<_>>();
+                        let positions_y =
+                            positions_y.iter().map(|y| y + offset_y).collect::<Vec<_>>();
+                        let mask = self.mask::<B>(&positions_x, &positions_y, &device);
+
+                        Corner {
+                            weights: B::mul(weights_x.clone(), weights_y.clone()),
+                            weights_grad: Some((
+                                B::mul_scalar(weights_y, sign_x.elem()),
+                                B::mul_scalar(weights_x, sign_y.elem()),
+                            )),
+                            positions_x,
+                            positions_y,
+                            mask,
+                        }
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    fn mask<B: Backend>(
+        &self,
+        positions_x: &[i64],
+        positions_y: &[i64],
+        device: &B::Device,
+    ) -> B::TensorPrimitive<2> {
+        let (width, height) = (self.width as i64, self.height as i64);
+        let mask = positions_x
+            .iter()
+            .zip(positions_y.iter())
+            .map(|(x, y)| (0..width).contains(x) && (0..height).contains(y))
+            .map(|valid| if valid { 1.0 } else { 0.0 })
+            .collect::<Vec<f32>>();
+
+        B::from_data(
+            Data::new(mask, Shape::new([self.batch_size, self.num_points])).convert(),
+            device,
+        )
+    }
+
+    /// Indices of the corner pixels in the flattened input, the positions outside of the input
+    /// being replaced by the first pixel.
+    fn indices<B: Backend>(&self, corner: &Corner<B>) -> B::IntTensorPrimitive<3> {
+        let (width, height) = (self.width as i64, self.height as i64);
+        let mut indices = Vec::with_capacity(self.batch_size * self.channels * self.num_points);
+
+        for batch in 0..self.batch_size {
+            let start = batch * self.num_points;
+            let end = start + self.num_points;
+
+            for _ in 0..self.channels {
+                indices.extend((start..end).map(|i| {
+                    let (x, y) = (corner.positions_x[i], corner.positions_y[i]);
+                    match (0..width).contains(&x) && (0..height).contains(&y) {
+                        true => y * width + x,
+                        false => 0,
+                    }
+                }));
+            }
+        }
+
+        B::int_from_data(
+            Data::new(
+                indices,
+                Shape::new([self.batch_size, self.channels, self.num_points]),
+            )
+            .convert(),
+            &B::device(&corner.mask),
+        )
+    }
+
+    /// Sample the flattened input at the corner pixels, the pixels outside of the input being
+    /// zeros.
+    fn gather<B: Backend>(
+        &self,
+        x: B::TensorPrimitive<3>,
+        corner: &Corner<B>,
+    ) -> B::TensorPrimitive<3> {
+        let indices = self.indices::<B>(corner);
+
+        B::mul(
+            B::gather(2, x, indices),
+            self.expand::<B>(corner.mask.clone()),
+        )
+    }
+
+    /// Reshape a tensor of shape `[batch_size, num_points]` to be broadcast over the channels.
+    fn expand<B: Backend>(&self, tensor: B::TensorPrimitive<2>) -> B::TensorPrimitive<3> {
+        B::reshape(tensor, Shape::new([self.batch_size, 1, self.num_points]))
+    }
+
+    fn flatten<B: Backend>(&self, x: B::TensorPrimitive<4>) -> B::TensorPrimitive<3> {
+        B::reshape(
+            x,
+            Shape::new([self.batch_size, self.channels, self.height * self.width]),
+        )
+    }
+
+    fn unflatten<B: Backend>(&self, output: B::TensorPrimitive<3>) -> B::TensorPrimitive<4> {
+        B::reshape(
+            output,
+            Shape::new([
+                self.batch_size,
+                self.channels,
+                self.height_out,
+                self.width_out,
+            ]),
+        )
+    }
+
+    fn unflatten_input<B: Backend>(&self, x: B::TensorPrimitive<3>) -> B::TensorPrimitive<4> {
+        B::reshape(
+            x,
+            Shape::new([self.batch_size, self.channels, self.height, self.width]),
+        )
+    }
+
+    fn float_tensor<B: Backend>(
+        &self,
+        values: &[i64],
+        device: &B::Device,
+    ) -> B::TensorPrimitive<2> {
+        let values = values.iter().map(|value| *value as f32).collect();
+
+        B::from_data(
+            Data::new(values, Shape::new([self.batch_size, self.num_points])).convert(),
+            device,
+        )
+    }
+}
+
+/// Convert the normalized coordinates from `[-1, 1]` to pixel coordinates, `-1` and `1` being the
+/// outer borders of the first and the last pixels.
+fn unnormalize<B: Backend>(
+    coordinates: B::TensorPrimitive<2>,
+    size: usize,
+) -> B::TensorPrimitive<2> {
+    let coordinates = B::add_scalar(coordinates, 1.0.elem());
+    let coordinates = B::mul_scalar(coordinates, (size as f32).elem());
+    let coordinates = B::sub_scalar(coordinates, 1.0.elem());
+
+    B::div_scalar(coordinates, 2.0.elem())
+}
+
+fn clamp<B: Backend>(coordinates: B::TensorPrimitive<2>, max: f32) -> B::TensorPrimitive<2> {
+    let mask = B::lower_elem(coordinates.clone(), 0.0.elem());
+    let coordinates = B::mask_fill(coordinates, mask, 0.0.elem());
+    let mask = B::greater_elem(coordinates.clone(), max.elem());
+
+    B::mask_fill(coordinates, mask, max.elem())
+}
+
+fn host_values<B: Backend>(tensor: B::TensorPrimitive<2>, round: fn(f64) -> f64) -> Vec<i64> {
+    B::into_data(tensor)
+        .value
+        .into_iter()
+        .map(|value| round(value.elem::<f64>()) as i64)
+        .collect()
+}
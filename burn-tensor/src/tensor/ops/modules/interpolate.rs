@@ -0,0 +1,127 @@
+use super::InterpolateMode;
+use crate::{backend::Backend, Data, Shape};
+use alloc::vec::Vec;
+
+pub(crate) fn interpolate1d<B: Backend>(
+    x: B::TensorPrimitive<3>,
+    output_size: usize,
+    mode: InterpolateMode,
+) -> B::TensorPrimitive<3> {
+    let [batch_size, channels, input_size] = B::shape(&x).dims;
+    let device = B::device(&x);
+
+    // There are no input elements to sample from.
+    if input_size == 0 {
+        return B::zeros(Shape::new([batch_size, channels, output_size]), &device);
+    }
+
+    match mode {
+        InterpolateMode::Nearest => {
+            let indices = nearest_indices(input_size, output_size);
+            B::select(x, 2, int_tensor::<B>(indices, &device))
+        }
+        InterpolateMode::Linear => {
+            let (indices_low, indices_high, weights) = linear_coordinates(input_size, output_size);
+            let low = B::select(x.clone(), 2, int_tensor::<B>(indices_low, &device));
+            let high = B::select(x, 2, int_tensor::<B>(indices_high, &device));
+            let weights = weight_tensor::<B>(weights, &device);
+
+            B::add(low.clone(), B::mul(B::sub(high, low), weights))
+        }
+    }
+}
+
+pub(crate) fn interpolate1d_backward<B: Backend>(
+    x: B::TensorPrimitive<3>,
+    grad: B::TensorPrimitive<3>,
+    mode: InterpolateMode,
+) -> B::TensorPrimitive<3> {
+    let [batch_size, channels, input_size] = B::shape(&x).dims;
+    let [_, _, output_size] = B::shape(&grad).dims;
+    let device = B::device(&x);
+
+    let grad_x = B::zeros(Shape::new([batch_size, channels, input_size]), &device);
+
+    if input_size == 0 {
+        return grad_x;
+    }
+
+    match mode {
+        InterpolateMode::Nearest => {
+            let indices = nearest_indices(input_size, output_size);
+            scatter_add::<B>(grad_x, &indices, grad)
+        }
+        InterpolateMode::Linear => {
+            let (indices_low, indices_high, weights) = linear_coordinates(input_size, output_size);
+            let weights = weight_tensor::<B>(weights, &device);
+
+            let grad_high = B::mul(grad.clone(), weights);
+            let grad_low = B::sub(grad, grad_high.clone());
+
+            let grad_x = scatter_add::<B>(grad_x, &indices_low, grad_low);
+            scatter_add::<B>(grad_x, &indices_high, grad_high)
+        }
+    }
+}
+
+/// Sums the gradient of each output element into the input element at the same position in
+/// `indices`, several output elements sharing the same input element when upsampling.
+fn scatter_add<B: Backend>(
+    grad_x: B::TensorPrimitive<3>,
+    indices: &[i64],
+    grad: B::TensorPrimitive<3>,
+) -> B::TensorPrimitive<3> {
+    let shape = B::shape(&grad);
+    let indices = indices
+        .iter()
+        .copied()
+        .cycle()
+        .take(shape.num_elements())
+        .collect();
+    let indices = B::int_from_data(Data::new(indices, shape).convert(), &B::device(&grad));
+
+    B::scatter(2, grad_x, indices, grad)
+}
+
+/// Index of the input element used by each output element.
+fn nearest_indices(input_size: usize, output_size: usize) -> Vec<i64> {
+    (0..output_size)
+        .map(|i| ((i * input_size) / output_size) as i64)
+        .collect()
+}
+
+/// Indices of the two input elements surrounding each output element, with the weight of the
+/// second one.
+///
+/// Elements are aligned on their centers, the output element `i` being located at
+/// `(i + 0.5) * input_size / output_size - 0.5` in the input.
+fn linear_coordinates(input_size: usize, output_size: usize) -> (Vec<i64>, Vec<i64>, Vec<f32>) {
+    let scale = input_size as f32 / output_size as f32;
+    let mut indices_low = Vec::with_capacity(output_size);
+    let mut indices_high = Vec::with_capacity(output_size);
+    let mut weights = Vec::with_capacity(output_size);
+
+    for i in 0..output_size {
+        let position = ((i as f32 + 0.5) * scale - 0.5).max(0.0);
+        let low = usize::min(position as usize, input_size - 1);
+        let high = usize::min(low + 1, input_size - 1);
+
+        indices_low.push(low as i64);
+        indices_high.push(high as i64);
+        weights.push(position - low as f32);
+    }
+
+    (indices_low, indices_high, weights)
+}
+
+fn int_tensor<B: Backend>(indices: Vec<i64>, device: &B::Device) -> B::IntTensorPrimitive<1> {
+    let shape = Shape::new([indices.len()]);
+
+    B::int_from_data(Data::new(indices, shape).convert(), device)
+}
+
+fn weight_tensor<B: Backend>(weights: Vec<f32>, device: &B::Device) -> B::TensorPrimitive<3> {
+    let shape = Shape::new([1, 1, weights.len()]);
+
+    B::from_data(Data::new(weights, shape).convert(), device)
+}
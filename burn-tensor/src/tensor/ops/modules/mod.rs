@@ -5,5 +5,7 @@ pub mod conv;
 pub mod pool;
 
 mod base;
+mod grid_sample;
+mod interpolate;
 
 pub use base::*;
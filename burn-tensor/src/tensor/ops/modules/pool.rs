@@ -1,3 +1,4 @@
+use super::{MaxPool1dBackward, MaxPool1dWithIndices};
 use crate::{backend::Backend, Shape};
 
 pub(crate) fn avg_pool1d_from_avg_pool2d<B: Backend>(
@@ -33,3 +34,73 @@ pub(crate) fn avg_pool1d_backward_from_avg_pool2d<B: Backend>(
 
     B::reshape(grad_x, Shape::from([batch_size, channels, length_in]))
 }
+
+pub(crate) fn max_pool1d_from_max_pool2d<B: Backend>(
+    x: B::TensorPrimitive<3>,
+    kernel_size: usize,
+    stride: usize,
+    padding: usize,
+) -> B::TensorPrimitive<3> {
+    let [batch_size, channels, length] = B::shape(&x).dims;
+
+    let x = B::reshape(x, Shape::from([batch_size, channels, length, 1]));
+    let x = B::max_pool2d(x, [kernel_size, 1], [stride, 1], [padding, 0]);
+
+    let [batch_size, channels, length, _] = B::shape(&x).dims;
+
+    B::reshape(x, Shape::from([batch_size, channels, length]))
+}
+
+pub(crate) fn max_pool1d_with_indices_from_max_pool2d<B: Backend>(
+    x: B::TensorPrimitive<3>,
+    kernel_size: usize,
+    stride: usize,
+    padding: usize,
+) -> MaxPool1dWithIndices<B> {
+    let [batch_size, channels, length] = B::shape(&x).dims;
+
+    let x = B::reshape(x, Shape::from([batch_size, channels, length, 1]));
+    let x = B::max_pool2d_with_indices(x, [kernel_size, 1], [stride, 1], [padding, 0]);
+
+    let [batch_size, channels, length, _] = B::shape(&x.output).dims;
+
+    // With a width of one, the indices flattened over the 2D input are the 1D indices.
+    let output = B::reshape(x.output, Shape::from([batch_size, channels, length]));
+    let indices = B::int_reshape(x.indices, Shape::from([batch_size, channels, length]));
+
+    MaxPool1dWithIndices::new(output, indices)
+}
+
+pub(crate) fn max_pool1d_with_indices_backward_from_max_pool2d<B: Backend>(
+    x: B::TensorPrimitive<3>,
+    kernel_size: usize,
+    stride: usize,
+    padding: usize,
+    output_grad: B::TensorPrimitive<3>,
+    indices: B::IntTensorPrimitive<3>,
+) -> MaxPool1dBackward<B> {
+    let [batch_size, channels, length_in] = B::shape(&x).dims;
+    let [_, _, length_out] = B::shape(&output_grad).dims;
+
+    let x = B::reshape(x, Shape::from([batch_size, channels, length_in, 1]));
+    let grad_x = B::reshape(
+        output_grad,
+        Shape::from([batch_size, channels, length_out, 1]),
+    );
+    let indices = B::int_reshape(indices, Shape::from([batch_size, channels, length_out, 1]));
+
+    let grad_x = B::max_pool2d_with_indices_backward(
+        x,
+        [kernel_size, 1],
+        [stride, 1],
+        [padding, 0],
+        grad_x,
+        indices,
+    )
+    .x_grad;
+
+    MaxPool1dBackward::new(B::reshape(
+        grad_x,
+        Shape::from([batch_size, channels, length_in]),
+    ))
+}
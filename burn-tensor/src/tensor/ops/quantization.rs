@@ -0,0 +1,50 @@
+use crate::{backend::Backend, ElementConversion, Shape};
+
+/// The smallest value of a quantized tensor, which uses the int8 range.
+pub const QUANTIZATION_MIN: i64 = i8::MIN as i64;
+
+/// The largest value of a quantized tensor, which uses the int8 range.
+pub const QUANTIZATION_MAX: i64 = i8::MAX as i64;
+
+/// Quantize each element with the scale and the zero point of its channel along the given axis,
+/// rounding half to even and clamping to the int8 range.
+pub(crate) fn quantize_per_channel<B: Backend, const D: usize>(
+    tensor: B::TensorPrimitive<D>,
+    scale: B::TensorPrimitive<1>,
+    zero_point: B::IntTensorPrimitive<1>,
+    axis: usize,
+) -> B::IntTensorPrimitive<D> {
+    let shape = channel_shape::<D>(&B::shape(&tensor), axis);
+    let scale = B::reshape(scale, shape.clone());
+    let zero_point = B::reshape(B::int_into_float(zero_point), shape);
+
+    let tensor = B::round(B::div(tensor, scale));
+    let tensor = B::add(tensor, zero_point);
+    let tensor = B::clamp(tensor, QUANTIZATION_MIN.elem(), QUANTIZATION_MAX.elem());
+
+    B::into_int(tensor)
+}
+
+/// Dequantize each element with the scale and the zero point of its channel along the given axis.
+pub(crate) fn dequantize_per_channel<B: Backend, const D: usize>(
+    tensor: B::IntTensorPrimitive<D>,
+    scale: B::TensorPrimitive<1>,
+    zero_point: B::IntTensorPrimitive<1>,
+    axis: usize,
+) -> B::TensorPrimitive<D> {
+    let shape = channel_shape::<D>(&B::int_shape(&tensor), axis);
+    let scale = B::reshape(scale, shape.clone());
+    let zero_point = B::int_reshape(zero_point, shape);
+
+    let tensor = B::int_into_float(B::int_sub(tensor, zero_point));
+
+    B::mul(tensor, scale)
+}
+
+/// The shape broadcasting the values of each channel along the axis.
+fn channel_shape<const D: usize>(shape: &Shape<D>, axis: usize) -> Shape<D> {
+    let mut dims = [1; D];
+    dims[axis] = shape.dims[axis];
+
+    Shape::new(dims)
+}
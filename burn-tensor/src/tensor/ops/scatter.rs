@@ -0,0 +1,52 @@
+use crate::{backend::Backend, ElementConversion};
+use core::ops::Range;
+
+/// Scatter the values into the tensor, replacing the current element at each position where
+/// `replace(current, candidate)` is true.
+///
+/// Returns the output along with the index along `dim` in the value tensor of the element kept at
+/// each position, or `-1` when the element of the original tensor is kept.
+pub(crate) fn scatter_reduce_with_indices<B: Backend, const D: usize>(
+    dim: usize,
+    tensor: B::TensorPrimitive<D>,
+    indices: B::IntTensorPrimitive<D>,
+    value: B::TensorPrimitive<D>,
+    replace: fn(B::TensorPrimitive<D>, B::TensorPrimitive<D>) -> B::BoolTensorPrimitive<D>,
+) -> (B::TensorPrimitive<D>, B::IntTensorPrimitive<D>) {
+    let device = B::device(&tensor);
+    let shape = B::shape(&tensor);
+    let shape_value = B::shape(&value);
+
+    let mut output = tensor;
+    let mut sources = B::int_full(shape.clone(), (-1).elem(), &device);
+
+    // The values are scattered one slice along `dim` at a time, in order, so that a later element
+    // only replaces the ones scattered before it when `replace` says so.
+    for i in 0..shape_value.dims[dim] {
+        let ranges: [Range<usize>; D] = core::array::from_fn(|d| match d == dim {
+            true => i..i + 1,
+            false => 0..shape_value.dims[d],
+        });
+        let indices = B::int_slice(indices.clone(), ranges.clone());
+        let candidates = B::slice(value.clone(), ranges);
+
+        let current = B::gather(dim, output.clone(), indices.clone());
+        let mask = B::bool_into_int(replace(current, candidates.clone()));
+
+        // A slice has a single element along `dim`, so it scatters at most one element at each
+        // position and the scatter-add into zeros assigns it.
+        let mask = B::int_scatter(
+            dim,
+            B::int_zeros(shape.clone(), &device),
+            indices.clone(),
+            mask,
+        );
+        let mask = B::int_greater_elem(mask, 0.elem());
+        let candidates = B::scatter(dim, B::zeros(shape.clone(), &device), indices, candidates);
+
+        output = B::mask_where(output, mask.clone(), candidates);
+        sources = B::int_mask_fill(sources, mask, (i as i64).elem());
+    }
+
+    (output, sources)
+}
@@ -0,0 +1,117 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::{Data, Element};
+
+/// Sorts each lane along the given dimension, returning the sorted elements with their indices.
+///
+/// The sort is stable, equal elements keep their original order, and NaN is considered greater
+/// than any other value.
+pub(crate) fn sort_data<E: Element, const D: usize>(
+    data: Data<E, D>,
+    dim: usize,
+    descending: bool,
+) -> (Data<E, D>, Data<i64, D>) {
+    let size = data.shape.dims[dim];
+
+    sort_lanes(data, size, dim, descending)
+}
+
+/// Selects the `k` largest elements of each lane along the given dimension, sorted in descending
+/// order, with their indices.
+///
+/// Ties are broken by keeping the element with the lowest index first, so the result doesn't
+/// depend on the sorting algorithm. NaN is considered greater than any other value.
+pub(crate) fn topk_data<E: Element, const D: usize>(
+    data: Data<E, D>,
+    k: usize,
+    dim: usize,
+) -> (Data<E, D>, Data<i64, D>) {
+    sort_lanes(data, k, dim, true)
+}
+
+/// Sorts each lane along the given dimension and keeps its first `k` elements.
+fn sort_lanes<E: Element, const D: usize>(
+    data: Data<E, D>,
+    k: usize,
+    dim: usize,
+    descending: bool,
+) -> (Data<E, D>, Data<i64, D>) {
+    let size = data.shape.dims[dim];
+    let batch_size: usize = data.shape.dims[..dim].iter().product();
+    let inner_size: usize = data.shape.dims[dim + 1..].iter().product();
+
+    let mut shape = data.shape.clone();
+    shape.dims[dim] = k;
+
+    let num_elements = batch_size * k * inner_size;
+    let mut values = vec![E::default(); num_elements];
+    let mut indices = vec![0; num_elements];
+
+    let mut lane = Vec::with_capacity(size);
+
+    for b in 0..batch_size {
+        for i in 0..inner_size {
+            let offset = b * size * inner_size + i;
+
+            lane.clear();
+            lane.extend((0..size).map(|j| (j, data.value[offset + j * inner_size].elem::<f64>())));
+            lane.sort_by(|lhs, rhs| cmp_lane_elem(lhs, rhs, descending));
+
+            let offset_output = b * k * inner_size + i;
+            for (j, (index, _)) in lane.iter().take(k).enumerate() {
+                values[offset_output + j * inner_size] = data.value[offset + index * inner_size];
+                indices[offset_output + j * inner_size] = *index as i64;
+            }
+        }
+    }
+
+    (Data::new(values, shape.clone()), Data::new(indices, shape))
+}
+
+fn cmp_lane_elem(lhs: &(usize, f64), rhs: &(usize, f64), descending: bool) -> Ordering {
+    let ordering = match descending {
+        true => cmp_nan_greatest(rhs.1, lhs.1),
+        false => cmp_nan_greatest(lhs.1, rhs.1),
+    };
+
+    ordering.then(lhs.0.cmp(&rhs.0))
+}
+
+/// Total order where NaN is greater than any other value, as with PyTorch.
+fn cmp_nan_greatest(lhs: f64, rhs: f64) -> Ordering {
+    match (lhs.is_nan(), rhs.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => lhs.partial_cmp(&rhs).unwrap(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn topk_data_should_consider_nan_the_largest_value() {
+        let data = Data::<f32, 2>::from([[1.0, f32::NAN, 3.0, f32::NAN, 2.0]]);
+
+        let (values, indices) = topk_data(data, 3, 1);
+
+        assert!(values.value[0].is_nan() && values.value[1].is_nan());
+        assert_eq!(values.value[2], 3.0);
+        assert_eq!(indices, Data::from([[1, 3, 2]]));
+    }
+
+    #[test]
+    fn sort_data_should_consider_nan_the_largest_value() {
+        let data = Data::<f32, 2>::from([[f32::NAN, 1.0, -2.0, f32::NAN, 0.5]]);
+
+        let (values, indices) = sort_data(data, 1, false);
+
+        assert_eq!(values.value[..3], [-2.0, 0.5, 1.0]);
+        assert!(values.value[3].is_nan() && values.value[4].is_nan());
+        assert_eq!(indices, Data::from([[2, 4, 1, 0, 3]]));
+    }
+}
@@ -1,6 +1,7 @@
 use alloc::vec::Vec;
 use core::ops::Range;
 
+use super::{cumsum, quantization, scatter, sort, triangular};
 use crate::{backend::Backend, tensor::Shape, Data, Distribution, ElementConversion};
 
 /// Operations on float tensors.
@@ -188,6 +189,29 @@ pub trait TensorOps<B: Backend> {
         B::int_from_data(data, device)
     }
 
+    /// Creates a new float tensor with values from `start` (inclusive) to `end` (exclusive)
+    /// spaced by the given step size, which may be negative.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The first value.
+    /// * `end` - The bound of the values, never included.
+    /// * `step` - The step size.
+    /// * `device` - The device to create the tensor on.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the given values.
+    fn arange_float(start: f64, end: f64, step: f64, device: &B::Device) -> B::TensorPrimitive<1> {
+        let num_elements = libm::ceil((end - start) / step).max(0.0) as usize;
+        let value = (0..num_elements)
+            .map(|i| (start + i as f64 * step).elem())
+            .collect::<Vec<B::FloatElem>>();
+        let shape = Shape::new([value.len()]);
+        let data = Data::new(value, shape);
+        B::from_data(data, device)
+    }
+
     /// Creates an empty tensor with the given shape.
     ///
     /// # Arguments
@@ -217,10 +241,8 @@ pub trait TensorOps<B: Backend> {
         times: usize,
     ) -> B::TensorPrimitive<D> {
         let mut shape = B::shape(&tensor);
-        if shape.dims[dim] != 1 {
-            panic!("Can only repeat dimension with dim=1");
-        }
-        shape.dims[dim] = times;
+        let size = shape.dims[dim];
+        shape.dims[dim] = size * times;
 
         let mut i = 0;
         let indices_select_all = [0; D].map(|_| {
@@ -233,7 +255,7 @@ pub trait TensorOps<B: Backend> {
         let mut tensor_output = B::empty(shape, &B::device(&tensor));
         for i in 0..times {
             let mut indices = indices_select_all.clone();
-            indices[dim] = i..i + 1;
+            indices[dim] = i * size..(i + 1) * size;
             tensor_output = B::slice_assign(tensor_output, indices, tensor.clone());
         }
 
@@ -300,6 +322,88 @@ pub trait TensorOps<B: Backend> {
         rhs: B::FloatElem,
     ) -> B::TensorPrimitive<D>;
 
+    /// Computes the n-th order discrete difference along the given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `dim` - The dimension along which the difference is computed.
+    /// * `n` - The number of times the difference is applied.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the difference of adjacent elements, where the size of the given
+    /// dimension is reduced by `n`.
+    fn diff<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        dim: usize,
+        n: usize,
+    ) -> B::TensorPrimitive<D> {
+        let mut output = tensor;
+
+        for _ in 0..n {
+            let shape = B::shape(&output);
+            let size = shape.dims[dim];
+
+            let mut ranges_lhs = shape.dims.map(|dim| 0..dim);
+            let mut ranges_rhs = ranges_lhs.clone();
+            ranges_lhs[dim] = 1..size;
+            ranges_rhs[dim] = 0..size - 1;
+
+            let lhs = B::slice(output.clone(), ranges_lhs);
+            let rhs = B::slice(output, ranges_rhs);
+            output = B::sub(lhs, rhs);
+        }
+
+        output
+    }
+
+    /// Returns the lower triangular part of the last two dimensions of the tensor, the other
+    /// elements are set to zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `diagonal` - The diagonal above which elements are zeroed, `0` being the main diagonal,
+    ///   positive values the diagonals above it and negative values the diagonals below it.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the elements above the given diagonal set to zero.
+    fn tril<const D: usize>(tensor: B::TensorPrimitive<D>, diagonal: i64) -> B::TensorPrimitive<D> {
+        let mask = triangular::triangular_mask::<B, D>(
+            &B::shape(&tensor),
+            diagonal,
+            false,
+            &B::device(&tensor),
+        );
+
+        B::mask_fill(tensor, mask, 0.elem())
+    }
+
+    /// Returns the upper triangular part of the last two dimensions of the tensor, the other
+    /// elements are set to zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `diagonal` - The diagonal below which elements are zeroed, `0` being the main diagonal,
+    ///   positive values the diagonals above it and negative values the diagonals below it.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the elements below the given diagonal set to zero.
+    fn triu<const D: usize>(tensor: B::TensorPrimitive<D>, diagonal: i64) -> B::TensorPrimitive<D> {
+        let mask = triangular::triangular_mask::<B, D>(
+            &B::shape(&tensor),
+            diagonal,
+            true,
+            &B::device(&tensor),
+        );
+
+        B::mask_fill(tensor, mask, 0.elem())
+    }
+
     /// Multiplies two tensors together element-wise.
     fn mul<const D: usize>(
         lhs: B::TensorPrimitive<D>,
@@ -401,6 +505,55 @@ pub trait TensorOps<B: Backend> {
         dim2: usize,
     ) -> B::TensorPrimitive<D>;
 
+    /// Permutes the dimensions of a tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to permute the dimensions of.
+    /// * `axes` - The new order of the dimensions, where the output dimension `i` is the input
+    ///   dimension `axes[i]`.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the dimensions permuted.
+    fn permute<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        axes: [usize; D],
+    ) -> B::TensorPrimitive<D>;
+
+    /// Reverses the order of the elements along the given axes.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to flip.
+    /// * `axes` - The axes to flip, each one appearing at most once.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the elements reversed along the axes.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation selects the elements of each axis with reversed indices,
+    /// backends should override it when they can reverse the strides instead.
+    fn flip<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        axes: &[usize],
+    ) -> B::TensorPrimitive<D> {
+        let device = B::device(&tensor);
+
+        axes.iter().fold(tensor, |tensor, &axis| {
+            let size = B::shape(&tensor).dims[axis];
+            let value = (0..size)
+                .rev()
+                .map(|i| (i as i64).elem())
+                .collect::<Vec<B::IntElem>>();
+            let indices = B::int_from_data(Data::new(value, Shape::new([size])), &device);
+
+            B::select(tensor, axis, indices)
+        })
+    }
+
     /// Reshapes a tensor.
     ///
     /// # Arguments
@@ -452,6 +605,102 @@ pub trait TensorOps<B: Backend> {
         value: B::TensorPrimitive<D>,
     ) -> B::TensorPrimitive<D>;
 
+    /// Scatter elements into a tensor, keeping the maximum between the existing element and the
+    /// elements scattered at each position.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to scatter into.
+    /// * `tensor` - The tensor to scatter into.
+    /// * `indices` - The indices to scatter into.
+    /// * `value` - The value to scatter.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the maximum of the scattered elements.
+    fn scatter_max<const D: usize>(
+        dim: usize,
+        tensor: B::TensorPrimitive<D>,
+        indices: B::IntTensorPrimitive<D>,
+        value: B::TensorPrimitive<D>,
+    ) -> B::TensorPrimitive<D> {
+        B::scatter_max_with_indices(dim, tensor, indices, value).0
+    }
+
+    /// Scatter elements into a tensor, keeping the maximum between the existing element and the
+    /// elements scattered at each position, along with the index of the kept element.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to scatter into.
+    /// * `tensor` - The tensor to scatter into.
+    /// * `indices` - The indices to scatter into.
+    /// * `value` - The value to scatter.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the maximum of the scattered elements, and for each position the index
+    /// along `dim` in the value tensor of the kept element, or `-1` when the existing element is
+    /// kept. On ties, the existing element is kept, then the first scattered element.
+    fn scatter_max_with_indices<const D: usize>(
+        dim: usize,
+        tensor: B::TensorPrimitive<D>,
+        indices: B::IntTensorPrimitive<D>,
+        value: B::TensorPrimitive<D>,
+    ) -> (B::TensorPrimitive<D>, B::IntTensorPrimitive<D>) {
+        scatter::scatter_reduce_with_indices::<B, D>(dim, tensor, indices, value, |a, b| {
+            B::greater(b, a)
+        })
+    }
+
+    /// Scatter elements into a tensor, keeping the minimum between the existing element and the
+    /// elements scattered at each position.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to scatter into.
+    /// * `tensor` - The tensor to scatter into.
+    /// * `indices` - The indices to scatter into.
+    /// * `value` - The value to scatter.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the minimum of the scattered elements.
+    fn scatter_min<const D: usize>(
+        dim: usize,
+        tensor: B::TensorPrimitive<D>,
+        indices: B::IntTensorPrimitive<D>,
+        value: B::TensorPrimitive<D>,
+    ) -> B::TensorPrimitive<D> {
+        B::scatter_min_with_indices(dim, tensor, indices, value).0
+    }
+
+    /// Scatter elements into a tensor, keeping the minimum between the existing element and the
+    /// elements scattered at each position, along with the index of the kept element.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to scatter into.
+    /// * `tensor` - The tensor to scatter into.
+    /// * `indices` - The indices to scatter into.
+    /// * `value` - The value to scatter.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the minimum of the scattered elements, and for each position the index
+    /// along `dim` in the value tensor of the kept element, or `-1` when the existing element is
+    /// kept. On ties, the existing element is kept, then the first scattered element.
+    fn scatter_min_with_indices<const D: usize>(
+        dim: usize,
+        tensor: B::TensorPrimitive<D>,
+        indices: B::IntTensorPrimitive<D>,
+        value: B::TensorPrimitive<D>,
+    ) -> (B::TensorPrimitive<D>, B::IntTensorPrimitive<D>) {
+        scatter::scatter_reduce_with_indices::<B, D>(dim, tensor, indices, value, |a, b| {
+            B::lower(b, a)
+        })
+    }
+
     /// Select tensor elements along the given dimension corresponding for the given indices.
     ///
     /// # Arguments
@@ -749,6 +998,28 @@ pub trait TensorOps<B: Backend> {
     /// A tensor with the sum of all elements in `tensor` along `dim`.
     fn sum_dim<const D: usize>(tensor: B::TensorPrimitive<D>, dim: usize) -> B::TensorPrimitive<D>;
 
+    /// Cumulative sum of the elements in a tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sum.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, where each element is the sum of all elements
+    /// before it (inclusive) along `dim`.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation goes through the CPU, backends should override it with a
+    /// dedicated kernel when available.
+    fn cumsum<const D: usize>(tensor: B::TensorPrimitive<D>, dim: usize) -> B::TensorPrimitive<D> {
+        let device = B::device(&tensor);
+
+        B::from_data(cumsum::cumsum_data(B::into_data(tensor), dim), &device)
+    }
+
     /// Mean of all elements in a tensor.
     ///
     /// # Arguments
@@ -776,6 +1047,41 @@ pub trait TensorOps<B: Backend> {
     fn mean_dim<const D: usize>(tensor: B::TensorPrimitive<D>, dim: usize)
         -> B::TensorPrimitive<D>;
 
+    /// Variance and mean of all elements in a tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to reduce.
+    /// * `dim` - The dimension along which to reduce.
+    /// * `unbiased` - Whether to apply the Bessel's correction to the variance.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the variance and the mean of all elements in `tensor` along `dim`.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation computes the variance from the mean, backends should override
+    /// it with a fused reduction when available.
+    fn var_mean<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        dim: usize,
+        unbiased: bool,
+    ) -> (B::TensorPrimitive<D>, B::TensorPrimitive<D>) {
+        let size = B::shape(&tensor).dims[dim];
+        let n = match unbiased {
+            true => size.saturating_sub(1),
+            false => size,
+        };
+
+        let mean = B::mean_dim(tensor.clone(), dim);
+        let diff = B::sub(tensor, mean.clone());
+        let var = B::sum_dim(B::mul(diff.clone(), diff), dim);
+        let var = B::div_scalar(var, (n as i64).elem());
+
+        (var, mean)
+    }
+
     /// Converts a tensor to full precision.
     ///
     /// # Arguments
@@ -802,6 +1108,29 @@ pub trait TensorOps<B: Backend> {
         tensor: <B::FullPrecisionBackend as Backend>::TensorPrimitive<D>,
     ) -> B::TensorPrimitive<D>;
 
+    /// Converts a float tensor to an int tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to convert.
+    ///
+    /// # Returns
+    ///
+    /// An int tensor with the values of `tensor` truncated toward zero.
+    fn into_int<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::IntTensorPrimitive<D> {
+        let device = B::device(&tensor);
+        let data = B::into_data(tensor);
+        // Casting through i64 saturates the values out of range and maps NaN to zero instead of
+        // panicking.
+        let value = data
+            .value
+            .into_iter()
+            .map(|value| (value.elem::<f64>() as i64).elem())
+            .collect();
+
+        B::int_from_data(Data::new(value, data.shape), &device)
+    }
+
     /// Returns a new tensor with exponential values.
     ///
     /// # Arguments
@@ -847,6 +1176,21 @@ pub trait TensorOps<B: Backend> {
     /// A tensor with the same shape as `tensor` with values raised to the power of `value`.
     fn powf<const D: usize>(tensor: B::TensorPrimitive<D>, value: f32) -> B::TensorPrimitive<D>;
 
+    /// Returns a new tensor with values raised element-wise to the power of `rhs`.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The tensor to exponentiate.
+    /// * `rhs` - The tensor of exponents.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the values of `lhs` raised to the power of the values of `rhs`.
+    fn powf_tensor<const D: usize>(
+        lhs: B::TensorPrimitive<D>,
+        rhs: B::TensorPrimitive<D>,
+    ) -> B::TensorPrimitive<D>;
+
     /// Returns a new tensor with square root values.
     ///
     /// # Arguments
@@ -902,6 +1246,40 @@ pub trait TensorOps<B: Backend> {
     /// A tensor with the same shape as `tensor` with error function values.
     fn erf<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
 
+    /// Returns a new tensor with the largest integer less than or equal to each element.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to floor.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with floor values.
+    fn floor<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+
+    /// Returns a new tensor with the smallest integer greater than or equal to each element.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to ceil.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with ceil values.
+    fn ceil<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+
+    /// Returns a new tensor with each element rounded to the nearest integer, rounding half-way
+    /// cases to the nearest even integer.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to round.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with rounded values.
+    fn round<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+
     /// Catcatenates tensors along a dimension.
     ///
     /// # Arguments
@@ -947,6 +1325,103 @@ pub trait TensorOps<B: Backend> {
         dim: usize,
     ) -> B::IntTensorPrimitive<D>;
 
+    /// Element-wise maximum of two tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the maximum of both tensors at each position, the element of `lhs` being
+    /// kept on ties.
+    fn maximum<const D: usize>(
+        lhs: B::TensorPrimitive<D>,
+        rhs: B::TensorPrimitive<D>,
+    ) -> B::TensorPrimitive<D> {
+        let mask = B::lower(lhs.clone(), rhs.clone());
+
+        B::mask_where(lhs, mask, rhs)
+    }
+
+    /// Element-wise minimum of two tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the minimum of both tensors at each position, the element of `lhs` being
+    /// kept on ties.
+    fn minimum<const D: usize>(
+        lhs: B::TensorPrimitive<D>,
+        rhs: B::TensorPrimitive<D>,
+    ) -> B::TensorPrimitive<D> {
+        let mask = B::lower(rhs.clone(), lhs.clone());
+
+        B::mask_where(lhs, mask, rhs)
+    }
+
+    /// Clamps a tensor between a minimum and a maximum value.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to clamp.
+    /// * `min` - The minimum value.
+    /// * `max` - The maximum value.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the elements of `tensor` clamped to `[min, max]`.
+    fn clamp<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        min: B::FloatElem,
+        max: B::FloatElem,
+    ) -> B::TensorPrimitive<D> {
+        B::clamp_max(B::clamp_min(tensor, min), max)
+    }
+
+    /// Clamps a tensor under a minimum value.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to clamp.
+    /// * `min` - The minimum value.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the elements of `tensor` lower than `min` replaced by `min`.
+    fn clamp_min<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        min: B::FloatElem,
+    ) -> B::TensorPrimitive<D> {
+        let mask = B::lower_elem(tensor.clone(), min);
+
+        B::mask_fill(tensor, mask, min)
+    }
+
+    /// Clamps a tensor over a maximum value.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to clamp.
+    /// * `max` - The maximum value.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the elements of `tensor` greater than `max` replaced by `max`.
+    fn clamp_max<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        max: B::FloatElem,
+    ) -> B::TensorPrimitive<D> {
+        let mask = B::greater_elem(tensor.clone(), max);
+
+        B::mask_fill(tensor, mask, max)
+    }
+
     /// Gets the maximum element of a tensor.
     ///
     /// # Arguments
@@ -976,7 +1451,7 @@ pub trait TensorOps<B: Backend> {
     fn max_dim<const D: usize>(tensor: B::TensorPrimitive<D>, dim: usize) -> B::TensorPrimitive<D> {
         let index = B::argmax(tensor.clone(), dim);
 
-        B::gather(D - 1, tensor, index)
+        B::gather(dim, tensor, index)
     }
 
     /// Gets the maximum elements of a tensor along an axis and their indices.
@@ -994,7 +1469,7 @@ pub trait TensorOps<B: Backend> {
         dim: usize,
     ) -> (B::TensorPrimitive<D>, B::IntTensorPrimitive<D>) {
         let index = B::argmax(tensor.clone(), dim);
-        let values = B::gather(D - 1, tensor, index.clone());
+        let values = B::gather(dim, tensor, index.clone());
 
         (values, index)
     }
@@ -1028,7 +1503,7 @@ pub trait TensorOps<B: Backend> {
     fn min_dim<const D: usize>(tensor: B::TensorPrimitive<D>, dim: usize) -> B::TensorPrimitive<D> {
         let index = B::argmin(tensor.clone(), dim);
 
-        B::gather(D - 1, tensor, index)
+        B::gather(dim, tensor, index)
     }
 
     /// Gets the minimum elements of a tensor along an axis and their indices.
@@ -1046,8 +1521,140 @@ pub trait TensorOps<B: Backend> {
         dim: usize,
     ) -> (B::TensorPrimitive<D>, B::IntTensorPrimitive<D>) {
         let index = B::argmin(tensor.clone(), dim);
-        let values = B::gather(D - 1, tensor, index.clone());
+        let values = B::gather(dim, tensor, index.clone());
 
         (values, index)
     }
+
+    /// Gets the `k` largest elements of a tensor along an axis and their indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to get the largest elements of.
+    /// * `k` - The number of elements to keep.
+    /// * `dim` - The dimension along which to get the largest elements.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the `k` largest elements of `tensor` along `dim`, sorted in descending order,
+    /// and their indices. Ties are ordered by increasing index.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation goes through the CPU, backends should override it with a
+    /// dedicated kernel when available.
+    fn topk<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        k: usize,
+        dim: usize,
+    ) -> (B::TensorPrimitive<D>, B::IntTensorPrimitive<D>) {
+        let device = B::device(&tensor);
+        let (values, indices) = sort::topk_data(B::into_data(tensor), k, dim);
+
+        (
+            B::from_data(values, &device),
+            B::int_from_data(indices.convert(), &device),
+        )
+    }
+
+    /// Sorts the elements of a tensor along an axis and returns their indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The dimension along which to sort.
+    /// * `descending` - Whether to sort in descending order instead of ascending order.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the sorted elements and the indices of these elements in `tensor` along
+    /// `dim`. The sort is stable, equal elements keep their original order.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation goes through the CPU, backends should override it with a
+    /// dedicated kernel when available.
+    fn sort<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> (B::TensorPrimitive<D>, B::IntTensorPrimitive<D>) {
+        let device = B::device(&tensor);
+        let (values, indices) = sort::sort_data(B::into_data(tensor), dim, descending);
+
+        (
+            B::from_data(values, &device),
+            B::int_from_data(indices.convert(), &device),
+        )
+    }
+
+    /// Quantizes a tensor to the int8 range with a scale and a zero point for each channel.
+    ///
+    /// `q = clamp(round(x / scale) + zero_point, -128, 127)`, rounding half to even.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to quantize.
+    /// * `scale` - The scale of each channel.
+    /// * `zero_point` - The zero point of each channel.
+    /// * `axis` - The axis of the channels.
+    ///
+    /// # Returns
+    ///
+    /// The quantized tensor.
+    fn quantize_per_channel<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        scale: B::TensorPrimitive<1>,
+        zero_point: B::IntTensorPrimitive<1>,
+        axis: usize,
+    ) -> B::IntTensorPrimitive<D> {
+        quantization::quantize_per_channel::<B, D>(tensor, scale, zero_point, axis)
+    }
+
+    /// Dequantizes a tensor with a scale and a zero point for each channel.
+    ///
+    /// `x = (q - zero_point) * scale`
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The quantized tensor.
+    /// * `scale` - The scale of each channel.
+    /// * `zero_point` - The zero point of each channel.
+    /// * `axis` - The axis of the channels.
+    ///
+    /// # Returns
+    ///
+    /// The dequantized tensor.
+    fn dequantize_per_channel<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        scale: B::TensorPrimitive<1>,
+        zero_point: B::IntTensorPrimitive<1>,
+        axis: usize,
+    ) -> B::TensorPrimitive<D> {
+        quantization::dequantize_per_channel::<B, D>(tensor, scale, zero_point, axis)
+    }
+
+    /// Quantizes and dequantizes a tensor with a scale and a zero point for each channel, which
+    /// simulates the quantization error in float precision.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to quantize.
+    /// * `scale` - The scale of each channel.
+    /// * `zero_point` - The zero point of each channel.
+    /// * `axis` - The axis of the channels.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the quantization error.
+    fn fake_quantize_per_channel<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        scale: B::TensorPrimitive<1>,
+        zero_point: B::IntTensorPrimitive<1>,
+        axis: usize,
+    ) -> B::TensorPrimitive<D> {
+        let tensor = B::quantize_per_channel(tensor, scale.clone(), zero_point.clone(), axis);
+
+        B::dequantize_per_channel(tensor, scale, zero_point, axis)
+    }
 }
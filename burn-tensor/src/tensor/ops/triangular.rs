@@ -0,0 +1,39 @@
+use crate::{backend::Backend, ElementConversion, Shape};
+
+/// Builds the mask of the elements outside of the triangular part of the last two dimensions.
+///
+/// The offset of an element is its column minus its row, so the main diagonal has an offset of
+/// `0`. The lower part keeps the offsets smaller than or equal to `diagonal` and the upper part
+/// keeps the offsets greater than or equal to `diagonal`; every other element is masked.
+pub(crate) fn triangular_mask<B: Backend, const D: usize>(
+    shape: &Shape<D>,
+    diagonal: i64,
+    upper: bool,
+    device: &B::Device,
+) -> B::BoolTensorPrimitive<D> {
+    let rows = shape.dims[D - 2];
+    let cols = shape.dims[D - 1];
+
+    let row_indices = B::int_reshape::<1, 2>(B::arange(0..rows, device), Shape::new([rows, 1]));
+    let col_indices = B::int_reshape::<1, 2>(B::arange(0..cols, device), Shape::new([1, cols]));
+    let offsets = B::int_sub(
+        B::int_repeat(col_indices, 0, rows),
+        B::int_repeat(row_indices, 1, cols),
+    );
+
+    let mask = match upper {
+        true => B::int_lower_elem(offsets, diagonal.elem()),
+        false => B::int_greater_elem(offsets, diagonal.elem()),
+    };
+
+    let mut dims = [1; D];
+    dims[D - 2] = rows;
+    dims[D - 1] = cols;
+
+    let mut mask = B::bool_reshape::<2, D>(mask, Shape::new(dims));
+    for dim in 0..D - 2 {
+        mask = B::bool_repeat(mask, dim, shape.dims[dim]);
+    }
+
+    mask
+}
@@ -1,3 +1,5 @@
+use alloc::vec;
+
 use crate::{backend::Backend, Tensor};
 
 pub fn var<B: Backend, const D: usize>(tensor: Tensor<B, D>, dim: usize) -> Tensor<B, D> {
@@ -10,7 +12,7 @@ pub fn var_with_mean<B: Backend, const D: usize>(
     mean: Tensor<B, D>,
     dim: usize,
 ) -> Tensor<B, D> {
-    let n = tensor.shape().dims[dim] - 1;
+    let n = tensor.shape().dims[dim].saturating_sub(1);
     var_with_mean_n(tensor, mean, dim, n)
 }
 
@@ -36,3 +38,63 @@ pub fn var_with_mean_n<B: Backend, const D: usize>(
 ) -> Tensor<B, D> {
     tensor.sub(mean).powf(2.0).sum_dim(dim).div_scalar(n as f32)
 }
+
+pub fn std<B: Backend, const D: usize>(tensor: Tensor<B, D>, dim: usize) -> Tensor<B, D> {
+    var(tensor, dim).sqrt()
+}
+
+pub fn std_bias<B: Backend, const D: usize>(tensor: Tensor<B, D>, dim: usize) -> Tensor<B, D> {
+    var_bias(tensor, dim).sqrt()
+}
+
+pub fn moving_average<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    window: usize,
+    dim: usize,
+) -> Tensor<B, D> {
+    let size = tensor.shape().dims[dim];
+    let cumsum = exclusive_cumsum(tensor, dim);
+
+    let sum_end = slice_dim(cumsum.clone(), dim, window..size + 1);
+    let sum_start = slice_dim(cumsum, dim, 0..size + 1 - window);
+
+    sum_end.sub(sum_start).div_scalar(window as f32)
+}
+
+/// Cumulative sum along the given dimension, starting with zero, so the size of the dimension is
+/// increased by one.
+///
+/// The scan is done in a logarithmic number of steps, each one adding the tensor shifted by a
+/// power of two.
+fn exclusive_cumsum<B: Backend, const D: usize>(tensor: Tensor<B, D>, dim: usize) -> Tensor<B, D> {
+    let mut shape = tensor.shape();
+    shape.dims[dim] = 1;
+    let zeros = Tensor::zeros_device(shape, &tensor.device());
+    let mut cumsum = Tensor::cat(vec![zeros, tensor], dim);
+
+    let size = cumsum.shape().dims[dim];
+    let mut offset = 1;
+
+    while offset < size {
+        let mut shape = cumsum.shape();
+        shape.dims[dim] = offset;
+        let zeros = Tensor::zeros_device(shape, &cumsum.device());
+        let shifted = slice_dim(cumsum.clone(), dim, 0..size - offset);
+
+        cumsum = cumsum.add(Tensor::cat(vec![zeros, shifted], dim));
+        offset *= 2;
+    }
+
+    cumsum
+}
+
+fn slice_dim<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    dim: usize,
+    range: core::ops::Range<usize>,
+) -> Tensor<B, D> {
+    let mut ranges = tensor.dims().map(|size| 0..size);
+    ranges[dim] = range;
+
+    tensor.slice(ranges)
+}
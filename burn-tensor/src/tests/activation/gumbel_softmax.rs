@@ -0,0 +1,33 @@
+#[burn_tensor_testgen::testgen(gumbel_softmax)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Data, Tensor};
+
+    #[test]
+    fn test_gumbel_softmax_soft_sums_to_one() {
+        let tensor = Tensor::<TestBackend, 2>::from_data([[1.0, 7.0, -2.0], [0.5, 0.5, 3.0]]);
+
+        let output = activation::gumbel_softmax(tensor, 0.5, false, 1);
+
+        output
+            .sum_dim(1)
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0], [1.0]]), 4);
+    }
+
+    #[test]
+    fn test_gumbel_softmax_hard_is_one_hot() {
+        let tensor = Tensor::<TestBackend, 2>::from_data([[1.0, 7.0, -2.0], [0.5, 0.5, 3.0]]);
+
+        let output = activation::gumbel_softmax(tensor, 1.0, true, 1);
+
+        output
+            .clone()
+            .sum_dim(1)
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0], [1.0]]), 4);
+        for value in output.into_data().value {
+            assert!(value == 0.0 || value == 1.0, "{value} isn't one-hot");
+        }
+    }
+}
@@ -0,0 +1,30 @@
+#[burn_tensor_testgen::testgen(leaky_relu)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Data, Tensor};
+
+    #[test]
+    fn test_leaky_relu_d2() {
+        let data = Data::from([[0.0, -1.0, 2.0], [3.0, -4.0, 5.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = activation::leaky_relu(tensor, 0.1).to_data();
+
+        let data_expected = Data::from([[0.0, -0.1, 2.0], [3.0, -0.4, 5.0]]);
+        data_expected.assert_approx_eq(&data_actual, 5);
+    }
+
+    #[test]
+    fn test_prelu_per_channel() {
+        let tensor = Tensor::<TestBackend, 3>::from_data(Data::from([
+            [[-1.0, 2.0], [-3.0, 4.0]],
+            [[5.0, -6.0], [0.0, -8.0]],
+        ]));
+        let alpha = Tensor::<TestBackend, 1>::from_data(Data::from([0.5, 0.25]));
+
+        let data_actual = activation::prelu(tensor, alpha).to_data();
+
+        let data_expected = Data::from([[[-0.5, 2.0], [-0.75, 4.0]], [[5.0, -3.0], [0.0, -2.0]]]);
+        data_expected.assert_approx_eq(&data_actual, 5);
+    }
+}
@@ -0,0 +1,27 @@
+#[burn_tensor_testgen::testgen(log_sigmoid)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Data, Tensor};
+
+    #[test]
+    fn test_log_sigmoid() {
+        let data = Data::from([[1.0, 7.0], [13.0, -3.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = activation::log_sigmoid(tensor).to_data();
+
+        let data_expected = Data::from([[-0.3133, -0.0009], [-0.0000, -3.0486]]);
+        data_actual.assert_approx_eq(&data_expected, 4);
+    }
+
+    #[test]
+    fn test_log_sigmoid_large_magnitude() {
+        let data = Data::from([-100.0, 100.0]);
+        let tensor = Tensor::<TestBackend, 1>::from_data(data);
+
+        let data_actual = activation::log_sigmoid(tensor).to_data();
+
+        assert!(data_actual.value.iter().all(|value| value.is_finite()));
+        data_actual.assert_approx_eq(&Data::from([-100.0, 0.0]), 4);
+    }
+}
@@ -1,4 +1,7 @@
 pub(crate) mod gelu;
+pub(crate) mod gumbel_softmax;
+pub(crate) mod leaky_relu;
+pub(crate) mod log_sigmoid;
 pub(crate) mod relu;
 pub(crate) mod sigmoid;
 pub(crate) mod silu;
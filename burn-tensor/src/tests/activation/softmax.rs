@@ -13,4 +13,30 @@ mod tests {
         let data_expected = Data::from([[2.47e-03, 9.975e-01], [1.0, 1.1254e-07]]);
         data_actual.assert_approx_eq(&data_expected, 4);
     }
+
+    #[test]
+    fn test_log_softmax_d2() {
+        let data = Data::from([[1.0, 7.0], [13.0, -3.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.log_softmax(1).to_data();
+
+        // log(exp(x_i) / sum_j(exp(x_j)))
+        let data_expected = Data::from([[-6.00248, -0.00248], [0.0, -16.0]]);
+        data_actual.assert_approx_eq(&data_expected, 3);
+    }
+
+    #[test]
+    fn test_softmax_large_values_should_not_overflow() {
+        let data = Data::from([[1000.0, 1001.0], [-1000.0, 500.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let softmax = tensor.clone().softmax(1).into_data();
+        let log_softmax = tensor.log_softmax(1).into_data();
+
+        assert!(softmax.value.iter().all(|value| value.is_finite()));
+        assert!(log_softmax.value.iter().all(|value| value.is_finite()));
+        softmax.assert_approx_eq(&Data::from([[0.26894, 0.73106], [0.0, 1.0]]), 4);
+        log_softmax.assert_approx_eq(&Data::from([[-1.31326, -0.31326], [-1500.0, 0.0]]), 3);
+    }
 }
@@ -0,0 +1,54 @@
+#[burn_tensor_testgen::testgen(cosine_similarity)]
+mod tests {
+    use super::*;
+    use burn_tensor::linalg::{cosine_similarity, DEFAULT_EPSILON};
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_give_one_for_identical_vectors() {
+        let a = TestTensor::from_floats([[1.0, 2.0, 3.0], [-4.0, 0.5, 2.0]]);
+
+        let similarity = cosine_similarity(a.clone(), a, 1, DEFAULT_EPSILON);
+
+        similarity
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0], [1.0]]), 3);
+    }
+
+    #[test]
+    fn should_give_zero_for_orthogonal_vectors() {
+        let a = TestTensor::from_floats([[1.0, 0.0], [1.0, 1.0]]);
+        let b = TestTensor::from_floats([[0.0, 3.0], [-2.0, 2.0]]);
+
+        let similarity = cosine_similarity(a, b, 1, DEFAULT_EPSILON);
+
+        similarity
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0], [0.0]]), 3);
+    }
+
+    #[test]
+    fn should_support_other_dims() {
+        let a = TestTensor::from_floats([[1.0, 2.0], [2.0, 4.0]]);
+        let b = TestTensor::from_floats([[-2.0, 1.0], [-4.0, 2.0]]);
+
+        let similarity = cosine_similarity(a, b, 0, DEFAULT_EPSILON);
+
+        // Column 0 is opposite, column 1 is aligned.
+        similarity
+            .into_data()
+            .assert_approx_eq(&Data::from([[-1.0, 1.0]]), 3);
+    }
+
+    #[test]
+    fn should_give_zero_for_zero_vectors() {
+        let a = TestTensor::from_floats([[0.0, 0.0]]);
+        let b = TestTensor::from_floats([[1.0, 2.0]]);
+
+        let similarity = cosine_similarity(a, b, 1, DEFAULT_EPSILON);
+
+        similarity
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0]]), 3);
+    }
+}
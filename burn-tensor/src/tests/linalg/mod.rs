@@ -0,0 +1 @@
+mod cosine_similarity;
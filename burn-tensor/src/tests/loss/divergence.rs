@@ -0,0 +1,75 @@
+#[burn_tensor_testgen::testgen(divergence)]
+mod tests {
+    use super::*;
+    use burn_tensor::loss::{js_divergence, kl_divergence, Reduction};
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_give_zero_kl_divergence_for_identical_distributions() {
+        let probs = TestTensor::from_floats([[0.2, 0.3, 0.5], [0.6, 0.1, 0.3]]);
+
+        let divergence = kl_divergence(probs.clone().log(), probs, 1, Reduction::Mean);
+
+        divergence
+            .into_data()
+            .assert_approx_eq(&Data::from([0.0]), 5);
+    }
+
+    #[test]
+    fn should_compute_kl_divergence() {
+        let log_p = TestTensor::from_floats([[0.5, 0.5], [0.25, 0.75]]).log();
+        let q = TestTensor::from_floats([[0.9, 0.1], [0.25, 0.75]]);
+
+        let divergence_sum = kl_divergence(log_p.clone(), q.clone(), 1, Reduction::Sum);
+        let divergence_mean = kl_divergence(log_p, q, 1, Reduction::Mean);
+
+        // 0.9 * ln(0.9 / 0.5) + 0.1 * ln(0.1 / 0.5)
+        divergence_sum
+            .into_data()
+            .assert_approx_eq(&Data::from([0.3681]), 3);
+        divergence_mean
+            .into_data()
+            .assert_approx_eq(&Data::from([0.1840]), 3);
+    }
+
+    #[test]
+    fn should_ignore_zero_target_probabilities() {
+        let log_p = TestTensor::from_floats([[0.5, 0.5]]).log();
+        let q = TestTensor::from_floats([[1.0, 0.0]]);
+
+        let divergence = kl_divergence(log_p, q, 1, Reduction::Mean);
+
+        divergence
+            .into_data()
+            .assert_approx_eq(&Data::from([core::f32::consts::LN_2]), 3);
+    }
+
+    #[test]
+    fn should_compute_js_divergence() {
+        let log_p = Tensor::<TestBackend, 1>::from_floats([0.5, 0.5]).log();
+        let log_q = Tensor::<TestBackend, 1>::from_floats([0.9, 0.1]).log();
+
+        let divergence_pq = js_divergence(log_p.clone(), log_q.clone(), 0, Reduction::Mean);
+        let divergence_qp = js_divergence(log_q, log_p, 0, Reduction::Mean);
+
+        divergence_pq
+            .into_data()
+            .assert_approx_eq(&Data::from([0.1017]), 3);
+        divergence_qp
+            .into_data()
+            .assert_approx_eq(&Data::from([0.1017]), 3);
+    }
+
+    #[test]
+    fn should_ignore_zero_probabilities_in_js_divergence() {
+        let log_p = TestTensor::from_floats([[1.0, 0.0], [0.5, 0.5]]).log();
+        let log_q = TestTensor::from_floats([[0.0, 1.0], [0.5, 0.5]]).log();
+
+        let divergence = js_divergence(log_p, log_q, 1, Reduction::Sum);
+
+        // Disjoint distributions have the maximum divergence of ln(2).
+        divergence
+            .into_data()
+            .assert_approx_eq(&Data::from([core::f32::consts::LN_2]), 3);
+    }
+}
@@ -1,4 +1,6 @@
 mod activation;
+mod linalg;
+mod loss;
 mod module;
 mod ops;
 mod stats;
@@ -10,32 +12,55 @@ macro_rules! testgen_all {
         // test activation
         burn_tensor::testgen_gelu!();
         burn_tensor::testgen_relu!();
+        burn_tensor::testgen_leaky_relu!();
         burn_tensor::testgen_softmax!();
+        burn_tensor::testgen_gumbel_softmax!();
         burn_tensor::testgen_sigmoid!();
+        burn_tensor::testgen_log_sigmoid!();
         burn_tensor::testgen_silu!();
 
+        // test linalg
+        burn_tensor::testgen_cosine_similarity!();
+
+        // test loss
+        burn_tensor::testgen_divergence!();
+
         // test module
         burn_tensor::testgen_module_forward!();
         burn_tensor::testgen_module_conv1d!();
         burn_tensor::testgen_module_conv2d!();
         burn_tensor::testgen_module_conv_transpose1d!();
         burn_tensor::testgen_module_conv_transpose2d!();
+        burn_tensor::testgen_module_max_pool1d!();
         burn_tensor::testgen_module_max_pool2d!();
         burn_tensor::testgen_module_avg_pool1d!();
         burn_tensor::testgen_module_avg_pool2d!();
+        burn_tensor::testgen_module_adaptive_avg_pool2d!();
+        burn_tensor::testgen_module_interpolate1d!();
+        burn_tensor::testgen_module_pixel_shuffle!();
+        burn_tensor::testgen_module_grid_sample!();
 
         // test ops
         burn_tensor::testgen_add!();
         burn_tensor::testgen_aggregation!();
         burn_tensor::testgen_arange!();
+        burn_tensor::testgen_arange_float!();
         burn_tensor::testgen_arange_step!();
+        burn_tensor::testgen_linspace!();
         burn_tensor::testgen_arg!();
+        burn_tensor::testgen_cast!();
         burn_tensor::testgen_cat!();
+        burn_tensor::testgen_ceil!();
+        burn_tensor::testgen_chunk!();
         burn_tensor::testgen_cos!();
+        burn_tensor::testgen_cumsum!();
+        burn_tensor::testgen_diff!();
         burn_tensor::testgen_div!();
         burn_tensor::testgen_erf!();
         burn_tensor::testgen_exp!();
         burn_tensor::testgen_flatten!();
+        burn_tensor::testgen_flip!();
+        burn_tensor::testgen_floor!();
         burn_tensor::testgen_full!();
         burn_tensor::testgen_gather_scatter!();
         burn_tensor::testgen_log!();
@@ -44,20 +69,29 @@ macro_rules! testgen_all {
         burn_tensor::testgen_mask!();
         burn_tensor::testgen_matmul!();
         burn_tensor::testgen_maxmin!();
+        burn_tensor::testgen_topk!();
+        burn_tensor::testgen_sort!();
+        burn_tensor::testgen_clamp!();
         burn_tensor::testgen_mul!();
         burn_tensor::testgen_neg!();
+        burn_tensor::testgen_one_hot!();
         burn_tensor::testgen_powf!();
+        burn_tensor::testgen_quantization!();
         burn_tensor::testgen_random!();
         burn_tensor::testgen_repeat!();
         burn_tensor::testgen_reshape!();
+        burn_tensor::testgen_round!();
         burn_tensor::testgen_select!();
         burn_tensor::testgen_sin!();
         burn_tensor::testgen_slice!();
+        burn_tensor::testgen_split!();
         burn_tensor::testgen_sqrt!();
         burn_tensor::testgen_squeeze!();
+        burn_tensor::testgen_stack!();
         burn_tensor::testgen_sub!();
         burn_tensor::testgen_tanh!();
         burn_tensor::testgen_transpose!();
+        burn_tensor::testgen_triangular!();
 
         // test stats
         burn_tensor::testgen_stats!();
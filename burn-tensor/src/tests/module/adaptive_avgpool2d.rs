@@ -0,0 +1,85 @@
+#[burn_tensor_testgen::testgen(module_adaptive_avg_pool2d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::adaptive_avg_pool2d;
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_adaptive_avg_pool2d_global() {
+        let test = AdaptiveAvgPool2dTestCase {
+            batch_size: 2,
+            channels: 3,
+            height: 7,
+            width: 7,
+            height_out: 1,
+            width_out: 1,
+        };
+
+        test.assert_output(TestTensor::from_floats([
+            [[[24.]], [[73.]], [[122.]]],
+            [[[171.]], [[220.]], [[269.]]],
+        ]));
+    }
+
+    #[test]
+    fn test_adaptive_avg_pool2d_non_divisible() {
+        let test = AdaptiveAvgPool2dTestCase {
+            batch_size: 1,
+            channels: 1,
+            height: 7,
+            width: 6,
+            height_out: 3,
+            width_out: 4,
+        };
+
+        test.assert_output(TestTensor::from_floats([[[
+            [6.5, 7.5, 9.5, 10.5],
+            [18.5, 19.5, 21.5, 22.5],
+            [30.5, 31.5, 33.5, 34.5],
+        ]]]));
+    }
+
+    #[test]
+    fn test_adaptive_avg_pool2d_upsample() {
+        let test = AdaptiveAvgPool2dTestCase {
+            batch_size: 1,
+            channels: 1,
+            height: 3,
+            width: 3,
+            height_out: 5,
+            width_out: 4,
+        };
+
+        test.assert_output(TestTensor::from_floats([[[
+            [0.0, 0.5, 1.5, 2.0],
+            [1.5, 2.0, 3.0, 3.5],
+            [3.0, 3.5, 4.5, 5.0],
+            [4.5, 5.0, 6.0, 6.5],
+            [6.0, 6.5, 7.5, 8.0],
+        ]]]));
+    }
+
+    struct AdaptiveAvgPool2dTestCase {
+        batch_size: usize,
+        channels: usize,
+        height: usize,
+        width: usize,
+        height_out: usize,
+        width_out: usize,
+    }
+
+    impl AdaptiveAvgPool2dTestCase {
+        fn assert_output(self, y: TestTensor<4>) {
+            let shape_x = Shape::new([self.batch_size, self.channels, self.height, self.width]);
+            let x = TestTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            );
+            let output = adaptive_avg_pool2d(x, [self.height_out, self.width_out]);
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+        }
+    }
+}
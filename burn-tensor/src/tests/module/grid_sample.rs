@@ -0,0 +1,120 @@
+#[burn_tensor_testgen::testgen(module_grid_sample)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::grid_sample;
+    use burn_tensor::ops::{GridSampleMode, GridSamplePadding};
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_grid_sample_identity() {
+        let x = TestTensor::from_floats([[[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]]]);
+        let grid = grid_from_pixels(
+            [
+                [[0.0, 0.0], [1.0, 0.0], [2.0, 0.0]],
+                [[0.0, 1.0], [1.0, 1.0], [2.0, 1.0]],
+            ],
+            3,
+            2,
+        );
+
+        let output = grid_sample(
+            x.clone(),
+            grid.clone(),
+            GridSampleMode::Bilinear,
+            GridSamplePadding::Zeros,
+        );
+        output.into_data().assert_approx_eq(&x.to_data(), 3);
+
+        let output = grid_sample(
+            x.clone(),
+            grid,
+            GridSampleMode::Nearest,
+            GridSamplePadding::Zeros,
+        );
+        output.into_data().assert_approx_eq(&x.into_data(), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear_shifted_zeros() {
+        let x = TestTensor::from_floats([[[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]]]);
+        let grid = grid_from_pixels(
+            [
+                [[0.5, 0.0], [1.5, 0.0], [2.5, 0.0]],
+                [[0.5, 1.0], [1.5, 1.0], [2.5, 1.0]],
+            ],
+            3,
+            2,
+        );
+
+        let output = grid_sample(x, grid, GridSampleMode::Bilinear, GridSamplePadding::Zeros);
+
+        // The last column is interpolated with the zeros outside of the input.
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[1.5, 2.5, 1.5], [4.5, 5.5, 3.0]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear_shifted_border() {
+        let x = TestTensor::from_floats([[[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]]]);
+        let grid = grid_from_pixels(
+            [
+                [[0.5, 0.0], [1.5, 0.0], [2.5, 0.0]],
+                [[0.5, 1.5], [1.5, 1.5], [2.5, 1.5]],
+            ],
+            3,
+            2,
+        );
+
+        let output = grid_sample(x, grid, GridSampleMode::Bilinear, GridSamplePadding::Border);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[1.5, 2.5, 3.0], [4.5, 5.5, 6.0]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear_matches_reference() {
+        let x = TestTensor::from_floats([[[[1.0, 2.0], [3.0, 4.0]], [[-1.0, 0.0], [2.0, 5.0]]]]);
+        let grid = grid_from_pixels([[[0.25, 0.5], [0.8, 0.1]]], 2, 2);
+
+        let output = grid_sample(x, grid, GridSampleMode::Bilinear, GridSamplePadding::Zeros);
+
+        // v = x00 (1 - wx) (1 - wy) + x01 wx (1 - wy) + x10 (1 - wx) wy + x11 wx wy
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[2.25, 2.0]], [[1.0, 0.26]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_nearest() {
+        let x = TestTensor::from_floats([[[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]]]);
+        let grid = grid_from_pixels([[[0.4, 0.6], [1.6, 0.2], [3.2, 0.0]]], 3, 2);
+
+        let output = grid_sample(x, grid, GridSampleMode::Nearest, GridSamplePadding::Zeros);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[4.0, 3.0, 0.0]]]]), 3);
+    }
+
+    /// Build a grid of normalized coordinates from pixel coordinates.
+    fn grid_from_pixels<const H: usize, const W: usize>(
+        pixels: [[[f32; 2]; W]; H],
+        width: usize,
+        height: usize,
+    ) -> TestTensor<4> {
+        let values = pixels
+            .iter()
+            .flatten()
+            .flat_map(|[x, y]| {
+                [
+                    (2.0 * x + 1.0) / width as f32 - 1.0,
+                    (2.0 * y + 1.0) / height as f32 - 1.0,
+                ]
+            })
+            .collect();
+
+        Tensor::from_data(Data::new(values, Shape::new([1, H, W, 2])).convert())
+    }
+}
@@ -0,0 +1,70 @@
+#[burn_tensor_testgen::testgen(module_interpolate1d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::interpolate1d;
+    use burn_tensor::ops::InterpolateMode;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn test_interpolate1d_linear_upsample() {
+        let values = [1.0, 3.0, 2.0, 6.0];
+        let x = TestTensor::from_floats([[values]]);
+
+        let output = interpolate1d(x, 8, InterpolateMode::Linear);
+
+        let expected = Data::from([[linear_reference::<8>(&values)]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn test_interpolate1d_linear_downsample() {
+        let x = TestTensor::from_floats([[[1.0, 3.0, 2.0, 6.0]], [[0.0, 1.0, 2.0, 3.0]]]);
+
+        let output = interpolate1d(x, 2, InterpolateMode::Linear);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[2.0, 4.0]], [[0.5, 2.5]]]), 3);
+    }
+
+    #[test]
+    fn test_interpolate1d_nearest() {
+        let x = TestTensor::from_floats([[[1.0, 3.0, 2.0, 6.0], [0.0, 1.0, 2.0, 3.0]]]);
+
+        let output = interpolate1d(x, 8, InterpolateMode::Nearest);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[
+                [1.0, 1.0, 3.0, 3.0, 2.0, 2.0, 6.0, 6.0],
+                [0.0, 0.0, 1.0, 1.0, 2.0, 2.0, 3.0, 3.0],
+            ]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_interpolate1d_empty_input() {
+        let x = TestTensor::zeros([2, 3, 0]);
+
+        let output = interpolate1d(x, 4, InterpolateMode::Linear);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::zeros([2, 3, 4]), 3);
+    }
+
+    /// Element by element linear interpolation, with the elements aligned on their centers.
+    fn linear_reference<const N: usize>(values: &[f32]) -> [f32; N] {
+        let scale = values.len() as f32 / N as f32;
+        let last = values.len() - 1;
+
+        core::array::from_fn(|i| {
+            let position = ((i as f32 + 0.5) * scale - 0.5).clamp(0.0, last as f32);
+            let low = position as usize;
+            let high = usize::min(low + 1, last);
+            let weight = position - low as f32;
+
+            values[low] * (1.0 - weight) + values[high] * weight
+        })
+    }
+}
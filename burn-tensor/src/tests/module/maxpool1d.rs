@@ -0,0 +1,74 @@
+#[burn_tensor_testgen::testgen(module_max_pool1d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{max_pool1d, max_pool1d_with_indices};
+    use burn_tensor::{backend::Backend, Data};
+
+    type IntElem = <TestBackend as Backend>::IntElem;
+
+    #[test]
+    fn test_max_pool1d_simple() {
+        let kernel_size = 3;
+        let padding = 1;
+        let stride = 1;
+
+        let x = TestTensor::from_floats([[
+            [0.9861, 0.5474, 0.4477, 0.0732, 0.3548, 0.8221],
+            [0.8148, 0.5474, 0.9490, 0.7890, 0.5537, 0.5689],
+        ]]);
+        let y = TestTensor::from_floats([[
+            [0.9861, 0.9861, 0.5474, 0.4477, 0.8221, 0.8221],
+            [0.8148, 0.9490, 0.9490, 0.9490, 0.7890, 0.5689],
+        ]]);
+
+        let output = max_pool1d(x, kernel_size, stride, padding);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_max_pool1d_complex() {
+        let kernel_size = 3;
+        let padding = 1;
+        let stride = 2;
+
+        let x = TestTensor::from_floats([[[0.5, 0.9, 0.1, 0.7, 0.3, 0.2]]]);
+        let y = TestTensor::from_floats([[[0.9, 0.9, 0.7]]]);
+
+        let output = max_pool1d(x, kernel_size, stride, padding);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_max_pool1d_with_indices() {
+        let kernel_size = 3;
+        let padding = 1;
+        let stride = 2;
+
+        let x = TestTensor::from_floats([[[0.5, 0.9, 0.1, 0.7, 0.3, 0.2]]]);
+        let indices = Data::<IntElem, 3>::from([[[1, 1, 3]]]);
+        let y = TestTensor::from_floats([[[0.9, 0.9, 0.7]]]);
+
+        let (output, output_indices) = max_pool1d_with_indices(x, kernel_size, stride, padding);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        assert_eq!(indices.value, output_indices.into_data().value);
+    }
+
+    #[test]
+    fn test_max_pool1d_with_indices_padding_edges() {
+        let kernel_size = 2;
+        let padding = 1;
+        let stride = 1;
+
+        let x = TestTensor::from_floats([[[0.6, 0.2, 0.4, 0.8]]]);
+        let indices = Data::<IntElem, 3>::from([[[0, 0, 2, 3, 3]]]);
+        let y = TestTensor::from_floats([[[0.6, 0.6, 0.4, 0.8, 0.8]]]);
+
+        let (output, output_indices) = max_pool1d_with_indices(x, kernel_size, stride, padding);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        assert_eq!(indices.value, output_indices.into_data().value);
+    }
+}
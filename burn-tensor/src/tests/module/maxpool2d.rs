@@ -224,6 +224,26 @@ mod tests {
         assert_eq!(indices.value, output_indices.into_data().value);
     }
 
+    #[test]
+    fn test_max_pool2d_with_indices_non_square() {
+        // The indices are flattened with the width, which differs from the height.
+        let x = TestTensor::from_floats([[[
+            [0.5388, 0.0676, 0.7122, 0.8316, 0.0653],
+            [0.9154, 0.1536, 0.9089, 0.8016, 0.7518],
+            [0.2073, 0.0501, 0.8811, 0.5604, 0.5075],
+        ]]]);
+        let indices = Data::<IntElem, 4>::from([[[[5, 5, 7, 7, 3], [5, 5, 7, 7, 8]]]]);
+        let y = TestTensor::from_floats([[[
+            [0.9154, 0.9154, 0.9089, 0.9089, 0.8316],
+            [0.9154, 0.9154, 0.9089, 0.9089, 0.8016],
+        ]]]);
+
+        let (output, output_indices) = max_pool2d_with_indices(x, [2, 3], [1, 1], [0, 1]);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        assert_eq!(indices.value, output_indices.into_data().value);
+    }
+
     #[test]
     fn test_max_pool2d_complex() {
         let batch_size = 1;
@@ -1,3 +1,4 @@
+mod adaptive_avgpool2d;
 mod avgpool1d;
 mod avgpool2d;
 mod conv1d;
@@ -5,4 +6,8 @@ mod conv2d;
 mod conv_transpose1d;
 mod conv_transpose2d;
 mod forward;
+mod grid_sample;
+mod interpolate1d;
+mod maxpool1d;
 mod maxpool2d;
+mod pixel_shuffle;
@@ -0,0 +1,56 @@
+#[burn_tensor_testgen::testgen(module_pixel_shuffle)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{pixel_shuffle, pixel_unshuffle};
+    use burn_tensor::{Data, Distribution, Shape, Tensor};
+
+    #[test]
+    fn test_pixel_shuffle() {
+        let x = TestTensor::from_floats([[
+            [[0.0, 1.0], [2.0, 3.0]],
+            [[4.0, 5.0], [6.0, 7.0]],
+            [[8.0, 9.0], [10.0, 11.0]],
+            [[12.0, 13.0], [14.0, 15.0]],
+        ]]);
+
+        let output = pixel_shuffle(x, 2);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[
+                [0.0, 4.0, 1.0, 5.0],
+                [8.0, 12.0, 9.0, 13.0],
+                [2.0, 6.0, 3.0, 7.0],
+                [10.0, 14.0, 11.0, 15.0],
+            ]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_pixel_shuffle_shape() {
+        let x = Tensor::<TestBackend, 4>::zeros([2, 18, 4, 5]);
+
+        let output = pixel_shuffle(x, 3);
+
+        assert_eq!(output.shape(), Shape::new([2, 2, 12, 15]));
+    }
+
+    #[test]
+    fn test_pixel_unshuffle_round_trip() {
+        let x = Tensor::<TestBackend, 4>::random([2, 8, 3, 5], Distribution::Default);
+
+        let shuffled = pixel_shuffle(x.clone(), 2);
+        let output = pixel_unshuffle(shuffled.clone(), 2);
+
+        assert_eq!(shuffled.shape(), Shape::new([2, 2, 6, 10]));
+        output.into_data().assert_approx_eq(&x.into_data(), 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_pixel_shuffle_invalid_channels() {
+        let x = Tensor::<TestBackend, 4>::zeros([1, 6, 2, 2]);
+
+        pixel_shuffle(x, 2);
+    }
+}
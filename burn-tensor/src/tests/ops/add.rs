@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(add)]
 mod tests {
     use super::*;
-    use burn_tensor::{Data, Tensor};
+    use burn_tensor::{Data, Int, Tensor};
 
     #[test]
     fn test_add_d2() {
@@ -28,4 +28,26 @@ mod tests {
         let data_expected = Data::from([[3.0, 5.0, 7.0], [6.0, 8.0, 10.0]]);
         assert_eq!(data_expected, data_actual);
     }
+
+    #[test]
+    fn test_add_d2_int() {
+        let tensor_1 = Tensor::<TestBackend, 2, Int>::from_data([[0, 1, 2], [3, 4, 5]]);
+        let tensor_2 = Tensor::<TestBackend, 2, Int>::from_data([[6, 7, 8], [9, 10, 11]]);
+
+        let data_actual = tensor_1.add(tensor_2).into_data();
+
+        let data_expected = Data::from([[6, 8, 10], [12, 14, 16]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_add_broadcast_int() {
+        let tensor_1 = Tensor::<TestBackend, 2, Int>::from_data([[0, 1, 2]]);
+        let tensor_2 = Tensor::<TestBackend, 2, Int>::from_data([[3, 4, 5], [6, 7, 8]]);
+
+        let data_actual = tensor_1.add(tensor_2).into_data();
+
+        let data_expected = Data::from([[3, 5, 7], [6, 8, 10]]);
+        assert_eq!(data_expected, data_actual);
+    }
 }
@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(aggregation)]
 mod tests {
     use super::*;
-    use burn_tensor::{Data, Shape, Tensor};
+    use burn_tensor::{Data, Int, Shape, Tensor};
 
     #[test]
     fn test_should_mean() {
@@ -21,6 +21,21 @@ mod tests {
         assert_eq!(data_actual, Data::from([15.0]));
     }
 
+    #[test]
+    fn test_should_sum_and_mean_full_precision() {
+        let tensor = TestTensor::from_data([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let sum = tensor.clone().sum_full_precision().to_data();
+        let mean = tensor.clone().mean_full_precision().to_data();
+        let sum_dim = tensor.clone().sum_dim_full_precision(1).to_data();
+        let mean_dim = tensor.mean_dim_full_precision(0).to_data();
+
+        assert_eq!(sum, Data::from([15.0]));
+        assert_eq!(mean, Data::from([15.0 / 6.0]));
+        assert_eq!(sum_dim, Data::from([[3.0], [12.0]]));
+        assert_eq!(mean_dim, Data::from([[1.5, 2.5, 3.5]]));
+    }
+
     #[test]
     fn test_should_mean_last_dim() {
         let tensor = TestTensor::from_data([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
@@ -86,4 +101,24 @@ mod tests {
             Data::new(vec![5.0, 5.0, 3.0, 11.0, -3.0, 6.0], Shape::new([2, 1, 3]))
         );
     }
+
+    #[test]
+    fn test_should_sum_int() {
+        let tensor = Tensor::<TestBackend, 2, Int>::from_data([[0, 1, 2], [3, 4, 5]]);
+
+        let data_actual = tensor.sum().into_data();
+
+        assert_eq!(data_actual, Data::from([15]));
+    }
+
+    #[test]
+    fn test_should_sum_dim_int() {
+        let tensor = Tensor::<TestBackend, 2, Int>::from_data([[0, 1, 2], [3, 4, 5]]);
+
+        let data_actual = tensor.clone().sum_dim(1).into_data();
+        assert_eq!(data_actual, Data::from([[3], [12]]));
+
+        let data_actual = tensor.sum_dim(0).into_data();
+        assert_eq!(data_actual, Data::from([[3, 5, 7]]));
+    }
 }
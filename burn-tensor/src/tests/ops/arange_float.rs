@@ -0,0 +1,29 @@
+#[burn_tensor_testgen::testgen(arange_float)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn test_arange_float() {
+        let tensor = Tensor::<TestBackend, 1>::arange_float(0.0, 2.0, 0.5);
+        assert_eq!(tensor.into_data(), Data::from([0.0, 0.5, 1.0, 1.5]));
+    }
+
+    #[test]
+    fn test_arange_float_step_2() {
+        let tensor = Tensor::<TestBackend, 1>::arange_float(1.0, 8.0, 2.0);
+        assert_eq!(tensor.into_data(), Data::from([1.0, 3.0, 5.0, 7.0]));
+    }
+
+    #[test]
+    fn test_arange_float_negative_step() {
+        let tensor = Tensor::<TestBackend, 1>::arange_float(2.0, -1.0, -1.0);
+        assert_eq!(tensor.into_data(), Data::from([2.0, 1.0, 0.0]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_arange_float_zero_step_panic() {
+        let _tensor = Tensor::<TestBackend, 1>::arange_float(0.0, 3.0, 0.0);
+    }
+}
@@ -46,4 +46,25 @@ mod tests {
         let data_expected = Data::from([[2], [1]]);
         assert_eq!(data_expected, data_actual.to_data());
     }
+
+    #[test]
+    fn test_argmax_ties_should_return_the_first_index() {
+        let tensor =
+            Tensor::<TestBackend, 2>::from_data([[1.0, 3.0, 3.0, 2.0], [4.0, 4.0, 4.0, 4.0]]);
+
+        let data_actual = tensor.argmax(1);
+
+        let data_expected = Data::from([[1], [0]]);
+        assert_eq!(data_expected, data_actual.to_data());
+    }
+
+    #[test]
+    fn test_argmin_ties_should_return_the_first_index() {
+        let tensor = Tensor::<TestBackend, 2>::from_data([[2.0, 1.0, 0.0], [2.0, 5.0, 0.0]]);
+
+        let data_actual = tensor.argmin(0);
+
+        let data_expected = Data::from([[0, 0, 0]]);
+        assert_eq!(data_expected, data_actual.to_data());
+    }
 }
@@ -0,0 +1,25 @@
+#[burn_tensor_testgen::testgen(cast)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_truncate_float_into_int() {
+        let tensor = Tensor::<TestBackend, 2>::from_data([[1.7, -1.7, 0.2], [3.0, -0.5, 10.9]]);
+
+        let data_actual = tensor.into_int().into_data();
+
+        let data_expected = Data::from([[1, -1, 0], [3, 0, 10]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_convert_int_into_float() {
+        let tensor = Tensor::<TestBackend, 2, Int>::from_ints([[1, -2, 0], [3, 4, -5]]);
+
+        let data_actual = tensor.into_float().into_data();
+
+        let data_expected = Data::from([[1.0, -2.0, 0.0], [3.0, 4.0, -5.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+}
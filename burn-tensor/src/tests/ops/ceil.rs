@@ -0,0 +1,16 @@
+#[burn_tensor_testgen::testgen(ceil)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_ceil_ops() {
+        let data = Data::from([[-1.5, -0.5, 0.0], [0.5, 1.2, 2.9]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.ceil().into_data();
+
+        let data_expected = Data::from([[-1.0, 0.0, 0.0], [1.0, 2.0, 3.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+}
@@ -0,0 +1,74 @@
+#[burn_tensor_testgen::testgen(chunk)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_chunk_evenly_divisible() {
+        let tensors = Tensor::<TestBackend, 1, Int>::arange(0..12).chunk(6, 0);
+        assert_eq!(tensors.len(), 6);
+
+        let expected = vec![
+            Data::from([0, 1]),
+            Data::from([2, 3]),
+            Data::from([4, 5]),
+            Data::from([6, 7]),
+            Data::from([8, 9]),
+            Data::from([10, 11]),
+        ];
+
+        for (index, tensor) in tensors.into_iter().enumerate() {
+            assert_eq!(tensor.to_data(), expected[index]);
+        }
+    }
+
+    #[test]
+    fn should_chunk_not_evenly_divisible() {
+        let tensors = Tensor::<TestBackend, 1, Int>::arange(0..11).chunk(6, 0);
+        assert_eq!(tensors.len(), 6);
+
+        let expected = vec![
+            Data::from([0, 1]),
+            Data::from([2, 3]),
+            Data::from([4, 5]),
+            Data::from([6, 7]),
+            Data::from([8, 9]),
+            Data::from([10]),
+        ];
+
+        for (index, tensor) in tensors.into_iter().enumerate() {
+            assert_eq!(tensor.to_data(), expected[index]);
+        }
+    }
+
+    #[test]
+    fn should_chunk_into_fewer_chunks_when_not_enough_elements() {
+        let tensors = Tensor::<TestBackend, 1, Int>::arange(0..3).chunk(5, 0);
+        assert_eq!(tensors.len(), 3);
+
+        let expected = vec![Data::from([0]), Data::from([1]), Data::from([2])];
+
+        for (index, tensor) in tensors.into_iter().enumerate() {
+            assert_eq!(tensor.to_data(), expected[index]);
+        }
+    }
+
+    #[test]
+    fn should_chunk_multi_dimension() {
+        let tensors =
+            Tensor::<TestBackend, 2, Int>::from_data(Data::from([[0, 1, 2, 3, 4]])).chunk(2, 1);
+        assert_eq!(tensors.len(), 2);
+
+        let expected = vec![Data::from([[0, 1, 2]]), Data::from([[3, 4]])];
+
+        for (index, tensor) in tensors.into_iter().enumerate() {
+            assert_eq!(tensor.to_data(), expected[index]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_chunk_dimension_is_out_of_bounds() {
+        let _tensors = Tensor::<TestBackend, 1, Int>::arange(0..12).chunk(6, 1);
+    }
+}
@@ -0,0 +1,38 @@
+#[burn_tensor_testgen::testgen(clamp)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_clamp_ops() {
+        let data = Data::from([[-2.0, -1.0, 0.0], [1.0, 2.0, 3.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.clamp(-1.0, 2.0).into_data();
+
+        let data_expected = Data::from([[-1.0, -1.0, 0.0], [1.0, 2.0, 2.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_clamp_min_ops() {
+        let data = Data::from([[-2.0, -1.0, 0.0], [1.0, 2.0, 3.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.clamp_min(1.0).into_data();
+
+        let data_expected = Data::from([[1.0, 1.0, 1.0], [1.0, 2.0, 3.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_clamp_max_ops() {
+        let data = Data::from([[-2.0, -1.0, 0.0], [1.0, 2.0, 3.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.clamp_max(0.0).into_data();
+
+        let data_expected = Data::from([[-2.0, -1.0, 0.0], [0.0, 0.0, 0.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+}
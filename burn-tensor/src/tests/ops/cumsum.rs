@@ -0,0 +1,86 @@
+#[burn_tensor_testgen::testgen(cumsum)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Shape};
+
+    #[test]
+    fn should_support_cumsum_1d() {
+        let tensor = TestTensor::from_floats([1.0, 2.0, 3.0, 4.0]);
+
+        let output = tensor.cumsum(0);
+
+        assert_eq!(output.into_data(), Data::from([1.0, 3.0, 6.0, 10.0]));
+    }
+
+    #[test]
+    fn should_support_cumsum_2d_dim0() {
+        let tensor = TestTensor::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0], [6.0, 7.0, 8.0]]);
+
+        let output = tensor.cumsum(0);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[0.0, 1.0, 2.0], [3.0, 5.0, 7.0], [9.0, 12.0, 15.0]])
+        );
+    }
+
+    #[test]
+    fn should_support_cumsum_2d_dim1() {
+        let tensor = TestTensor::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let output = tensor.cumsum(1);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[0.0, 1.0, 3.0], [3.0, 7.0, 12.0]])
+        );
+    }
+
+    #[test]
+    fn should_support_cumsum_3d_middle_dim() {
+        let tensor = TestTensor::from_floats([
+            [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]],
+            [[-1.0, 0.0], [1.0, 0.0], [-1.0, 2.0]],
+        ]);
+
+        let output = tensor.cumsum(1);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([
+                [[1.0, 2.0], [4.0, 6.0], [9.0, 12.0]],
+                [[-1.0, 0.0], [0.0, 0.0], [-1.0, 2.0]],
+            ])
+        );
+    }
+
+    #[test]
+    fn should_support_cumsum_after_transpose() {
+        let tensor = TestTensor::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let output = tensor.transpose().cumsum(0);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[0.0, 3.0], [1.0, 7.0], [3.0, 12.0]])
+        );
+    }
+
+    #[test]
+    fn should_support_cumsum_empty_dim() {
+        let tensor = TestTensor::<2>::from_data(Data::new(vec![], Shape::new([2, 0])));
+
+        let output = tensor.cumsum(1);
+
+        assert_eq!(output.shape(), Shape::new([2, 0]));
+        assert_eq!(output.into_data().value.len(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_cumsum_dim_exceeds_rank() {
+        let tensor = TestTensor::from_floats([[0.0, 1.0], [2.0, 3.0]]);
+
+        let _output = tensor.cumsum(2);
+    }
+}
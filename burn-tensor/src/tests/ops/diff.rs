@@ -0,0 +1,41 @@
+#[burn_tensor_testgen::testgen(diff)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_diff_1d() {
+        let tensor = TestTensor::from_floats([1.0, 4.0, 9.0, 16.0]);
+
+        let output = tensor.diff(0, 1);
+
+        assert_eq!(output.into_data(), Data::from([3.0, 5.0, 7.0]));
+    }
+
+    #[test]
+    fn should_support_diff_second_order() {
+        let tensor = TestTensor::from_floats([1.0, 4.0, 9.0, 16.0]);
+
+        let output = tensor.diff(0, 2);
+
+        assert_eq!(output.into_data(), Data::from([2.0, 2.0]));
+    }
+
+    #[test]
+    fn should_support_diff_2d_dim1() {
+        let tensor = TestTensor::from_floats([[0.0, 1.0, 3.0], [6.0, 4.0, 5.0]]);
+
+        let output = tensor.diff(1, 1);
+
+        assert_eq!(output.into_data(), Data::from([[1.0, 2.0], [-2.0, 1.0]]));
+    }
+
+    #[test]
+    fn should_support_diff_zero_order() {
+        let tensor = TestTensor::from_floats([[0.0, 1.0, 3.0], [6.0, 4.0, 5.0]]);
+
+        let output = tensor.clone().diff(0, 0);
+
+        assert_eq!(output.into_data(), tensor.into_data());
+    }
+}
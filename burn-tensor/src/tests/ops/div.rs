@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(div)]
 mod tests {
     use super::*;
-    use burn_tensor::{Data, Tensor};
+    use burn_tensor::{Data, Int, Tensor};
 
     #[test]
     fn should_support_div_ops() {
@@ -29,4 +29,26 @@ mod tests {
         let data_expected = Data::from([[0.0, 0.5, 1.0], [1.5, 2.0, 2.5]]);
         assert_eq!(data_expected, data_actual);
     }
+
+    #[test]
+    fn should_support_div_ops_int() {
+        let tensor_1 = Tensor::<TestBackend, 2, Int>::from_data([[0, 7, 9], [-7, 4, 5]]);
+        let tensor_2 = Tensor::<TestBackend, 2, Int>::from_data([[1, 2, 3], [2, 4, -2]]);
+
+        let data_actual = tensor_1.div(tensor_2).into_data();
+
+        // Integer division truncates toward zero.
+        let data_expected = Data::from([[0, 3, 3], [-3, 1, -2]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_div_scalar_ops_int() {
+        let tensor = Tensor::<TestBackend, 2, Int>::from_data([[0, 1, 2], [3, 4, -5]]);
+
+        let data_actual = tensor.div_scalar(2).into_data();
+
+        let data_expected = Data::from([[0, 0, 1], [1, 2, -2]]);
+        assert_eq!(data_expected, data_actual);
+    }
 }
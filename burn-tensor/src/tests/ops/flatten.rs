@@ -46,4 +46,12 @@ mod tests {
         let tensor = Tensor::<TestBackend, 4>::ones(Shape::new([2, 3, 4, 5]));
         let flattened_tensor: Tensor<TestBackend, 2> = tensor.flatten(2, 0);
     }
+
+    /// Test if the function panics when the result dimension doesn't match the flattened range.
+    #[test]
+    #[should_panic]
+    fn should_flatten_wrong_result_dim_panic() {
+        let tensor = Tensor::<TestBackend, 4>::ones(Shape::new([2, 3, 4, 5]));
+        let flattened_tensor: Tensor<TestBackend, 2> = tensor.flatten(1, 2);
+    }
 }
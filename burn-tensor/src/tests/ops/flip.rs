@@ -0,0 +1,67 @@
+#[burn_tensor_testgen::testgen(flip)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_flip_one_dimension() {
+        let tensor = TestTensor::from_data([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let data_actual = tensor.flip(&[1]).into_data();
+
+        let data_expected = Data::from([[2.0, 1.0, 0.0], [5.0, 4.0, 3.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_flip_two_dimensions() {
+        let tensor = TestTensor::from_data([
+            [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]],
+            [[6.0, 7.0, 8.0], [9.0, 10.0, 11.0]],
+        ]);
+
+        let data_actual = tensor.flip(&[0, 2]).into_data();
+
+        let data_expected = Data::from([
+            [[8.0, 7.0, 6.0], [11.0, 10.0, 9.0]],
+            [[2.0, 1.0, 0.0], [5.0, 4.0, 3.0]],
+        ]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_flip_then_reshape() {
+        let tensor = TestTensor::from_data([[0.0, 1.0], [2.0, 3.0]]);
+
+        let data_actual = tensor.flip(&[0]).reshape([4]).into_data();
+
+        let data_expected = Data::from([2.0, 3.0, 0.0, 1.0]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_not_change_with_no_axes() {
+        let tensor = TestTensor::from_data([[0.0, 1.0], [2.0, 3.0]]);
+
+        let data_actual = tensor.flip(&[]).into_data();
+
+        let data_expected = Data::from([[0.0, 1.0], [2.0, 3.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_axes_are_duplicated() {
+        let tensor = TestTensor::from_data([[0.0, 1.0], [2.0, 3.0]]);
+
+        let _output = tensor.flip(&[1, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_axis_is_out_of_bounds() {
+        let tensor = TestTensor::from_data([[0.0, 1.0], [2.0, 3.0]]);
+
+        let _output = tensor.flip(&[2]);
+    }
+}
@@ -26,6 +26,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_gather_2d_dim0_int() {
+        let tensor = TestTensorInt::from_ints([[0, 1, 2], [3, 4, 5]]);
+        let tensor_float = TestTensor::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        let indices = TestTensorInt::from_ints([[0, 1, 0], [1, 0, 1]]);
+
+        let output = tensor.gather(0, indices.clone());
+        let output_float = tensor_float.gather(0, indices);
+
+        assert_eq!(output.into_data(), output_float.into_data().convert());
+    }
+
     #[test]
     fn should_gather_2d_dim1() {
         let tensor = TestTensor::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
@@ -143,4 +155,32 @@ mod tests {
             Data::from([[0.0, 1.0, 0.0], [0.0, 0.0, 4.0]])
         );
     }
+
+    #[test]
+    fn should_scatter_max_with_colliding_indices() {
+        let tensor = TestTensor::from_floats([[0.0, 8.0, 0.0], [4.0, 0.0, 0.0]]);
+        let values = TestTensor::from_floats([[1.0, 3.0, 7.0, 2.0], [4.0, 1.0, 6.0, 9.0]]);
+        let indices = TestTensorInt::from_ints([[0, 0, 1, 2], [0, 2, 2, 1]]);
+
+        let output = tensor.scatter_max(1, indices, values);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[3.0, 8.0, 2.0], [4.0, 9.0, 6.0]])
+        );
+    }
+
+    #[test]
+    fn should_scatter_min_with_colliding_indices() {
+        let tensor = TestTensor::from_floats([[4.0, 5.0, 4.0], [8.0, 8.0, 8.0]]);
+        let values = TestTensor::from_floats([[1.0, 3.0, 7.0, 2.0], [4.0, 1.0, 6.0, 9.0]]);
+        let indices = TestTensorInt::from_ints([[0, 0, 1, 2], [0, 2, 2, 1]]);
+
+        let output = tensor.scatter_min(1, indices, values);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[1.0, 5.0, 2.0], [4.0, 8.0, 1.0]])
+        );
+    }
 }
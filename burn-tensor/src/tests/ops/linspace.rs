@@ -0,0 +1,23 @@
+#[burn_tensor_testgen::testgen(linspace)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn test_linspace() {
+        let tensor = Tensor::<TestBackend, 1>::linspace(0.0, 1.0, 5);
+        assert_eq!(tensor.into_data(), Data::from([0.0, 0.25, 0.5, 0.75, 1.0]));
+    }
+
+    #[test]
+    fn test_linspace_decreasing() {
+        let tensor = Tensor::<TestBackend, 1>::linspace(2.0, -2.0, 3);
+        assert_eq!(tensor.into_data(), Data::from([2.0, 0.0, -2.0]));
+    }
+
+    #[test]
+    fn test_linspace_single_step_returns_start() {
+        let tensor = Tensor::<TestBackend, 1>::linspace(3.0, 7.0, 1);
+        assert_eq!(tensor.into_data(), Data::from([3.0]));
+    }
+}
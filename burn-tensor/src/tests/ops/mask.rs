@@ -16,6 +16,30 @@ mod tests {
         assert_eq!(data_expected, data_actual);
     }
 
+    #[test]
+    fn should_support_mask_where_broadcasted_mask() {
+        let tensor = TestTensor::from_data([[1.0, 7.0], [2.0, 3.0]]);
+        let mask = Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([[true, false]]));
+        let value = Tensor::<TestBackend, 2>::from_data(Data::from([[8.8, 9.8], [10.8, 11.8]]));
+
+        let data_actual = tensor.mask_where(mask, value).into_data();
+
+        let data_expected = Data::from([[8.8, 7.0], [10.8, 3.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_mask_where_broadcasted_mask_and_value() {
+        let tensor = TestTensor::from_data([[1.0, 7.0], [2.0, 3.0]]);
+        let mask = Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([[false], [true]]));
+        let value = Tensor::<TestBackend, 2>::from_data(Data::from([[8.8, 9.8]]));
+
+        let data_actual = tensor.mask_where(mask, value).into_data();
+
+        let data_expected = Data::from([[1.0, 7.0], [8.8, 9.8]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
     #[test]
     fn should_support_mask_fill_ops() {
         let tensor = TestTensor::from_data([[1.0, 7.0], [2.0, 3.0]]);
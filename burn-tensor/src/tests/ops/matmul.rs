@@ -43,6 +43,117 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_matmul_batched_d3() {
+        let tensor_1 = TestTensor::from_floats([
+            [
+                [1.0, 2.0, 3.0, 4.0],
+                [0.0, 1.0, 0.0, 1.0],
+                [2.0, 0.0, 1.0, 0.0],
+            ],
+            [
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 2.0],
+                [1.0, 1.0, 1.0, 1.0],
+            ],
+        ]);
+        let tensor_2 = TestTensor::from_floats([
+            [
+                [1.0, 0.0, 2.0, 0.0, 1.0],
+                [0.0, 1.0, 0.0, 2.0, 1.0],
+                [1.0, 1.0, 0.0, 0.0, 1.0],
+                [0.0, 0.0, 1.0, 1.0, 1.0],
+            ],
+            [
+                [2.0, 1.0, 0.0, 0.0, 3.0],
+                [0.0, 1.0, 1.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0, 1.0, 0.0],
+                [0.0, 2.0, 0.0, 1.0, 1.0],
+            ],
+        ]);
+
+        let tensor_3 = tensor_1.matmul(tensor_2);
+
+        assert_eq!(
+            tensor_3.into_data(),
+            Data::from([
+                [
+                    [4.0, 5.0, 6.0, 8.0, 10.0],
+                    [0.0, 1.0, 1.0, 3.0, 2.0],
+                    [3.0, 1.0, 4.0, 0.0, 3.0],
+                ],
+                [
+                    [2.0, 1.0, 0.0, 0.0, 3.0],
+                    [0.0, 4.0, 0.0, 2.0, 2.0],
+                    [3.0, 4.0, 1.0, 2.0, 4.0],
+                ],
+            ])
+        );
+    }
+
+    #[test]
+    fn test_matmul_broadcast_d3() {
+        let tensor_1 = TestTensor::from_floats([[
+            [1.0, 2.0, 3.0, 4.0],
+            [0.0, 1.0, 0.0, 1.0],
+            [2.0, 0.0, 1.0, 0.0],
+        ]]);
+        let tensor_2 = TestTensor::from_floats([
+            [
+                [1.0, 0.0, 2.0, 0.0, 1.0],
+                [0.0, 1.0, 0.0, 2.0, 1.0],
+                [1.0, 1.0, 0.0, 0.0, 1.0],
+                [0.0, 0.0, 1.0, 1.0, 1.0],
+            ],
+            [
+                [2.0, 1.0, 0.0, 0.0, 3.0],
+                [0.0, 1.0, 1.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0, 1.0, 0.0],
+                [0.0, 2.0, 0.0, 1.0, 1.0],
+            ],
+        ]);
+
+        let tensor_3 = tensor_1.matmul(tensor_2);
+
+        assert_eq!(
+            tensor_3.into_data(),
+            Data::from([
+                [
+                    [4.0, 5.0, 6.0, 8.0, 10.0],
+                    [0.0, 1.0, 1.0, 3.0, 2.0],
+                    [3.0, 1.0, 4.0, 0.0, 3.0],
+                ],
+                [
+                    [5.0, 11.0, 2.0, 7.0, 7.0],
+                    [0.0, 3.0, 1.0, 1.0, 1.0],
+                    [5.0, 2.0, 0.0, 1.0, 6.0],
+                ],
+            ])
+        );
+    }
+
+    #[test]
+    fn test_matmul_broadcast_batch_dims_independently() {
+        let tensor_1 = TestTensor::from_floats([[[[1.0, 2.0]]], [[[3.0, 4.0]]]]);
+        let tensor_2 = TestTensor::from_floats([[[[1.0], [0.0]], [[0.0], [1.0]], [[1.0], [1.0]]]]);
+
+        let tensor_3 = tensor_1.matmul(tensor_2);
+
+        assert_eq!(
+            tensor_3.into_data(),
+            Data::from([[[[1.0]], [[2.0]], [[3.0]]], [[[3.0]], [[4.0]], [[7.0]]]])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_matmul_should_panic_when_batch_dims_not_broadcastable() {
+        let tensor_1 = TestTensor::<3>::zeros([2, 3, 4]);
+        let tensor_2 = TestTensor::<3>::zeros([3, 4, 5]);
+
+        let _ = tensor_1.matmul(tensor_2);
+    }
+
     #[test]
     fn test_matmul_simple_1() {
         let tensor_1 = TestTensor::from_floats([[5.0, 14.0], [14.0, 50.0]]);
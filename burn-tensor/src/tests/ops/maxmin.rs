@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(maxmin)]
 mod tests {
     use super::*;
-    use burn_tensor::{Data, Tensor};
+    use burn_tensor::{Data, Int, Tensor};
 
     #[test]
     fn test_max_dim_2d() {
@@ -48,4 +48,88 @@ mod tests {
         assert_eq!(output_expected, output_actual.into_data());
         assert_eq!(index_expected, index_actual.into_data());
     }
+
+    #[test]
+    fn test_max_dim_with_indices_3d_first_dim() {
+        let tensor = TestTensor::from_floats([
+            [[0.0, 7.0], [2.0, 3.0]],
+            [[4.0, 5.0], [2.0, -1.0]],
+            [[1.0, 7.0], [8.0, 0.0]],
+        ]);
+
+        let (output_actual, index_actual) = tensor.max_dim_with_indices(0);
+
+        // The first index is kept on ties.
+        let output_expected = Data::from([[[4.0, 7.0], [8.0, 3.0]]]);
+        let index_expected = Data::from([[[1, 0], [2, 0]]]);
+
+        assert_eq!(output_expected, output_actual.into_data());
+        assert_eq!(index_expected, index_actual.into_data());
+    }
+
+    #[test]
+    fn test_min_dim_with_indices_3d_middle_dim() {
+        let tensor = TestTensor::from_floats([[[0.0, 7.0], [2.0, 3.0]], [[4.0, 5.0], [4.0, -1.0]]]);
+
+        let (output_actual, index_actual) = tensor.min_dim_with_indices(1);
+
+        let output_expected = Data::from([[[0.0, 3.0]], [[4.0, -1.0]]]);
+        let index_expected = Data::from([[[0, 1]], [[0, 1]]]);
+
+        assert_eq!(output_expected, output_actual.into_data());
+        assert_eq!(index_expected, index_actual.into_data());
+    }
+
+    #[test]
+    fn test_int_max_min_dim_with_indices() {
+        let tensor = Tensor::<TestBackend, 2, Int>::from_data(Data::from([[1, 8, -3], [6, 2, 9]]));
+
+        let (max_actual, max_index_actual) = tensor.clone().max_dim_with_indices(0);
+        let (min_actual, min_index_actual) = tensor.min_dim_with_indices(0);
+
+        assert_eq!(Data::from([[6, 8, 9]]), max_actual.into_data());
+        assert_eq!(Data::from([[1, 0, 1]]), max_index_actual.into_data());
+        assert_eq!(Data::from([[1, 2, -3]]), min_actual.into_data());
+        assert_eq!(Data::from([[0, 1, 0]]), min_index_actual.into_data());
+    }
+
+    #[test]
+    fn test_max_pair() {
+        let tensor_1 = TestTensor::from_floats([[1.0, 5.0, -3.0], [3.0, -2.0, 0.0]]);
+        let tensor_2 = TestTensor::from_floats([[2.0, 5.0, -4.0], [1.0, 0.0, 0.5]]);
+
+        let output_actual = tensor_1.max_pair(tensor_2);
+
+        let output_expected = Data::from([[2.0, 5.0, -3.0], [3.0, 0.0, 0.5]]);
+        assert_eq!(output_expected, output_actual.into_data());
+    }
+
+    #[test]
+    fn test_min_pair() {
+        let tensor_1 = TestTensor::from_floats([[1.0, 5.0, -3.0], [3.0, -2.0, 0.0]]);
+        let tensor_2 = TestTensor::from_floats([[2.0, 5.0, -4.0], [1.0, 0.0, 0.5]]);
+
+        let output_actual = tensor_1.min_pair(tensor_2);
+
+        let output_expected = Data::from([[1.0, 5.0, -4.0], [1.0, -2.0, 0.0]]);
+        assert_eq!(output_expected, output_actual.into_data());
+    }
+
+    #[test]
+    fn test_max_min_pair_broadcast() {
+        let tensor_1 = TestTensor::from_floats([[1.0, 5.0, -3.0], [3.0, -2.0, 0.0]]);
+        let tensor_2 = TestTensor::from_floats([[2.0, 0.0, -1.0]]);
+
+        let output_max = tensor_1.clone().max_pair(tensor_2.clone());
+        let output_min = tensor_1.min_pair(tensor_2);
+
+        assert_eq!(
+            Data::from([[2.0, 5.0, -1.0], [3.0, 0.0, 0.0]]),
+            output_max.into_data()
+        );
+        assert_eq!(
+            Data::from([[1.0, 0.0, -3.0], [2.0, -2.0, -1.0]]),
+            output_min.into_data()
+        );
+    }
 }
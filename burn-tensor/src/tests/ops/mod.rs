@@ -1,16 +1,26 @@
 mod add;
 mod aggregation;
 mod arange;
+mod arange_float;
 mod arange_step;
 mod arg;
+mod cast;
 mod cat;
+mod ceil;
+mod chunk;
+mod clamp;
 mod cos;
+mod cumsum;
+mod diff;
 mod div;
 mod erf;
 mod exp;
 mod flatten;
+mod flip;
+mod floor;
 mod full;
 mod gather_scatter;
+mod linspace;
 mod log;
 mod log1p;
 mod map_comparison;
@@ -19,15 +29,23 @@ mod matmul;
 mod maxmin;
 mod mul;
 mod neg;
+mod one_hot;
 mod powf;
+mod quantization;
 mod random;
 mod repeat;
 mod reshape;
+mod round;
 mod select;
 mod sin;
 mod slice;
+mod sort;
+mod split;
 mod sqrt;
 mod squeeze;
+mod stack;
 mod sub;
 mod tanh;
+mod topk;
 mod transpose;
+mod triangular;
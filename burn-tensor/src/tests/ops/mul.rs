@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(mul)]
 mod tests {
     use super::*;
-    use burn_tensor::{Data, Tensor};
+    use burn_tensor::{Data, Int, Tensor};
 
     #[test]
     fn should_support_mul_ops() {
@@ -29,4 +29,25 @@ mod tests {
         let data_expected = Data::from([[0.0, 2.0, 4.0], [6.0, 8.0, 10.0]]);
         assert_eq!(data_expected, data_actual);
     }
+
+    #[test]
+    fn should_support_mul_ops_int() {
+        let tensor_1 = Tensor::<TestBackend, 2, Int>::from_data([[0, 1, 2], [3, 4, 5]]);
+        let tensor_2 = Tensor::<TestBackend, 2, Int>::from_data([[2, -1, 3], [1, 0, -2]]);
+
+        let data_actual = tensor_1.mul(tensor_2).into_data();
+
+        let data_expected = Data::from([[0, -1, 6], [3, 0, -10]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_mul_scalar_ops_int() {
+        let tensor = Tensor::<TestBackend, 2, Int>::from_data([[0, 1, 2], [3, 4, 5]]);
+
+        let data_actual = tensor.mul_scalar(3).into_data();
+
+        let data_expected = Data::from([[0, 3, 6], [9, 12, 15]]);
+        assert_eq!(data_expected, data_actual);
+    }
 }
@@ -0,0 +1,40 @@
+#[burn_tensor_testgen::testgen(one_hot)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_support_one_hot() {
+        let indices = Tensor::<TestBackend, 1, Int>::from_ints([0, 2]);
+
+        let data_actual = indices.one_hot(3).into_data();
+
+        let data_expected = Data::from([[1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_one_hot_with_repeated_indices() {
+        let indices = Tensor::<TestBackend, 1, Int>::from_ints([1, 1, 3, 0]);
+
+        let data_actual = indices.one_hot(4).into_data();
+
+        let data_expected = Data::from([
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+            [1.0, 0.0, 0.0, 0.0],
+        ]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_encode_out_of_range_indices_as_zeros() {
+        let indices = Tensor::<TestBackend, 1, Int>::from_ints([-1, 1, 3]);
+
+        let data_actual = indices.one_hot(3).into_data();
+
+        let data_expected = Data::from([[0.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 0.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+}
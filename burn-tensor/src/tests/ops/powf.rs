@@ -46,4 +46,26 @@ mod tests {
         let data_expected = Data::from([[0.0, -1.0, -8.0], [-27.0, -64.0, -125.0]]);
         data_expected.assert_approx_eq(&data_actual, 3);
     }
+
+    #[test]
+    fn should_support_powf_tensor_ops() {
+        let base = Tensor::<TestBackend, 2>::from_data([[1.0, 9.0, 3.0], [4.0, 5.0, 6.0]]);
+        let exponent = Tensor::<TestBackend, 2>::from_data([[2.0, 0.5, 1.0], [3.0, -1.0, 0.0]]);
+
+        let data_actual = base.powf_tensor(exponent).into_data();
+
+        let data_expected = Data::from([[1.0, 3.0, 3.0], [64.0, 0.2, 1.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_powf_tensor_broadcast() {
+        let base = Tensor::<TestBackend, 2>::from_data([[1.0, 2.0], [3.0, 4.0]]);
+        let exponent = Tensor::<TestBackend, 2>::from_data([[2.0], [0.5]]);
+
+        let data_actual = base.powf_tensor(exponent).into_data();
+
+        let data_expected = Data::from([[1.0, 4.0], [1.7321, 2.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
 }
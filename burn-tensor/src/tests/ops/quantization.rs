@@ -0,0 +1,67 @@
+#[burn_tensor_testgen::testgen(quantization)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Distribution, Tensor};
+
+    #[test]
+    fn should_quantize_per_channel() {
+        let tensor = TestTensor::from_floats([[0.25, 0.75, -1.26], [100.0, 0.25, -0.75]]);
+        let scale = TestTensor::from_floats([0.5, 0.25]);
+        let zero_point = TestTensorInt::from_ints([0, 10]);
+
+        let output = tensor.quantize_per_channel(scale, zero_point, 0);
+
+        // Each row uses its own scale and zero point: the first row rounds half to even and the
+        // first element of the second row is clamped to the maximum of the quantized range.
+        assert_eq!(output.into_data(), Data::from([[0, 2, -3], [127, 11, 7]]));
+    }
+
+    #[test]
+    fn should_dequantize_per_channel() {
+        let tensor = TestTensorInt::from_ints([[0, 2, -3], [127, 11, 7]]);
+        let scale = TestTensor::from_floats([0.5, 0.25, 1.0]);
+        let zero_point = TestTensorInt::from_ints([0, 10, -1]);
+
+        let output = tensor.dequantize_per_channel(scale, zero_point, 1);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0, -2.0, -2.0], [63.5, 0.25, 8.0]]), 3);
+    }
+
+    #[test]
+    fn should_round_trip_within_one_quantization_step() {
+        let tensor = Tensor::<TestBackend, 3>::random([2, 4, 3], Distribution::Uniform(-1.0, 1.0));
+        let scale_data = [0.01, 0.02, 0.05, 0.1];
+        let scale = TestTensor::from_floats(scale_data);
+        let zero_point = TestTensorInt::from_ints([0, 3, -5, 1]);
+
+        let output = tensor
+            .clone()
+            .quantize_per_channel(scale.clone(), zero_point.clone(), 1)
+            .dequantize_per_channel(scale, zero_point, 1);
+
+        let tensor = tensor.into_data();
+        let output = output.into_data();
+
+        for (position, (expected, actual)) in
+            tensor.value.iter().zip(output.value.iter()).enumerate()
+        {
+            let scale = scale_data[(position / 3) % 4];
+            assert!(
+                (expected - actual).abs() <= scale / 2.0 + 1e-6,
+                "{actual} should be within half a quantization step of {expected}"
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_scale_size_differs_from_channels() {
+        let tensor = TestTensor::from_floats([[0.25, 0.75, -1.26], [100.0, 0.25, -0.75]]);
+        let scale = TestTensor::from_floats([0.5, 0.25]);
+        let zero_point = TestTensorInt::from_ints([0, 10]);
+
+        tensor.quantize_per_channel(scale, zero_point, 1);
+    }
+}
@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(random)]
 mod tests {
     use super::*;
-    use burn_tensor::{Distribution, Tensor};
+    use burn_tensor::{Distribution, ElementConversion, Int, Tensor};
 
     #[test]
     fn rand_standard() {
@@ -10,4 +10,32 @@ mod tests {
         // check that the tensor is within the range of [0..1) (1 is exclusive)
         tensor.into_data().assert_within_range(0.0..1.0);
     }
+
+    #[test]
+    fn rand_int_uniform() {
+        let tensor = Tensor::<TestBackend, 2, Int>::random(
+            [20, 10],
+            Distribution::Uniform(5.elem(), 10.elem()),
+        );
+
+        // check that the tensor is within the range of [5..10) (10 is exclusive)
+        tensor.into_data().assert_within_range(5..10);
+    }
+
+    #[test]
+    fn rand_int_bernoulli() {
+        let tensor = Tensor::<TestBackend, 1, Int>::random([20], Distribution::Bernoulli(0.5));
+
+        tensor.into_data().assert_within_range(0..2);
+    }
+
+    #[test]
+    fn rand_int_default_samples_the_whole_range() {
+        let tensor = Tensor::<TestBackend, 1, Int>::random([200], Distribution::Default);
+        let values = tensor.into_data().value;
+
+        // The chance that all the values have the same sign is negligible.
+        assert!(values.iter().any(|value| value.elem::<i64>() < 0));
+        assert!(values.iter().any(|value| value.elem::<i64>() > 0));
+    }
 }
@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(repeat)]
 mod tests {
     use super::*;
-    use burn_tensor::{Data, Tensor};
+    use burn_tensor::{Bool, Data, Int, Tensor};
 
     #[test]
     fn should_support_repeat_ops() {
@@ -18,4 +18,69 @@ mod tests {
         ]);
         assert_eq!(data_expected, data_actual);
     }
+
+    #[test]
+    fn should_support_repeat_dim_larger_than_one() {
+        let data = Data::from([[0.0, 1.0], [2.0, 3.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.repeat(1, 3).into_data();
+
+        let data_expected = Data::from([
+            [0.0, 1.0, 0.0, 1.0, 0.0, 1.0],
+            [2.0, 3.0, 2.0, 3.0, 2.0, 3.0],
+        ]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_repeat_3d() {
+        let data = Data::from([[[0.0, 1.0]], [[2.0, 3.0]]]);
+        let tensor = Tensor::<TestBackend, 3>::from_data(data);
+
+        let data_actual = tensor.repeat(0, 2).into_data();
+
+        let data_expected = Data::from([[[0.0, 1.0]], [[2.0, 3.0]], [[0.0, 1.0]], [[2.0, 3.0]]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_int_repeat() {
+        let data = Data::from([[1, 2], [3, 4]]);
+        let tensor = Tensor::<TestBackend, 2, Int>::from_data(data);
+
+        let data_actual = tensor.repeat(0, 2).into_data();
+
+        let data_expected = Data::from([[1, 2], [3, 4], [1, 2], [3, 4]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_bool_repeat() {
+        let data = Data::from([[true, false]]);
+        let tensor = Tensor::<TestBackend, 2, Bool>::from_data(data);
+
+        let data_actual = tensor.repeat(1, 2).into_data();
+
+        let data_expected = Data::from([[true, false, true, false]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_repeat_zero_times() {
+        let tensor = Tensor::<TestBackend, 2>::from_data([[0.0, 1.0], [2.0, 3.0]]);
+
+        let output = tensor.repeat(1, 0);
+
+        assert_eq!(output.shape().dims, [2, 0]);
+        assert_eq!(output.into_data().value.len(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_repeat_dim_out_of_bounds() {
+        let tensor = Tensor::<TestBackend, 2>::from_data([[0.0, 1.0]]);
+
+        let _ = tensor.repeat(2, 2);
+    }
 }
@@ -0,0 +1,27 @@
+#[burn_tensor_testgen::testgen(round)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_round_ops() {
+        let data = Data::from([[-1.7, -0.2, 0.0], [0.4, 1.6, 2.2]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.round().into_data();
+
+        let data_expected = Data::from([[-2.0, 0.0, 0.0], [0.0, 2.0, 2.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_round_half_to_even() {
+        let data = Data::from([[-2.5, -1.5, -0.5], [0.5, 1.5, 2.5]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.round().into_data();
+
+        let data_expected = Data::from([[-2.0, -2.0, 0.0], [0.0, 2.0, 2.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+}
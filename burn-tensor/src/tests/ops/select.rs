@@ -68,6 +68,40 @@ mod tests {
         assert_eq!(output.into_data(), Data::from([3.0, 12.0, 3.0]));
     }
 
+    #[test]
+    fn should_select_2d_dim0_int() {
+        let tensor = TestTensorInt::from_data([[0, 1, 2], [3, 4, 5]]);
+        let tensor_float = TestTensor::from_data([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        let indices = TestTensorInt::from_data([1, 0, 1]);
+
+        let output = tensor.select(0, indices.clone());
+        let output_float = tensor_float.select(0, indices);
+
+        assert_eq!(output.into_data(), output_float.into_data().convert());
+    }
+
+    #[test]
+    fn should_select_assign_2d_dim1_int() {
+        let tensor = TestTensorInt::from_data([[0, 1, 2], [3, 4, 5]]);
+        let values = TestTensorInt::from_data([[1, 2, 3], [4, 5, 6]]);
+        let indices = TestTensorInt::from_data(Data::from([1, 0, 2]));
+
+        let output = tensor.select_assign(1, indices, values);
+
+        assert_eq!(output.into_data(), Data::from([[2, 2, 5], [8, 8, 11]]));
+    }
+
+    #[test]
+    fn should_select_by_mask() {
+        let tensor = TestTensor::from_data([[1.0, 5.0, 2.0], [7.0, 3.0, 9.0]]);
+        let mask = tensor.clone().greater_elem(4.0);
+
+        let (values, indices) = tensor.select_by_mask(mask);
+
+        assert_eq!(values.into_data(), Data::from([5.0, 7.0, 9.0]));
+        assert_eq!(indices.into_data(), Data::from([1, 3, 5]));
+    }
+
     #[test]
     fn should_select_assign_2d_dim0() {
         let tensor = TestTensor::from_data([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
@@ -95,4 +129,45 @@ mod tests {
             Data::from([[2.0, 2.0, 5.0], [8.0, 8.0, 11.0]])
         );
     }
+
+    #[test]
+    fn should_batched_index_select_dim1() {
+        let tensor = TestTensor::from_data([
+            [[0.0, 1.0], [2.0, 3.0], [4.0, 5.0]],
+            [[6.0, 7.0], [8.0, 9.0], [10.0, 11.0]],
+        ]);
+        let indices = TestTensorInt::from_data([[2, 0], [1, 1]]);
+
+        let output = tensor.batched_index_select(1, indices);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[[4.0, 5.0], [0.0, 1.0]], [[8.0, 9.0], [8.0, 9.0]]])
+        );
+    }
+
+    #[test]
+    fn should_batched_index_select_dim2() {
+        let tensor = TestTensor::from_data([
+            [[0.0, 1.0], [2.0, 3.0], [4.0, 5.0]],
+            [[6.0, 7.0], [8.0, 9.0], [10.0, 11.0]],
+        ]);
+        let indices = TestTensorInt::from_data([[1], [0]]);
+
+        let output = tensor.batched_index_select(2, indices);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[[1.0], [3.0], [5.0]], [[6.0], [8.0], [10.0]]])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_batched_index_select_batch_dim() {
+        let tensor = TestTensor::from_data([[[0.0, 1.0]], [[2.0, 3.0]]]);
+        let indices = TestTensorInt::from_data([[0], [0]]);
+
+        tensor.batched_index_select(0, indices);
+    }
 }
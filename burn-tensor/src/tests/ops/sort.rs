@@ -0,0 +1,74 @@
+#[burn_tensor_testgen::testgen(sort)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_sort_ascending_with_duplicates() {
+        let tensor = TestTensor::from_floats([[3.0, 1.0, 2.0, 1.0, 3.0]]);
+
+        let (values, indices) = tensor.sort(1, false);
+
+        values
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 1.0, 2.0, 3.0, 3.0]]), 3);
+        assert_eq!(indices.into_data(), Data::from([[1, 3, 2, 0, 4]]));
+    }
+
+    #[test]
+    fn test_sort_descending_with_duplicates() {
+        let tensor = TestTensor::from_floats([[3.0, 1.0, 2.0, 1.0, 3.0]]);
+
+        let (values, indices) = tensor.sort(1, true);
+
+        values
+            .into_data()
+            .assert_approx_eq(&Data::from([[3.0, 3.0, 2.0, 1.0, 1.0]]), 3);
+        assert_eq!(indices.into_data(), Data::from([[0, 4, 2, 1, 3]]));
+    }
+
+    #[test]
+    fn test_sort_first_dim() {
+        let tensor = TestTensor::from_floats([[2.0, -1.0], [0.0, 5.0], [2.0, 3.0]]);
+
+        let (values, indices) = tensor.clone().sort(0, false);
+
+        values
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0, -1.0], [2.0, 3.0], [2.0, 5.0]]), 3);
+        assert_eq!(
+            indices.clone().into_data(),
+            Data::from([[1, 0], [0, 2], [2, 1]])
+        );
+        // The indices map each sorted value back to its position in the input.
+        tensor
+            .gather(0, indices)
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0, -1.0], [2.0, 3.0], [2.0, 5.0]]), 3);
+    }
+
+    #[test]
+    fn test_sort_nan_is_the_largest_value() {
+        let tensor = TestTensor::from_floats([[f32::NAN, 1.0, -2.0, f32::NAN, 0.5]]);
+
+        let (values, indices) = tensor.clone().sort(1, false);
+        let values = values.into_data().value;
+        assert_eq!(values[..3], [-2.0, 0.5, 1.0]);
+        assert!(values[3].is_nan() && values[4].is_nan());
+        assert_eq!(indices.into_data(), Data::from([[2, 4, 1, 0, 3]]));
+
+        let (values, indices) = tensor.sort(1, true);
+        let values = values.into_data().value;
+        assert!(values[0].is_nan() && values[1].is_nan());
+        assert_eq!(values[2..], [1.0, 0.5, -2.0]);
+        assert_eq!(indices.into_data(), Data::from([[0, 3, 1, 4, 2]]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sort_invalid_dim_panic() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0, 3.0]]);
+
+        let _result = tensor.sort(2, false);
+    }
+}
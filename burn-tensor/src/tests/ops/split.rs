@@ -0,0 +1,66 @@
+#[burn_tensor_testgen::testgen(split)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_split_evenly_divisible() {
+        let tensors = Tensor::<TestBackend, 1, Int>::arange(0..6).split(2, 0);
+        assert_eq!(tensors.len(), 3);
+
+        let expected = vec![Data::from([0, 1]), Data::from([2, 3]), Data::from([4, 5])];
+
+        for (index, tensor) in tensors.into_iter().enumerate() {
+            assert_eq!(tensor.to_data(), expected[index]);
+        }
+    }
+
+    #[test]
+    fn should_split_not_evenly_divisible() {
+        let tensors = Tensor::<TestBackend, 1, Int>::arange(0..7).split(3, 0);
+        assert_eq!(tensors.len(), 3);
+
+        let expected = vec![
+            Data::from([0, 1, 2]),
+            Data::from([3, 4, 5]),
+            Data::from([6]),
+        ];
+
+        for (index, tensor) in tensors.into_iter().enumerate() {
+            assert_eq!(tensor.to_data(), expected[index]);
+        }
+    }
+
+    #[test]
+    fn should_split_with_size_larger_than_dimension() {
+        let tensors = Tensor::<TestBackend, 1, Int>::arange(0..3).split(5, 0);
+        assert_eq!(tensors.len(), 1);
+        assert_eq!(tensors[0].to_data(), Data::from([0, 1, 2]));
+    }
+
+    #[test]
+    fn should_split_multi_dimension() {
+        let tensors = Tensor::<TestBackend, 2, Int>::from_data(Data::from([
+            [0, 1, 2, 3],
+            [4, 5, 6, 7],
+            [8, 9, 10, 11],
+        ]))
+        .split(2, 0);
+        assert_eq!(tensors.len(), 2);
+
+        let expected = vec![
+            Data::from([[0, 1, 2, 3], [4, 5, 6, 7]]),
+            Data::from([[8, 9, 10, 11]]),
+        ];
+
+        for (index, tensor) in tensors.into_iter().enumerate() {
+            assert_eq!(tensor.to_data(), expected[index]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_split_size_is_zero() {
+        let _tensors = Tensor::<TestBackend, 1, Int>::arange(0..6).split(0, 0);
+    }
+}
@@ -34,4 +34,61 @@ mod tests {
         let tensor = Tensor::<TestBackend, 4>::ones(Shape::new([2, 3, 4, 5]));
         let squeezed_tensor: Tensor<TestBackend, 3> = tensor.squeeze(2);
     }
+
+    /// Test if the function can successfully squeeze multiple size 1 dimensions of a 4D tensor.
+    #[test]
+    fn should_squeeze_dims() {
+        let tensor = Tensor::<TestBackend, 4>::ones(Shape::new([2, 1, 4, 1]));
+        let squeezed_tensor: Tensor<TestBackend, 2> = tensor.squeeze_dims(&[1, 3]);
+        let expected_shape = Shape::new([2, 4]);
+        assert_eq!(squeezed_tensor.shape(), expected_shape);
+    }
+
+    /// Test if the function keeps the data in order when squeezing multiple dimensions.
+    #[test]
+    fn should_squeeze_dims_keep_data() {
+        let tensor = Tensor::<TestBackend, 3>::from_data([[[1.0, 2.0, 3.0]]]);
+        let squeezed_tensor: Tensor<TestBackend, 1> = tensor.squeeze_dims(&[0, 1]);
+        assert_eq!(squeezed_tensor.into_data(), Data::from([1.0, 2.0, 3.0]));
+    }
+
+    /// Test if the function panics when one of the squeezed dimensions is not of size 1.
+    #[test]
+    #[should_panic]
+    fn should_squeeze_dims_panic() {
+        let tensor = Tensor::<TestBackend, 4>::ones(Shape::new([2, 1, 4, 5]));
+        let squeezed_tensor: Tensor<TestBackend, 2> = tensor.squeeze_dims(&[1, 2]);
+    }
+
+    /// Test if the function can successfully insert a size 1 dimension at the end of a 3D tensor.
+    #[test]
+    fn should_unsqueeze_dim_last() {
+        let tensor = Tensor::<TestBackend, 3>::ones(Shape::new([2, 3, 4]));
+        let unsqueezed_tensor: Tensor<TestBackend, 4> = tensor.unsqueeze_dim(3);
+        let expected_shape = Shape::new([2, 3, 4, 1]);
+        assert_eq!(unsqueezed_tensor.shape(), expected_shape);
+    }
+    /// Test if the function can successfully insert a size 1 dimension in the middle of a 3D tensor.
+    #[test]
+    fn should_unsqueeze_dim_middle() {
+        let tensor = Tensor::<TestBackend, 3>::ones(Shape::new([2, 3, 4]));
+        let unsqueezed_tensor: Tensor<TestBackend, 4> = tensor.unsqueeze_dim(1);
+        let expected_shape = Shape::new([2, 1, 3, 4]);
+        assert_eq!(unsqueezed_tensor.shape(), expected_shape);
+    }
+    /// Test if the function keeps the values when inserting a size 1 dimension.
+    #[test]
+    fn should_unsqueeze_dim_keep_values() {
+        let tensor = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+        let unsqueezed_tensor: Tensor<TestBackend, 3> = tensor.unsqueeze_dim(2);
+        let expected = Data::from([[[1.0], [2.0]], [[3.0], [4.0]]]);
+        assert_eq!(unsqueezed_tensor.into_data(), expected);
+    }
+    /// Test if the function panics when the dimension is out of range.
+    #[test]
+    #[should_panic]
+    fn should_unsqueeze_dim_panic() {
+        let tensor = Tensor::<TestBackend, 3>::ones(Shape::new([2, 3, 4]));
+        let unsqueezed_tensor: Tensor<TestBackend, 4> = tensor.unsqueeze_dim(4);
+    }
 }
@@ -0,0 +1,52 @@
+#[burn_tensor_testgen::testgen(stack)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_stack_on_first_dimension() {
+        let tensor_1 = TestTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let tensor_2 = TestTensor::from_data([[7.0, 8.0, 9.0], [10.0, 11.0, 12.0]]);
+        let tensor_3 = TestTensor::from_data([[13.0, 14.0, 15.0], [16.0, 17.0, 18.0]]);
+
+        let output = Tensor::stack::<3>(vec![tensor_1, tensor_2, tensor_3], 0);
+
+        assert_eq!(output.dims(), [3, 2, 3]);
+        let data_expected = Data::from([
+            [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+            [[7.0, 8.0, 9.0], [10.0, 11.0, 12.0]],
+            [[13.0, 14.0, 15.0], [16.0, 17.0, 18.0]],
+        ]);
+        output.into_data().assert_approx_eq(&data_expected, 3);
+    }
+
+    #[test]
+    fn should_stack_on_last_dimension() {
+        let tensor_1 = TestTensor::from_data([[1.0, 2.0], [3.0, 4.0]]);
+        let tensor_2 = TestTensor::from_data([[5.0, 6.0], [7.0, 8.0]]);
+
+        let output = Tensor::stack::<3>(vec![tensor_1, tensor_2], 2);
+
+        assert_eq!(output.dims(), [2, 2, 2]);
+        let data_expected = Data::from([[[1.0, 5.0], [2.0, 6.0]], [[3.0, 7.0], [4.0, 8.0]]]);
+        output.into_data().assert_approx_eq(&data_expected, 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_shapes_are_different() {
+        let tensor_1 = TestTensor::from_data([[1.0, 2.0, 3.0]]);
+        let tensor_2 = TestTensor::from_data([[1.0, 2.0]]);
+
+        let _output = Tensor::stack::<3>(vec![tensor_1, tensor_2], 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_output_rank_is_invalid() {
+        let tensor_1 = TestTensor::from_data([[1.0, 2.0, 3.0]]);
+        let tensor_2 = TestTensor::from_data([[4.0, 5.0, 6.0]]);
+
+        let _output = Tensor::stack::<4>(vec![tensor_1, tensor_2], 0);
+    }
+}
@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(sub)]
 mod tests {
     use super::*;
-    use burn_tensor::{Data, Tensor};
+    use burn_tensor::{Data, Int, Tensor};
 
     #[test]
     fn should_support_sub_ops() {
@@ -15,4 +15,15 @@ mod tests {
 
         assert_eq!(data_expected, data_actual);
     }
+
+    #[test]
+    fn should_support_sub_ops_int() {
+        let tensor_1 = Tensor::<TestBackend, 2, Int>::from_data([[0, 1, 2], [3, 4, 5]]);
+        let tensor_2 = Tensor::<TestBackend, 2, Int>::from_data([[6, 7, 8], [9, 10, 11]]);
+
+        let data_actual = tensor_1.sub(tensor_2).into_data();
+
+        let data_expected = Data::from([[-6, -6, -6], [-6, -6, -6]]);
+        assert_eq!(data_expected, data_actual);
+    }
 }
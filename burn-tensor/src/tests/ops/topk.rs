@@ -0,0 +1,85 @@
+#[burn_tensor_testgen::testgen(topk)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_topk_last_dim() {
+        let tensor = TestTensor::from_floats([[1.0, 5.0, 3.0, 4.0], [9.0, -1.0, 2.0, 8.0]]);
+
+        let (values, indices) = tensor.topk(2, 1);
+
+        values
+            .into_data()
+            .assert_approx_eq(&Data::from([[5.0, 4.0], [9.0, 8.0]]), 3);
+        assert_eq!(indices.into_data(), Data::from([[1, 3], [0, 3]]));
+    }
+
+    #[test]
+    fn test_topk_first_dim() {
+        let tensor = TestTensor::from_floats([[1.0, 5.0, 3.0], [9.0, -1.0, 2.0], [4.0, 0.0, 7.0]]);
+
+        let (values, indices) = tensor.topk(2, 0);
+
+        values
+            .into_data()
+            .assert_approx_eq(&Data::from([[9.0, 5.0, 7.0], [4.0, 0.0, 3.0]]), 3);
+        assert_eq!(indices.into_data(), Data::from([[1, 0, 2], [2, 2, 0]]));
+    }
+
+    #[test]
+    fn test_topk_values_are_sorted_descending() {
+        let tensor = TestTensor::from_floats([[0.5, -2.0, 3.5, 1.0, 2.0, -1.0]]);
+
+        let (values, indices) = tensor.clone().topk(6, 1);
+
+        values
+            .into_data()
+            .assert_approx_eq(&Data::from([[3.5, 2.0, 1.0, 0.5, -1.0, -2.0]]), 3);
+        // The indices map each value back to its position in the input.
+        tensor
+            .gather(1, indices)
+            .into_data()
+            .assert_approx_eq(&Data::from([[3.5, 2.0, 1.0, 0.5, -1.0, -2.0]]), 3);
+    }
+
+    #[test]
+    fn test_topk_ties_keep_lowest_index_first() {
+        let tensor = TestTensor::from_floats([[2.0, 7.0, 2.0, 7.0, 1.0]]);
+
+        let (values, indices) = tensor.topk(3, 1);
+
+        values
+            .into_data()
+            .assert_approx_eq(&Data::from([[7.0, 7.0, 2.0]]), 3);
+        assert_eq!(indices.into_data(), Data::from([[1, 3, 0]]));
+    }
+
+    #[test]
+    fn test_topk_nan_is_the_largest_value() {
+        let tensor = TestTensor::from_floats([[1.0, f32::NAN, 3.0, f32::NAN, 2.0]]);
+
+        let (values, indices) = tensor.topk(3, 1);
+
+        let values = values.into_data().value;
+        assert!(values[0].is_nan() && values[1].is_nan());
+        assert_eq!(values[2], 3.0);
+        assert_eq!(indices.into_data(), Data::from([[1, 3, 2]]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_topk_k_higher_than_dim_size_panic() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0, 3.0]]);
+
+        let _result = tensor.topk(4, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_topk_invalid_dim_panic() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0, 3.0]]);
+
+        let _result = tensor.topk(1, 2);
+    }
+}
@@ -35,4 +35,51 @@ mod tests {
         ]);
         data_expected.assert_approx_eq(&data_actual, 3);
     }
+
+    #[test]
+    fn should_support_permute() {
+        let tensor = TestTensor::from_floats([
+            [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]],
+            [[6.0, 7.0, 8.0], [9.0, 10.0, 11.0]],
+        ]);
+
+        let data_actual = tensor.permute([2, 0, 1]).into_data();
+
+        let data_expected = Data::from([
+            [[0.0, 3.0], [6.0, 9.0]],
+            [[1.0, 4.0], [7.0, 10.0]],
+            [[2.0, 5.0], [8.0, 11.0]],
+        ]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_permute_identity() {
+        let tensor = TestTensor::from_floats([
+            [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]],
+            [[6.0, 7.0, 8.0], [9.0, 10.0, 11.0]],
+        ]);
+
+        let data_actual = tensor.clone().permute([0, 1, 2]).into_data();
+
+        tensor.into_data().assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_permute_then_contiguous_op() {
+        let tensor = TestTensor::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let data_actual = tensor.permute([1, 0]).add_scalar(1.0).into_data();
+
+        let data_expected = Data::from([[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_permute_axes_are_not_a_permutation() {
+        let tensor = TestTensor::from_floats([[[0.0, 1.0], [2.0, 3.0]]]);
+
+        let _output = tensor.permute([0, 0, 1]);
+    }
 }
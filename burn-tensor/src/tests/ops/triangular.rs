@@ -0,0 +1,116 @@
+#[burn_tensor_testgen::testgen(triangular)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    fn matrix() -> Tensor<TestBackend, 2> {
+        Tensor::from_data(Data::from([
+            [1.0, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [9.0, 10.0, 11.0, 12.0],
+            [13.0, 14.0, 15.0, 16.0],
+        ]))
+    }
+
+    #[test]
+    fn should_support_tril_main_diagonal() {
+        let data_actual = matrix().tril(0).into_data();
+
+        let data_expected = Data::from([
+            [1.0, 0.0, 0.0, 0.0],
+            [5.0, 6.0, 0.0, 0.0],
+            [9.0, 10.0, 11.0, 0.0],
+            [13.0, 14.0, 15.0, 16.0],
+        ]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_tril_negative_diagonal() {
+        let data_actual = matrix().tril(-1).into_data();
+
+        let data_expected = Data::from([
+            [0.0, 0.0, 0.0, 0.0],
+            [5.0, 0.0, 0.0, 0.0],
+            [9.0, 10.0, 0.0, 0.0],
+            [13.0, 14.0, 15.0, 0.0],
+        ]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_tril_positive_diagonal() {
+        let data_actual = matrix().tril(1).into_data();
+
+        let data_expected = Data::from([
+            [1.0, 2.0, 0.0, 0.0],
+            [5.0, 6.0, 7.0, 0.0],
+            [9.0, 10.0, 11.0, 12.0],
+            [13.0, 14.0, 15.0, 16.0],
+        ]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_triu_main_diagonal() {
+        let data_actual = matrix().triu(0).into_data();
+
+        let data_expected = Data::from([
+            [1.0, 2.0, 3.0, 4.0],
+            [0.0, 6.0, 7.0, 8.0],
+            [0.0, 0.0, 11.0, 12.0],
+            [0.0, 0.0, 0.0, 16.0],
+        ]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_triu_negative_diagonal() {
+        let data_actual = matrix().triu(-1).into_data();
+
+        let data_expected = Data::from([
+            [1.0, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [0.0, 10.0, 11.0, 12.0],
+            [0.0, 0.0, 15.0, 16.0],
+        ]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_triu_positive_diagonal() {
+        let data_actual = matrix().triu(1).into_data();
+
+        let data_expected = Data::from([
+            [0.0, 2.0, 3.0, 4.0],
+            [0.0, 0.0, 7.0, 8.0],
+            [0.0, 0.0, 0.0, 12.0],
+            [0.0, 0.0, 0.0, 0.0],
+        ]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_tril_batched_non_square() {
+        let tensor = Tensor::<TestBackend, 3>::from_data(Data::from([
+            [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+            [[7.0, 8.0, 9.0], [10.0, 11.0, 12.0]],
+        ]));
+
+        let data_actual = tensor.tril(0).into_data();
+
+        let data_expected = Data::from([
+            [[1.0, 0.0, 0.0], [4.0, 5.0, 0.0]],
+            [[7.0, 0.0, 0.0], [10.0, 11.0, 0.0]],
+        ]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_triangular_on_1d_tensor() {
+        let tensor = Tensor::<TestBackend, 1>::from_data([1.0, 2.0]);
+
+        let _ = tensor.tril(0);
+    }
+}
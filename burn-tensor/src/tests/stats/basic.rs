@@ -18,6 +18,110 @@ mod tests {
         data_expected.assert_approx_eq(&data_actual, 3);
     }
 
+    #[test]
+    fn test_var_bias() {
+        let data = Data::from([[0.5, 1.8, 0.2, -2.0], [3.0, -4.0, 5.0, 0.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.var_bias(1).into_data();
+
+        let data_expected = Data::from([[1.8669], [11.5]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn test_var_dim0() {
+        let data = Data::from([[1.0, 2.0], [3.0, 6.0], [5.0, 10.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.var(0).into_data();
+
+        let data_expected = Data::from([[4.0, 16.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn test_var_mean() {
+        let data = Data::from([[0.5, 1.8, 0.2, -2.0], [3.0, -4.0, 5.0, 0.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let (var, mean) = tensor.clone().var_mean(1);
+
+        var.into_data()
+            .assert_approx_eq(&tensor.clone().var(1).into_data(), 3);
+        mean.into_data()
+            .assert_approx_eq(&tensor.mean_dim(1).into_data(), 3);
+    }
+
+    #[test]
+    fn test_var_mean_empty_dim() {
+        let tensor = Tensor::<TestBackend, 2>::zeros([2, 0]);
+
+        let (var, mean) = tensor.var_mean(1);
+
+        assert_eq!(var.shape().dims, [2, 1]);
+        assert_eq!(mean.shape().dims, [2, 1]);
+        let var = var.into_data().convert::<f32>();
+        assert!(var.value.iter().all(|value| value.is_nan()));
+    }
+
+    #[test]
+    fn test_var_mean_bias_dim0() {
+        let data = Data::from([[1.0, 2.0], [3.0, 6.0], [5.0, 10.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let (var, mean) = tensor.clone().var_mean_bias(0);
+
+        var.into_data()
+            .assert_approx_eq(&tensor.clone().var_bias(0).into_data(), 3);
+        mean.into_data()
+            .assert_approx_eq(&tensor.mean_dim(0).into_data(), 3);
+    }
+
+    #[test]
+    fn test_std() {
+        let data = Data::from([[0.5, 1.8, 0.2, -2.0], [3.0, -4.0, 5.0, 0.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.std(1).into_data();
+
+        let data_expected = Data::from([[1.5777], [3.9158]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn test_std_bias() {
+        let data = Data::from([[0.5, 1.8, 0.2, -2.0], [3.0, -4.0, 5.0, 0.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.std_bias(1).into_data();
+
+        let data_expected = Data::from([[1.3663], [3.3912]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn test_moving_average() {
+        let data = [1.0, 4.0, 2.0, 8.0, 5.0, 7.0];
+        let tensor = Tensor::<TestBackend, 1>::from_floats(data);
+
+        let data_actual = tensor.moving_average(3, 0).into_data();
+
+        let naive: [f32; 4] = core::array::from_fn(|i| data[i..i + 3].iter().sum::<f32>() / 3.0);
+        Data::from(naive).assert_approx_eq(&data_actual.convert(), 3);
+    }
+
+    #[test]
+    fn test_moving_average_2d_dim0() {
+        let data = Data::from([[1.0, 2.0], [3.0, 4.0], [5.0, 9.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.moving_average(2, 0).into_data();
+
+        let data_expected = Data::from([[2.0, 3.0], [4.0, 6.5]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
     #[test]
     fn test_display_2d_int_tensor() {
         let int_data = Data::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
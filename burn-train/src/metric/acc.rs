@@ -83,6 +83,55 @@ impl<B: Backend> Numeric for AccuracyMetric<B> {
     }
 }
 
+/// Computes the top-k accuracy, which is the fraction of examples where the target is among the
+/// `k` classes with the highest scores.
+///
+/// # Arguments
+///
+/// * `outputs` - The scores of each class, of shape `[batch_size, num_classes]`.
+/// * `targets` - The target classes, of shape `[batch_size]`.
+/// * `k` - The number of top predictions considered.
+///
+/// # Notes
+///
+/// Ties are in favor of the target, so a target sharing its score with the k-th prediction is
+/// counted as correct. An empty batch has an accuracy of zero.
+///
+/// # Panics
+///
+/// If `k` is zero or larger than the number of classes.
+pub fn topk_accuracy<B: Backend>(
+    outputs: Tensor<B, 2>,
+    targets: Tensor<B, 1, Int>,
+    k: usize,
+) -> f64 {
+    let [batch_size, num_classes] = outputs.dims();
+
+    assert!(
+        k > 0 && k <= num_classes,
+        "k ({k}) must be between 1 and the number of classes ({num_classes})"
+    );
+
+    if batch_size == 0 {
+        return 0.0;
+    }
+
+    let device = outputs.device();
+    let targets = targets.to_device(&device).reshape([batch_size, 1]);
+    let target_scores = outputs.clone().gather(1, targets).repeat(1, num_classes);
+
+    // The target is in the top-k if strictly less than k classes have a higher score.
+    let num_higher = outputs.greater(target_scores).into_int().sum_dim(1);
+    let num_correct = num_higher
+        .lower_elem(k as i64)
+        .into_int()
+        .sum()
+        .into_scalar()
+        .elem::<f64>();
+
+    num_correct / batch_size as f64
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -124,4 +173,40 @@ mod tests {
         let _entry = metric.update(&input, &MetricMetadata::fake());
         assert_eq!(50.0, metric.value());
     }
+
+    #[test]
+    fn test_topk_accuracy() {
+        let outputs = Tensor::<TestBackend, 2>::from_data([
+            [0.1, 0.5, 0.3, 0.0], // order: 1, 2, 0, 3
+            [0.9, 0.0, 0.2, 0.4], // order: 0, 3, 2, 1
+            [0.3, 0.2, 0.1, 0.6], // order: 3, 0, 1, 2
+            [0.0, 0.4, 0.8, 0.5], // order: 2, 3, 1, 0
+        ]);
+        let targets = Tensor::<TestBackend, 1, Int>::from_data([1, 2, 2, 0]);
+
+        let top1 = topk_accuracy(outputs.clone(), targets.clone(), 1);
+        let top3 = topk_accuracy(outputs.clone(), targets.clone(), 3);
+        let top4 = topk_accuracy(outputs, targets, 4);
+
+        assert_eq!(top1, 0.25);
+        assert_eq!(top3, 0.5);
+        assert_eq!(top4, 1.0);
+    }
+
+    #[test]
+    fn test_topk_accuracy_empty_batch() {
+        let outputs = Tensor::<TestBackend, 2>::zeros([0, 3]);
+        let targets = Tensor::<TestBackend, 1, Int>::zeros([0]);
+
+        assert_eq!(topk_accuracy(outputs, targets, 1), 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_topk_accuracy_k_larger_than_num_classes() {
+        let outputs = Tensor::<TestBackend, 2>::from_data([[0.1, 0.5, 0.3]]);
+        let targets = Tensor::<TestBackend, 1, Int>::from_data([1]);
+
+        topk_accuracy(outputs, targets, 4);
+    }
 }
@@ -0,0 +1,97 @@
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{ElementConversion, Int, Tensor};
+
+/// Computes the confusion matrix of the given predictions.
+///
+/// # Arguments
+///
+/// * `predictions` - The predicted classes, of shape `[batch_size]`.
+/// * `targets` - The target classes, of shape `[batch_size]`.
+/// * `num_classes` - The number of classes.
+///
+/// # Returns
+///
+/// The count matrix of shape `[num_classes, num_classes]`, where the element `[i, j]` is the
+/// number of examples of class `i` predicted as class `j`. Correct predictions are therefore
+/// counted on the diagonal. An empty batch gives a matrix of zeros.
+///
+/// # Panics
+///
+/// If the predictions and targets don't have the same size or if a class is out of range.
+pub fn confusion_matrix<B: Backend>(
+    predictions: Tensor<B, 1, Int>,
+    targets: Tensor<B, 1, Int>,
+    num_classes: usize,
+) -> Tensor<B, 2, Int> {
+    let [batch_size] = predictions.dims();
+    let [num_targets] = targets.dims();
+
+    assert_eq!(
+        batch_size, num_targets,
+        "The number of predictions ({batch_size}) must be equal to the number of targets ({num_targets})"
+    );
+
+    let device = predictions.device();
+
+    if batch_size == 0 {
+        return Tensor::zeros_device([num_classes, num_classes], &device);
+    }
+
+    let targets = targets.to_device(&device);
+
+    for (name, classes) in [("predictions", &predictions), ("targets", &targets)] {
+        let min = classes.clone().min().into_scalar().elem::<i64>();
+        let max = classes.clone().max().into_scalar().elem::<i64>();
+
+        assert!(
+            min >= 0 && max < num_classes as i64,
+            "The {name} must be in the range [0, {num_classes}), got [{min}, {max}]"
+        );
+    }
+
+    let indices = targets.mul_scalar(num_classes as i64).add(predictions);
+    let counts = Tensor::ones_device([batch_size], &device);
+
+    Tensor::zeros_device([num_classes * num_classes], &device)
+        .scatter(0, indices, counts)
+        .reshape([num_classes, num_classes])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_core::tensor::Data;
+
+    #[test]
+    fn test_confusion_matrix() {
+        let predictions = Tensor::<TestBackend, 1, Int>::from_data([0, 1, 2, 2, 1, 0, 2]);
+        let targets = Tensor::<TestBackend, 1, Int>::from_data([0, 1, 2, 1, 1, 2, 2]);
+
+        let matrix = confusion_matrix(predictions, targets, 3);
+
+        assert_eq!(
+            matrix.into_data(),
+            Data::from([[1, 0, 0], [0, 2, 1], [1, 0, 2]])
+        );
+    }
+
+    #[test]
+    fn test_confusion_matrix_empty_batch() {
+        let predictions = Tensor::<TestBackend, 1, Int>::from_data(Data::new(vec![], [0].into()));
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(Data::new(vec![], [0].into()));
+
+        let matrix = confusion_matrix(predictions, targets, 2);
+
+        assert_eq!(matrix.into_data(), Data::from([[0, 0], [0, 0]]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_confusion_matrix_class_out_of_range() {
+        let predictions = Tensor::<TestBackend, 1, Int>::from_data([0, 3]);
+        let targets = Tensor::<TestBackend, 1, Int>::from_data([0, 1]);
+
+        confusion_matrix(predictions, targets, 3);
+    }
+}
@@ -6,12 +6,14 @@ pub mod state;
 
 mod acc;
 mod base;
+mod confusion;
 mod cuda;
 mod learning_rate;
 mod loss;
 
 pub use acc::*;
 pub use base::*;
+pub use confusion::*;
 pub use cuda::*;
 pub use learning_rate::*;
 pub use loss::*;
@@ -91,7 +91,13 @@ benchmark!(
 fn main() {
     let num_repeats = 3;
     let batch_size = 3;
-    let matrix_size = 1000;
+    let matrix_size = 1024;
+    run_benchmark!(MatmulBenchmark::<NaiveMatmul, 3> {
+        shape_lhs: [batch_size, matrix_size, matrix_size].into(),
+        shape_rhs: [batch_size, matrix_size, matrix_size].into(),
+        num_repeats,
+        matmul: PhantomData
+    });
     run_benchmark!(MatmulBenchmark::<MemCoalescingMatmul, 3> {
         shape_lhs: [batch_size, matrix_size, matrix_size].into(),
         shape_rhs: [batch_size, matrix_size, matrix_size].into(),
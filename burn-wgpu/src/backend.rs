@@ -8,6 +8,8 @@ use crate::{
 };
 use std::{marker::PhantomData, sync::Mutex};
 
+/// Random values are generated on the host with this RNG before being uploaded, so tensors
+/// created after [seed](Backend::seed) are reproducible.
 pub(crate) static SEED: Mutex<Option<StdRng>> = Mutex::new(None);
 
 /// Wgpu backend.
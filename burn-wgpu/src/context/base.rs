@@ -1,4 +1,4 @@
-use super::client::ContextClient;
+use super::{client::ContextClient, BufferReadback};
 use crate::{
     context::server::ContextServer,
     kernel::{DynamicKernel, StaticKernel},
@@ -165,6 +165,12 @@ impl Context {
         self.client.read_buffer(buffer)
     }
 
+    /// Submit a read of a buffer from the GPU and return a [readback](BufferReadback) handle,
+    /// letting computation continue until its content is needed.
+    pub fn read_buffer_async(&self, buffer: Arc<Buffer>) -> BufferReadback {
+        self.client.read_buffer_async(buffer)
+    }
+
     /// Compile a kernel template if not present in the cache.
     pub fn compile_static<K: StaticKernel>(&self) -> Arc<ComputePipeline> {
         let mut cache = self.cache.lock();
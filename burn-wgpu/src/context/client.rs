@@ -1,4 +1,4 @@
-use super::WorkGroup;
+use super::{BufferReadback, WorkGroup};
 use std::sync::Arc;
 use wgpu::{BindGroup, Buffer, ComputePipeline};
 
@@ -29,6 +29,13 @@ pub trait ContextClient {
     ///
     /// All pending compute tasks will be executed.
     fn read_buffer(&self, buffer: Arc<Buffer>) -> Vec<u8>;
+    /// Submit a read of a [buffer](Buffer) without waiting for it to be completed.
+    ///
+    /// # Notes
+    ///
+    /// All pending compute tasks will be executed, but the returned [readback](BufferReadback)
+    /// only blocks when its content is read.
+    fn read_buffer_async(&self, buffer: Arc<Buffer>) -> BufferReadback;
     /// Register a new computing task.
     fn register_compute(
         &self,
@@ -46,8 +53,8 @@ pub trait ContextClient {
 mod async_client {
     use super::ContextClient;
     use crate::context::{
-        server::{ComputeTask, ContextTask, CopyBufferTask, ReadBufferTask},
-        WorkGroup,
+        server::{ComputeTask, ContextTask, CopyBufferTask, ReadBufferAsyncTask, ReadBufferTask},
+        BufferReadback, WorkGroup,
     };
     use std::sync::{mpsc, Arc};
     use wgpu::{BindGroup, Buffer, ComputePipeline};
@@ -120,6 +127,20 @@ mod async_client {
                 panic!("Unable to read buffer")
             }
         }
+        fn read_buffer_async(&self, buffer: Arc<Buffer>) -> BufferReadback {
+            let (sender, receiver) = std::sync::mpsc::channel();
+
+            self.sender
+                .send(ReadBufferAsyncTask::new(buffer, sender).into())
+                .unwrap();
+
+            let mut iter = receiver.iter();
+            if let Some(readback) = iter.next() {
+                readback
+            } else {
+                panic!("Unable to submit buffer read")
+            }
+        }
         fn register_compute(
             &self,
             bind_group: BindGroup,
@@ -138,7 +159,7 @@ mod sync_client {
     use super::ContextClient;
     use crate::context::{
         server::{ComputeTask, SyncContextServer},
-        WorkGroup,
+        BufferReadback, WorkGroup,
     };
     use std::sync::Arc;
     use wgpu::{BindGroup, Buffer, ComputePipeline};
@@ -176,6 +197,10 @@ mod sync_client {
             let mut server = self.server.lock();
             server.read_buffer(&buffer)
         }
+        fn read_buffer_async(&self, buffer: Arc<Buffer>) -> BufferReadback {
+            let mut server = self.server.lock();
+            server.read_buffer_async(&buffer)
+        }
 
         fn register_compute(
             &self,
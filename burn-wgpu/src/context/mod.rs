@@ -2,5 +2,7 @@ pub(super) mod client;
 pub(super) mod server;
 
 mod base;
+mod readback;
 
 pub use base::*;
+pub use readback::*;
@@ -0,0 +1,59 @@
+use futures_intrusive::channel::shared::OneshotReceiver;
+use std::sync::Arc;
+use wgpu::{Buffer, BufferAsyncError};
+
+/// Handle to a buffer read that was submitted to the GPU.
+///
+/// The copy into the staging buffer is already queued when the handle is created, so computing
+/// tasks registered afterward don't affect the data that will be read. Multiple readbacks can be
+/// submitted before waiting on any of them, which avoids stalling the pipeline for each read.
+pub struct BufferReadback {
+    device: Arc<wgpu::Device>,
+    buffer: Buffer,
+    receiver: OneshotReceiver<Result<(), BufferAsyncError>>,
+}
+
+impl BufferReadback {
+    /// Create a new readback from a staging buffer where the copy was already submitted.
+    pub(crate) fn new(device: Arc<wgpu::Device>, buffer: Buffer) -> Self {
+        let (sender, receiver) = futures_intrusive::channel::shared::oneshot_channel();
+
+        buffer.slice(..).map_async(wgpu::MapMode::Read, move |v| {
+            sender
+                .send(v)
+                .expect("Unable to send buffer slice result to async channel.")
+        });
+
+        Self {
+            device,
+            buffer,
+            receiver,
+        }
+    }
+
+    /// Wait for the copy to be completed and return the content of the buffer as bytes.
+    pub fn read(self) -> Vec<u8> {
+        self.device.poll(wgpu::Maintain::Wait);
+
+        let result = pollster::block_on(self.receiver.receive());
+
+        if let Some(Ok(())) = result {
+            let data = self.buffer.slice(..).get_mapped_range();
+            let result = bytemuck::cast_slice(&data).to_vec();
+
+            drop(data);
+            self.buffer.unmap();
+            result
+        } else {
+            panic!("Unable to read buffer {:?}", result)
+        }
+    }
+}
+
+impl core::fmt::Debug for BufferReadback {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BufferReadback")
+            .field("buffer", &self.buffer)
+            .finish()
+    }
+}
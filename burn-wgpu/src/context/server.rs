@@ -1,9 +1,11 @@
-use super::{client::ContextClient, WorkGroup};
+use super::{client::ContextClient, BufferReadback, WorkGroup};
 use std::sync::Arc;
 use wgpu::{BindGroup, Buffer, CommandEncoder, ComputePipeline};
 
 #[cfg(feature = "async")]
-pub use async_server::{AsyncContextServer, ContextTask, CopyBufferTask, ReadBufferTask};
+pub use async_server::{
+    AsyncContextServer, ContextTask, CopyBufferTask, ReadBufferAsyncTask, ReadBufferTask,
+};
 
 /// Context server allow to run tasks on the GPU.
 ///
@@ -71,6 +73,10 @@ impl SyncContextServer {
     }
 
     pub fn read_buffer(&mut self, buffer: &Buffer) -> Vec<u8> {
+        self.read_buffer_async(buffer).read()
+    }
+
+    pub fn read_buffer_async(&mut self, buffer: &Buffer) -> BufferReadback {
         // Register previous tasks before reading the buffer so that it is up to date.
         self.register_tasks();
 
@@ -87,28 +93,7 @@ impl SyncContextServer {
 
         self.submit();
 
-        let buffer_slice = buffer_dest.slice(..);
-        let (sender, receiver) = futures_intrusive::channel::shared::oneshot_channel();
-        buffer_slice.map_async(wgpu::MapMode::Read, move |v| {
-            sender
-                .send(v)
-                .expect("Unable to send buffer slice result to async channel.")
-        });
-
-        self.device.poll(wgpu::Maintain::Wait);
-
-        let result = pollster::block_on(receiver.receive());
-
-        if let Some(Ok(())) = result {
-            let data = buffer_slice.get_mapped_range();
-            let result = bytemuck::cast_slice(&data).to_vec();
-
-            drop(data);
-            buffer_dest.unmap();
-            result
-        } else {
-            panic!("Unable to read buffer {:?}", result)
-        }
+        BufferReadback::new(self.device.clone(), buffer_dest)
     }
 
     pub fn sync(&mut self) {
@@ -154,7 +139,7 @@ impl SyncContextServer {
 
 #[cfg(feature = "async")]
 mod async_server {
-    use crate::context::client::AsyncContextClient;
+    use crate::context::{client::AsyncContextClient, BufferReadback};
 
     use super::{ComputeTask, ContextServer, SyncContextServer};
     use std::sync::{mpsc, Arc};
@@ -166,6 +151,12 @@ mod async_server {
         sender: mpsc::Sender<Vec<u8>>,
     }
 
+    #[derive(new)]
+    pub struct ReadBufferAsyncTask {
+        buffer: Arc<Buffer>,
+        sender: mpsc::Sender<BufferReadback>,
+    }
+
     #[derive(new)]
     pub struct CopyBufferTask {
         pub(crate) buffer_src: Arc<Buffer>,
@@ -175,6 +166,7 @@ mod async_server {
     pub enum ContextTask {
         Compute(ComputeTask),
         ReadBuffer(ReadBufferTask),
+        ReadBufferAsync(ReadBufferAsyncTask),
         CopyBuffer(CopyBufferTask),
         Sync(mpsc::Sender<()>),
     }
@@ -191,6 +183,12 @@ mod async_server {
         }
     }
 
+    impl From<ReadBufferAsyncTask> for ContextTask {
+        fn from(val: ReadBufferAsyncTask) -> Self {
+            ContextTask::ReadBufferAsync(val)
+        }
+    }
+
     impl From<CopyBufferTask> for ContextTask {
         fn from(val: CopyBufferTask) -> Self {
             ContextTask::CopyBuffer(val)
@@ -221,6 +219,10 @@ mod async_server {
                         let bytes = self.server.read_buffer(&task.buffer);
                         task.sender.send(bytes).unwrap();
                     }
+                    ContextTask::ReadBufferAsync(task) => {
+                        let readback = self.server.read_buffer_async(&task.buffer);
+                        task.sender.send(readback).unwrap();
+                    }
                     ContextTask::Sync(callback) => {
                         self.server.sync();
                         callback.send(()).unwrap();
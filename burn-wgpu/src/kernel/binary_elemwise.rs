@@ -11,6 +11,41 @@ kernel_wgsl!(
 /// Creates a binary elementwise kernel.
 #[macro_export]
 macro_rules! binary_elemwise {
+    (
+        $struct:ident,
+        func $func:expr,
+        include $file:expr
+    ) => {
+        pub struct $struct;
+
+        impl $crate::kernel::StaticKernel for $struct {
+            fn source_template() -> $crate::kernel::SourceTemplate {
+                $crate::kernel::BinaryElemwiseRaw::source_template()
+                    .register(
+                        "body",
+                        format!("output[id] = {}(lhs[index_lhs], rhs[index_rhs]);", $func),
+                    )
+                    .add_template(include_str!($file))
+            }
+        }
+    };
+
+    (
+        $struct:ident,
+        func $func:expr
+    ) => {
+        pub struct $struct;
+
+        impl $crate::kernel::StaticKernel for $struct {
+            fn source_template() -> $crate::kernel::SourceTemplate {
+                $crate::kernel::BinaryElemwiseRaw::source_template().register(
+                    "body",
+                    format!("output[id] = {}(lhs[index_lhs], rhs[index_rhs]);", $func),
+                )
+            }
+        }
+    };
+
     (
         $struct:ident,
         $ops:expr
@@ -31,6 +66,41 @@ macro_rules! binary_elemwise {
 /// Creates a binary elementwise inplace kernel.
 #[macro_export]
 macro_rules! binary_elemwise_inplace {
+    (
+        $struct:ident,
+        func $func:expr,
+        include $file:expr
+    ) => {
+        pub struct $struct;
+
+        impl $crate::kernel::StaticKernel for $struct {
+            fn source_template() -> $crate::kernel::SourceTemplate {
+                $crate::kernel::BinaryElemwiseInplaceRaw::source_template()
+                    .register(
+                        "body",
+                        format!("lhs[id] = {}(lhs[id], rhs[index_rhs]);", $func),
+                    )
+                    .add_template(include_str!($file))
+            }
+        }
+    };
+
+    (
+        $struct:ident,
+        func $func:expr
+    ) => {
+        pub struct $struct;
+
+        impl $crate::kernel::StaticKernel for $struct {
+            fn source_template() -> $crate::kernel::SourceTemplate {
+                $crate::kernel::BinaryElemwiseInplaceRaw::source_template().register(
+                    "body",
+                    format!("lhs[id] = {}(lhs[id], rhs[index_rhs]);", $func),
+                )
+            }
+        }
+    };
+
     (
         $struct:ident,
         $ops:expr
@@ -0,0 +1,76 @@
+use crate::{
+    element::WgpuElement,
+    kernel::{elemwise_workgroup, KernelSettings},
+    kernel_wgsl,
+    tensor::WgpuTensor,
+};
+
+kernel_wgsl!(Clamp, "../template/clamp.wgsl");
+kernel_wgsl!(ClampInplace, "../template/clamp_inplace.wgsl");
+
+pub(crate) fn clamp<E: WgpuElement, const D: usize>(
+    input: WgpuTensor<E, D>,
+    min: E,
+    max: E,
+) -> WgpuTensor<E, D> {
+    const WORKGROUP: usize = 32;
+
+    let num_elems = input.shape.num_elements();
+    let bounds_buffer = input
+        .context
+        .create_buffer_with_data(E::as_bytes(&[min, max]));
+
+    if input.can_mut() {
+        let kernel = input
+            .context
+            .compile_static::<KernelSettings<ClampInplace, E, i32, WORKGROUP, WORKGROUP, 1>>();
+
+        input.context.execute(
+            elemwise_workgroup(num_elems, WORKGROUP),
+            kernel,
+            &[&input.buffer, &bounds_buffer],
+        );
+
+        return input;
+    }
+
+    let buffer = input
+        .context
+        .create_buffer(num_elems * std::mem::size_of::<E>());
+    let output = WgpuTensor::new(input.context.clone(), input.shape.clone(), buffer);
+    let kernel = input
+        .context
+        .compile_static::<KernelSettings<Clamp, E, i32, WORKGROUP, WORKGROUP, 1>>();
+
+    input.context.execute(
+        elemwise_workgroup(num_elems, WORKGROUP),
+        kernel,
+        &[&input.buffer, &bounds_buffer, &output.buffer],
+    );
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{ReferenceBackend, TestBackend};
+    use burn_tensor::{Distribution, Tensor};
+
+    #[test]
+    fn clamp_should_work_with_multiple_invocations() {
+        let tensor = Tensor::<TestBackend, 2>::random([6, 256], Distribution::Default);
+        let tensor_ref = Tensor::<ReferenceBackend, 2>::from_data(tensor.to_data());
+
+        let actual = Tensor::<TestBackend, 2>::from_primitive(clamp::<f32, 2>(
+            tensor.into_primitive(),
+            0.25,
+            0.75,
+        ));
+        let expected = tensor_ref.clamp(0.25, 0.75);
+
+        expected
+            .into_data()
+            .assert_approx_eq(&actual.into_data(), 3);
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{
+    element::WgpuElement,
+    kernel::{build_info, elemwise_workgroup, KernelSettings},
+    kernel_wgsl,
+    tensor::WgpuTensor,
+};
+
+kernel_wgsl!(Cumsum, "../template/cumsum.wgsl");
+
+/// Execute the cumulative sum kernel, scanning each lane along `dim` in a single invocation.
+///
+/// This is a serial fallback: the lanes are scanned in parallel, but the elements of a lane are
+/// accumulated one after the other, which is slow for long lanes.
+pub fn cumsum<E: WgpuElement, const D: usize>(
+    input: WgpuTensor<E, D>,
+    dim: usize,
+) -> WgpuTensor<E, D> {
+    const WORKGROUP: usize = 32;
+
+    let num_elems = input.shape.num_elements();
+
+    if num_elems == 0 {
+        return input;
+    }
+
+    let num_lanes = num_elems / input.shape.dims[dim];
+    let buffer = input
+        .context
+        .create_buffer(num_elems * core::mem::size_of::<E>());
+    let output = WgpuTensor::new(input.context.clone(), input.shape.clone(), buffer);
+
+    let kernel = input
+        .context
+        .compile_static::<KernelSettings<Cumsum, E, i32, WORKGROUP, WORKGROUP, 1>>();
+
+    let mut info = build_info(&[&input, &output]);
+    info.push(dim as u32);
+    info.push(num_lanes as u32);
+    let info_buffer = input
+        .context
+        .create_buffer_with_data(bytemuck::cast_slice(&info));
+
+    input.context.execute(
+        elemwise_workgroup(num_lanes, WORKGROUP),
+        kernel,
+        &[&input.buffer, &output.buffer, &info_buffer],
+    );
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{ReferenceBackend, TestBackend};
+    use burn_tensor::{backend::Backend, Distribution, Tensor};
+
+    #[test]
+    fn cumsum_should_work_with_multiple_invocations_dim0() {
+        test_same_as_reference([6, 256], 0);
+    }
+
+    #[test]
+    fn cumsum_should_work_with_multiple_invocations_dim1() {
+        test_same_as_reference([6, 256], 1);
+    }
+
+    #[test]
+    fn cumsum_should_support_uneven_launch() {
+        test_same_as_reference([37, 13], 1);
+    }
+
+    fn test_same_as_reference(shape: [usize; 2], dim: usize) {
+        TestBackend::seed(0);
+        let tensor = Tensor::<TestBackend, 2>::random(shape, Distribution::Default);
+        let tensor_ref = Tensor::<ReferenceBackend, 2>::from_data(tensor.to_data());
+
+        let output = tensor.cumsum(dim);
+        let output_ref = tensor_ref.cumsum(dim);
+
+        output
+            .into_data()
+            .assert_approx_eq(&output_ref.into_data(), 2);
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{
+    element::WgpuElement,
+    kernel::{build_info, elemwise_workgroup, KernelSettings},
+    kernel_wgsl,
+    tensor::WgpuTensor,
+};
+
+kernel_wgsl!(FlipRaw, "../../template/index/flip.wgsl");
+
+pub(crate) fn flip<E: WgpuElement, const D: usize>(
+    tensor: WgpuTensor<E, D>,
+    axes: &[usize],
+) -> WgpuTensor<E, D> {
+    const WORKGROUP: usize = 32;
+
+    let num_elems = tensor.shape.num_elements();
+    let buffer = tensor
+        .context
+        .create_buffer(num_elems * core::mem::size_of::<E>());
+    let output = WgpuTensor::new(tensor.context.clone(), tensor.shape.clone(), buffer);
+
+    let mut info = build_info(&[&tensor, &output]);
+
+    for i in 0..D {
+        info.push(axes.contains(&i) as u32);
+    }
+
+    let info_buffer = tensor
+        .context
+        .create_buffer_with_data(bytemuck::cast_slice(&info));
+
+    let kernel = tensor
+        .context
+        .compile_static::<KernelSettings<FlipRaw, E, i32, WORKGROUP, WORKGROUP, 1>>();
+
+    tensor.context.execute(
+        elemwise_workgroup(num_elems, WORKGROUP),
+        kernel,
+        &[&tensor.buffer, &output.buffer, &info_buffer],
+    );
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{ReferenceBackend, TestBackend};
+    use burn_tensor::{Distribution, Tensor};
+
+    #[test]
+    fn flip_should_work_with_multiple_workgroups() {
+        let tensor = Tensor::<TestBackend, 3>::random([6, 256, 7], Distribution::Default);
+        let tensor_ref = Tensor::<ReferenceBackend, 3>::from_data(tensor.to_data());
+
+        let actual = tensor.flip(&[0, 2]);
+        let expected = tensor_ref.flip(&[0, 2]);
+
+        expected
+            .into_data()
+            .assert_approx_eq(&actual.into_data(), 3);
+    }
+
+    #[test]
+    fn flip_should_work_with_swapped_dims() {
+        let tensor = Tensor::<TestBackend, 2>::random([17, 33], Distribution::Default);
+        let tensor_ref = Tensor::<ReferenceBackend, 2>::from_data(tensor.to_data());
+
+        let actual = tensor.swap_dims(0, 1).flip(&[1]);
+        let expected = tensor_ref.swap_dims(0, 1).flip(&[1]);
+
+        expected
+            .into_data()
+            .assert_approx_eq(&actual.into_data(), 3);
+    }
+}
@@ -1,9 +1,13 @@
+mod flip;
 mod gather;
+mod repeat;
 mod scatter;
 mod select;
 mod slice;
 
+pub use flip::*;
 pub use gather::*;
+pub use repeat::*;
 pub use scatter::*;
 pub use select::*;
 pub use slice::*;
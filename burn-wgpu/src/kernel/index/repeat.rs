@@ -0,0 +1,81 @@
+use crate::{
+    element::WgpuElement,
+    kernel::{build_info, elemwise_workgroup, KernelSettings},
+    kernel_wgsl,
+    tensor::WgpuTensor,
+};
+
+kernel_wgsl!(RepeatRaw, "../../template/index/repeat.wgsl");
+
+pub(crate) fn repeat<E: WgpuElement, const D: usize>(
+    input: WgpuTensor<E, D>,
+    dim: usize,
+    times: usize,
+) -> WgpuTensor<E, D> {
+    const WORKGROUP: usize = 32;
+
+    let mut shape = input.shape.clone();
+    shape.dims[dim] *= times;
+    let num_elems = shape.num_elements();
+
+    let buffer = input
+        .context
+        .create_buffer(num_elems * core::mem::size_of::<E>());
+    let output = WgpuTensor::new(input.context.clone(), shape, buffer);
+
+    if num_elems == 0 {
+        return output;
+    }
+
+    let info = build_info(&[&input, &output]);
+    let info_buffer = input
+        .context
+        .create_buffer_with_data(bytemuck::cast_slice(&info));
+
+    let kernel = input
+        .context
+        .compile_static::<KernelSettings<RepeatRaw, E, i32, WORKGROUP, WORKGROUP, 1>>();
+
+    input.context.execute(
+        elemwise_workgroup(num_elems, WORKGROUP),
+        kernel,
+        &[&input.buffer, &output.buffer, &info_buffer],
+    );
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{ReferenceBackend, TestBackend};
+    use burn_tensor::{Distribution, Tensor};
+
+    #[test]
+    fn repeat_should_work_with_multiple_workgroups() {
+        let tensor = Tensor::<TestBackend, 3>::random([6, 1, 256], Distribution::Default);
+        let tensor_ref = Tensor::<ReferenceBackend, 3>::from_data(tensor.to_data());
+
+        let actual = repeat(tensor.into_primitive(), 1, 3);
+        let expected = tensor_ref.repeat(1, 3);
+
+        expected.into_data().assert_approx_eq(
+            &Tensor::<TestBackend, 3>::from_primitive(actual).into_data(),
+            3,
+        );
+    }
+
+    #[test]
+    fn repeat_should_tile_dim_larger_than_one() {
+        let tensor = Tensor::<TestBackend, 2>::random([4, 67], Distribution::Default);
+        let tensor_ref = Tensor::<ReferenceBackend, 2>::from_data(tensor.to_data());
+
+        let actual = repeat(tensor.into_primitive(), 1, 5);
+        let expected = tensor_ref.repeat(1, 5);
+
+        expected.into_data().assert_approx_eq(
+            &Tensor::<TestBackend, 2>::from_primitive(actual).into_data(),
+            3,
+        );
+    }
+}
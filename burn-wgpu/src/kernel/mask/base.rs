@@ -19,10 +19,11 @@ pub fn mask_where<E: WgpuElement, const D: usize>(
     mask: WgpuTensor<u32, D>,
     value: WgpuTensor<E, D>,
 ) -> WgpuTensor<E, D> {
-    if tensor.can_mut_broadcast(&value) {
+    // The mask can also be broadcasted, so it must fit in the tensor updated inplace.
+    if tensor.can_mut_broadcast(&value) && tensor.can_mut_broadcast(&mask) {
         return super::mask_where::mask_where_inplace(tensor, mask, value, false);
     }
-    if value.can_mut_broadcast(&tensor) {
+    if value.can_mut_broadcast(&tensor) && value.can_mut_broadcast(&mask) {
         return super::mask_where::mask_where_inplace(value, mask, tensor, true);
     }
 
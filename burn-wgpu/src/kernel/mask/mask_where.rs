@@ -15,11 +15,19 @@ pub fn mask_where<E: WgpuElement, const D: usize>(
 ) -> WgpuTensor<E, D> {
     const WORKGROUP: usize = 32;
 
-    let num_elems = input.shape.num_elements();
+    let mut shape_output = input.shape.clone();
+    for i in 0..D {
+        shape_output.dims[i] = usize::max(
+            shape_output.dims[i],
+            usize::max(value.shape.dims[i], mask.shape.dims[i]),
+        );
+    }
+
+    let num_elems = shape_output.num_elements();
     let buffer = input
         .context
         .create_buffer(num_elems * core::mem::size_of::<E>());
-    let output = WgpuTensor::new(input.context.clone(), input.shape.clone(), buffer);
+    let output = WgpuTensor::new(input.context.clone(), shape_output, buffer);
 
     let kernel = input
         .context
@@ -96,6 +104,29 @@ mod tests {
             .into_data()
             .assert_approx_eq(&actual.into_data(), 3);
     }
+    #[test]
+    fn mask_where_should_broadcast_the_input() {
+        TestBackend::seed(0);
+        let tensor = Tensor::<TestBackend, 3>::random([2, 1, 256], Distribution::Default);
+        let value = Tensor::<TestBackend, 3>::random([2, 6, 256], Distribution::Default);
+        let mask = Tensor::<TestBackend, 3>::random([1, 6, 256], Distribution::Uniform(0., 1.))
+            .lower_equal_elem(0.5);
+        let tensor_ref = Tensor::<ReferenceBackend, 3>::from_data(tensor.to_data());
+        let value_ref = Tensor::<ReferenceBackend, 3>::from_data(value.to_data());
+        let mask_ref = Tensor::<ReferenceBackend, 3, Bool>::from_data(mask.to_data());
+
+        let actual = Tensor::<TestBackend, 3>::from_primitive(mask_where::<f32, 3>(
+            tensor.into_primitive(),
+            mask.into_primitive(),
+            value.into_primitive(),
+        ));
+        let expected = tensor_ref.mask_where(mask_ref, value_ref);
+
+        expected
+            .into_data()
+            .assert_approx_eq(&actual.into_data(), 3);
+    }
+
     #[test]
     fn mask_where_inplace_direction_1_should_work_with_multiple_invocations() {
         let (tensor, value, mask, tensor_ref, value_ref, mask_ref) = inputs_mask_where();
@@ -130,6 +161,30 @@ mod tests {
             .assert_approx_eq(&actual.into_data(), 3);
     }
 
+    #[test]
+    fn mask_where_should_not_be_inplace_with_a_broadcasted_mask() {
+        TestBackend::seed(0);
+        let tensor = Tensor::<TestBackend, 3>::random([2, 1, 256], Distribution::Default);
+        let value = Tensor::<TestBackend, 3>::random([2, 1, 256], Distribution::Default);
+        let mask = Tensor::<TestBackend, 3>::random([2, 6, 256], Distribution::Uniform(0., 1.))
+            .lower_equal_elem(0.5);
+        let tensor_ref = Tensor::<ReferenceBackend, 3>::from_data(tensor.to_data());
+        let value_ref = Tensor::<ReferenceBackend, 3>::from_data(value.to_data());
+        let mask_ref = Tensor::<ReferenceBackend, 3, Bool>::from_data(mask.to_data());
+
+        let actual = Tensor::<TestBackend, 3>::from_primitive(crate::kernel::mask_where(
+            tensor.into_primitive(),
+            mask.into_primitive(),
+            value.into_primitive(),
+        ));
+        let expected = tensor_ref.mask_where(mask_ref, value_ref);
+
+        assert_eq!(actual.dims(), [2, 6, 256]);
+        expected
+            .into_data()
+            .assert_approx_eq(&actual.into_data(), 3);
+    }
+
     #[allow(clippy::type_complexity)]
     fn inputs_mask_where() -> (
         Tensor<TestBackend, 3>,
@@ -149,6 +149,16 @@ macro_rules! matmul_tile_2d {
             use $crate::kernel::matmul::utils::tests::same_as_reference;
             use $crate::kernel::matmul::utils::tests::same_as_reference_swapped_dims;
 
+            #[test]
+            pub fn test_matmul_tiling_2d_default_non_square() {
+                same_as_reference(matmul_tiling_2d_default, [67, 33], [33, 129]);
+            }
+
+            #[test]
+            pub fn test_matmul_tiling_2d_default_batched() {
+                same_as_reference(matmul_tiling_2d_default, [3, 70, 50], [3, 50, 30]);
+            }
+
             #[test]
             pub fn test_matmul_tiling_2d_large_blocks() {
                 test_with_params::<128, 128, 8, 4, 4, 32, 32>(8, 8, 8, 1, 1);
@@ -2,7 +2,9 @@ mod base;
 mod binary_elemwise;
 mod cast;
 mod cat;
+mod clamp;
 mod comparison;
+mod cumsum;
 mod index;
 mod mask;
 mod reduction;
@@ -25,7 +27,9 @@ pub mod matmul;
 pub mod pool;
 
 pub(crate) use cat::*;
+pub(crate) use clamp::*;
 pub(crate) use comparison::*;
+pub(crate) use cumsum::*;
 pub(crate) use index::*;
 pub(crate) use mask::*;
 pub(crate) use reduction::*;
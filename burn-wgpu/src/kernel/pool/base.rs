@@ -69,3 +69,59 @@ pub fn build_pool2d_info<E: WgpuElement>(
 
     info_buffer
 }
+
+/// Build basic info to lauch pool 1d kernels.
+pub fn build_output_and_info_pool1d<E: WgpuElement>(
+    x: &WgpuTensor<E, 3>,
+    kernel_size: usize,
+    stride: usize,
+    padding: usize,
+) -> (Arc<Buffer>, WgpuTensor<E, 3>) {
+    let [batch_size, channels, x_length] = x.shape.dims;
+
+    let out_length = ((x_length + 2 * padding - kernel_size) / stride) + 1;
+    let shape_out = Shape::new([batch_size, channels, out_length]);
+    let num_elems = shape_out.num_elements();
+
+    let buffer = x
+        .context
+        .create_buffer(num_elems * core::mem::size_of::<E>());
+    let output = WgpuTensor::new(x.context.clone(), shape_out, buffer);
+
+    let info_buffer = build_pool1d_info(x, &output, kernel_size, stride, padding);
+
+    (info_buffer, output)
+}
+
+pub fn build_pool1d_info<E: WgpuElement>(
+    input: &WgpuTensor<E, 3>,
+    output: &WgpuTensor<E, 3>,
+    kernel_size: usize,
+    stride: usize,
+    padding: usize,
+) -> Arc<Buffer> {
+    let mut info: [u32; 15] = [0; 15];
+    info[0] = input.strides[0] as u32;
+    info[1] = input.strides[1] as u32;
+    info[2] = input.strides[2] as u32;
+    info[3] = input.shape.dims[0] as u32;
+    info[4] = input.shape.dims[1] as u32;
+    info[5] = input.shape.dims[2] as u32;
+
+    info[6] = output.strides[0] as u32;
+    info[7] = output.strides[1] as u32;
+    info[8] = output.strides[2] as u32;
+    info[9] = output.shape.dims[0] as u32;
+    info[10] = output.shape.dims[1] as u32;
+    info[11] = output.shape.dims[2] as u32;
+
+    info[12] = kernel_size as u32;
+    info[13] = stride as u32;
+    info[14] = padding as u32;
+
+    let info_buffer = input
+        .context
+        .create_buffer_with_data(bytemuck::cast_slice(&info));
+
+    info_buffer
+}
@@ -0,0 +1,191 @@
+use crate::{
+    element::WgpuElement,
+    kernel::{
+        self, elemwise_workgroup,
+        pool::{build_output_and_info_pool1d, build_pool1d_info},
+        KernelSettings,
+    },
+    kernel_wgsl,
+    tensor::WgpuTensor,
+};
+
+kernel_wgsl!(MaxPool1d, "../../template/pool/max_pool1d.wgsl");
+kernel_wgsl!(
+    MaxPool1dWithIndicesBackward,
+    "../../template/pool/max_pool1d_with_indices_backward.wgsl"
+);
+kernel_wgsl!(
+    MaxPool1dWithIndices,
+    "../../template/pool/max_pool1d_with_indices.wgsl"
+);
+
+pub(crate) fn max_pool1d<E: WgpuElement>(
+    x: WgpuTensor<E, 3>,
+    kernel_size: usize,
+    stride: usize,
+    padding: usize,
+) -> WgpuTensor<E, 3> {
+    const WORKGROUP: usize = 32;
+
+    let (info_buffer, output) = build_output_and_info_pool1d(&x, kernel_size, stride, padding);
+    let kernel = x
+        .context
+        .compile_static::<KernelSettings<MaxPool1d, E, i32, WORKGROUP, WORKGROUP, 1>>();
+
+    x.context.execute(
+        elemwise_workgroup(output.shape.num_elements(), WORKGROUP),
+        kernel,
+        &[&x.buffer, &output.buffer, &info_buffer],
+    );
+
+    output
+}
+
+pub(crate) fn max_pool1d_with_indices<E: WgpuElement, I: WgpuElement>(
+    x: WgpuTensor<E, 3>,
+    kernel_size: usize,
+    stride: usize,
+    padding: usize,
+) -> (WgpuTensor<E, 3>, WgpuTensor<I, 3>) {
+    const WORKGROUP: usize = 32;
+
+    let (info_buffer, output) = build_output_and_info_pool1d(&x, kernel_size, stride, padding);
+    let num_elems = output.shape.num_elements();
+
+    let indices = WgpuTensor::new(
+        x.context.clone(),
+        output.shape.clone(),
+        x.context
+            .create_buffer(num_elems * std::mem::size_of::<I>()),
+    );
+
+    let kernel = x
+        .context
+        .compile_static::<KernelSettings<MaxPool1dWithIndices, E, I, WORKGROUP, WORKGROUP, 1>>();
+
+    x.context.execute(
+        elemwise_workgroup(output.shape.num_elements(), WORKGROUP),
+        kernel,
+        &[&x.buffer, &output.buffer, &indices.buffer, &info_buffer],
+    );
+
+    (output, indices)
+}
+
+pub(crate) fn max_pool1d_with_indices_backward<E: WgpuElement, I: WgpuElement>(
+    x: WgpuTensor<E, 3>,
+    grad: WgpuTensor<E, 3>,
+    indices: WgpuTensor<I, 3>,
+    kernel_size: usize,
+    stride: usize,
+    padding: usize,
+) -> WgpuTensor<E, 3> {
+    const WORKGROUP: usize = 32;
+
+    let grad = kernel::into_contiguous(grad);
+    let indices = kernel::into_contiguous(indices);
+
+    let num_elems = x.shape.num_elements();
+    let buffer = x
+        .context
+        .create_buffer(num_elems * core::mem::size_of::<E>());
+    let output = WgpuTensor::new(x.context.clone(), x.shape.clone(), buffer);
+    let info_buffer = build_pool1d_info(&x, &grad, kernel_size, stride, padding);
+
+    let kernel = x.context.compile_static::<KernelSettings<
+        MaxPool1dWithIndicesBackward,
+        E,
+        I,
+        WORKGROUP,
+        WORKGROUP,
+        1,
+    >>();
+
+    x.context.execute(
+        elemwise_workgroup(output.shape.num_elements(), WORKGROUP),
+        kernel,
+        &[&indices.buffer, &grad.buffer, &output.buffer, &info_buffer],
+    );
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{ReferenceBackend, TestBackend};
+    use burn_tensor::{module, ops::ModuleOps, Distribution, Tensor};
+
+    #[test]
+    pub fn max_pool1d_should_work_with_multiple_invocations() {
+        let tensor = Tensor::<TestBackend, 3>::random([32, 32, 64], Distribution::Default);
+        let tensor_ref = Tensor::<ReferenceBackend, 3>::from_data(tensor.to_data());
+
+        for (kernel_size, stride, padding) in [(3, 1, 1), (4, 2, 2), (2, 3, 0)] {
+            let pooled = module::max_pool1d(tensor.clone(), kernel_size, stride, padding);
+            let pooled_ref = module::max_pool1d(tensor_ref.clone(), kernel_size, stride, padding);
+
+            pooled
+                .into_data()
+                .assert_approx_eq(&pooled_ref.into_data(), 3);
+        }
+    }
+
+    #[test]
+    pub fn max_pool1d_with_indices_should_work_with_multiple_invocations() {
+        let tensor = Tensor::<TestBackend, 3>::random([32, 32, 64], Distribution::Default);
+        let tensor_ref = Tensor::<ReferenceBackend, 3>::from_data(tensor.to_data());
+
+        for (kernel_size, stride, padding) in [(3, 1, 1), (4, 2, 2), (2, 3, 0)] {
+            let (pooled, indices) =
+                module::max_pool1d_with_indices(tensor.clone(), kernel_size, stride, padding);
+            let (pooled_ref, indices_ref) =
+                module::max_pool1d_with_indices(tensor_ref.clone(), kernel_size, stride, padding);
+
+            pooled
+                .into_data()
+                .assert_approx_eq(&pooled_ref.into_data(), 3);
+            assert_eq!(indices.into_data(), indices_ref.into_data().convert());
+        }
+    }
+
+    #[test]
+    pub fn max_pool1d_with_indices_backward_should_work_with_multiple_invocations() {
+        let tensor = Tensor::<TestBackend, 3>::random([32, 32, 64], Distribution::Default);
+        let tensor_ref = Tensor::<ReferenceBackend, 3>::from_data(tensor.to_data());
+
+        for (kernel_size, stride, padding) in [(3, 1, 1), (4, 2, 2), (2, 3, 0)] {
+            let (pooled, indices) =
+                module::max_pool1d_with_indices(tensor.clone(), kernel_size, stride, padding);
+            let (_, indices_ref) =
+                module::max_pool1d_with_indices(tensor_ref.clone(), kernel_size, stride, padding);
+            let grad_output =
+                Tensor::<TestBackend, 3>::random(pooled.shape(), Distribution::Default);
+            let grad_output_ref = Tensor::<ReferenceBackend, 3>::from_data(grad_output.to_data());
+
+            let grad = TestBackend::max_pool1d_with_indices_backward(
+                tensor.clone().into_primitive(),
+                kernel_size,
+                stride,
+                padding,
+                grad_output.into_primitive(),
+                indices.into_primitive(),
+            )
+            .x_grad;
+            let grad_ref = ReferenceBackend::max_pool1d_with_indices_backward(
+                tensor_ref.clone().into_primitive(),
+                kernel_size,
+                stride,
+                padding,
+                grad_output_ref.into_primitive(),
+                indices_ref.into_primitive(),
+            )
+            .x_grad;
+
+            Tensor::<TestBackend, 3>::from_primitive(grad)
+                .into_data()
+                .assert_approx_eq(
+                    &Tensor::<ReferenceBackend, 3>::from_primitive(grad_ref).into_data(),
+                    3,
+                );
+        }
+    }
+}
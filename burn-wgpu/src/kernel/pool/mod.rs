@@ -1,7 +1,13 @@
+mod adaptive_avg_pool2d;
+mod avg_pool1d;
 mod avg_pool2d;
 mod base;
+mod max_pool1d;
 mod max_pool2d;
 
+pub use adaptive_avg_pool2d::*;
+pub use avg_pool1d::*;
 pub use avg_pool2d::*;
 pub(super) use base::*;
+pub use max_pool1d::*;
 pub use max_pool2d::*;
@@ -5,27 +5,69 @@ use burn_tensor::Shape;
 kernel_wgsl!(RecursiveSumRaw, "../template/reduction/recursive_sum.wgsl");
 kernel_wgsl!(ReductionDimRaw, "../template/reduction/reduce_dim.wgsl");
 kernel_wgsl!(ReductionArgsRaw, "../template/reduction/args.wgsl");
+kernel_wgsl!(
+    ReductionArgsWithValuesRaw,
+    "../template/reduction/args_with_values.wgsl"
+);
+kernel_wgsl!(
+    ReductionDimSharedMemoryRaw,
+    "../template/reduction/reduce_dim_shared_memory.wgsl"
+);
+kernel_wgsl!(
+    ReductionArgsWithValuesSharedMemoryRaw,
+    "../template/reduction/args_with_values_shared_memory.wgsl"
+);
+
+/// Reduced dimensions at least this long are reduced by a whole workgroup using shared memory
+/// instead of a single invocation per output element.
+const SHARED_MEMORY_THRESHOLD: usize = 256;
 
 pub struct ArgsMax;
 pub struct ArgsMin;
+pub struct MaxDimWithIndices;
+pub struct MinDimWithIndices;
+pub struct MaxDimWithIndicesSharedMemory;
+pub struct MinDimWithIndicesSharedMemory;
 pub struct SumDim;
 pub struct MeanDim;
+pub struct SumDimSharedMemory;
+pub struct MeanDimSharedMemory;
+
+fn sum_dim_template(template: SourceTemplate) -> SourceTemplate {
+    template.register("assign", "output[id] = sum;")
+}
+
+fn mean_dim_template(template: SourceTemplate) -> SourceTemplate {
+    template
+        .add_template(
+            "fn mean_dim(sum: {{ elem }}, dim: u32) -> {{ elem }} { 
+    return sum / {{ elem }}(dim);
+}",
+        )
+        .register("assign", "output[id] = mean_dim(sum, shape_dim);")
+}
 
 impl StaticKernel for SumDim {
     fn source_template() -> SourceTemplate {
-        ReductionDimRaw::source_template().register("assign", "output[id] = sum;")
+        sum_dim_template(ReductionDimRaw::source_template())
     }
 }
 
 impl StaticKernel for MeanDim {
     fn source_template() -> SourceTemplate {
-        ReductionDimRaw::source_template()
-            .add_template(
-                "fn mean_dim(sum: {{ elem }}, dim: u32) -> {{ elem }} { 
-    return sum / {{ elem }}(dim);
-}",
-            )
-            .register("assign", "output[id] = mean_dim(sum, shape_dim);")
+        mean_dim_template(ReductionDimRaw::source_template())
+    }
+}
+
+impl StaticKernel for SumDimSharedMemory {
+    fn source_template() -> SourceTemplate {
+        sum_dim_template(ReductionDimSharedMemoryRaw::source_template())
+    }
+}
+
+impl StaticKernel for MeanDimSharedMemory {
+    fn source_template() -> SourceTemplate {
+        mean_dim_template(ReductionDimSharedMemoryRaw::source_template())
     }
 }
 
@@ -45,6 +87,30 @@ impl StaticKernel for ArgsMin {
     }
 }
 
+impl StaticKernel for MaxDimWithIndices {
+    fn source_template() -> SourceTemplate {
+        ReductionArgsWithValuesRaw::source_template().register("cmp", ">")
+    }
+}
+
+impl StaticKernel for MinDimWithIndices {
+    fn source_template() -> SourceTemplate {
+        ReductionArgsWithValuesRaw::source_template().register("cmp", "<")
+    }
+}
+
+impl StaticKernel for MaxDimWithIndicesSharedMemory {
+    fn source_template() -> SourceTemplate {
+        ReductionArgsWithValuesSharedMemoryRaw::source_template().register("cmp", ">")
+    }
+}
+
+impl StaticKernel for MinDimWithIndicesSharedMemory {
+    fn source_template() -> SourceTemplate {
+        ReductionArgsWithValuesSharedMemoryRaw::source_template().register("cmp", "<")
+    }
+}
+
 /// Sum all elements in the input buffer.
 pub fn sum<E: WgpuElement, const D: usize>(input: WgpuTensor<E, D>) -> WgpuTensor<E, 1> {
     const WORKGROUP: usize = 32;
@@ -80,6 +146,10 @@ pub fn sum_dim<E: WgpuElement, const D: usize>(
     input: WgpuTensor<E, D>,
     dim: usize,
 ) -> WgpuTensor<E, D> {
+    if input.shape.dims[dim] >= SHARED_MEMORY_THRESHOLD {
+        return reduction_dim_shared_memory::<SumDimSharedMemory, E, D>(input, dim);
+    }
+
     reduction_dim::<SumDim, E, D>(input, dim)
 }
 
@@ -88,6 +158,10 @@ pub fn mean_dim<E: WgpuElement, const D: usize>(
     input: WgpuTensor<E, D>,
     dim: usize,
 ) -> WgpuTensor<E, D> {
+    if input.shape.dims[dim] >= SHARED_MEMORY_THRESHOLD {
+        return reduction_dim_shared_memory::<MeanDimSharedMemory, E, D>(input, dim);
+    }
+
     reduction_dim::<MeanDim, E, D>(input, dim)
 }
 
@@ -124,6 +198,41 @@ fn reduction_dim<K: StaticKernel, E: WgpuElement, const D: usize>(
     output
 }
 
+fn reduction_dim_shared_memory<K: StaticKernel, E: WgpuElement, const D: usize>(
+    input: WgpuTensor<E, D>,
+    dim: usize,
+) -> WgpuTensor<E, D> {
+    const WORKGROUP: usize = 8;
+
+    let mut shape_out = input.shape.clone();
+    shape_out.dims[dim] = 1;
+    let num_elems = shape_out.num_elements();
+    let buffer = input
+        .context
+        .create_buffer(num_elems * core::mem::size_of::<E>());
+    let output = WgpuTensor::new(input.context.clone(), shape_out, buffer);
+
+    let kernel = input
+        .context
+        .compile_static::<KernelSettings<K, E, i32, WORKGROUP, WORKGROUP, 1>>();
+
+    let mut info = build_info(&[&input, &output]);
+    info.push(dim as u32);
+    info.push(num_elems as u32);
+    let info_buffers = input
+        .context
+        .create_buffer_with_data(bytemuck::cast_slice(&info));
+
+    // One workgroup per output element.
+    input.context.execute(
+        elemwise_workgroup(num_elems * WORKGROUP * WORKGROUP, WORKGROUP),
+        kernel,
+        &[&input.buffer, &output.buffer, &info_buffers],
+    );
+
+    output
+}
+
 /// Execute the argmax kernel.
 pub fn argmax<E: WgpuElement, I: WgpuElement, const D: usize>(
     input: WgpuTensor<E, D>,
@@ -172,6 +281,123 @@ fn reduction_args_dim<K: StaticKernel, E: WgpuElement, I: WgpuElement, const D:
     WgpuTensor::new(output.context, output.shape, output.buffer)
 }
 
+/// Execute the max dim kernel, returning the values and their indices.
+pub fn max_dim_with_indices<E: WgpuElement, I: WgpuElement, const D: usize>(
+    input: WgpuTensor<E, D>,
+    dim: usize,
+) -> (WgpuTensor<E, D>, WgpuTensor<I, D>) {
+    if input.shape.dims[dim] >= SHARED_MEMORY_THRESHOLD {
+        return reduction_dim_with_indices_shared_memory::<MaxDimWithIndicesSharedMemory, E, I, D>(
+            input, dim,
+        );
+    }
+
+    reduction_dim_with_indices::<MaxDimWithIndices, E, I, D>(input, dim)
+}
+
+/// Execute the min dim kernel, returning the values and their indices.
+pub fn min_dim_with_indices<E: WgpuElement, I: WgpuElement, const D: usize>(
+    input: WgpuTensor<E, D>,
+    dim: usize,
+) -> (WgpuTensor<E, D>, WgpuTensor<I, D>) {
+    if input.shape.dims[dim] >= SHARED_MEMORY_THRESHOLD {
+        return reduction_dim_with_indices_shared_memory::<MinDimWithIndicesSharedMemory, E, I, D>(
+            input, dim,
+        );
+    }
+
+    reduction_dim_with_indices::<MinDimWithIndices, E, I, D>(input, dim)
+}
+
+fn reduction_dim_with_indices<K: StaticKernel, E: WgpuElement, I: WgpuElement, const D: usize>(
+    input: WgpuTensor<E, D>,
+    dim: usize,
+) -> (WgpuTensor<E, D>, WgpuTensor<I, D>) {
+    const WORKGROUP: usize = 32;
+
+    let mut shape_out = input.shape.clone();
+    shape_out.dims[dim] = 1;
+    let num_elems = shape_out.num_elements();
+    let buffer_values = input
+        .context
+        .create_buffer(num_elems * core::mem::size_of::<E>());
+    let buffer_indices = input
+        .context
+        .create_buffer(num_elems * core::mem::size_of::<I>());
+    let values = WgpuTensor::new(input.context.clone(), shape_out.clone(), buffer_values);
+    let indices = WgpuTensor::new(input.context.clone(), shape_out, buffer_indices);
+
+    let kernel = input
+        .context
+        .compile_static::<KernelSettings<K, E, I, WORKGROUP, WORKGROUP, 1>>();
+    let mut info = build_info(&[&input, &values]);
+    info.push(dim as u32);
+    let info_buffers = input
+        .context
+        .create_buffer_with_data(bytemuck::cast_slice(&info));
+
+    input.context.execute(
+        elemwise_workgroup(num_elems, WORKGROUP),
+        kernel,
+        &[
+            &input.buffer,
+            &values.buffer,
+            &indices.buffer,
+            &info_buffers,
+        ],
+    );
+
+    (values, indices)
+}
+
+fn reduction_dim_with_indices_shared_memory<
+    K: StaticKernel,
+    E: WgpuElement,
+    I: WgpuElement,
+    const D: usize,
+>(
+    input: WgpuTensor<E, D>,
+    dim: usize,
+) -> (WgpuTensor<E, D>, WgpuTensor<I, D>) {
+    const WORKGROUP: usize = 8;
+
+    let mut shape_out = input.shape.clone();
+    shape_out.dims[dim] = 1;
+    let num_elems = shape_out.num_elements();
+    let buffer_values = input
+        .context
+        .create_buffer(num_elems * core::mem::size_of::<E>());
+    let buffer_indices = input
+        .context
+        .create_buffer(num_elems * core::mem::size_of::<I>());
+    let values = WgpuTensor::new(input.context.clone(), shape_out.clone(), buffer_values);
+    let indices = WgpuTensor::new(input.context.clone(), shape_out, buffer_indices);
+
+    let kernel = input
+        .context
+        .compile_static::<KernelSettings<K, E, I, WORKGROUP, WORKGROUP, 1>>();
+    let mut info = build_info(&[&input, &values]);
+    info.push(dim as u32);
+    info.push(num_elems as u32);
+    let info_buffers = input
+        .context
+        .create_buffer_with_data(bytemuck::cast_slice(&info));
+
+    // One workgroup per output element.
+    input.context.execute(
+        elemwise_workgroup(num_elems * WORKGROUP * WORKGROUP, WORKGROUP),
+        kernel,
+        &[
+            &input.buffer,
+            &values.buffer,
+            &indices.buffer,
+            &info_buffers,
+        ],
+    );
+
+    (values, indices)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -213,4 +439,119 @@ mod tests {
 
         assert_eq!(val_ref.into_data().convert(), val.into_data());
     }
+
+    #[test]
+    fn reduction_max_dim_with_indices_should_work_with_multiple_invocations() {
+        let tensor = Tensor::<TestBackend, 3>::random([6, 256, 4], Distribution::Default);
+        let tensor_ref = Tensor::<ReferenceBackend, 3>::from_data(tensor.to_data());
+
+        let (values, indices) = max_dim_with_indices(tensor.into_primitive(), 1);
+        let values = Tensor::<TestBackend, 3>::from_primitive(values);
+        let indices = Tensor::<TestBackend, 3, Int>::from_primitive(indices);
+        let (values_ref, indices_ref) = tensor_ref.max_dim_with_indices(1);
+
+        values_ref
+            .into_data()
+            .assert_approx_eq(&values.into_data(), 3);
+        assert_eq!(indices_ref.into_data().convert(), indices.into_data());
+    }
+
+    #[test]
+    fn reduction_sum_dim_shared_memory_should_work_on_each_axis() {
+        let tensor = Tensor::<TestBackend, 4>::random([3, 1, 300, 70], Distribution::Default);
+        let tensor_ref = Tensor::<ReferenceBackend, 4>::from_data(tensor.to_data());
+
+        for dim in 0..4 {
+            let val = Tensor::<TestBackend, 4>::from_primitive(reduction_dim_shared_memory::<
+                SumDimSharedMemory,
+                f32,
+                4,
+            >(
+                tensor.clone().into_primitive(),
+                dim,
+            ));
+            let val_ref = tensor_ref.clone().sum_dim(dim);
+
+            val_ref.into_data().assert_approx_eq(&val.into_data(), 3);
+        }
+    }
+
+    #[test]
+    fn reduction_mean_dim_shared_memory_should_work_on_each_axis() {
+        let tensor = Tensor::<TestBackend, 4>::random([3, 1, 300, 70], Distribution::Default);
+        let tensor_ref = Tensor::<ReferenceBackend, 4>::from_data(tensor.to_data());
+
+        for dim in 0..4 {
+            let val = Tensor::<TestBackend, 4>::from_primitive(reduction_dim_shared_memory::<
+                MeanDimSharedMemory,
+                f32,
+                4,
+            >(
+                tensor.clone().into_primitive(),
+                dim,
+            ));
+            let val_ref = tensor_ref.clone().mean_dim(dim);
+
+            val_ref.into_data().assert_approx_eq(&val.into_data(), 3);
+        }
+    }
+
+    #[test]
+    fn reduction_max_dim_with_indices_shared_memory_should_work_on_each_axis() {
+        let tensor = Tensor::<TestBackend, 4>::random([3, 1, 300, 70], Distribution::Default);
+        let tensor_ref = Tensor::<ReferenceBackend, 4>::from_data(tensor.to_data());
+
+        for dim in 0..4 {
+            let (values, indices) = reduction_dim_with_indices_shared_memory::<
+                MaxDimWithIndicesSharedMemory,
+                f32,
+                i32,
+                4,
+            >(tensor.clone().into_primitive(), dim);
+            let values = Tensor::<TestBackend, 4>::from_primitive(values);
+            let indices = Tensor::<TestBackend, 4, Int>::from_primitive(indices);
+            let (values_ref, indices_ref) = tensor_ref.clone().max_dim_with_indices(dim);
+
+            values_ref
+                .into_data()
+                .assert_approx_eq(&values.into_data(), 3);
+            assert_eq!(indices_ref.into_data().convert(), indices.into_data());
+        }
+    }
+
+    #[test]
+    fn reduction_dims_should_match_reference_on_each_axis() {
+        let tensor = Tensor::<TestBackend, 4>::random([3, 1, 300, 70], Distribution::Default);
+        let tensor_ref = Tensor::<ReferenceBackend, 4>::from_data(tensor.to_data());
+
+        for dim in 0..4 {
+            let sum = Tensor::<TestBackend, 4>::from_primitive(sum_dim(
+                tensor.clone().into_primitive(),
+                dim,
+            ));
+            let mean = Tensor::<TestBackend, 4>::from_primitive(mean_dim(
+                tensor.clone().into_primitive(),
+                dim,
+            ));
+            let (max, _) =
+                max_dim_with_indices::<f32, i32, 4>(tensor.clone().into_primitive(), dim);
+            let max = Tensor::<TestBackend, 4>::from_primitive(max);
+
+            tensor_ref
+                .clone()
+                .sum_dim(dim)
+                .into_data()
+                .assert_approx_eq(&sum.into_data(), 3);
+            tensor_ref
+                .clone()
+                .mean_dim(dim)
+                .into_data()
+                .assert_approx_eq(&mean.into_data(), 3);
+            tensor_ref
+                .clone()
+                .max_dim(dim)
+                .into_data()
+                .assert_approx_eq(&max.into_data(), 3);
+        }
+    }
 }
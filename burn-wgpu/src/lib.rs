@@ -26,6 +26,9 @@ pub use backend::*;
 mod graphics;
 pub use graphics::*;
 
+mod readback;
+pub use readback::*;
+
 #[cfg(test)]
 mod tests {
     use super::*;
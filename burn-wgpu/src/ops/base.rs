@@ -65,6 +65,19 @@ pub fn swap_dims<E: WgpuElement, const D: usize>(
     tensor
 }
 
+pub fn permute<E: WgpuElement, const D: usize>(
+    mut tensor: WgpuTensor<E, D>,
+    axes: [usize; D],
+) -> WgpuTensor<E, D> {
+    let strides = tensor.strides;
+    let dims = tensor.shape.dims;
+
+    tensor.strides = axes.map(|axis| strides[axis]);
+    tensor.shape.dims = axes.map(|axis| dims[axis]);
+
+    tensor
+}
+
 pub fn reshape<E: WgpuElement, const D1: usize, const D2: usize>(
     tensor: WgpuTensor<E, D1>,
     shape: Shape<D2>,
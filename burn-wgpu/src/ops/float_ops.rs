@@ -1,10 +1,12 @@
 use super::{numeric, BoolTensor, Device, FloatElem, FloatTensor, FullPrecisionBackend, IntTensor};
 use crate::kernel::{
-    self, unary_default, unary_inplace_default, unary_scalar_default, unary_scalar_inplace_default,
+    self, binary_elemwise_default, binary_elemwise_inplace_default, unary_default,
+    unary_inplace_default, unary_scalar_default, unary_scalar_inplace_default,
 };
 
 use crate::unary_scalar_inplace;
 use crate::{
+    binary_elemwise, binary_elemwise_inplace,
     element::{FloatElement, IntElement},
     unary, unary_inplace, unary_scalar, GraphicsApi, WgpuBackend, SEED,
 };
@@ -149,6 +151,17 @@ where
         super::swap_dims(tensor, dim1, dim2)
     }
 
+    fn permute<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        axes: [usize; D],
+    ) -> FloatTensor<Self, D> {
+        super::permute(tensor, axes)
+    }
+
+    fn flip<const D: usize>(tensor: FloatTensor<Self, D>, axes: &[usize]) -> FloatTensor<Self, D> {
+        kernel::flip(tensor, axes)
+    }
+
     fn reshape<const D1: usize, const D2: usize>(
         tensor: FloatTensor<Self, D1>,
         shape: Shape<D2>,
@@ -299,6 +312,10 @@ where
         kernel::sum_dim(tensor, dim)
     }
 
+    fn cumsum<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
+        kernel::cumsum(tensor, dim)
+    }
+
     fn mean_dim<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
         kernel::mean_dim(tensor, dim)
     }
@@ -315,6 +332,10 @@ where
         kernel::cast(tensor.clone())
     }
 
+    fn into_int<const D: usize>(tensor: FloatTensor<Self, D>) -> IntTensor<Self, D> {
+        kernel::cast(tensor)
+    }
+
     fn exp<const D: usize>(lhs: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
         unary!(Exp, func "exp");
         unary_inplace!(ExpInplace, func "exp");
@@ -359,6 +380,84 @@ where
         unary_scalar_default::<Powf, F, D>(lhs, rhs.elem())
     }
 
+    fn powf_tensor<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        binary_elemwise!(PowfTensor, func "powf", include "../template/powf.wgsl");
+        binary_elemwise_inplace!(PowfTensorInplace, func "powf", include "../template/powf.wgsl");
+
+        if lhs.can_mut_broadcast(&rhs) {
+            return binary_elemwise_inplace_default::<PowfTensorInplace, F, D>(lhs, rhs);
+        }
+
+        binary_elemwise_default::<PowfTensor, F, D>(lhs, rhs)
+    }
+
+    fn maximum<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        binary_elemwise!(Maximum, func "max");
+        binary_elemwise_inplace!(MaximumInplace, func "max");
+
+        if lhs.can_mut_broadcast(&rhs) {
+            return binary_elemwise_inplace_default::<MaximumInplace, F, D>(lhs, rhs);
+        }
+
+        binary_elemwise_default::<Maximum, F, D>(lhs, rhs)
+    }
+
+    fn minimum<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        binary_elemwise!(Minimum, func "min");
+        binary_elemwise_inplace!(MinimumInplace, func "min");
+
+        if lhs.can_mut_broadcast(&rhs) {
+            return binary_elemwise_inplace_default::<MinimumInplace, F, D>(lhs, rhs);
+        }
+
+        binary_elemwise_default::<Minimum, F, D>(lhs, rhs)
+    }
+
+    fn clamp<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        min: FloatElem<Self>,
+        max: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        kernel::clamp(tensor, min, max)
+    }
+
+    fn clamp_min<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        min: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        unary_scalar!(ClampMin, func "max");
+        unary_scalar_inplace!(ClampMinInplace, func "max");
+
+        if tensor.can_mut() {
+            return unary_scalar_inplace_default::<ClampMinInplace, F, D>(tensor, min);
+        }
+
+        unary_scalar_default::<ClampMin, F, D>(tensor, min)
+    }
+
+    fn clamp_max<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        max: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        unary_scalar!(ClampMax, func "min");
+        unary_scalar_inplace!(ClampMaxInplace, func "min");
+
+        if tensor.can_mut() {
+            return unary_scalar_inplace_default::<ClampMaxInplace, F, D>(tensor, max);
+        }
+
+        unary_scalar_default::<ClampMax, F, D>(tensor, max)
+    }
+
     fn sqrt<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
         unary!(Sqrt, func "sqrt");
         unary_inplace!(SqrtInplace, func "sqrt");
@@ -422,10 +521,52 @@ where
         unary_default::<Erf, F, D>(tensor)
     }
 
+    fn floor<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary!(Floor, func "floor");
+        unary_inplace!(FloorInplace, func "floor");
+
+        if tensor.can_mut() {
+            return unary_inplace_default::<FloorInplace, F, D>(tensor);
+        }
+
+        unary_default::<Floor, F, D>(tensor)
+    }
+
+    fn ceil<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary!(Ceil, func "ceil");
+        unary_inplace!(CeilInplace, func "ceil");
+
+        if tensor.can_mut() {
+            return unary_inplace_default::<CeilInplace, F, D>(tensor);
+        }
+
+        unary_default::<Ceil, F, D>(tensor)
+    }
+
+    fn round<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        // WGSL rounds half-way cases to the nearest even integer.
+        unary!(Round, func "round");
+        unary_inplace!(RoundInplace, func "round");
+
+        if tensor.can_mut() {
+            return unary_inplace_default::<RoundInplace, F, D>(tensor);
+        }
+
+        unary_default::<Round, F, D>(tensor)
+    }
+
     fn cat<const D: usize>(tensors: Vec<FloatTensor<Self, D>>, dim: usize) -> FloatTensor<Self, D> {
         kernel::cat(tensors, dim)
     }
 
+    fn repeat<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        times: usize,
+    ) -> FloatTensor<Self, D> {
+        kernel::repeat(tensor, dim, times)
+    }
+
     fn argmax<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
         kernel::argmax(tensor, dim)
     }
@@ -433,4 +574,26 @@ where
     fn argmin<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
         kernel::argmin(tensor, dim)
     }
+
+    fn max_dim<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
+        kernel::max_dim_with_indices::<F, I, D>(tensor, dim).0
+    }
+
+    fn max_dim_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        kernel::max_dim_with_indices(tensor, dim)
+    }
+
+    fn min_dim<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
+        kernel::min_dim_with_indices::<F, I, D>(tensor, dim).0
+    }
+
+    fn min_dim_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        kernel::min_dim_with_indices(tensor, dim)
+    }
 }
@@ -1,9 +1,10 @@
-use super::{numeric, BoolTensor, Device, IntElem, IntTensor};
+use super::{numeric, BoolTensor, Device, FloatTensor, IntElem, IntTensor};
 use crate::{
     element::{FloatElement, IntElement},
-    kernel, GraphicsApi, WgpuBackend,
+    kernel, GraphicsApi, WgpuBackend, SEED,
 };
-use burn_tensor::{ops::IntTensorOps, Data, Shape};
+use burn_common::rand::get_seeded_rng;
+use burn_tensor::{ops::IntTensorOps, Data, Distribution, Shape};
 use std::ops::Range;
 
 impl<G, F, I> IntTensorOps<WgpuBackend<G, F, I>> for WgpuBackend<G, F, I>
@@ -31,6 +32,22 @@ where
         super::from_data::<G, I, D>(data, device)
     }
 
+    fn int_random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution<I>,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        let mut seed = SEED.lock().unwrap();
+        let mut rng = if let Some(rng_seeded) = seed.as_ref() {
+            rng_seeded.clone()
+        } else {
+            get_seeded_rng()
+        };
+        let tensor = Self::int_from_data(Data::random(shape, distribution, &mut rng), device);
+        *seed = Some(rng);
+        tensor
+    }
+
     fn int_device<const D: usize>(tensor: &IntTensor<Self, D>) -> Device<Self> {
         tensor.context.device.clone()
     }
@@ -42,6 +59,10 @@ where
         super::to_device::<G, I, D>(tensor, device)
     }
 
+    fn int_into_float<const D: usize>(tensor: IntTensor<Self, D>) -> FloatTensor<Self, D> {
+        kernel::cast(tensor)
+    }
+
     fn int_reshape<const D1: usize, const D2: usize>(
         tensor: IntTensor<Self, D1>,
         shape: Shape<D2>,
@@ -118,6 +139,14 @@ where
         kernel::cat(tensors, dim)
     }
 
+    fn int_repeat<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        times: usize,
+    ) -> IntTensor<Self, D> {
+        kernel::repeat(tensor, dim, times)
+    }
+
     fn int_equal<const D: usize>(
         lhs: IntTensor<Self, D>,
         rhs: IntTensor<Self, D>,
@@ -271,4 +300,26 @@ where
     fn int_argmin<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
         kernel::argmin(tensor, dim)
     }
+
+    fn int_max_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        kernel::max_dim_with_indices::<I, I, D>(tensor, dim).0
+    }
+
+    fn int_max_dim_with_indices<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+    ) -> (IntTensor<Self, D>, IntTensor<Self, D>) {
+        kernel::max_dim_with_indices(tensor, dim)
+    }
+
+    fn int_min_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        kernel::min_dim_with_indices::<I, I, D>(tensor, dim).0
+    }
+
+    fn int_min_dim_with_indices<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+    ) -> (IntTensor<Self, D>, IntTensor<Self, D>) {
+        kernel::min_dim_with_indices(tensor, dim)
+    }
 }
@@ -1,5 +1,6 @@
 use burn_tensor::ops::{
-    ConvOptions, ConvTransposeOptions, MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
+    ConvOptions, ConvTransposeOptions, MaxPool1dBackward, MaxPool1dWithIndices, MaxPool2dBackward,
+    MaxPool2dWithIndices, ModuleOps,
 };
 
 use crate::{
@@ -15,6 +16,15 @@ where
     F: FloatElement,
     I: IntElement,
 {
+    fn conv1d(
+        x: FloatTensor<Self, 3>,
+        weight: FloatTensor<Self, 3>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvOptions<1>,
+    ) -> FloatTensor<Self, 3> {
+        kernel::conv::conv1d(x, weight, bias, options)
+    }
+
     fn conv2d(
         x: FloatTensor<Self, 4>,
         weight: FloatTensor<Self, 4>,
@@ -33,6 +43,64 @@ where
         kernel::conv::conv_transpose2d(x, weight, bias, options)
     }
 
+    fn avg_pool1d(
+        x: FloatTensor<Self, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+    ) -> FloatTensor<Self, 3> {
+        kernel::pool::avg_pool1d(x, kernel_size, stride, padding)
+    }
+
+    fn avg_pool1d_backward(
+        x: FloatTensor<Self, 3>,
+        grad: FloatTensor<Self, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+    ) -> FloatTensor<Self, 3> {
+        kernel::pool::avg_pool1d_backward(x, grad, kernel_size, stride, padding)
+    }
+
+    fn max_pool1d(
+        x: FloatTensor<Self, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+    ) -> FloatTensor<Self, 3> {
+        kernel::pool::max_pool1d(x, kernel_size, stride, padding)
+    }
+
+    fn max_pool1d_with_indices(
+        x: FloatTensor<Self, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+    ) -> MaxPool1dWithIndices<WgpuBackend<G, F, I>> {
+        let (output, indices) =
+            kernel::pool::max_pool1d_with_indices(x, kernel_size, stride, padding);
+
+        MaxPool1dWithIndices::new(output, indices)
+    }
+
+    fn max_pool1d_with_indices_backward(
+        x: FloatTensor<Self, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        output_grad: FloatTensor<Self, 3>,
+        indices: IntTensor<Self, 3>,
+    ) -> MaxPool1dBackward<WgpuBackend<G, F, I>> {
+        MaxPool1dBackward::new(kernel::pool::max_pool1d_with_indices_backward(
+            x,
+            output_grad,
+            indices,
+            kernel_size,
+            stride,
+            padding,
+        ))
+    }
+
     fn avg_pool2d(
         x: FloatTensor<Self, 4>,
         kernel_size: [usize; 2],
@@ -52,6 +120,20 @@ where
         kernel::pool::avg_pool2d_backward(x, grad, kernel_size, stride, padding)
     }
 
+    fn adaptive_avg_pool2d(
+        x: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+    ) -> FloatTensor<Self, 4> {
+        kernel::pool::adaptive_avg_pool2d(x, output_size)
+    }
+
+    fn adaptive_avg_pool2d_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+    ) -> FloatTensor<Self, 4> {
+        kernel::pool::adaptive_avg_pool2d_backward(x, grad)
+    }
+
     fn max_pool2d(
         x: FloatTensor<Self, 4>,
         kernel_size: [usize; 2],
@@ -0,0 +1,86 @@
+use crate::{
+    context::BufferReadback,
+    element::{FloatElement, IntElement, WgpuElement},
+    kernel, GraphicsApi, WgpuBackend,
+};
+use burn_tensor::{Data, Shape, Tensor};
+use core::marker::PhantomData;
+
+/// Handle to the data of a tensor being read from the GPU.
+///
+/// Created with [into_data_async](into_data_async).
+#[derive(Debug)]
+pub struct DataReadback<E, const D: usize> {
+    readback: BufferReadback,
+    shape: Shape<D>,
+    elem: PhantomData<E>,
+}
+
+impl<E: WgpuElement, const D: usize> DataReadback<E, D> {
+    /// Wait for the readback to be completed and return the data.
+    pub fn read(self) -> Data<E, D> {
+        let bytes = self.readback.read();
+        let values = E::from_bytes(&bytes);
+
+        Data::new(values.to_vec(), self.shape)
+    }
+}
+
+/// Submit a read of the tensor data without waiting for it to be completed.
+///
+/// Unlike [into_data](Tensor::into_data), computation can continue while the data is transferred
+/// from the GPU, which is useful when reading many tensors, e.g. when logging metrics. The data
+/// corresponds to the tensor at the time of the call, even if the returned handles are read in a
+/// different order.
+pub fn into_data_async<G: GraphicsApi + 'static, F: FloatElement, I: IntElement, const D: usize>(
+    tensor: Tensor<WgpuBackend<G, F, I>, D>,
+) -> DataReadback<F, D> {
+    let tensor = kernel::into_contiguous(tensor.into_primitive());
+    let readback = tensor.context.read_buffer_async(tensor.buffer);
+
+    DataReadback {
+        readback,
+        shape: tensor.shape,
+        elem: PhantomData,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestTensor;
+    use burn_tensor::Distribution;
+
+    #[test]
+    fn should_read_multiple_tensors_async() {
+        let tensors = (0..4)
+            .map(|_| TestTensor::<2>::random([16, 32], Distribution::Default))
+            .collect::<Vec<_>>();
+
+        let readbacks = tensors
+            .iter()
+            .map(|tensor| into_data_async(tensor.clone().mul_scalar(2.0)))
+            .collect::<Vec<_>>();
+
+        for (tensor, readback) in tensors.into_iter().zip(readbacks).rev() {
+            readback
+                .read()
+                .assert_approx_eq(&tensor.mul_scalar(2.0).into_data(), 3);
+        }
+    }
+
+    #[test]
+    fn should_read_data_at_submission_time() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+
+        let readback_1 = into_data_async(tensor.clone());
+        let tensor = tensor.add_scalar(1.0);
+        let readback_2 = into_data_async(tensor.clone());
+        let tensor = tensor.mul_scalar(2.0);
+        let readback_3 = into_data_async(tensor);
+
+        assert_eq!(readback_3.read(), Data::from([[4.0, 6.0], [8.0, 10.0]]));
+        assert_eq!(readback_1.read(), Data::from([[1.0, 2.0], [3.0, 4.0]]));
+        assert_eq!(readback_2.read(), Data::from([[2.0, 3.0], [4.0, 5.0]]));
+    }
+}
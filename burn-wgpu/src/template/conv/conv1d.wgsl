@@ -0,0 +1,88 @@
+@group(0)
+@binding(0)
+var<storage, read> input: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read> weight: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> bias: array<{{ elem }}>;
+
+@group(0)
+@binding(3)
+var<storage, read_write> output: array<{{ elem }}>;
+
+@group(0)
+@binding(4)
+var<storage, read> info: array<u32, 23>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+
+    let input_stride_0 = info[1];
+    let input_stride_1 = info[2];
+    let input_stride_2 = info[3];
+    let output_stride_0 = info[4];
+    let output_stride_1 = info[5];
+    let output_stride_2 = info[6];
+    let weight_stride_0 = info[7];
+    let weight_stride_1 = info[8];
+    let weight_stride_2 = info[9];
+
+    let input_shape_0 = info[10];
+    let input_shape_1 = info[11];
+    let input_shape_2 = info[12];
+    let output_shape_0 = info[13];
+    let output_shape_1 = info[14];
+    let output_shape_2 = info[15];
+    let weight_shape_0 = info[16];
+    let weight_shape_1 = info[17];
+    let weight_shape_2 = info[18];
+
+    let stride = info[19];
+    let padding = info[20];
+    let dilation = info[21];
+    let groups = info[22];
+
+    let in_channels = weight_shape_1;
+    let kernel_size = weight_shape_2;
+
+    let b = id / output_stride_0 % output_shape_0;
+    let oc = id / output_stride_1 % output_shape_1;
+    let ol = id / output_stride_2 % output_shape_2;
+    let g = oc / (weight_shape_0 / groups);
+
+    var sum = bias[oc];
+
+    let ic_start = in_channels * g;
+    let ic_end = in_channels * (g + 1u);
+
+    for (var ic = ic_start; ic < ic_end; ic++) {
+        for (var k = 0u; k < kernel_size; k++) {
+            let il = ol * stride + k * dilation;
+
+            // Padding
+            if il >= padding && il < input_shape_2 + padding {
+                // Correct for padding
+                let il_pad = il - padding;
+
+                let weight_ic = ic - (g * in_channels);
+                let index_input = b * input_stride_0 + ic * input_stride_1 + il_pad * input_stride_2;
+                let index_weight = oc * weight_stride_0 + weight_ic * weight_stride_1 + k * weight_stride_2;
+
+                sum += input[index_input] * weight[index_weight];
+            }
+        }
+    }
+
+    output[id] = sum;
+}
@@ -0,0 +1,60 @@
+@group(0)
+@binding(0)
+var<storage, read> input: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> output: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> info: array<u32>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    // Each invocation scans one lane along the accumulated dimension serially.
+    // TODO: Scan long lanes with a whole workgroup using shared memory, like the dim reductions.
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+    let dim: u32 = info[0];
+    let dim_cumsum = info[4u * dim + 1u];
+    let num_lanes = info[4u * dim + 2u];
+
+    if id >= num_lanes {
+        return;
+    }
+
+    var lane = id;
+    var index_input = 0u;
+    var index_output = 0u;
+
+    for (var i: u32 = dim; i >= 1u; i--) {
+        if i - 1u != dim_cumsum {
+            let stride_input = info[i];
+            let stride_output = info[i + dim];
+            let shape = info[i + 2u * dim];
+
+            let num_block = lane % shape;
+            lane = lane / shape;
+
+            index_input += num_block * stride_input;
+            index_output += num_block * stride_output;
+        }
+    }
+
+    let stride_input_dim = info[dim_cumsum + 1u];
+    let stride_output_dim = info[dim_cumsum + 1u + dim];
+    let shape_dim = info[dim_cumsum + 1u + 2u * dim];
+
+    var sum = {{ elem }}(0);
+
+    for (var i = 0u; i < shape_dim; i++) {
+        sum += input[index_input + i * stride_input_dim];
+        output[index_output + i * stride_output_dim] = sum;
+    }
+}
@@ -0,0 +1,63 @@
+@group(0)
+@binding(0)
+var<storage, read> x: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> output: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> info: array<u32, 15>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+
+    let input_stride_0 = info[0];
+    let input_stride_1 = info[1];
+    let input_stride_2 = info[2];
+    let input_shape_0 = info[3];
+    let input_shape_1 = info[4];
+    let input_shape_2 = info[5];
+
+    let output_stride_0 = info[6];
+    let output_stride_1 = info[7];
+    let output_stride_2 = info[8];
+    let output_shape_0 = info[9];
+    let output_shape_1 = info[10];
+    let output_shape_2 = info[11];
+
+    let kernel_size = info[12];
+    let pool_stride = info[13];
+    let padding = info[14];
+
+    let b = id / output_stride_0 % output_shape_0;
+    let c = id / output_stride_1 % output_shape_1;
+    let ol = id / output_stride_2 % output_shape_2;
+
+    var sum = 0.0;
+
+    for (var kl = 0u; kl < kernel_size; kl++) {
+        let il = ol * pool_stride + kl;
+
+        // Padding
+        if il < padding || il >= input_shape_2 + padding {
+            continue;
+        }
+
+        // Correct index for padding
+        let il_pad = il - padding;
+
+        let index_input = b * input_stride_0 + c * input_stride_1 + il_pad * input_stride_2;
+        sum += x[index_input];
+    }
+
+    output[id] = sum / {{ elem }}(kernel_size);
+}
@@ -0,0 +1,63 @@
+@group(0)
+@binding(0)
+var<storage, read> grad: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> output: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> info: array<u32, 15>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+
+    let input_stride_0 = info[0];
+    let input_stride_1 = info[1];
+    let input_stride_2 = info[2];
+    let input_shape_0 = info[3];
+    let input_shape_1 = info[4];
+    let input_shape_2 = info[5];
+
+    let grad_stride_0 = info[6];
+    let grad_stride_1 = info[7];
+    let grad_stride_2 = info[8];
+    let grad_shape_0 = info[9];
+    let grad_shape_1 = info[10];
+    let grad_shape_2 = info[11];
+
+    let kernel_size = info[12];
+    let pool_stride = info[13];
+    let padding = info[14];
+
+    let b = id / input_stride_0 % input_shape_0;
+    let c = id / input_stride_1 % input_shape_1;
+    let il = id / input_stride_2 % input_shape_2;
+
+    // The output positions whose window contains the current index are the ones
+    // verifying `ol * stride <= il + padding < ol * stride + kernel_size`.
+    let il_pad = il + padding;
+
+    var ol_start = 0u;
+    if il_pad + 1u > kernel_size {
+        ol_start = (il_pad + 1u - kernel_size + pool_stride - 1u) / pool_stride;
+    }
+    let ol_end = min(il_pad / pool_stride + 1u, grad_shape_2);
+
+    var grad_acc = 0.0;
+
+    for (var ol = ol_start; ol < ol_end; ol++) {
+        let index = b * grad_stride_0 + c * grad_stride_1 + ol * grad_stride_2;
+        grad_acc += grad[index];
+    }
+
+    output[id] = grad_acc / {{ elem }}(kernel_size);
+}
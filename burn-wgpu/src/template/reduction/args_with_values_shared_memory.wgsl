@@ -0,0 +1,102 @@
+@group(0)
+@binding(0)
+var<storage, read> input: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> values: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> indices: array<{{ int }}>;
+
+@group(0)
+@binding(3)
+var<storage, read> info: array<u32>;
+
+const WORKGROUP_SIZE = {{ workgroup_size }}u;
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+var<workgroup> shared_values: array<{{ elem }}, WORKGROUP_SIZE>;
+var<workgroup> shared_indices: array<u32, WORKGROUP_SIZE>;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(local_invocation_id) local_id: vec3<u32>,
+    @builtin(workgroup_id) workgroup_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    // Each workgroup computes one output element.
+    let id = workgroup_id.y * num_workgroups.x + workgroup_id.x;
+    let id_local = local_id.y * WORKGROUP_SIZE_X + local_id.x;
+    let dim: u32 = info[0];
+    let dim_reduce = info[4u * dim + 1u];
+    let num_elems = info[4u * dim + 2u];
+
+    if id >= num_elems {
+        return;
+    }
+
+    var index_offset: u32 = 0u;
+    var stride_dim: u32 = 0u;
+    var shape_dim: u32 = 0u;
+
+    for (var i: u32 = 1u; i <= dim; i++) {
+        let stride_input = info[i];
+        let stride_output = info[i + dim];
+        let shape_output = info[i + 3u * dim];
+
+        let num_block = id / stride_output % shape_output;
+
+        if i - 1u != dim_reduce {
+            index_offset += num_block * stride_input;
+        } else {
+            let shape_input = info[i + 2u * dim];
+            index_offset += num_block;
+            stride_dim = stride_input;
+            shape_dim = shape_input;
+        }
+    }
+
+    // First pass: each invocation reduces a strided part of the reduced dimension.
+    // An index equal to `shape_dim` marks an invocation without any element.
+    var current_value = {{ elem }}(0);
+    var current_index = shape_dim;
+
+    for (var i = id_local; i < shape_dim; i += WORKGROUP_SIZE) {
+        let index_input = i * stride_dim;
+        let value = input[index_input + index_offset];
+
+        if current_index == shape_dim || value {{ cmp }} current_value {
+            current_value = value;
+            current_index = i;
+        }
+    }
+
+    shared_values[id_local] = current_value;
+    shared_indices[id_local] = current_index;
+    workgroupBarrier();
+
+    // Second pass: tree reduction of the partial results in shared memory,
+    // keeping the first index on ties like the sequential kernel.
+    for (var stride = WORKGROUP_SIZE / 2u; stride > 0u; stride /= 2u) {
+        if id_local < stride {
+            let value = shared_values[id_local];
+            let index = shared_indices[id_local];
+            let other_value = shared_values[id_local + stride];
+            let other_index = shared_indices[id_local + stride];
+
+            if other_index < shape_dim && (index == shape_dim || other_value {{ cmp }} value || (other_value == value && other_index < index)) {
+                shared_values[id_local] = other_value;
+                shared_indices[id_local] = other_index;
+            }
+        }
+        workgroupBarrier();
+    }
+
+    if id_local == 0u {
+        values[id] = shared_values[0u];
+        indices[id] = {{ int }}(shared_indices[0u]);
+    }
+}
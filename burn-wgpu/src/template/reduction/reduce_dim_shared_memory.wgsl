@@ -0,0 +1,80 @@
+@group(0)
+@binding(0)
+var<storage, read> input: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> output: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> info: array<u32>;
+
+const WORKGROUP_SIZE = {{ workgroup_size }}u;
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+var<workgroup> shared_memory: array<{{ elem }}, WORKGROUP_SIZE>;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(local_invocation_id) local_id: vec3<u32>,
+    @builtin(workgroup_id) workgroup_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    // Each workgroup computes one output element.
+    let id = workgroup_id.y * num_workgroups.x + workgroup_id.x;
+    let id_local = local_id.y * WORKGROUP_SIZE_X + local_id.x;
+    let dim: u32 = info[0];
+    let dim_reduce = info[4u * dim + 1u];
+    let num_elems = info[4u * dim + 2u];
+
+    if id >= num_elems {
+        return;
+    }
+
+    var index_offset: u32 = 0u;
+    var stride_dim: u32 = 0u;
+    var shape_dim: u32 = 0u;
+
+    for (var i: u32 = 1u; i <= dim; i++) {
+        let stride_input = info[i];
+        let stride_output = info[i + dim];
+        let shape_output = info[i + 3u * dim];
+
+        let num_block = id / stride_output % shape_output;
+
+        if i - 1u != dim_reduce {
+            index_offset += num_block * stride_input;
+        } else {
+            let shape_input = info[i + 2u * dim];
+            index_offset += num_block;
+            stride_dim = stride_input;
+            shape_dim = shape_input;
+        }
+    }
+
+    // First pass: each invocation accumulates a strided part of the reduced dimension.
+    var partial = {{ elem }}(0);
+
+    for (var i = id_local; i < shape_dim; i += WORKGROUP_SIZE) {
+        let index_input = i * stride_dim;
+        partial += input[index_input + index_offset];
+    }
+
+    shared_memory[id_local] = partial;
+    workgroupBarrier();
+
+    // Second pass: tree reduction of the partial results in shared memory.
+    for (var stride = WORKGROUP_SIZE / 2u; stride > 0u; stride /= 2u) {
+        if id_local < stride {
+            shared_memory[id_local] += shared_memory[id_local + stride];
+        }
+        workgroupBarrier();
+    }
+
+    if id_local == 0u {
+        let sum = shared_memory[0u];
+        {{ assign }}
+    }
+}
@@ -49,7 +49,7 @@ impl<E: WgpuElement, const D: usize> WgpuTensor<E, D> {
             elem: PhantomData,
         }
     }
-    pub fn can_mut_broadcast(&self, tensor_other: &WgpuTensor<E, D>) -> bool {
+    pub fn can_mut_broadcast<E2: WgpuElement>(&self, tensor_other: &WgpuTensor<E2, D>) -> bool {
         if Arc::strong_count(&self.buffer) > 1 {
             return false;
         }
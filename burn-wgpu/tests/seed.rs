@@ -0,0 +1,22 @@
+// The seed is global to the backend, so this test runs in its own binary to avoid other tests
+// drawing random values between the two seeded calls.
+use burn_tensor::{backend::Backend, Distribution, Tensor};
+use burn_wgpu::WgpuBackend;
+
+#[cfg(target_os = "macos")]
+type GraphicsApi = burn_wgpu::Metal;
+
+#[cfg(not(target_os = "macos"))]
+type GraphicsApi = burn_wgpu::Vulkan;
+
+type TestBackend = WgpuBackend<GraphicsApi, f32, i32>;
+
+#[test]
+fn random_should_be_reproducible_with_the_same_seed() {
+    TestBackend::seed(42);
+    let tensor_1 = Tensor::<TestBackend, 2>::random([32, 32], Distribution::Default);
+    TestBackend::seed(42);
+    let tensor_2 = Tensor::<TestBackend, 2>::random([32, 32], Distribution::Default);
+
+    assert_eq!(tensor_1.into_data(), tensor_2.into_data());
+}